@@ -38,6 +38,8 @@ pub fn run(port: u16, lint: Option<&String>) -> ! {
                 let _result = opener::open(url);
             });
         }
+        // polling for changes, the period doesn't need to be exact
+        #[allow(clippy::interval_tick_drift)]
         thread::sleep(Duration::from_millis(1000));
     }
 }
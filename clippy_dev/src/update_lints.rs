@@ -418,7 +418,7 @@ fn remove_lint_declaration(name: &str, path: &Path, lints: &mut Vec<Lint>) -> io
             } else {
                 // We can't delete the entire file, just remove the declaration
 
-                if let Some(Some("mod.rs")) = path.file_name().map(OsStr::to_str) {
+                if let Some("mod.rs") = path.file_name().and_then(OsStr::to_str) {
                     // Remove clippy_lints/src/some_mod/some_lint.rs
                     let mut lint_mod_path = path.to_path_buf();
                     lint_mod_path.set_file_name(name);
@@ -0,0 +1,218 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::get_parent_expr;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{Expr, ExprKind, Mutability, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+use rustc_middle::ty::{self, IntTy, Ty, UintTy};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for statics and struct fields of type `Mutex<T>` or `Arc<Mutex<T>>`, where `T` is a
+    /// `bool` or an integer, which are only used to store a value in one place and to load it in
+    /// others.
+    ///
+    /// ### Why is this bad?
+    /// The atomic types of `std::sync::atomic` do the same without locking, so they can't block
+    /// or be poisoned.
+    ///
+    /// ### Known problems
+    /// Only the uses of the form `*x.lock().unwrap() = value` and `*x.lock().unwrap()` are
+    /// recognized, and the lint gives up on statics and fields which are visible outside of the
+    /// crate. Uses through references to the `Mutex` or clones of the `Arc` aren't checked.
+    ///
+    /// Unlike a `Mutex`, an atomic load or store only orders other memory accesses with a
+    /// suitable `Ordering`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// static DONE: Mutex<bool> = Mutex::new(false);
+    ///
+    /// *DONE.lock().unwrap() = true;
+    /// let done = *DONE.lock().unwrap();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// static DONE: AtomicBool = AtomicBool::new(false);
+    ///
+    /// DONE.store(true, Ordering::Release);
+    /// let done = DONE.load(Ordering::Acquire);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub ARC_MUTEX_OF_COPY_PRIMITIVE,
+    nursery,
+    "using a `Mutex` of a `bool` or an integer only to load and store it"
+}
+
+#[derive(Default)]
+pub struct ArcMutexOfCopyPrimitive {
+    /// Whether each static or field has been stored to, or `None` if it's used any other way
+    /// than by a load or a store.
+    places: FxIndexMap<LocalDefId, Option<bool>>,
+}
+
+impl_lint_pass!(ArcMutexOfCopyPrimitive => [ARC_MUTEX_OF_COPY_PRIMITIVE]);
+
+#[derive(Clone, Copy)]
+enum Access {
+    Load,
+    Store,
+    Other,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ArcMutexOfCopyPrimitive {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let Some(place) = mutex_place(cx, expr) else {
+            return;
+        };
+        if cx.effective_visibilities.is_exported(place)
+            || mutex_of_primitive(cx, cx.typeck_results().expr_ty(expr)).is_none()
+        {
+            return;
+        }
+        // e.g. the field accesses of `#[derive(Debug)]`
+        if in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        let access = if expr.span.from_expansion() {
+            Access::Other
+        } else {
+            access(cx, expr)
+        };
+        let stored = self.places.entry(place).or_insert(Some(false));
+        *stored = match (access, *stored) {
+            (Access::Other, _) | (_, None) => None,
+            (Access::Store, _) => Some(true),
+            (Access::Load, stored) => stored,
+        };
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (&place, &stored) in &self.places {
+            if stored != Some(true) {
+                continue;
+            }
+            let ty = cx.tcx.type_of(place.to_def_id()).subst_identity();
+            let Some((in_arc, atomic)) = mutex_of_primitive(cx, ty) else {
+                continue;
+            };
+            let (ty, sugg) = if in_arc {
+                ("Arc<Mutex<..>>", format!("Arc<{atomic}>"))
+            } else {
+                ("Mutex<..>", atomic.to_owned())
+            };
+            let span = cx.tcx.def_span(place);
+            span_lint_hir_and_then(
+                cx,
+                ARC_MUTEX_OF_COPY_PRIMITIVE,
+                cx.tcx.hir().local_def_id_to_hir_id(place),
+                span,
+                &format!("this `{ty}` is only used to load and store its value"),
+                |diag| {
+                    diag.help(format!("consider using an `{sugg}` instead"));
+                    diag.note(
+                        "unlike locking the `Mutex`, atomic loads and stores only order other memory accesses \
+                         with a suitable `Ordering`, like `Acquire` loads and `Release` stores",
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// If `expr` is a path to a static or a field access of a struct of the crate, returns its
+/// `LocalDefId`.
+fn mutex_place(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<LocalDefId> {
+    match expr.kind {
+        ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, expr.hir_id) {
+            Res::Def(DefKind::Static(_), id) => id.as_local(),
+            _ => None,
+        },
+        ExprKind::Field(base, _) => {
+            let ty::Adt(adt, _) = cx.typeck_results().expr_ty_adjusted(base).peel_refs().kind() else {
+                return None;
+            };
+            if !adt.is_struct() {
+                return None;
+            }
+            let index = cx.typeck_results().opt_field_index(expr.hir_id)?;
+            adt.non_enum_variant().fields[index].did.as_local()
+        },
+        _ => None,
+    }
+}
+
+/// If `ty` is `Mutex<T>` or `Arc<Mutex<T>>` of a `bool` or an integer with an atomic
+/// counterpart, returns whether it's in an `Arc` and the name of the atomic type.
+fn mutex_of_primitive(cx: &LateContext<'_>, ty: Ty<'_>) -> Option<(bool, &'static str)> {
+    let ty::Adt(_, substs) = ty.kind() else {
+        return None;
+    };
+    let (in_arc, mutex) = if is_type_diagnostic_item(cx, ty, sym::Arc) {
+        (true, substs.type_at(0))
+    } else {
+        (false, ty)
+    };
+    let ty::Adt(_, substs) = mutex.kind() else {
+        return None;
+    };
+    if !is_type_diagnostic_item(cx, mutex, sym::Mutex) {
+        return None;
+    }
+    let atomic = match substs.type_at(0).kind() {
+        ty::Bool => "AtomicBool",
+        ty::Int(IntTy::I8) => "AtomicI8",
+        ty::Int(IntTy::I16) => "AtomicI16",
+        ty::Int(IntTy::I32) => "AtomicI32",
+        ty::Int(IntTy::I64) => "AtomicI64",
+        ty::Int(IntTy::Isize) => "AtomicIsize",
+        ty::Uint(UintTy::U8) => "AtomicU8",
+        ty::Uint(UintTy::U16) => "AtomicU16",
+        ty::Uint(UintTy::U32) => "AtomicU32",
+        ty::Uint(UintTy::U64) => "AtomicU64",
+        ty::Uint(UintTy::Usize) => "AtomicUsize",
+        _ => return None,
+    };
+    Some((in_arc, atomic))
+}
+
+/// Checks how the `Mutex` in `place` is used: `*place.lock().unwrap() = value` is a store, and
+/// `*place.lock().unwrap()` is a load unless it's borrowed mutably or assigned to with an
+/// operator. The guard is a temporary, so shared borrows can't outlive the lock.
+fn access(cx: &LateContext<'_>, place: &Expr<'_>) -> Access {
+    if let Some(lock) = get_parent_expr(cx, place)
+        && let ExprKind::MethodCall(path, recv, [], _) = lock.kind
+        && recv.hir_id == place.hir_id
+        && path.ident.as_str() == "lock"
+        && let Some(unwrap) = get_parent_expr(cx, lock)
+        && let ExprKind::MethodCall(path, recv, _, _) = unwrap.kind
+        && recv.hir_id == lock.hir_id
+        && matches!(path.ident.as_str(), "unwrap" | "expect")
+        && let Some(deref) = get_parent_expr(cx, unwrap)
+        && let ExprKind::Unary(UnOp::Deref, _) = deref.kind
+    {
+        let borrowed_mut = cx.typeck_results().expr_adjustments(deref).iter().any(|adjust| {
+            matches!(
+                adjust.kind,
+                Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Mut { .. }))
+            )
+        });
+        match get_parent_expr(cx, deref).map(|e| e.kind) {
+            Some(ExprKind::Assign(lhs, ..)) if lhs.hir_id == deref.hir_id => Access::Store,
+            Some(ExprKind::AssignOp(_, lhs, _)) if lhs.hir_id == deref.hir_id => Access::Other,
+            Some(ExprKind::AddrOf(_, Mutability::Mut, _)) => Access::Other,
+            _ if borrowed_mut => Access::Other,
+            _ => Access::Load,
+        }
+    } else {
+        Access::Other
+    }
+}
@@ -0,0 +1,235 @@
+use crate::types::RC_BUFFER;
+use clippy_utils::diagnostics::{span_lint_and_then, span_lint_hir_and_then};
+use clippy_utils::source::snippet;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use clippy_utils::visitors::for_each_expr_with_closures;
+use clippy_utils::{get_enclosing_block, get_parent_expr, is_lint_allowed, path_to_local_id, qpath_generic_tys};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_errors::{Applicability, Diagnostic};
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{self as hir, Expr, ExprKind, LangItem, Local, Mutability, Node, PatKind, QPath, TyKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::print::with_forced_trimmed_paths;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for locals and struct fields of type `Arc<Vec<T>>` or `Arc<String>`, which are
+    /// never mutated or unwrapped, e.g. with `Arc::make_mut` or `Arc::try_unwrap`, and whose
+    /// `Vec` or `String` is only used like a slice or a `str`.
+    ///
+    /// ### Why is this bad?
+    /// `Arc<[T]>` and `Arc<str>` store the data next to the reference counts, while
+    /// `Arc<Vec<T>>` and `Arc<String>` point to a `Vec` or `String`, which point to the data.
+    /// Every access goes through one more pointer, and the unused capacity is kept around.
+    ///
+    /// ### Known problems
+    /// The lint gives up on fields which are visible outside of the crate. Uses of fields in
+    /// patterns, and uses of clones of the `Arc`, aren't checked.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// let names: Arc<Vec<String>> = Arc::new(vec![String::from("a")]);
+    /// let len = names.len();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// let names: Arc<[String]> = Arc::from(vec![String::from("a")]);
+    /// let len = names.len();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub ARC_SLICE_CANDIDATE,
+    pedantic,
+    "`Arc<Vec<T>>` or `Arc<String>` which could be an `Arc<[T]>` or `Arc<str>`"
+}
+
+#[derive(Default)]
+pub struct ArcSliceCandidate {
+    /// Whether the `Vec` or `String` in each field is needed, e.g. because it's mutated.
+    fields: FxIndexMap<LocalDefId, bool>,
+}
+
+impl_lint_pass!(ArcSliceCandidate => [ARC_SLICE_CANDIDATE]);
+
+impl<'tcx> LateLintPass<'tcx> for ArcSliceCandidate {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'tcx>) {
+        if let PatKind::Binding(_, id, _, None) = local.pat.kind
+            && let Some(init) = local.init
+            && let ExprKind::Call(new, [_]) = init.kind
+            && let ExprKind::Path(QPath::TypeRelative(arc, path)) = new.kind
+            && path.ident.name == sym::new
+            && !in_external_macro(cx.sess(), local.span)
+            && !local.span.from_expansion()
+            // `rc_buffer` already lints this
+            && is_lint_allowed(cx, RC_BUFFER, local.hir_id)
+            && let Some(slice) = slice_ty(cx, cx.typeck_results().pat_ty(local.pat))
+            && let Some(block) = get_enclosing_block(cx, local.hir_id)
+            && for_each_expr_with_closures(cx, block, |e| {
+                if path_to_local_id(e, id) && !is_shared_use(cx, e) {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .is_none()
+        {
+            span_lint_and_then(
+                cx,
+                ARC_SLICE_CANDIDATE,
+                local.pat.span,
+                &format!("this `Arc` could be an `Arc<{slice}>`"),
+                |diag| {
+                    let arc = snippet(cx, arc.span, "Arc");
+                    if let Some(hir_ty) = local.ty
+                        && let Some((buffer, sugg)) = slice_ty_sugg(cx, hir_ty)
+                    {
+                        diag.multipart_suggestion(
+                            "use a slice instead",
+                            vec![(buffer, sugg), (new.span, format!("{arc}::from"))],
+                            Applicability::Unspecified,
+                        );
+                    } else {
+                        diag.span_suggestion(
+                            new.span,
+                            "create it from the `Vec` or `String`",
+                            format!("{arc}::<{slice}>::from"),
+                            Applicability::Unspecified,
+                        );
+                    }
+                    note(diag);
+                },
+            );
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let Some(field) = field(cx, expr) else {
+            return;
+        };
+        if cx.effective_visibilities.is_exported(field)
+            || slice_ty(cx, cx.typeck_results().expr_ty(expr)).is_none()
+            // e.g. the field accesses of `#[derive(Debug)]`
+            || in_external_macro(cx.sess(), expr.span)
+        {
+            return;
+        }
+        let needed = expr.span.from_expansion() || !is_shared_use(cx, expr);
+        *self.fields.entry(field).or_default() |= needed;
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (&field, &needed) in &self.fields {
+            let hir_id = cx.tcx.hir().local_def_id_to_hir_id(field);
+            if needed || !is_lint_allowed(cx, RC_BUFFER, hir_id) {
+                continue;
+            }
+            let Some(slice) = slice_ty(cx, cx.tcx.type_of(field.to_def_id()).subst_identity()) else {
+                continue;
+            };
+            span_lint_hir_and_then(
+                cx,
+                ARC_SLICE_CANDIDATE,
+                hir_id,
+                cx.tcx.def_span(field),
+                &format!("this `Arc` could be an `Arc<{slice}>`"),
+                |diag| {
+                    if let Some(Node::Field(field)) = cx.tcx.hir().find_by_def_id(field)
+                        && let Some((buffer, sugg)) = slice_ty_sugg(cx, field.ty)
+                    {
+                        diag.span_suggestion(buffer, "use a slice instead", sugg, Applicability::Unspecified);
+                    }
+                    note(diag);
+                },
+            );
+        }
+    }
+}
+
+fn note(diag: &mut Diagnostic) {
+    diag.note("the data is stored next to the reference counts, which saves a pointer indirection on every access");
+}
+
+/// Returns the span of the `Vec<T>` or `String` in the `Arc<..>` written as `hir_ty`, and the
+/// `[T]` or `str` to replace it with.
+fn slice_ty_sugg(cx: &LateContext<'_>, hir_ty: &hir::Ty<'_>) -> Option<(Span, String)> {
+    let TyKind::Path(qpath) = &hir_ty.kind else {
+        return None;
+    };
+    let buffer = qpath_generic_tys(qpath).next()?;
+    let TyKind::Path(buffer_qpath) = &buffer.kind else {
+        return None;
+    };
+    let sugg = match qpath_generic_tys(buffer_qpath).next() {
+        Some(elem) => format!("[{}]", snippet(cx, elem.span, "..")),
+        None => String::from("str"),
+    };
+    Some((buffer.span, sugg))
+}
+
+/// If `ty` is `Arc<Vec<T>>` or `Arc<String>`, returns `[T]` or `str`.
+fn slice_ty<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<String> {
+    if let ty::Adt(_, substs) = ty.kind()
+        && is_type_diagnostic_item(cx, ty, sym::Arc)
+    {
+        let buffer = substs.type_at(0);
+        if is_type_lang_item(cx, buffer, LangItem::String) {
+            return Some(String::from("str"));
+        }
+        if let ty::Adt(_, substs) = buffer.kind()
+            && is_type_diagnostic_item(cx, buffer, sym::Vec)
+        {
+            return Some(with_forced_trimmed_paths!(format!("[{}]", substs.type_at(0))));
+        }
+    }
+    None
+}
+
+/// If `expr` is a field access of a struct of the crate, returns the field's `LocalDefId`.
+fn field(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<LocalDefId> {
+    let ExprKind::Field(base, _) = expr.kind else {
+        return None;
+    };
+    let ty::Adt(adt, _) = cx.typeck_results().expr_ty_adjusted(base).peel_refs().kind() else {
+        return None;
+    };
+    if !adt.is_struct() {
+        return None;
+    }
+    let index = cx.typeck_results().opt_field_index(expr.hir_id)?;
+    adt.non_enum_variant().fields[index].did.as_local()
+}
+
+/// Whether the `Arc<Vec<T>>` or `Arc<String>` (or the `Vec` or `String` in it) in `e` is only
+/// used in a way which works with a slice or a `str` as well.
+fn is_shared_use(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let Some(parent) = get_parent_expr(cx, e) else {
+        return false;
+    };
+    match parent.kind {
+        ExprKind::MethodCall(path, recv, ..) if recv.hir_id == e.hir_id => {
+            // the methods of `Vec` and `String` which slices and `str` don't have
+            cx.typeck_results()
+                .type_dependent_def_id(parent.hir_id)
+                .and_then(|id| cx.tcx.impl_of_method(id))
+                .map_or(true, |id| {
+                    let self_ty = cx.tcx.type_of(id).subst_identity();
+                    !(is_type_diagnostic_item(cx, self_ty, sym::Vec)
+                        || is_type_lang_item(cx, self_ty, LangItem::String))
+                        || matches!(path.ident.as_str(), "len" | "is_empty" | "as_ptr" | "as_bytes")
+                })
+        },
+        ExprKind::Unary(UnOp::Deref, _) => is_shared_use(cx, parent),
+        ExprKind::Index(base, _) => base.hir_id == e.hir_id,
+        ExprKind::AddrOf(_, Mutability::Not, _) => {
+            let ty = cx.typeck_results().expr_ty_adjusted(parent).peel_refs();
+            !(is_type_diagnostic_item(cx, ty, sym::Vec) || is_type_lang_item(cx, ty, LangItem::String))
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,261 @@
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_hir_and_then};
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{match_def_path, path_def_id, paths};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_errors::Applicability;
+use rustc_hir::def::{CtorOf, DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::{Expr, ExprKind, HirId, QPath};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `compare_exchange` and `compare_exchange_weak` calls whose success ordering is
+    /// weaker than their failure ordering.
+    ///
+    /// ### Why is this bad?
+    /// Since a successful exchange also loads the value, a failure ordering which is stronger than
+    /// the success ordering means the load is only ordered if the exchange fails, which is rarely
+    /// intended.
+    ///
+    /// ### Known problems
+    /// `fence(Relaxed)` is linted by rustc's `invalid_atomic_ordering`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicUsize, Ordering};
+    /// # let value = AtomicUsize::new(0);
+    /// let _ = value.compare_exchange(0, 1, Ordering::Relaxed, Ordering::Acquire);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicUsize, Ordering};
+    /// # let value = AtomicUsize::new(0);
+    /// let _ = value.compare_exchange(0, 1, Ordering::Acquire, Ordering::Acquire);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub ATOMICS_ORDERING_MISMATCH,
+    suspicious,
+    "atomic orderings which don't synchronize as intended"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Relaxed` loads of a static or field which is stored to with `Release`
+    /// elsewhere in the crate.
+    ///
+    /// ### Why is this bad?
+    /// A `Release` store only synchronizes with `Acquire` loads. A `Relaxed` load of the same
+    /// atomic may not see the writes made before the store, which usually defeats the purpose of
+    /// the `Release`.
+    ///
+    /// ### Known problems
+    /// The loads and stores are matched by the static or field they access, without checking
+    /// whether the same value is accessed or whether the load needs to synchronize at all, like
+    /// reads of counters or statistics don't. `Relaxed` loads followed by an acquiring `fence`
+    /// in the same function aren't linted.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// static READY: AtomicBool = AtomicBool::new(false);
+    ///
+    /// READY.store(true, Ordering::Release);
+    /// let ready = READY.load(Ordering::Relaxed);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// static READY: AtomicBool = AtomicBool::new(false);
+    ///
+    /// READY.store(true, Ordering::Release);
+    /// let ready = READY.load(Ordering::Acquire);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub RELAXED_LOAD_WITH_RELEASE_STORE,
+    nursery,
+    "a `Relaxed` load of an atomic which is stored to with `Release`"
+}
+
+#[derive(Default)]
+pub struct AtomicsOrderingMismatch {
+    /// The first `Release` store to each static or field.
+    release_stores: FxHashMap<LocalDefId, Span>,
+    /// The `Relaxed` loads, with the span of their ordering.
+    relaxed_loads: Vec<(LocalDefId, HirId, Span)>,
+}
+
+impl_lint_pass!(AtomicsOrderingMismatch => [ATOMICS_ORDERING_MISMATCH, RELAXED_LOAD_WITH_RELEASE_STORE]);
+
+impl<'tcx> LateLintPass<'tcx> for AtomicsOrderingMismatch {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::MethodCall(path, recv, args, _) = expr.kind else {
+            return;
+        };
+        if in_external_macro(cx.sess(), expr.span) || !is_atomic_method(cx, expr) {
+            return;
+        }
+        match (path.ident.as_str(), args) {
+            ("load", [order]) => {
+                if let Some(place) = atomic_place(cx, recv)
+                    && let Some(("Relaxed", span)) = ordering(cx, order)
+                    && !is_followed_by_acquire_fence(cx, expr)
+                {
+                    self.relaxed_loads.push((place, expr.hir_id, span));
+                }
+            },
+            ("store", [_, order]) => {
+                if let Some(place) = atomic_place(cx, recv)
+                    && let Some(("Release", _)) = ordering(cx, order)
+                {
+                    self.release_stores.entry(place).or_insert(expr.span);
+                }
+            },
+            ("compare_exchange" | "compare_exchange_weak", [_, _, success, failure]) => {
+                check_compare_exchange(cx, success, failure);
+            },
+            _ => {},
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for &(place, hir_id, span) in &self.relaxed_loads {
+            let Some(&store_span) = self.release_stores.get(&place) else {
+                continue;
+            };
+            let name = cx.tcx.item_name(place.to_def_id());
+            span_lint_hir_and_then(
+                cx,
+                RELAXED_LOAD_WITH_RELEASE_STORE,
+                hir_id,
+                span,
+                &format!("`Relaxed` load of `{name}`, which is stored to with `Release`"),
+                |diag| {
+                    diag.span_note(store_span, "the `Release` store only synchronizes with `Acquire` loads");
+                    diag.span_suggestion(
+                        span,
+                        "use `Acquire` to see the writes made before the store",
+                        "Acquire",
+                        Applicability::MaybeIncorrect,
+                    );
+                },
+            );
+        }
+    }
+}
+
+fn check_compare_exchange(cx: &LateContext<'_>, success: &Expr<'_>, failure: &Expr<'_>) {
+    let (Some((success, span)), Some((failure, _))) = (ordering(cx, success), ordering(cx, failure)) else {
+        return;
+    };
+    // a successful exchange also loads the value, with `Relaxed` for `Release`
+    let sugg = match (success, failure) {
+        ("Relaxed", "Acquire") => "Acquire",
+        ("Release", "Acquire") => "AcqRel",
+        ("Relaxed" | "Release" | "Acquire" | "AcqRel", "SeqCst") => "SeqCst",
+        _ => return,
+    };
+    span_lint_and_sugg(
+        cx,
+        ATOMICS_ORDERING_MISMATCH,
+        span,
+        &format!("the success ordering `{success}` is weaker than the failure ordering `{failure}`"),
+        "use a success ordering which is at least as strong",
+        sugg.to_owned(),
+        Applicability::MachineApplicable,
+    );
+}
+
+/// Whether the body containing the load `expr` has an acquiring `fence` after it, which
+/// synchronizes with the `Release` store like an `Acquire` load would.
+fn is_followed_by_acquire_fence(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let Some(body_id) = cx.enclosing_body else {
+        return false;
+    };
+    for_each_expr(cx.tcx.hir().body(body_id).value, |e| {
+        if let ExprKind::Call(func, [order]) = e.kind
+            && e.span.lo() >= expr.span.hi()
+            && path_def_id(cx, func).map_or(false, |id| match_def_path(cx, id, &paths::ATOMIC_FENCE))
+            && let Some(("Acquire" | "AcqRel" | "SeqCst", _)) = ordering(cx, order)
+        {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+/// Whether `expr` calls a method of one of the atomic types of `core::sync::atomic`.
+fn is_atomic_method(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    cx.typeck_results()
+        .type_dependent_def_id(expr.hir_id)
+        .and_then(|id| cx.tcx.impl_of_method(id))
+        .map_or(false, |impl_id| {
+            if let ty::Adt(adt, _) = cx.tcx.type_of(impl_id).subst_identity().kind() {
+                let path = cx.get_def_path(adt.did());
+                let path: Vec<_> = path.iter().map(Symbol::as_str).collect();
+                matches!(*path, ["core", "sync", "atomic", name] if name.starts_with("Atomic"))
+            } else {
+                false
+            }
+        })
+}
+
+/// If `recv` is a static or a field of a struct of the crate, returns its `LocalDefId`.
+fn atomic_place(cx: &LateContext<'_>, recv: &Expr<'_>) -> Option<LocalDefId> {
+    match recv.kind {
+        ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, recv.hir_id) {
+            Res::Def(DefKind::Static(_), id) => id.as_local(),
+            _ => None,
+        },
+        ExprKind::Field(base, _) => {
+            let ty::Adt(adt, _) = cx.typeck_results().expr_ty_adjusted(base).peel_refs().kind() else {
+                return None;
+            };
+            if !adt.is_struct() {
+                return None;
+            }
+            let index = cx.typeck_results().opt_field_index(recv.hir_id)?;
+            adt.non_enum_variant().fields[index].did.as_local()
+        },
+        _ => None,
+    }
+}
+
+/// If `e` is a path to a variant of `core::sync::atomic::Ordering`, returns the name of the
+/// variant and the span of the name.
+fn ordering(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<(&'static str, Span)> {
+    let ExprKind::Path(ref qpath) = e.kind else {
+        return None;
+    };
+    let Res::Def(DefKind::Ctor(CtorOf::Variant, _), ctor_id) = cx.qpath_res(qpath, e.hir_id) else {
+        return None;
+    };
+    let variant_id: DefId = cx.tcx.parent(ctor_id);
+    if !match_def_path(cx, cx.tcx.parent(variant_id), &paths::ATOMIC_ORDERING) {
+        return None;
+    }
+    let span = match qpath {
+        QPath::Resolved(_, path) => path.segments.last()?.ident.span,
+        QPath::TypeRelative(_, segment) => segment.ident.span,
+        QPath::LangItem(..) => return None,
+    };
+    if span.from_expansion() {
+        return None;
+    }
+    let name = match cx.tcx.item_name(variant_id).as_str() {
+        "Relaxed" => "Relaxed",
+        "Release" => "Release",
+        "Acquire" => "Acquire",
+        "AcqRel" => "AcqRel",
+        "SeqCst" => "SeqCst",
+        _ => return None,
+    };
+    Some((name, span))
+}
@@ -1,9 +1,9 @@
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::{match_def_path, paths};
+use clippy_utils::{get_attr, match_def_path, paths};
 use rustc_data_structures::fx::FxHashMap;
 use rustc_hir::def_id::DefId;
 use rustc_hir::{AsyncGeneratorKind, Body, BodyId, GeneratorKind};
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::ty::GeneratorInteriorTypeCause;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::{sym, Span};
@@ -167,7 +167,87 @@ declare_clippy_lint! {
     "holding a type across an await point which is not allowed to be held as per the configuration"
 }
 
-impl_lint_pass!(AwaitHolding => [AWAIT_HOLDING_LOCK, AWAIT_HOLDING_REFCELL_REF, AWAIT_HOLDING_INVALID_TYPE]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to await while holding a value of a type marked with
+    /// `#[clippy::has_significant_drop]`.
+    ///
+    /// ### Why is this bad?
+    /// The attribute marks types whose drop does something important, like releasing a lock or
+    /// a pooled connection. Holding such a value across an `await` suspension point keeps that
+    /// resource for as long as the future is suspended, which may be a long time or deadlock.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # async fn baz() {}
+    /// #[clippy::has_significant_drop]
+    /// struct Connection;
+    ///
+    /// async fn foo(conn: Connection) {
+    ///   baz().await;
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// # async fn baz() {}
+    /// #[clippy::has_significant_drop]
+    /// struct Connection;
+    ///
+    /// async fn foo(conn: Connection) {
+    ///   drop(conn);
+    ///   baz().await;
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub AWAIT_HOLDING_REFCELL_BORROW_MUT_EXTENDED,
+    suspicious,
+    "inside an async function, holding a type marked with `#[clippy::has_significant_drop]` while calling `await`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to await while holding the guard of an entered `tracing` span, as
+    /// returned by `Span::enter` or `Span::entered`.
+    ///
+    /// ### Why is this bad?
+    /// The span stays entered on the thread while the future is suspended, so whatever else the
+    /// executor runs on that thread is recorded inside the span, and the span may be exited on
+    /// another thread after the future is resumed there. This corrupts the traces.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// # async fn baz() {}
+    /// async fn foo() {
+    ///   let span = tracing::info_span!("foo");
+    ///   let _guard = span.enter();
+    ///   baz().await;
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// # async fn baz() {}
+    /// use tracing::Instrument;
+    ///
+    /// async fn foo() {
+    ///   let span = tracing::info_span!("foo");
+    ///   baz().instrument(span).await;
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub AWAIT_HOLDING_SPAN_GUARD,
+    suspicious,
+    "inside an async function, holding the guard of an entered `tracing` span while calling `await`"
+}
+
+impl_lint_pass!(AwaitHolding => [
+    AWAIT_HOLDING_LOCK,
+    AWAIT_HOLDING_REFCELL_REF,
+    AWAIT_HOLDING_INVALID_TYPE,
+    AWAIT_HOLDING_REFCELL_BORROW_MUT_EXTENDED,
+    AWAIT_HOLDING_SPAN_GUARD,
+]);
 
 #[derive(Debug)]
 pub struct AwaitHolding {
@@ -245,8 +325,42 @@ impl AwaitHolding {
                             );
                         },
                     );
+                } else if is_span_guard(cx, adt.did()) {
+                    span_lint_and_then(
+                        cx,
+                        AWAIT_HOLDING_SPAN_GUARD,
+                        ty_cause.span,
+                        "this guard of an entered `tracing` span is held across an `await` point",
+                        |diag| {
+                            diag.help(
+                                "consider using `Span::in_scope` for the synchronous code, or instrumenting the \
+                                future with `Instrument::instrument`",
+                            );
+                            diag.span_note(
+                                ty_cause.scope_span.unwrap_or(span),
+                                "these are all the `await` points this span is entered through",
+                            );
+                        },
+                    );
                 } else if let Some(disallowed) = self.def_ids.get(&adt.did()) {
                     emit_invalid_type(cx, ty_cause.span, disallowed);
+                } else if has_significant_drop(cx, adt.did()) {
+                    span_lint_and_then(
+                        cx,
+                        AWAIT_HOLDING_REFCELL_BORROW_MUT_EXTENDED,
+                        ty_cause.span,
+                        &format!(
+                            "this `{}`, which has a significant drop, is held across an `await` point",
+                            cx.tcx.item_name(adt.did())
+                        ),
+                        |diag| {
+                            diag.help("ensure the value is dropped before calling `await`");
+                            diag.span_note(
+                                ty_cause.scope_span.unwrap_or(span),
+                                "these are all the `await` points this value is held through",
+                            );
+                        },
+                    );
                 }
             }
         }
@@ -270,15 +384,32 @@ fn emit_invalid_type(cx: &LateContext<'_>, span: Span, disallowed: &DisallowedPa
     );
 }
 
-fn is_mutex_guard(cx: &LateContext<'_>, def_id: DefId) -> bool {
+pub(crate) fn is_mutex_guard(cx: &LateContext<'_>, def_id: DefId) -> bool {
     cx.tcx.is_diagnostic_item(sym::MutexGuard, def_id)
         || cx.tcx.is_diagnostic_item(sym::RwLockReadGuard, def_id)
         || cx.tcx.is_diagnostic_item(sym::RwLockWriteGuard, def_id)
         || match_def_path(cx, def_id, &paths::PARKING_LOT_MUTEX_GUARD)
         || match_def_path(cx, def_id, &paths::PARKING_LOT_RWLOCK_READ_GUARD)
         || match_def_path(cx, def_id, &paths::PARKING_LOT_RWLOCK_WRITE_GUARD)
+        || match_def_path(cx, def_id, &paths::PARKING_LOT_RWLOCK_UPGRADABLE_READ_GUARD)
+        || match_def_path(cx, def_id, &paths::PARKING_LOT_MAPPED_MUTEX_GUARD)
+        || match_def_path(cx, def_id, &paths::PARKING_LOT_MAPPED_RWLOCK_READ_GUARD)
+        || match_def_path(cx, def_id, &paths::PARKING_LOT_MAPPED_RWLOCK_WRITE_GUARD)
+        || match_def_path(cx, def_id, &paths::PARKING_LOT_REENTRANT_MUTEX_GUARD)
+        || match_def_path(cx, def_id, &paths::PARKING_LOT_MAPPED_REENTRANT_MUTEX_GUARD)
 }
 
-fn is_refcell_ref(cx: &LateContext<'_>, def_id: DefId) -> bool {
+pub(crate) fn is_refcell_ref(cx: &LateContext<'_>, def_id: DefId) -> bool {
     match_def_path(cx, def_id, &paths::REFCELL_REF) || match_def_path(cx, def_id, &paths::REFCELL_REFMUT)
 }
+
+fn is_span_guard(cx: &LateContext<'_>, def_id: DefId) -> bool {
+    match_def_path(cx, def_id, &paths::TRACING_SPAN_ENTERED)
+        || match_def_path(cx, def_id, &paths::TRACING_SPAN_ENTERED_SPAN)
+}
+
+fn has_significant_drop(cx: &LateContext<'_>, def_id: DefId) -> bool {
+    get_attr(cx.sess(), cx.tcx.get_attrs_unchecked(def_id), "has_significant_drop")
+        .next()
+        .is_some()
+}
@@ -0,0 +1,101 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{match_def_path, path_def_id, paths};
+use rustc_hir::{AsyncGeneratorKind, Body, Expr, ExprKind, GeneratorKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `futures::executor::block_on`, `tokio::runtime::Runtime::block_on`
+    /// and `tokio::runtime::Handle::block_on` inside of `async fn`s and `async` blocks.
+    ///
+    /// ### Why is this bad?
+    /// The executor running the enclosing future is blocked until the inner future completes.
+    /// If the inner future waits on a task of the same executor, this deadlocks, and tokio
+    /// panics when a runtime is started from within a runtime.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// async fn load(handle: &tokio::runtime::Handle) -> String {
+    ///     handle.block_on(fetch())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// async fn load() -> String {
+    ///     fetch().await
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub BLOCK_ON_INSIDE_ASYNC,
+    suspicious,
+    "calling `block_on` inside of an `async fn` or `async` block"
+}
+
+#[derive(Default)]
+pub struct BlockOnInsideAsync {
+    /// The span of each of the bodies currently being checked, if they are `async`.
+    async_bodies: Vec<Option<Span>>,
+}
+
+impl_lint_pass!(BlockOnInsideAsync => [BLOCK_ON_INSIDE_ASYNC]);
+
+impl<'tcx> LateLintPass<'tcx> for BlockOnInsideAsync {
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &'tcx Body<'tcx>) {
+        let span = match body.generator_kind {
+            Some(GeneratorKind::Async(AsyncGeneratorKind::Fn)) => {
+                Some(cx.tcx.def_span(cx.tcx.hir().get_parent_item(body.value.hir_id)))
+            },
+            Some(GeneratorKind::Async(_)) => Some(cx.tcx.def_span(cx.tcx.hir().body_owner_def_id(body.id()))),
+            _ => None,
+        };
+        self.async_bodies.push(span);
+    }
+
+    fn check_body_post(&mut self, _: &LateContext<'tcx>, _: &'tcx Body<'tcx>) {
+        self.async_bodies.pop();
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let Some(&Some(async_span)) = self.async_bodies.last() else {
+            return;
+        };
+        if in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        let id = match expr.kind {
+            ExprKind::Call(func, [_]) => path_def_id(cx, func),
+            ExprKind::MethodCall(_, _, [_], _) => cx.typeck_results().type_dependent_def_id(expr.hir_id),
+            _ => None,
+        };
+        let Some(id) = id else {
+            return;
+        };
+        let consequence = if match_def_path(cx, id, &paths::FUTURES_EXECUTOR_BLOCK_ON) {
+            "this deadlocks if the inner future waits on a task of the same executor"
+        } else if match_def_path(cx, id, &paths::TOKIO_RUNTIME_BLOCK_ON)
+            || match_def_path(cx, id, &paths::TOKIO_HANDLE_BLOCK_ON)
+        {
+            "this panics if the thread is running a tokio runtime"
+        } else {
+            return;
+        };
+
+        span_lint_and_then(
+            cx,
+            BLOCK_ON_INSIDE_ASYNC,
+            expr.span,
+            &format!("call to `{}` in an async context", cx.tcx.def_path_str(id)),
+            |diag| {
+                diag.span_note(
+                    async_span,
+                    "the thread running this async code is blocked until the inner future completes",
+                );
+                diag.note(consequence);
+                diag.help("`.await` the future instead");
+            },
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::{def_path_def_ids, is_lint_allowed, match_def_path, path_def_id, paths};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Body, Expr, ExprKind, GeneratorKind, Node, TraitFn, TraitItemKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::symbol::Ident;
+use rustc_span::{sym, Symbol};
+
+use crate::utils::conf::DisallowedPath;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to blocking functions of the standard library inside of `async fn`s and
+    /// `async` blocks. These are the functions of `std::fs` and the methods of `std::fs::File`,
+    /// `std::net::TcpStream::connect`, `std::thread::sleep` and `std::io::Stdin::read_line`, as
+    /// well as the paths configured in `blocking-call-paths`.
+    ///
+    /// ### Why is this bad?
+    /// A blocking call doesn't yield to the executor, so it stalls every other task which is run
+    /// on the same thread until it returns.
+    ///
+    /// ### Known problems
+    /// Whether a short blocking call is a problem depends on the executor and the program.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// async fn load() -> std::io::Result<String> {
+    ///     std::fs::read_to_string("config.toml")
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// async fn load() -> std::io::Result<String> {
+    ///     tokio::fs::read_to_string("config.toml").await
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub BLOCKING_CALL_IN_ASYNC_FN,
+    pedantic,
+    "calling a blocking function inside of an `async fn` or `async` block"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `std::thread::sleep` inside of `async fn`s and `async` blocks.
+    ///
+    /// ### Why is this bad?
+    /// `std::thread::sleep` blocks the thread, so every other task which is run on the same
+    /// thread stalls until it returns. The async runtimes have a timer which only suspends the
+    /// current task.
+    ///
+    /// The suggested replacement is `tokio::time::sleep` or `async_std::task::sleep`, depending
+    /// on which of the crates is used.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// async fn retry() {
+    ///     std::thread::sleep(std::time::Duration::from_secs(1));
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// async fn retry() {
+    ///     tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub SLEEP_IN_ASYNC_WITH_STD,
+    suspicious,
+    "calling `std::thread::sleep` inside of an `async fn` or `async` block"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the blocking calls linted by `blocking_call_in_async_fn` in the default bodies
+    /// of trait methods returning a future, like `async fn`s in traits, methods of traits using
+    /// `#[async_trait]` and methods returning `impl Future`.
+    ///
+    /// ### Why is this bad?
+    /// Every implementor which doesn't override the method inherits the blocking call, without
+    /// it being visible in the implementation.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// #[async_trait]
+    /// trait Store {
+    ///     async fn load(&self) -> std::io::Result<String> {
+    ///         std::fs::read_to_string("store.json")
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// #[async_trait]
+    /// trait Store {
+    ///     async fn load(&self) -> std::io::Result<String> {
+    ///         tokio::fs::read_to_string("store.json").await
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub ASYNC_TRAIT_BLOCKING_DEFAULT_METHOD,
+    pedantic,
+    "calling a blocking function in the default body of an async trait method"
+}
+
+pub struct BlockingCallInAsyncFn {
+    conf_blocking_paths: Vec<DisallowedPath>,
+    def_ids: FxHashMap<DefId, DisallowedPath>,
+    /// Whether each of the bodies currently being checked is `async`.
+    async_bodies: Vec<bool>,
+    /// The async `sleep` function of the runtime the crate uses, if any.
+    async_sleep: Option<&'static str>,
+}
+
+impl BlockingCallInAsyncFn {
+    pub fn new(conf_blocking_paths: Vec<DisallowedPath>) -> Self {
+        Self {
+            conf_blocking_paths,
+            def_ids: FxHashMap::default(),
+            async_bodies: Vec::new(),
+            async_sleep: None,
+        }
+    }
+}
+
+impl_lint_pass!(BlockingCallInAsyncFn => [
+    BLOCKING_CALL_IN_ASYNC_FN,
+    SLEEP_IN_ASYNC_WITH_STD,
+    ASYNC_TRAIT_BLOCKING_DEFAULT_METHOD,
+]);
+
+impl<'tcx> LateLintPass<'tcx> for BlockingCallInAsyncFn {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for conf in &self.conf_blocking_paths {
+            let segs: Vec<_> = conf.path().split("::").collect();
+            for id in def_path_def_ids(cx, &segs) {
+                self.def_ids.insert(id, conf.clone());
+            }
+        }
+        self.async_sleep = [
+            ("tokio::time::sleep", ["tokio", "time", "sleep"]),
+            ("async_std::task::sleep", ["async_std", "task", "sleep"]),
+        ]
+        .into_iter()
+        .find_map(|(name, path)| def_path_def_ids(cx, &path).next().map(|_| name));
+    }
+
+    fn check_body(&mut self, _: &LateContext<'tcx>, body: &'tcx Body<'tcx>) {
+        self.async_bodies
+            .push(matches!(body.generator_kind, Some(GeneratorKind::Async(_))));
+    }
+
+    fn check_body_post(&mut self, _: &LateContext<'tcx>, _: &'tcx Body<'tcx>) {
+        self.async_bodies.pop();
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if self.async_bodies.last() != Some(&true) || in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        let id = match expr.kind {
+            ExprKind::Call(func, _) => path_def_id(cx, func),
+            ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(expr.hir_id),
+            _ => None,
+        };
+        let Some(id) = id else {
+            return;
+        };
+        if match_def_path(cx, id, &paths::STD_THREAD_SLEEP)
+            && !is_lint_allowed(cx, SLEEP_IN_ASYNC_WITH_STD, expr.hir_id)
+        {
+            self.check_sleep(cx, expr);
+            return;
+        }
+        let (help, reason) = if let Some(help) = std_alternative(cx, id) {
+            (help, None)
+        } else if let Some(conf) = self.def_ids.get(&id) {
+            ("consider using a non-blocking alternative", conf.reason())
+        } else {
+            return;
+        };
+
+        let default_method = provided_trait_method(cx, expr)
+            .filter(|_| !is_lint_allowed(cx, ASYNC_TRAIT_BLOCKING_DEFAULT_METHOD, expr.hir_id));
+        let (lint, msg) = if default_method.is_some() {
+            (
+                ASYNC_TRAIT_BLOCKING_DEFAULT_METHOD,
+                "the default body of an async trait method",
+            )
+        } else {
+            (BLOCKING_CALL_IN_ASYNC_FN, "an async context")
+        };
+        span_lint_and_then(
+            cx,
+            lint,
+            expr.span,
+            &format!("blocking call to `{}` in {msg}", cx.tcx.def_path_str(id)),
+            |diag| {
+                if let Some(method) = default_method {
+                    diag.span_note(
+                        method.span,
+                        format!("every implementor which doesn't override `{method}` inherits the blocking call"),
+                    );
+                }
+                if let Some(reason) = reason {
+                    diag.note(reason);
+                }
+                diag.help(format!(
+                    "{help}, or move the call to a thread where blocking is fine, e.g. with `spawn_blocking`"
+                ));
+            },
+        );
+    }
+}
+
+/// If `expr` is in the default body of a trait method, returns the name of the method.
+fn provided_trait_method(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<Ident> {
+    let owner = cx.tcx.hir().get_parent_item(expr.hir_id);
+    if let Some(Node::TraitItem(item)) = cx.tcx.hir().find_by_def_id(owner.def_id)
+        && let TraitItemKind::Fn(_, TraitFn::Provided(_)) = item.kind
+    {
+        Some(item.ident)
+    } else {
+        None
+    }
+}
+
+/// If `id` is a blocking function of the standard library, returns what to use instead.
+fn std_alternative(cx: &LateContext<'_>, id: DefId) -> Option<&'static str> {
+    if cx.tcx.crate_name(id.krate) != sym::std {
+        return None;
+    }
+    let path = cx.get_def_path(id);
+    let path: Vec<_> = path.iter().map(Symbol::as_str).collect();
+    // the builder methods of `OpenOptions` and the accessors of `Metadata` and the like don't block
+    if matches!(
+        *path,
+        ["std", "fs", _] | ["std", "fs", "File", _] | ["std", "fs", "OpenOptions", "open"]
+    ) {
+        Some("consider using an async file system API like `tokio::fs`")
+    } else if match_def_path(cx, id, &paths::STD_NET_TCP_STREAM_CONNECT) {
+        Some("consider using an async socket like `tokio::net::TcpStream`")
+    } else if match_def_path(cx, id, &paths::STD_THREAD_SLEEP) {
+        Some("consider using an async timer like `tokio::time::sleep`")
+    } else if match_def_path(cx, id, &paths::STD_IO_STDIN_READ_LINE) {
+        Some("consider using an async reader like `tokio::io::stdin`")
+    } else {
+        None
+    }
+}
+
+impl BlockingCallInAsyncFn {
+    fn check_sleep(&self, cx: &LateContext<'_>, expr: &Expr<'_>) {
+        span_lint_and_then(
+            cx,
+            SLEEP_IN_ASYNC_WITH_STD,
+            expr.span,
+            "`std::thread::sleep` blocks the thread in an async context",
+            |diag| {
+                if let ExprKind::Call(_, [duration]) = expr.kind
+                    && let Some(async_sleep) = self.async_sleep
+                {
+                    let mut app = Applicability::MachineApplicable;
+                    let duration = snippet_with_context(cx, duration.span, expr.span.ctxt(), "..", &mut app).0;
+                    diag.span_suggestion(
+                        expr.span,
+                        "use the timer of the async runtime",
+                        format!("{async_sleep}({duration}).await"),
+                        app,
+                    );
+                } else {
+                    diag.help("use the timer of the async runtime, like `tokio::time::sleep`, and `.await` it");
+                }
+            },
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{get_parent_expr, path_def_id, path_to_local};
+use rustc_errors::Applicability;
+use rustc_hir::{BorrowKind, Expr, ExprKind, Mutability, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Box::new(x)`, `Rc::new(x)` and `Arc::new(x)` which are dereferenced right
+    /// away, like `Box::new(vec![1, 2]).iter()`.
+    ///
+    /// ### Why is this bad?
+    /// The value is moved to the heap only to be borrowed once, after which the allocation is
+    /// freed again. Using the value directly does the same without allocating.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let sum: i32 = Box::new(vec![1, 2]).iter().sum();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let sum: i32 = vec![1, 2].iter().sum();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub BOX_COLLECTION_TEMPORARY,
+    perf,
+    "creating a `Box`, `Rc` or `Arc` only to dereference it right away"
+}
+
+declare_lint_pass!(BoxCollectionTemporary => [BOX_COLLECTION_TEMPORARY]);
+
+impl LateLintPass<'_> for BoxCollectionTemporary {
+    fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
+        if let ExprKind::Call(func, [arg]) = expr.kind
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some(wrapper) = wrapper_new(cx, func)
+            && let Some((span, is_deref)) = dereferenced_span(cx, expr)
+        {
+            // dropping a binding at the end of the call might not be the same as dropping it
+            // later on
+            let mut app = if path_to_local(arg).is_some() {
+                Applicability::MaybeIncorrect
+            } else {
+                Applicability::MachineApplicable
+            };
+            let arg = Sugg::hir_with_context(cx, arg, expr.span.ctxt(), "..", &mut app);
+            // a replaced `*Box::new(x)` is in a prefix position, and so only needs parentheses for
+            // binary operators
+            let arg = match (is_deref, arg) {
+                (true, arg @ (Sugg::NonParen(_) | Sugg::MaybeParen(_))) => arg,
+                (_, arg) => arg.maybe_par(),
+            };
+            span_lint_and_sugg(
+                cx,
+                BOX_COLLECTION_TEMPORARY,
+                span,
+                &format!("`{wrapper}` created only to be dereferenced right away"),
+                "use the value directly",
+                arg.to_string(),
+                app,
+            );
+        }
+    }
+}
+
+/// If `func` is `Box::new`, `Rc::new` or `Arc::new`, returns the name of the type.
+fn wrapper_new(cx: &LateContext<'_>, func: &Expr<'_>) -> Option<&'static str> {
+    let id = path_def_id(cx, func)?;
+    if cx.tcx.item_name(id) != sym::new {
+        return None;
+    }
+    let impl_ty = cx.tcx.type_of(cx.tcx.impl_of_method(id)?).subst_identity();
+    if impl_ty.is_box() {
+        Some("Box")
+    } else if is_type_diagnostic_item(cx, impl_ty, sym::Rc) {
+        Some("Rc")
+    } else if is_type_diagnostic_item(cx, impl_ty, sym::Arc) {
+        Some("Arc")
+    } else {
+        None
+    }
+}
+
+/// If the wrapper created by `expr` is dereferenced before being used, returns the span to replace
+/// with the wrapped value, and whether it's an explicit `*Box::new(x)`.
+fn dereferenced_span(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<(Span, bool)> {
+    if let [first, ..] = cx.typeck_results().expr_adjustments(expr)
+        && let Adjust::Deref(_) = first.kind
+    {
+        // an auto-deref, e.g. `Box::new(x).len()` or `Box::new(x).field`
+        return Some((expr.span, false));
+    }
+    let parent = get_parent_expr(cx, expr)?;
+    match parent.kind {
+        ExprKind::Unary(UnOp::Deref, _) => Some((parent.span, true)),
+        // a deref coercion from `&Box<T>` to `&T`
+        ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _)
+            if let [first, second, ..] = cx.typeck_results().expr_adjustments(parent)
+                && let (Adjust::Deref(_), Adjust::Deref(_)) = (&first.kind, &second.kind) =>
+        {
+            Some((expr.span, false))
+        },
+        _ => None,
+    }
+}
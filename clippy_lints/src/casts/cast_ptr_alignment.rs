@@ -1,4 +1,4 @@
-use clippy_utils::diagnostics::span_lint;
+use clippy_utils::diagnostics::span_lint_and_help;
 use clippy_utils::ty::is_c_void;
 use clippy_utils::{get_parent_expr, is_hir_ty_cfg_dependant, match_any_def_paths, paths};
 use rustc_hir::{Expr, ExprKind, GenericArg};
@@ -17,7 +17,7 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>) {
             cx.typeck_results().expr_ty(cast_expr),
             cx.typeck_results().expr_ty(expr),
         );
-        lint_cast_ptr_alignment(cx, expr, cast_from, cast_to);
+        lint_cast_ptr_alignment(cx, expr, cast_expr, cast_from, cast_to);
     } else if let ExprKind::MethodCall(method_path, self_arg, ..) = &expr.kind {
         if method_path.ident.name == sym!(cast)
             && let Some(generic_args) = method_path.args
@@ -27,12 +27,18 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>) {
         {
             let (cast_from, cast_to) =
                 (cx.typeck_results().expr_ty(self_arg), cx.typeck_results().expr_ty(expr));
-            lint_cast_ptr_alignment(cx, expr, cast_from, cast_to);
+            lint_cast_ptr_alignment(cx, expr, self_arg, cast_from, cast_to);
         }
     }
 }
 
-fn lint_cast_ptr_alignment<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, cast_from: Ty<'tcx>, cast_to: Ty<'tcx>) {
+fn lint_cast_ptr_alignment<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    cast_expr: &Expr<'_>,
+    cast_from: Ty<'tcx>,
+    cast_to: Ty<'tcx>,
+) {
     if let ty::RawPtr(from_ptr_ty) = &cast_from.kind()
         && let ty::RawPtr(to_ptr_ty) = &cast_to.kind()
         && let Ok(from_layout) = cx.layout_of(from_ptr_ty.ty)
@@ -43,8 +49,9 @@ fn lint_cast_ptr_alignment<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, cast_f
         // when casting from a ZST, we don't know enough to properly lint
         && !from_layout.is_zst()
         && !is_used_as_unaligned(cx, expr)
+        && !is_cast_from_aligned_ptr(cx, cast_expr, to_layout.align.abi.bytes())
     {
-        span_lint(
+        span_lint_and_help(
             cx,
             CAST_PTR_ALIGNMENT,
             expr.span,
@@ -53,10 +60,35 @@ fn lint_cast_ptr_alignment<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, cast_f
                 from_layout.align.abi.bytes(),
                 to_layout.align.abi.bytes(),
             ),
+            None,
+            "dereferencing the result is undefined behavior if it isn't aligned, consider using \
+             `read_unaligned` and `write_unaligned`, or `align_to` on a slice",
         );
     }
 }
 
+/// Whether `e` is a chain of casts starting from a reference or a pointer which is at least
+/// `align` bytes aligned, like `&1u32 as *const u32 as *const u8`.
+fn is_cast_from_aligned_ptr(cx: &LateContext<'_>, mut e: &Expr<'_>, align: u64) -> bool {
+    loop {
+        e = match e.kind {
+            ExprKind::Cast(inner, _) => inner,
+            ExprKind::MethodCall(path, recv, [], _)
+                if path.ident.name == sym!(cast) && cx.typeck_results().expr_ty(recv).is_unsafe_ptr() =>
+            {
+                recv
+            },
+            _ => return false,
+        };
+        if let ty::RawPtr(ty::TypeAndMut { ty, .. }) | ty::Ref(_, ty, _) = cx.typeck_results().expr_ty(e).kind()
+            && let Ok(layout) = cx.layout_of(*ty)
+            && layout.align.abi.bytes() >= align
+        {
+            return true;
+        }
+    }
+}
+
 fn is_used_as_unaligned(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
     let Some(parent) = get_parent_expr(cx, e) else {
         return false;
@@ -18,6 +18,7 @@ mod fn_to_numeric_cast_any;
 mod fn_to_numeric_cast_with_truncation;
 mod ptr_as_ptr;
 mod ptr_cast_constness;
+mod truncating_cast_of_len;
 mod unnecessary_cast;
 mod utils;
 
@@ -210,8 +211,9 @@ declare_clippy_lint! {
     ///
     /// ### Known problems
     /// Using `std::ptr::read_unaligned` and `std::ptr::write_unaligned` or similar
-    /// on the resulting pointer is fine. Is over-zealous: Casts with manual alignment checks or casts like
-    /// u64-> u8 -> u16 can be fine. Miri is able to do a more in-depth analysis.
+    /// on the resulting pointer is fine, and so are chains of casts like `u64 -> u8 -> u16`.
+    /// Is over-zealous: Casts with manual alignment checks can be fine. Miri is able to do
+    /// a more in-depth analysis.
     ///
     /// ### Example
     /// ```rust
@@ -657,14 +659,52 @@ declare_clippy_lint! {
     "casting a known floating-point NaN into an integer"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for lengths which are cast to narrower integer types, like `v.len() as u32`, or
+    /// converted with `v.len().try_into().unwrap()`.
+    ///
+    /// ### Why is this bad?
+    /// Lengths are `usize`, which is 64 bits wide on 64-bit platforms. Casting them silently
+    /// truncates lengths which don't fit, and the truncated length is usually written to a wire
+    /// format or used for indexing. Converting them with `unwrap` panics instead.
+    ///
+    /// ### Configuration
+    /// Casts to types with at least `min-len-cast-bits` bits aren't linted.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let mut buf = Vec::new();
+    /// # let payload = vec![0u8; 4];
+    /// buf.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn f() -> Result<(), std::num::TryFromIntError> {
+    /// # let mut buf = Vec::new();
+    /// # let payload = vec![0u8; 4];
+    /// buf.extend_from_slice(&u32::try_from(payload.len())?.to_le_bytes());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub TRUNCATING_CAST_OF_LEN,
+    pedantic,
+    "casting a length to an integer type which may be too narrow for it"
+}
+
 pub struct Casts {
     msrv: Msrv,
+    min_len_cast_bits: u64,
 }
 
 impl Casts {
     #[must_use]
-    pub fn new(msrv: Msrv) -> Self {
-        Self { msrv }
+    pub fn new(msrv: Msrv, min_len_cast_bits: u64) -> Self {
+        Self {
+            msrv,
+            min_len_cast_bits,
+        }
     }
 }
 
@@ -691,6 +731,7 @@ impl_lint_pass!(Casts => [
     CAST_SLICE_FROM_RAW_PARTS,
     AS_PTR_CAST_MUT,
     CAST_NAN_TO_INT,
+    TRUNCATING_CAST_OF_LEN,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Casts {
@@ -733,6 +774,7 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
                 }
                 cast_lossless::check(cx, expr, cast_expr, cast_from, cast_to, &self.msrv);
                 cast_enum_constructor::check(cx, expr, cast_expr, cast_from);
+                truncating_cast_of_len::check_cast(cx, expr, cast_expr, cast_to, self.min_len_cast_bits);
             }
 
             as_underscore::check(cx, expr, cast_to_hir);
@@ -742,6 +784,9 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
             }
         }
 
+        if !in_external_macro(cx.sess(), expr.span) {
+            truncating_cast_of_len::check_conversion(cx, expr, self.min_len_cast_bits);
+        }
         cast_ptr_alignment::check(cx, expr);
         char_lit_as_u8::check(cx, expr);
         ptr_as_ptr::check(cx, expr, &self.msrv);
@@ -0,0 +1,80 @@
+use super::utils::int_ty_to_nbits;
+use super::TRUNCATING_CAST_OF_LEN;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::fn_def_id;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::sym;
+
+/// Checks for `v.len() as u32`.
+pub(super) fn check_cast<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    cast_expr: &'tcx Expr<'_>,
+    cast_to: Ty<'tcx>,
+    min_bits: u64,
+) {
+    if is_len_call(cx, cast_expr) && is_too_narrow(cx, cast_to, min_bits) {
+        span_lint_and_then(
+            cx,
+            TRUNCATING_CAST_OF_LEN,
+            expr.span,
+            &format!("casting a length to `{cast_to}` may truncate it"),
+            |diag| {
+                diag.note("lengths which don't fit are silently truncated");
+                diag.help(format!(
+                    "keep the length as `usize` or `u64`, or convert it with `{cast_to}::try_from` and handle the error"
+                ));
+            },
+        );
+    }
+}
+
+/// Checks for `v.len().try_into().unwrap()` and `u32::try_from(v.len()).unwrap()`.
+pub(super) fn check_conversion<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, min_bits: u64) {
+    if let ExprKind::MethodCall(path, conversion, _, _) = expr.kind
+        && matches!(path.ident.as_str(), "unwrap" | "expect")
+        && let Some(len) = converted_expr(cx, conversion)
+        && is_len_call(cx, len)
+        && let target = cx.typeck_results().expr_ty(expr)
+        && is_too_narrow(cx, target, min_bits)
+    {
+        span_lint_and_then(
+            cx,
+            TRUNCATING_CAST_OF_LEN,
+            expr.span,
+            &format!("converting a length to `{target}` panics if it doesn't fit"),
+            |diag| {
+                diag.help("keep the length as `usize` or `u64`, or handle the conversion error");
+            },
+        );
+    }
+}
+
+/// Returns `x` for `x.try_into()` and `T::try_from(x)`.
+fn converted_expr<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match e.kind {
+        ExprKind::MethodCall(path, recv, [], _) if path.ident.name == sym::try_into => Some(recv),
+        ExprKind::Call(_, [arg]) if cx.tcx.item_name(fn_def_id(cx, e)?) == sym::try_from => Some(arg),
+        _ => None,
+    }
+}
+
+fn is_len_call(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    if let ExprKind::MethodCall(path, recv, [], _) = e.kind
+        && path.ident.name == sym::len
+        && *cx.typeck_results().expr_ty(e).kind() == ty::Uint(ty::UintTy::Usize)
+    {
+        // the length of an array is known
+        !matches!(cx.typeck_results().expr_ty(recv).peel_refs().kind(), ty::Array(..))
+    } else {
+        false
+    }
+}
+
+fn is_too_narrow(cx: &LateContext<'_>, ty: Ty<'_>, min_bits: u64) -> bool {
+    matches!(ty.kind(), ty::Int(_) | ty::Uint(_))
+        && !ty.is_ptr_sized_integral()
+        && int_ty_to_nbits(ty, cx.tcx) < min_bits
+}
@@ -40,6 +40,8 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::allow_attributes::ALLOW_ATTRIBUTES_INFO,
     crate::almost_complete_range::ALMOST_COMPLETE_RANGE_INFO,
     crate::approx_const::APPROX_CONSTANT_INFO,
+    crate::arc_mutex_of_copy_primitive::ARC_MUTEX_OF_COPY_PRIMITIVE_INFO,
+    crate::arc_slice_candidate::ARC_SLICE_CANDIDATE_INFO,
     crate::arc_with_non_send_sync::ARC_WITH_NON_SEND_SYNC_INFO,
     crate::as_conversions::AS_CONVERSIONS_INFO,
     crate::asm_syntax::INLINE_ASM_X86_ATT_SYNTAX_INFO,
@@ -47,6 +49,8 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::assertions_on_constants::ASSERTIONS_ON_CONSTANTS_INFO,
     crate::assertions_on_result_states::ASSERTIONS_ON_RESULT_STATES_INFO,
     crate::async_yields_async::ASYNC_YIELDS_ASYNC_INFO,
+    crate::atomics_ordering_mismatch::ATOMICS_ORDERING_MISMATCH_INFO,
+    crate::atomics_ordering_mismatch::RELAXED_LOAD_WITH_RELEASE_STORE_INFO,
     crate::attrs::ALLOW_ATTRIBUTES_WITHOUT_REASON_INFO,
     crate::attrs::BLANKET_CLIPPY_RESTRICTION_LINTS_INFO,
     crate::attrs::DEPRECATED_CFG_ATTR_INFO,
@@ -60,13 +64,20 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::attrs::USELESS_ATTRIBUTE_INFO,
     crate::await_holding_invalid::AWAIT_HOLDING_INVALID_TYPE_INFO,
     crate::await_holding_invalid::AWAIT_HOLDING_LOCK_INFO,
+    crate::await_holding_invalid::AWAIT_HOLDING_REFCELL_BORROW_MUT_EXTENDED_INFO,
     crate::await_holding_invalid::AWAIT_HOLDING_REFCELL_REF_INFO,
+    crate::await_holding_invalid::AWAIT_HOLDING_SPAN_GUARD_INFO,
+    crate::block_on_inside_async::BLOCK_ON_INSIDE_ASYNC_INFO,
+    crate::blocking_call_in_async_fn::ASYNC_TRAIT_BLOCKING_DEFAULT_METHOD_INFO,
+    crate::blocking_call_in_async_fn::BLOCKING_CALL_IN_ASYNC_FN_INFO,
+    crate::blocking_call_in_async_fn::SLEEP_IN_ASYNC_WITH_STD_INFO,
     crate::blocks_in_if_conditions::BLOCKS_IN_IF_CONDITIONS_INFO,
     crate::bool_assert_comparison::BOOL_ASSERT_COMPARISON_INFO,
     crate::bool_to_int_with_if::BOOL_TO_INT_WITH_IF_INFO,
     crate::booleans::NONMINIMAL_BOOL_INFO,
     crate::booleans::OVERLY_COMPLEX_BOOL_EXPR_INFO,
     crate::borrow_deref_ref::BORROW_DEREF_REF_INFO,
+    crate::box_collection_temporary::BOX_COLLECTION_TEMPORARY_INFO,
     crate::box_default::BOX_DEFAULT_INFO,
     crate::cargo::CARGO_COMMON_METADATA_INFO,
     crate::cargo::MULTIPLE_CRATE_VERSIONS_INFO,
@@ -94,6 +105,7 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::casts::FN_TO_NUMERIC_CAST_WITH_TRUNCATION_INFO,
     crate::casts::PTR_AS_PTR_INFO,
     crate::casts::PTR_CAST_CONSTNESS_INFO,
+    crate::casts::TRUNCATING_CAST_OF_LEN_INFO,
     crate::casts::UNNECESSARY_CAST_INFO,
     crate::checked_conversions::CHECKED_CONVERSIONS_INFO,
     crate::cognitive_complexity::COGNITIVE_COMPLEXITY_INFO,
@@ -125,6 +137,8 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::derive::DERIVE_PARTIAL_EQ_WITHOUT_EQ_INFO,
     crate::derive::EXPL_IMPL_CLONE_ON_COPY_INFO,
     crate::derive::UNSAFE_DERIVE_DESERIALIZE_INFO,
+    crate::deserialize_skipped_field_without_default::DESERIALIZE_SKIPPED_FIELD_WITHOUT_DEFAULT_INFO,
+    crate::detached_spawn_result::DETACHED_SPAWN_RESULT_INFO,
     crate::disallowed_macros::DISALLOWED_MACROS_INFO,
     crate::disallowed_methods::DISALLOWED_METHODS_INFO,
     crate::disallowed_names::DISALLOWED_NAMES_INFO,
@@ -137,11 +151,14 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::doc::MISSING_SAFETY_DOC_INFO,
     crate::doc::NEEDLESS_DOCTEST_MAIN_INFO,
     crate::doc::UNNECESSARY_SAFETY_DOC_INFO,
+    crate::double_lock_same_mutex::DOUBLE_LOCK_SAME_MUTEX_INFO,
     crate::double_parens::DOUBLE_PARENS_INFO,
     crate::drop_forget_ref::DROP_NON_DROP_INFO,
     crate::drop_forget_ref::FORGET_NON_DROP_INFO,
     crate::drop_forget_ref::UNDROPPED_MANUALLY_DROPS_INFO,
+    crate::drop_impl_may_panic::DROP_IMPL_MAY_PANIC_INFO,
     crate::duplicate_mod::DUPLICATE_MOD_INFO,
+    crate::duration_from_float_seconds_precision::DURATION_FROM_FLOAT_SECONDS_PRECISION_INFO,
     crate::else_if_without_else::ELSE_IF_WITHOUT_ELSE_INFO,
     crate::empty_drop::EMPTY_DROP_INFO,
     crate::empty_enum::EMPTY_ENUM_INFO,
@@ -154,6 +171,7 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::enum_variants::ENUM_VARIANT_NAMES_INFO,
     crate::enum_variants::MODULE_INCEPTION_INFO,
     crate::enum_variants::MODULE_NAME_REPETITIONS_INFO,
+    crate::env_var_parsed_without_error_context::ENV_VAR_PARSED_WITHOUT_ERROR_CONTEXT_INFO,
     crate::equatable_if_let::EQUATABLE_IF_LET_INFO,
     crate::escape::BOXED_LOCAL_INFO,
     crate::eta_reduction::REDUNDANT_CLOSURE_INFO,
@@ -165,8 +183,10 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::exhaustive_items::EXHAUSTIVE_STRUCTS_INFO,
     crate::exit::EXIT_INFO,
     crate::explicit_write::EXPLICIT_WRITE_INFO,
+    crate::extern_fn_unwind_panic::EXTERN_FN_UNWIND_PANIC_INFO,
     crate::extra_unused_type_parameters::EXTRA_UNUSED_TYPE_PARAMETERS_INFO,
     crate::fallible_impl_from::FALLIBLE_IMPL_FROM_INFO,
+    crate::ffi_string_pointer_lifetime::FFI_STRING_POINTER_LIFETIME_INFO,
     crate::float_literal::EXCESSIVE_PRECISION_INFO,
     crate::float_literal::LOSSY_FLOAT_LITERAL_INFO,
     crate::floating_point_arithmetic::IMPRECISE_FLOPS_INFO,
@@ -198,6 +218,9 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::functions::TOO_MANY_ARGUMENTS_INFO,
     crate::functions::TOO_MANY_LINES_INFO,
     crate::future_not_send::FUTURE_NOT_SEND_INFO,
+    crate::hash_ignores_field_used_in_eq::HASH_IGNORES_FIELD_USED_IN_EQ_INFO,
+    crate::hash_ignores_field_used_in_eq::HASH_USES_FIELD_IGNORED_BY_EQ_INFO,
+    crate::hashmap_default_hasher_hot_path::HASHMAP_DEFAULT_HASHER_HOT_PATH_INFO,
     crate::if_let_mutex::IF_LET_MUTEX_INFO,
     crate::if_not_else::IF_NOT_ELSE_INFO,
     crate::if_then_some_else_none::IF_THEN_SOME_ELSE_NONE_INFO,
@@ -223,12 +246,15 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::items_after_statements::ITEMS_AFTER_STATEMENTS_INFO,
     crate::items_after_test_module::ITEMS_AFTER_TEST_MODULE_INFO,
     crate::iter_not_returning_iterator::ITER_NOT_RETURNING_ITERATOR_INFO,
+    crate::iter_on_locked_collection::ITER_ON_LOCKED_COLLECTION_INFO,
+    crate::join_handle_unwrap_in_drop::JOIN_HANDLE_UNWRAP_IN_DROP_INFO,
     crate::large_const_arrays::LARGE_CONST_ARRAYS_INFO,
     crate::large_enum_variant::LARGE_ENUM_VARIANT_INFO,
     crate::large_futures::LARGE_FUTURES_INFO,
     crate::large_include_file::LARGE_INCLUDE_FILE_INFO,
     crate::large_stack_arrays::LARGE_STACK_ARRAYS_INFO,
     crate::large_stack_frames::LARGE_STACK_FRAMES_INFO,
+    crate::leaky_global_from_loop::LEAKY_GLOBAL_FROM_LOOP_INFO,
     crate::len_zero::COMPARISON_TO_EMPTY_INFO,
     crate::len_zero::LEN_WITHOUT_IS_EMPTY_INFO,
     crate::len_zero::LEN_ZERO_INFO,
@@ -247,22 +273,31 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::literal_representation::MISTYPED_LITERAL_SUFFIXES_INFO,
     crate::literal_representation::UNREADABLE_LITERAL_INFO,
     crate::literal_representation::UNUSUAL_BYTE_GROUPINGS_INFO,
+    crate::loops::AWAIT_IN_LOOP_COLLECTABLE_INFO,
+    crate::loops::CHANNEL_RECV_BUSY_LOOP_INFO,
     crate::loops::EMPTY_LOOP_INFO,
     crate::loops::EXPLICIT_COUNTER_LOOP_INFO,
     crate::loops::EXPLICIT_INTO_ITER_LOOP_INFO,
     crate::loops::EXPLICIT_ITER_LOOP_INFO,
+    crate::loops::FLOAT_ACCUMULATION_IN_LOOP_INFO,
     crate::loops::FOR_KV_MAP_INFO,
+    crate::loops::INDEX_LOOP_OFF_BY_ONE_INCLUSIVE_INFO,
+    crate::loops::INSTANT_ELAPSED_IN_CONDITION_INFO,
+    crate::loops::INTERVAL_TICK_DRIFT_INFO,
     crate::loops::ITER_NEXT_LOOP_INFO,
     crate::loops::MANUAL_FIND_INFO,
     crate::loops::MANUAL_FLATTEN_INFO,
     crate::loops::MANUAL_MEMCPY_INFO,
     crate::loops::MANUAL_WHILE_LET_SOME_INFO,
+    crate::loops::MANUAL_WINDOWS_CHUNKS_INFO,
     crate::loops::MISSING_SPIN_LOOP_INFO,
+    crate::loops::MODIFICATION_WHILE_ITERATING_INDICES_INFO,
     crate::loops::MUT_RANGE_BOUND_INFO,
     crate::loops::NEEDLESS_RANGE_LOOP_INFO,
     crate::loops::NEVER_LOOP_INFO,
     crate::loops::SAME_ITEM_PUSH_INFO,
     crate::loops::SINGLE_ELEMENT_LOOP_INFO,
+    crate::loops::STRING_CONCAT_IN_LOOP_INFO,
     crate::loops::WHILE_IMMUTABLE_CONDITION_INFO,
     crate::loops::WHILE_LET_LOOP_INFO,
     crate::loops::WHILE_LET_ON_ITERATOR_INFO,
@@ -271,16 +306,20 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::manual_assert::MANUAL_ASSERT_INFO,
     crate::manual_async_fn::MANUAL_ASYNC_FN_INFO,
     crate::manual_bits::MANUAL_BITS_INFO,
+    crate::manual_checked_arith_via_compare::MANUAL_CHECKED_ARITH_VIA_COMPARE_INFO,
     crate::manual_clamp::MANUAL_CLAMP_INFO,
+    crate::manual_contains_key::MANUAL_CONTAINS_KEY_INFO,
     crate::manual_is_ascii_check::MANUAL_IS_ASCII_CHECK_INFO,
     crate::manual_let_else::MANUAL_LET_ELSE_INFO,
     crate::manual_main_separator_str::MANUAL_MAIN_SEPARATOR_STR_INFO,
     crate::manual_non_exhaustive::MANUAL_NON_EXHAUSTIVE_INFO,
     crate::manual_rem_euclid::MANUAL_REM_EUCLID_INFO,
     crate::manual_retain::MANUAL_RETAIN_INFO,
+    crate::manual_retain::MANUAL_RETAIN_INDEX_LOOP_INFO,
     crate::manual_slice_size_calculation::MANUAL_SLICE_SIZE_CALCULATION_INFO,
     crate::manual_string_new::MANUAL_STRING_NEW_INFO,
     crate::manual_strip::MANUAL_STRIP_INFO,
+    crate::manual_strip::MANUAL_STRIP_SUFFIX_INDEX_INFO,
     crate::map_unit_fn::OPTION_MAP_UNIT_FN_INFO,
     crate::map_unit_fn::RESULT_MAP_UNIT_FN_INFO,
     crate::match_result_ok::MATCH_RESULT_OK_INFO,
@@ -310,20 +349,32 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::matches::WILDCARD_ENUM_MATCH_ARM_INFO,
     crate::matches::WILDCARD_IN_OR_PATTERNS_INFO,
     crate::mem_forget::MEM_FORGET_INFO,
+    crate::mem_forget::MEM_FORGET_GUARD_TYPE_INFO,
     crate::mem_replace::MEM_REPLACE_OPTION_WITH_NONE_INFO,
     crate::mem_replace::MEM_REPLACE_WITH_DEFAULT_INFO,
     crate::mem_replace::MEM_REPLACE_WITH_UNINIT_INFO,
+    crate::methods::ALLOCATION_INSIDE_HOT_ITERATOR_ADAPTER_INFO,
     crate::methods::BIND_INSTEAD_OF_MAP_INFO,
     crate::methods::BYTES_COUNT_TO_LEN_INFO,
     crate::methods::BYTES_NTH_INFO,
     crate::methods::CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS_INFO,
+    crate::methods::CHAINED_REPLACE_INFO,
     crate::methods::CHARS_LAST_CMP_INFO,
     crate::methods::CHARS_NEXT_CMP_INFO,
+    crate::methods::CHARS_REV_COLLECT_STRING_INFO,
+    crate::methods::CHAR_TO_LOWERCASE_NEXT_UNWRAP_INFO,
     crate::methods::CLEAR_WITH_DRAIN_INFO,
     crate::methods::CLONED_INSTEAD_OF_COPIED_INFO,
+    crate::methods::CLONE_INTO_ITER_INFO,
     crate::methods::CLONE_ON_COPY_INFO,
     crate::methods::CLONE_ON_REF_PTR_INFO,
     crate::methods::COLLAPSIBLE_STR_REPLACE_INFO,
+    crate::methods::COLLECT_THEN_LEN_INFO,
+    crate::methods::COLLECT_TO_STRING_VIA_VEC_INFO,
+    crate::methods::CONDVAR_WAIT_WITHOUT_LOOP_INFO,
+    crate::methods::DRAIN_COLLECT_INFO,
+    crate::methods::EAGER_MAP_ERR_FORMAT_INFO,
+    crate::methods::ENTRY_OR_INSERT_DEFAULT_INFO,
     crate::methods::ERR_EXPECT_INFO,
     crate::methods::EXPECT_FUN_CALL_INFO,
     crate::methods::EXPECT_USED_INFO,
@@ -344,6 +395,7 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::methods::INTO_ITER_ON_REF_INFO,
     crate::methods::IS_DIGIT_ASCII_RADIX_INFO,
     crate::methods::ITERATOR_STEP_BY_ZERO_INFO,
+    crate::methods::ITERATOR_STEP_BY_ZERO_RUNTIME_INFO,
     crate::methods::ITER_CLONED_COLLECT_INFO,
     crate::methods::ITER_COUNT_INFO,
     crate::methods::ITER_KV_MAP_INFO,
@@ -354,7 +406,9 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::methods::ITER_ON_SINGLE_ITEMS_INFO,
     crate::methods::ITER_OVEREAGER_CLONED_INFO,
     crate::methods::ITER_SKIP_NEXT_INFO,
+    crate::methods::ITER_SKIP_THEN_NTH_INFO,
     crate::methods::ITER_WITH_DRAIN_INFO,
+    crate::methods::LOCK_UNWRAP_POISON_NOTE_INFO,
     crate::methods::MANUAL_FILTER_MAP_INFO,
     crate::methods::MANUAL_FIND_MAP_INFO,
     crate::methods::MANUAL_NEXT_BACK_INFO,
@@ -368,6 +422,7 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::methods::MAP_FLATTEN_INFO,
     crate::methods::MAP_IDENTITY_INFO,
     crate::methods::MAP_UNWRAP_OR_INFO,
+    crate::methods::MIN_MAX_BY_PARTIAL_CMP_UNWRAP_INFO,
     crate::methods::MUT_MUTEX_LOCK_INFO,
     crate::methods::NAIVE_BYTECOUNT_INFO,
     crate::methods::NEEDLESS_COLLECT_INFO,
@@ -380,14 +435,23 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::methods::OBFUSCATED_IF_ELSE_INFO,
     crate::methods::OK_EXPECT_INFO,
     crate::methods::OPTION_AS_REF_DEREF_INFO,
+    crate::methods::OPTION_AS_REF_MAP_CLONE_CHAIN_INFO,
     crate::methods::OPTION_FILTER_MAP_INFO,
+    crate::methods::OPTION_FILTER_MAP_BOOL_INFO,
     crate::methods::OPTION_MAP_OR_NONE_INFO,
+    crate::methods::OPTION_ZIP_THEN_MAP_TUPLE_INFO,
     crate::methods::OR_FUN_CALL_INFO,
     crate::methods::OR_THEN_UNWRAP_INFO,
     crate::methods::PATH_BUF_PUSH_OVERWRITE_INFO,
+    crate::methods::PATH_JOIN_WITH_ABSOLUTE_LITERAL_INFO,
+    crate::methods::POINTER_ARITHMETIC_WRAPPING_OFFSET_MISUSE_INFO,
     crate::methods::RANGE_ZIP_WITH_LEN_INFO,
+    crate::methods::READ_LINE_WITHOUT_CLEAR_INFO,
+    crate::methods::REDUNDANT_SORT_BEFORE_EXTREME_INFO,
     crate::methods::REPEAT_ONCE_INFO,
     crate::methods::RESULT_MAP_OR_INTO_OPTION_INFO,
+    crate::methods::RESULT_OK_THEN_UNWRAP_CHAIN_INFO,
+    crate::methods::RETAIN_WITH_CONSTANT_PREDICATE_INFO,
     crate::methods::SEARCH_IS_SOME_INFO,
     crate::methods::SEEK_FROM_CURRENT_INFO,
     crate::methods::SEEK_TO_START_INSTEAD_OF_REWIND_INFO,
@@ -395,12 +459,18 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::methods::SINGLE_CHAR_ADD_STR_INFO,
     crate::methods::SINGLE_CHAR_PATTERN_INFO,
     crate::methods::SKIP_WHILE_NEXT_INFO,
+    crate::methods::SORT_UNSTABLE_CANDIDATE_INFO,
+    crate::methods::SPLIT_WHITESPACE_COLLECT_COUNT_INFO,
     crate::methods::STABLE_SORT_PRIMITIVE_INFO,
     crate::methods::STRING_EXTEND_CHARS_INFO,
+    crate::methods::STRING_FROM_UTF8_LOSSY_INTO_OWNED_ROUNDTRIP_INFO,
     crate::methods::SUSPICIOUS_COMMAND_ARG_SPACE_INFO,
     crate::methods::SUSPICIOUS_MAP_INFO,
     crate::methods::SUSPICIOUS_SPLITN_INFO,
     crate::methods::SUSPICIOUS_TO_OWNED_INFO,
+    crate::methods::SYNC_MUTEX_IN_ASYNC_CONTEXT_INFO,
+    crate::methods::THREAD_SPAWN_JOIN_IMMEDIATELY_INFO,
+    crate::methods::UNBUFFERED_FILE_IO_IN_LOOP_INFO,
     crate::methods::UNINIT_ASSUMED_INIT_INFO,
     crate::methods::UNIT_HASH_INFO,
     crate::methods::UNNECESSARY_FILTER_MAP_INFO,
@@ -415,6 +485,7 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::methods::UNWRAP_USED_INFO,
     crate::methods::USELESS_ASREF_INFO,
     crate::methods::VEC_RESIZE_TO_ZERO_INFO,
+    crate::methods::VEC_RESIZE_ZERO_THEN_EXTEND_INFO,
     crate::methods::VERBOSE_FILE_READS_INFO,
     crate::methods::WRONG_SELF_CONVENTION_INFO,
     crate::methods::ZST_OFFSET_INFO,
@@ -448,6 +519,7 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::module_style::SELF_NAMED_MODULE_FILES_INFO,
     crate::multi_assignments::MULTI_ASSIGNMENTS_INFO,
     crate::multiple_unsafe_ops_per_block::MULTIPLE_UNSAFE_OPS_PER_BLOCK_INFO,
+    crate::mut_key::INTERIOR_MUTABLE_KEY_EXTENDED_INFO,
     crate::mut_key::MUTABLE_KEY_TYPE_INFO,
     crate::mut_mut::MUT_MUT_INFO,
     crate::mut_reference::UNNECESSARY_MUT_PASSED_INFO,
@@ -470,6 +542,7 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::needless_update::NEEDLESS_UPDATE_INFO,
     crate::neg_cmp_op_on_partial_ord::NEG_CMP_OP_ON_PARTIAL_ORD_INFO,
     crate::neg_multiply::NEG_MULTIPLY_INFO,
+    crate::nested_option_result_flattenable::NESTED_OPTION_RESULT_FLATTENABLE_INFO,
     crate::new_without_default::NEW_WITHOUT_DEFAULT_INFO,
     crate::no_effect::NO_EFFECT_INFO,
     crate::no_effect::NO_EFFECT_UNDERSCORE_BINDING_INFO,
@@ -480,6 +553,7 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::non_expressive_names::JUST_UNDERSCORES_AND_DIGITS_INFO,
     crate::non_expressive_names::MANY_SINGLE_CHAR_NAMES_INFO,
     crate::non_expressive_names::SIMILAR_NAMES_INFO,
+    crate::non_monotonic_time_for_durations::NON_MONOTONIC_TIME_FOR_DURATIONS_INFO,
     crate::non_octal_unix_permissions::NON_OCTAL_UNIX_PERMISSIONS_INFO,
     crate::non_send_fields_in_send_ty::NON_SEND_FIELDS_IN_SEND_TY_INFO,
     crate::nonstandard_macro_braces::NONSTANDARD_MACRO_BRACES_INFO,
@@ -507,6 +581,7 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::operators::MODULO_ONE_INFO,
     crate::operators::NEEDLESS_BITWISE_BOOL_INFO,
     crate::operators::OP_REF_INFO,
+    crate::operators::OSSTR_LOSSY_COMPARISON_INFO,
     crate::operators::PTR_EQ_INFO,
     crate::operators::SELF_ASSIGNMENT_INFO,
     crate::operators::VERBOSE_BIT_MASK_INFO,
@@ -518,6 +593,7 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::panic_unimplemented::TODO_INFO,
     crate::panic_unimplemented::UNIMPLEMENTED_INFO,
     crate::panic_unimplemented::UNREACHABLE_INFO,
+    crate::partial_ord_inconsistent_with_ord::PARTIAL_ORD_INCONSISTENT_WITH_ORD_INFO,
     crate::partial_pub_fields::PARTIAL_PUB_FIELDS_INFO,
     crate::partialeq_ne_impl::PARTIALEQ_NE_IMPL_INFO,
     crate::partialeq_to_none::PARTIALEQ_TO_NONE_INFO,
@@ -539,6 +615,7 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::ranges::RANGE_PLUS_ONE_INFO,
     crate::ranges::REVERSED_EMPTY_RANGES_INFO,
     crate::rc_clone_in_vec_init::RC_CLONE_IN_VEC_INIT_INFO,
+    crate::rc_in_send_context::RC_IN_SEND_CONTEXT_INFO,
     crate::read_zero_byte_vec::READ_ZERO_BYTE_VEC_INFO,
     crate::redundant_async_block::REDUNDANT_ASYNC_BLOCK_INFO,
     crate::redundant_clone::REDUNDANT_CLONE_INFO,
@@ -554,15 +631,20 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::ref_patterns::REF_PATTERNS_INFO,
     crate::reference::DEREF_ADDROF_INFO,
     crate::regex::INVALID_REGEX_INFO,
+    crate::regex::REGEX_COMPILED_IN_LOOP_OR_HOT_FN_INFO,
     crate::regex::TRIVIAL_REGEX_INFO,
+    crate::repeated_push_str_literal::REPEATED_PUSH_STR_LITERAL_INFO,
+    crate::repr_c_enum_with_implicit_discriminants_ffi::REPR_C_ENUM_WITH_IMPLICIT_DISCRIMINANTS_FFI_INFO,
     crate::return_self_not_must_use::RETURN_SELF_NOT_MUST_USE_INFO,
     crate::returns::LET_AND_RETURN_INFO,
     crate::returns::NEEDLESS_RETURN_INFO,
     crate::same_name_method::SAME_NAME_METHOD_INFO,
+    crate::select_without_biased_or_loop_guard::SELECT_WITHOUT_BIASED_OR_LOOP_GUARD_INFO,
     crate::self_named_constructors::SELF_NAMED_CONSTRUCTORS_INFO,
     crate::semicolon_block::SEMICOLON_INSIDE_BLOCK_INFO,
     crate::semicolon_block::SEMICOLON_OUTSIDE_BLOCK_INFO,
     crate::semicolon_if_nothing_returned::SEMICOLON_IF_NOTHING_RETURNED_INFO,
+    crate::send_non_send_across_spawn::SEND_NON_SEND_ACROSS_SPAWN_INFO,
     crate::serde_api::SERDE_API_MISUSE_INFO,
     crate::shadow::SHADOW_REUSE_INFO,
     crate::shadow::SHADOW_SAME_INFO,
@@ -572,7 +654,9 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::single_component_path_imports::SINGLE_COMPONENT_PATH_IMPORTS_INFO,
     crate::size_of_in_element_count::SIZE_OF_IN_ELEMENT_COUNT_INFO,
     crate::size_of_ref::SIZE_OF_REF_INFO,
+    crate::slice_from_raw_parts_len_overflow::SLICE_FROM_RAW_PARTS_LEN_OVERFLOW_INFO,
     crate::slow_vector_initialization::SLOW_VECTOR_INITIALIZATION_INFO,
+    crate::spawn_blocking_async_closure::SPAWN_BLOCKING_ASYNC_CLOSURE_INFO,
     crate::std_instead_of_core::ALLOC_INSTEAD_OF_CORE_INFO,
     crate::std_instead_of_core::STD_INSTEAD_OF_ALLOC_INFO,
     crate::std_instead_of_core::STD_INSTEAD_OF_CORE_INFO,
@@ -597,11 +681,13 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::temporary_assignment::TEMPORARY_ASSIGNMENT_INFO,
     crate::tests_outside_test_module::TESTS_OUTSIDE_TEST_MODULE_INFO,
     crate::to_digit_is_some::TO_DIGIT_IS_SOME_INFO,
+    crate::to_owned_then_as_ref_argument::TO_OWNED_THEN_AS_REF_ARGUMENT_INFO,
     crate::trailing_empty_array::TRAILING_EMPTY_ARRAY_INFO,
     crate::trait_bounds::TRAIT_DUPLICATION_IN_BOUNDS_INFO,
     crate::trait_bounds::TYPE_REPETITION_IN_BOUNDS_INFO,
     crate::transmute::CROSSPOINTER_TRANSMUTE_INFO,
     crate::transmute::TRANSMUTES_EXPRESSIBLE_AS_PTR_CASTS_INFO,
+    crate::transmute::TRANSMUTE_BETWEEN_REPR_RUST_TYPES_INFO,
     crate::transmute::TRANSMUTE_BYTES_TO_STR_INFO,
     crate::transmute::TRANSMUTE_FLOAT_TO_INT_INFO,
     crate::transmute::TRANSMUTE_INT_TO_BOOL_INFO,
@@ -632,6 +718,7 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::unicode::NON_ASCII_LITERAL_INFO,
     crate::unicode::UNICODE_NOT_NFC_INFO,
     crate::uninit_vec::UNINIT_VEC_INFO,
+    crate::union_field_access_without_cfg_layout::UNION_FIELD_ACCESS_WITHOUT_CFG_LAYOUT_INFO,
     crate::unit_return_expecting_ord::UNIT_RETURN_EXPECTING_ORD_INFO,
     crate::unit_types::LET_UNIT_VALUE_INFO,
     crate::unit_types::UNIT_ARG_INFO,
@@ -644,7 +731,9 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::unnecessary_struct_initialization::UNNECESSARY_STRUCT_INITIALIZATION_INFO,
     crate::unnecessary_wraps::UNNECESSARY_WRAPS_INFO,
     crate::unnested_or_patterns::UNNESTED_OR_PATTERNS_INFO,
+    crate::unpolled_future_created::UNPOLLED_FUTURE_CREATED_INFO,
     crate::unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME_INFO,
+    crate::unsound_send_sync_impl_heuristic::UNSOUND_SEND_SYNC_IMPL_HEURISTIC_INFO,
     crate::unused_async::UNUSED_ASYNC_INFO,
     crate::unused_io_amount::UNUSED_IO_AMOUNT_INFO,
     crate::unused_peekable::UNUSED_PEEKABLE_INFO,
@@ -659,6 +748,7 @@ pub(crate) static LINTS: &[&crate::LintInfo] = &[
     crate::useless_conversion::USELESS_CONVERSION_INFO,
     crate::vec::USELESS_VEC_INFO,
     crate::vec_init_then_push::VEC_INIT_THEN_PUSH_INFO,
+    crate::volatile_ordinary_read_write::VOLATILE_ORDINARY_READ_WRITE_INFO,
     crate::wildcard_imports::ENUM_GLOB_USE_INFO,
     crate::wildcard_imports::WILDCARD_IMPORTS_INFO,
     crate::write::PRINTLN_EMPTY_STRING_INFO,
@@ -0,0 +1,172 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::ty::implements_trait_with_env;
+use clippy_utils::{match_def_path, paths};
+use rustc_ast::{Attribute, NestedMetaItem};
+use rustc_hir::{Impl, Item, ItemKind, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks structs deriving `serde::Deserialize` for:
+    /// * fields with `#[serde(skip)]` or `#[serde(skip_deserializing)]` whose type doesn't always
+    ///   implement `Default`, and which don't have a `#[serde(default = "...")]` attribute
+    /// * `#[serde(flatten)]` fields in structs with `#[serde(deny_unknown_fields)]`, or of types
+    ///   with it
+    ///
+    /// ### Why is this bad?
+    /// Skipped fields are initialized with `Default::default()`. For fields of generic types,
+    /// serde adds a `T: Default` bound to the derived implementation, so the struct silently
+    /// doesn't implement `Deserialize` for the other types.
+    ///
+    /// `deny_unknown_fields` isn't supported together with `flatten`: the fields of the
+    /// flattened struct are reported as unknown, and deserializing fails at runtime.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use serde::Deserialize;
+    /// # use std::collections::HashMap;
+    /// #[derive(Deserialize)]
+    /// struct Cache<T> {
+    ///     name: String,
+    ///     #[serde(skip)]
+    ///     entries: Option<T>,
+    /// }
+    ///
+    /// #[derive(Deserialize)]
+    /// #[serde(deny_unknown_fields)]
+    /// struct Config {
+    ///     name: String,
+    ///     #[serde(flatten)]
+    ///     extra: HashMap<String, String>,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use serde::Deserialize;
+    /// # use std::collections::HashMap;
+    /// #[derive(Deserialize)]
+    /// struct Cache<T> {
+    ///     name: String,
+    ///     #[serde(skip, default = "Option::default")]
+    ///     entries: Option<T>,
+    /// }
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Config {
+    ///     name: String,
+    ///     #[serde(flatten)]
+    ///     extra: HashMap<String, String>,
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub DESERIALIZE_SKIPPED_FIELD_WITHOUT_DEFAULT,
+    suspicious,
+    "a derived `Deserialize` with skipped fields which can't be defaulted, or with `flatten` and `deny_unknown_fields`"
+}
+
+declare_lint_pass!(DeserializeSkippedFieldWithoutDefault => [DESERIALIZE_SKIPPED_FIELD_WITHOUT_DEFAULT]);
+
+impl<'tcx> LateLintPass<'tcx> for DeserializeSkippedFieldWithoutDefault {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        let ItemKind::Impl(Impl {
+            of_trait: Some(ref trait_ref),
+            ..
+        }) = item.kind
+        else {
+            return;
+        };
+        if !cx.tcx.has_attr(item.owner_id, sym::automatically_derived)
+            || !trait_ref
+                .trait_def_id()
+                .map_or(false, |id| match_def_path(cx, id, &paths::SERDE_DESERIALIZE))
+        {
+            return;
+        }
+        let ty::Adt(adt, _) = cx.tcx.type_of(item.owner_id).subst_identity().kind() else {
+            return;
+        };
+        let Some(Node::Item(Item {
+            kind: ItemKind::Struct(data, _),
+            owner_id,
+            ..
+        })) = cx.tcx.hir().get_if_local(adt.did())
+        else {
+            return;
+        };
+
+        let container_attrs = cx.tcx.hir().attrs((*owner_id).into());
+        let container_default = serde_meta(container_attrs, "default").is_some();
+        let deny_unknown_fields = serde_meta(container_attrs, "deny_unknown_fields");
+        let param_env = cx.tcx.param_env(adt.did());
+
+        for field in data.fields() {
+            let attrs = cx.tcx.hir().attrs(field.hir_id);
+            let field_ty = cx.tcx.type_of(field.def_id).subst_identity();
+
+            if (serde_meta(attrs, "skip").is_some() || serde_meta(attrs, "skip_deserializing").is_some())
+                && !container_default
+                && serde_meta(attrs, "default").is_none()
+                && let Some(default_trait) = cx.tcx.get_diagnostic_item(sym::Default)
+                && !implements_trait_with_env(cx.tcx, param_env, field_ty, default_trait, [])
+            {
+                span_lint_hir_and_then(
+                    cx,
+                    DESERIALIZE_SKIPPED_FIELD_WITHOUT_DEFAULT,
+                    field.hir_id,
+                    field.span,
+                    &format!(
+                        "this field is skipped when deserializing, but `{field_ty}` doesn't always implement `Default`"
+                    ),
+                    |diag| {
+                        diag.note(format!(
+                            "the derived `Deserialize` implementation is only implemented if `{field_ty}: Default`"
+                        ));
+                        diag.help("initialize the field with `#[serde(default = \"...\")]`");
+                    },
+                );
+            }
+
+            if serde_meta(attrs, "flatten").is_some() {
+                let deny_span = deny_unknown_fields.or_else(|| flattened_deny_unknown_fields(cx, field_ty));
+                if let Some(deny_span) = deny_span {
+                    span_lint_hir_and_then(
+                        cx,
+                        DESERIALIZE_SKIPPED_FIELD_WITHOUT_DEFAULT,
+                        field.hir_id,
+                        field.span,
+                        "`#[serde(flatten)]` isn't supported together with `#[serde(deny_unknown_fields)]`",
+                        |diag| {
+                            diag.span_note(deny_span, "unknown fields are denied here");
+                            diag.note(
+                                "deserializing fails at runtime, because the fields of one struct are unknown to the other",
+                            );
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Finds `name` in the `#[serde(..)]` attributes, returning its span.
+fn serde_meta(attrs: &[Attribute], name: &str) -> Option<Span> {
+    attrs
+        .iter()
+        .filter(|attr| attr.ident().map_or(false, |ident| ident.as_str() == "serde"))
+        .filter_map(Attribute::meta_item_list)
+        .flatten()
+        .find(|meta| meta.name_or_empty().as_str() == name)
+        .map(|meta: NestedMetaItem| meta.span())
+}
+
+/// Finds `#[serde(deny_unknown_fields)]` on the local struct `ty`.
+fn flattened_deny_unknown_fields(cx: &LateContext<'_>, ty: Ty<'_>) -> Option<Span> {
+    let ty::Adt(adt, _) = ty.kind() else {
+        return None;
+    };
+    let hir_id = cx.tcx.hir().local_def_id_to_hir_id(adt.did().as_local()?);
+    serde_meta(cx.tcx.hir().attrs(hir_id), "deny_unknown_fields")
+}
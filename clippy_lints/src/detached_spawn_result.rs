@@ -0,0 +1,91 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{def_path_def_ids, match_def_path, path_def_id, paths};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{ExprKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for statements like `tokio::spawn(task);` and `async_std::task::spawn(task);`,
+    /// which drop the returned `JoinHandle` right away.
+    ///
+    /// ### Why is this bad?
+    /// Dropping the handle detaches the task. If it panics or returns an error, nobody notices.
+    ///
+    /// Functions which are meant to detach tasks can be listed in
+    /// `allowed-detached-spawn-wrappers`.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// tokio::spawn(async { save().await.unwrap() });
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let handle = tokio::spawn(async { save().await.unwrap() });
+    /// // ..
+    /// handle.await?;
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub DETACHED_SPAWN_RESULT,
+    pedantic,
+    "dropping the `JoinHandle` of a spawned task right away"
+}
+
+pub struct DetachedSpawnResult {
+    conf_allowed_wrappers: Vec<String>,
+    allowed_wrappers: FxHashSet<DefId>,
+}
+
+impl DetachedSpawnResult {
+    pub fn new(conf_allowed_wrappers: Vec<String>) -> Self {
+        Self {
+            conf_allowed_wrappers,
+            allowed_wrappers: FxHashSet::default(),
+        }
+    }
+}
+
+impl_lint_pass!(DetachedSpawnResult => [DETACHED_SPAWN_RESULT]);
+
+impl<'tcx> LateLintPass<'tcx> for DetachedSpawnResult {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for path in &self.conf_allowed_wrappers {
+            let segs: Vec<_> = path.split("::").collect();
+            self.allowed_wrappers.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'tcx>) {
+        if let StmtKind::Semi(e) = stmt.kind
+            && let ExprKind::Call(func, [_]) = e.kind
+            && !in_external_macro(cx.sess(), stmt.span)
+            && let Some(id) = path_def_id(cx, func)
+            && let Some(spawn) = spawn_fn(cx, id)
+            && !self
+                .allowed_wrappers
+                .contains(&cx.tcx.hir().get_parent_item(stmt.hir_id).to_def_id())
+        {
+            span_lint_and_help(
+                cx,
+                DETACHED_SPAWN_RESULT,
+                e.span,
+                &format!("the `JoinHandle` returned by `{spawn}` is dropped right away"),
+                None,
+                "panics and errors of the task are lost; consider keeping the handle and awaiting it",
+            );
+        }
+    }
+}
+
+fn spawn_fn(cx: &LateContext<'_>, id: DefId) -> Option<&'static str> {
+    if match_def_path(cx, id, &paths::TOKIO_SPAWN) {
+        Some("tokio::spawn")
+    } else if match_def_path(cx, id, &paths::ASYNC_STD_TASK_SPAWN) {
+        Some("async_std::task::spawn")
+    } else {
+        None
+    }
+}
@@ -0,0 +1,165 @@
+use crate::iter_on_locked_collection::is_place;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{get_parent_expr, path_to_local_id, SpanlessEq};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::{Block, Expr, ExprKind, HirId, PatKind, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a `Mutex` or `RwLock` which is locked while a guard of the same lock, bound to
+    /// a variable earlier in the block, is still alive. Two `read` locks of an `RwLock` are
+    /// allowed.
+    ///
+    /// ### Why is this bad?
+    /// The locks of `std::sync` aren't reentrant, so the second lock waits for the first guard to
+    /// be dropped, which never happens. This deadlocks or panics.
+    ///
+    /// ### Known problems
+    /// Only the locks in the same function are checked, not the ones in functions it calls. The
+    /// lock is recognized by the path to it, so the same `Mutex` reached through different paths
+    /// isn't linted.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # use std::sync::Mutex;
+    /// # let jobs = Mutex::new(vec![1]);
+    /// let guard = jobs.lock().unwrap();
+    /// for job in guard.iter() {
+    ///     jobs.lock().unwrap().push(job + 1);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// # let jobs = Mutex::new(vec![1]);
+    /// let mut guard = jobs.lock().unwrap();
+    /// for i in 0..guard.len() {
+    ///     let job = guard[i];
+    ///     guard.push(job + 1);
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub DOUBLE_LOCK_SAME_MUTEX,
+    suspicious,
+    "locking a `Mutex` or an `RwLock` while a guard of it is still alive"
+}
+
+#[derive(Default)]
+pub struct DoubleLockSameMutex {
+    /// The locks already linted as part of an outer block.
+    linted: FxHashSet<HirId>,
+}
+
+impl_lint_pass!(DoubleLockSameMutex => [DOUBLE_LOCK_SAME_MUTEX]);
+
+impl<'tcx> LateLintPass<'tcx> for DoubleLockSameMutex {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if block.span.from_expansion() {
+            return;
+        }
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            let StmtKind::Local(local) = stmt.kind else {
+                continue;
+            };
+            let (PatKind::Binding(_, guard_id, guard, None), Some(init)) = (local.pat.kind, local.init) else {
+                continue;
+            };
+            let init = match init.kind {
+                ExprKind::MethodCall(path, recv, ..) if matches!(path.ident.as_str(), "unwrap" | "expect") => recv,
+                _ => init,
+            };
+            let Some((mutex, method)) = lock_call(cx, init) else {
+                continue;
+            };
+
+            let rest = block.stmts[i + 1..]
+                .iter()
+                .filter_map(|stmt| match stmt.kind {
+                    StmtKind::Local(local) => local.init,
+                    StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+                    StmtKind::Item(_) => None,
+                })
+                .chain(block.expr);
+            for e in rest {
+                let released = for_each_expr(e, |e| {
+                    // closures may be run after the guard is dropped
+                    if let ExprKind::Closure(_) = e.kind {
+                        return ControlFlow::Continue(Descend::No);
+                    }
+                    if path_to_local_id(e, guard_id) && !is_borrowed(cx, e) {
+                        return ControlFlow::Break(());
+                    }
+                    if let Some((other, other_method)) = lock_call(cx, e)
+                        && (method.as_str() != "read" || other_method.as_str() != "read")
+                        && SpanlessEq::new(cx).eq_expr(mutex, other)
+                        && self.linted.insert(e.hir_id)
+                    {
+                        span_lint_and_then(
+                            cx,
+                            DOUBLE_LOCK_SAME_MUTEX,
+                            e.span,
+                            &format!(
+                                "`{}` is locked while it's already locked",
+                                snippet(cx, other.span, "..")
+                            ),
+                            |diag| {
+                                diag.span_note(
+                                    local.span,
+                                    format!("first locked here, and held until `{guard}` is dropped"),
+                                );
+                                diag.note("the locks of `std::sync` aren't reentrant, so this deadlocks or panics");
+                                diag.help(format!("drop `{guard}` before locking again, or use it instead"));
+                            },
+                        );
+                    }
+                    ControlFlow::Continue(Descend::Yes)
+                })
+                .is_some();
+                if released {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// If `e` is `m.lock()` on a `Mutex`, or `m.read()` or `m.write()` on an `RwLock`, returns the
+/// lock and the name of the method.
+fn lock_call<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, Symbol)> {
+    let ExprKind::MethodCall(method, lock, [], _) = e.kind else {
+        return None;
+    };
+    if e.span.from_expansion() || !is_place(lock) {
+        return None;
+    }
+    let impl_ty = cx
+        .typeck_results()
+        .type_dependent_def_id(e.hir_id)
+        .and_then(|id| cx.tcx.impl_of_method(id))
+        .map(|impl_id| cx.tcx.type_of(impl_id).subst_identity())?;
+    let is_lock_method = match method.ident.as_str() {
+        "lock" => is_type_diagnostic_item(cx, impl_ty, sym::Mutex),
+        "read" | "write" => is_type_diagnostic_item(cx, impl_ty, sym::RwLock),
+        _ => false,
+    };
+    is_lock_method.then_some((lock, method.ident.name))
+}
+
+/// Whether the guard `e` is only borrowed, rather than moved, for example by `drop(guard)`.
+fn is_borrowed(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    get_parent_expr(cx, e).map_or(false, |parent| match parent.kind {
+        ExprKind::MethodCall(_, recv, ..) => recv.hir_id == e.hir_id,
+        ExprKind::Field(base, _)
+        | ExprKind::Index(base, _)
+        | ExprKind::Unary(UnOp::Deref, base)
+        | ExprKind::AddrOf(_, _, base) => base.hir_id == e.hir_id,
+        _ => false,
+    })
+}
@@ -0,0 +1,180 @@
+use clippy_utils::consts::constant;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::{is_panic, root_macro_call_first_node};
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{def_path_def_ids, fn_def_id, higher};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, Expr, ExprKind, FnDecl};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Drop::drop` implementations which may panic, by calling `unwrap` or `expect`,
+    /// indexing or slicing, using `panic!` or `assert!`, or calling other `#[track_caller]`
+    /// functions, which panic on the caller's behalf.
+    ///
+    /// ### Why is this bad?
+    /// Values are also dropped while the thread unwinds from another panic. Panicking in `drop`
+    /// then aborts the whole process, and the original panic message may never be printed.
+    ///
+    /// ### Configuration
+    /// Functions which are known not to panic in practice can be allowed with the
+    /// `allowed-drop-panic-functions` configuration.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::fs::File;
+    /// # use std::io::Write;
+    /// struct Log(File);
+    ///
+    /// impl Drop for Log {
+    ///     fn drop(&mut self) {
+    ///         self.0.flush().unwrap();
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::fs::File;
+    /// # use std::io::Write;
+    /// struct Log(File);
+    ///
+    /// impl Drop for Log {
+    ///     fn drop(&mut self) {
+    ///         if let Err(e) = self.0.flush() {
+    ///             eprintln!("failed to flush the log: {e}");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub DROP_IMPL_MAY_PANIC,
+    pedantic,
+    "a `Drop` implementation which may panic"
+}
+
+pub struct DropImplMayPanic {
+    conf_allowed_functions: Vec<String>,
+    allowed_functions: FxHashSet<DefId>,
+}
+
+impl DropImplMayPanic {
+    pub fn new(conf_allowed_functions: Vec<String>) -> Self {
+        Self {
+            conf_allowed_functions,
+            allowed_functions: FxHashSet::default(),
+        }
+    }
+
+    fn find_panics<'tcx>(&self, cx: &LateContext<'tcx>, body: &'tcx Body<'tcx>) -> Vec<Span> {
+        let mut panics = Vec::new();
+        let _: Option<!> = for_each_expr(body.value, |e| {
+            if let Some(macro_call) = root_macro_call_first_node(cx, e) {
+                if is_panic(cx, macro_call.def_id)
+                    || matches!(
+                        cx.tcx.item_name(macro_call.def_id).as_str(),
+                        "unimplemented" | "unreachable" | "todo" | "assert" | "assert_eq" | "assert_ne"
+                    )
+                {
+                    panics.push(macro_call.span);
+                    return ControlFlow::Continue(Descend::No);
+                }
+            } else if let ExprKind::Closure(_) = e.kind {
+                // closures may be run elsewhere
+                return ControlFlow::Continue(Descend::No);
+            } else if !e.span.from_expansion() && self.may_panic(cx, e) {
+                panics.push(e.span);
+            }
+            ControlFlow::Continue(Descend::Yes)
+        });
+        panics
+    }
+
+    fn may_panic<'tcx>(&self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> bool {
+        match e.kind {
+            // this includes `Option::unwrap`, `Result::expect` and so on
+            ExprKind::Call(..) | ExprKind::MethodCall(..) => fn_def_id(cx, e).map_or(false, |id| {
+                matches!(cx.tcx.def_kind(id), DefKind::Fn | DefKind::AssocFn)
+                    && cx
+                        .tcx
+                        .codegen_fn_attrs(id)
+                        .flags
+                        .contains(CodegenFnAttrFlags::TRACK_CALLER)
+                    && !self.allowed_functions.contains(&id)
+            }),
+            ExprKind::Index(base, index) => {
+                if let Some(range) = higher::Range::hir(index) {
+                    // `[..]` can't be out of bounds
+                    return range.start.is_some() || range.end.is_some();
+                }
+                // rustc already checks constant indices into arrays
+                let typeck = cx.typeck_results();
+                !(matches!(typeck.expr_ty_adjusted(base).peel_refs().kind(), ty::Array(..))
+                    && constant(cx, typeck, index).is_some())
+            },
+            _ => false,
+        }
+    }
+}
+
+impl_lint_pass!(DropImplMayPanic => [DROP_IMPL_MAY_PANIC]);
+
+impl<'tcx> LateLintPass<'tcx> for DropImplMayPanic {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for path in &self.conf_allowed_functions {
+            let segs: Vec<_> = path.split("::").collect();
+            self.allowed_functions.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if !matches!(kind, FnKind::Method(..))
+            || in_external_macro(cx.sess(), span)
+            || cx
+                .tcx
+                .impl_of_method(def_id.to_def_id())
+                .and_then(|impl_id| cx.tcx.trait_id_of_impl(impl_id))
+                != cx.tcx.lang_items().drop_trait()
+        {
+            return;
+        }
+
+        let panics = self.find_panics(cx, body);
+        if panics.is_empty() {
+            return;
+        }
+        span_lint_and_then(
+            cx,
+            DROP_IMPL_MAY_PANIC,
+            cx.tcx.def_span(def_id),
+            "this `Drop` implementation may panic",
+            |diag| {
+                diag.span_note(
+                    panics,
+                    "panicking here while the thread is already unwinding aborts the process",
+                );
+                diag.help(
+                    "log or ignore the errors instead, or handle them in a method which is called before dropping",
+                );
+            },
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, UintTy};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `Duration::from_secs_f64` and `Duration::from_secs_f32` with an
+    /// integer number of milliseconds, microseconds or nanoseconds, which is converted to seconds
+    /// by dividing it.
+    ///
+    /// ### Why is this bad?
+    /// The division and the conversion back from seconds round the value, so the resulting
+    /// `Duration` may not be exact. `Duration::from_millis`, `Duration::from_micros` and
+    /// `Duration::from_nanos` take the integer directly.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// let millis: u64 = 1500;
+    /// let timeout = Duration::from_secs_f64(millis as f64 / 1000.0);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::time::Duration;
+    /// let millis: u64 = 1500;
+    /// let timeout = Duration::from_millis(millis);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub DURATION_FROM_FLOAT_SECONDS_PRECISION,
+    complexity,
+    "creating a `Duration` from float seconds computed from integer milliseconds or nanoseconds"
+}
+
+declare_lint_pass!(DurationFromFloatSecondsPrecision => [DURATION_FROM_FLOAT_SECONDS_PRECISION]);
+
+impl<'tcx> LateLintPass<'tcx> for DurationFromFloatSecondsPrecision {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(func, [arg]) = expr.kind
+            && let ExprKind::Path(QPath::TypeRelative(ty, segment)) = func.kind
+            && matches!(segment.ident.as_str(), "from_secs_f64" | "from_secs_f32")
+            && is_type_diagnostic_item(cx, cx.typeck_results().node_type(ty.hir_id), sym::Duration)
+            && let ExprKind::Binary(op, num, den) = arg.kind
+            && op.node == BinOpKind::Div
+            && let ExprKind::Cast(int, _) = num.kind
+            && let ty::Uint(int_ty) = *cx.typeck_results().expr_ty(int).kind()
+            && let Some(constructor) = integer_constructor(cx, den)
+            && !expr.span.from_expansion()
+        {
+            let mut app = Applicability::MachineApplicable;
+            let int = snippet_with_context(cx, int.span, expr.span.ctxt(), "..", &mut app).0;
+            let int = match int_ty {
+                UintTy::U64 => int.into_owned(),
+                UintTy::U8 | UintTy::U16 | UintTy::U32 => format!("u64::from({int})"),
+                UintTy::Usize => format!("{int} as u64"),
+                UintTy::U128 => return,
+            };
+            span_lint_and_then(
+                cx,
+                DURATION_FROM_FLOAT_SECONDS_PRECISION,
+                expr.span,
+                &format!("converting an integer to seconds to call `{}`", segment.ident),
+                |diag| {
+                    diag.multipart_suggestion(
+                        format!("use `{constructor}` to create the `Duration` exactly"),
+                        vec![(segment.ident.span, constructor.to_owned()), (arg.span, int)],
+                        app,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Returns the constructor taking the unit which is converted to seconds by dividing by `den`.
+#[expect(clippy::float_cmp)]
+fn integer_constructor(cx: &LateContext<'_>, den: &Expr<'_>) -> Option<&'static str> {
+    let den = match constant(cx, cx.typeck_results(), den)? {
+        Constant::F64(value) => value,
+        Constant::F32(value) => f64::from(value),
+        _ => return None,
+    };
+    if den == 1e3 {
+        Some("from_millis")
+    } else if den == 1e6 {
+        Some("from_micros")
+    } else if den == 1e9 {
+        Some("from_nanos")
+    } else {
+        None
+    }
+}
@@ -0,0 +1,124 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{expr_or_init, match_def_path, path_def_id, paths};
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, MatchSource};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `unwrap` on the result of `std::env::var` and `std::env::var_os`, and on the
+    /// result of parsing their values.
+    ///
+    /// ### Why is this bad?
+    /// The panic message doesn't name the environment variable, e.g. it's only
+    /// ``called `Result::unwrap()` on an `Err` value: NotPresent``, which makes it hard to find
+    /// out which variable is missing or invalid.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// let port: u16 = std::env::var("PORT").unwrap().parse().unwrap();
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// let port: u16 = std::env::var("PORT")
+    ///     .expect("environment variable `PORT` should be set")
+    ///     .parse()
+    ///     .expect("environment variable `PORT` should be a valid `u16`");
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub ENV_VAR_PARSED_WITHOUT_ERROR_CONTEXT,
+    pedantic,
+    "unwrapping an environment variable, or its parsed value, without naming the variable"
+}
+
+declare_lint_pass!(EnvVarParsedWithoutErrorContext => [ENV_VAR_PARSED_WITHOUT_ERROR_CONTEXT]);
+
+impl<'tcx> LateLintPass<'tcx> for EnvVarParsedWithoutErrorContext {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let ExprKind::MethodCall(path, recv, [], _) = expr.kind else {
+            return;
+        };
+        if path.ident.name != sym::unwrap || expr.span.from_expansion() {
+            return;
+        }
+
+        let (msg, name, expectation) = if let Some(name) = env_var_call(cx, expr_or_init(cx, recv)) {
+            (
+                "unwrapping an environment variable without naming it",
+                name,
+                String::from("should be set"),
+            )
+        } else if let ExprKind::MethodCall(parse, value, [], _) = recv.kind
+            && parse.ident.as_str() == "parse"
+            && let Some(name) = env_var_value(cx, value)
+            && let ty::Adt(_, substs) = cx.typeck_results().expr_ty(recv).kind()
+        {
+            (
+                "unwrapping the parsed value of an environment variable without naming it",
+                name,
+                format!("should be a valid `{}`", substs.type_at(0)),
+            )
+        } else {
+            return;
+        };
+
+        span_lint_and_then(cx, ENV_VAR_PARSED_WITHOUT_ERROR_CONTEXT, expr.span, msg, |diag| {
+            diag.note("the panic message doesn't name the environment variable");
+            if let ExprKind::Lit(lit) = name.kind
+                && let LitKind::Str(name, _) = lit.node
+            {
+                let expect = format!("environment variable `{name}` {expectation}");
+                diag.span_suggestion(
+                    path.ident.span.with_hi(expr.span.hi()),
+                    "name the variable with `expect`",
+                    format!("expect({expect:?})"),
+                    Applicability::MachineApplicable,
+                );
+            } else {
+                diag.help("name the variable with `expect`, or return an error which names it");
+            }
+        });
+    }
+}
+
+/// Returns `name` for `std::env::var(name)` and `std::env::var_os(name)`.
+fn env_var_call<'tcx>(cx: &LateContext<'_>, e: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::Call(func, [name]) = e.kind
+        && let Some(id) = path_def_id(cx, func)
+        && (match_def_path(cx, id, &paths::ENV_VAR) || match_def_path(cx, id, &paths::ENV_VAR_OS))
+    {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+/// Returns `name` if `e` is the value of `std::env::var(name)`, e.g. `std::env::var(name)?` or
+/// `std::env::var(name).unwrap().trim()`.
+fn env_var_value<'tcx>(cx: &LateContext<'tcx>, mut e: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    loop {
+        e = expr_or_init(cx, e);
+        if let Some(name) = env_var_call(cx, e) {
+            return Some(name);
+        }
+        e = match e.kind {
+            ExprKind::MethodCall(path, recv, ..)
+                if matches!(
+                    path.ident.as_str(),
+                    "unwrap" | "expect" | "unwrap_or" | "unwrap_or_default" | "unwrap_or_else" | "trim" | "as_str"
+                ) =>
+            {
+                recv
+            },
+            ExprKind::Match(scrutinee, _, MatchSource::TryDesugar) => match scrutinee.kind {
+                ExprKind::Call(_, [inner]) => inner,
+                _ => return None,
+            },
+            _ => return None,
+        };
+    }
+}
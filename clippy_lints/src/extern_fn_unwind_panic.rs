@@ -0,0 +1,250 @@
+use clippy_utils::consts::constant;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::{is_panic, root_macro_call_first_node};
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{higher, path_to_local, path_to_local_id, SpanlessEq};
+use core::ops::ControlFlow;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{BinOpKind, Body, Expr, ExprKind, FnDecl, HirId, Node, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::{sym, Span};
+use rustc_target::spec::abi::Abi;
+use rustc_target::spec::PanicStrategy;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions with the `"C"` ABI, or another foreign ABI which doesn't allow
+    /// unwinding, that contain code which may panic: `panic!` and assertions, `unwrap()` and
+    /// `expect()`, and indexing which isn't bounds checked with `if i < v.len()` or similar.
+    ///
+    /// The body of a closure isn't checked, so a panic inside `std::panic::catch_unwind` is
+    /// fine.
+    ///
+    /// ### Why is this bad?
+    /// Unwinding out of an `extern "C"` function is undefined behavior. These functions are
+    /// usually called from foreign code, for example as callbacks, which doesn't expect a Rust
+    /// panic.
+    ///
+    /// ### Known problems
+    /// Functions called from the `extern "C"` function may panic as well. This is not checked.
+    /// Neither is arithmetic, which only panics on overflow if overflow checks are enabled.
+    ///
+    /// ### Example
+    /// ```rust
+    /// extern "C" fn get(values: *const u32, len: usize, i: usize) -> u32 {
+    ///     let values = unsafe { std::slice::from_raw_parts(values, len) };
+    ///     values[i]
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// extern "C" fn get(values: *const u32, len: usize, i: usize) -> u32 {
+    ///     let values = unsafe { std::slice::from_raw_parts(values, len) };
+    ///     values.get(i).copied().unwrap_or(0)
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub EXTERN_FN_UNWIND_PANIC,
+    suspicious,
+    "an `extern \"C\"` function which may panic, which is undefined behavior"
+}
+
+declare_lint_pass!(ExternFnUnwindPanic => [EXTERN_FN_UNWIND_PANIC]);
+
+impl<'tcx> LateLintPass<'tcx> for ExternFnUnwindPanic {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'tcx>,
+        body: &'tcx Body<'tcx>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        let abi = match kind {
+            FnKind::ItemFn(_, _, header) => header.abi,
+            FnKind::Method(_, sig) => sig.header.abi,
+            FnKind::Closure => return,
+        };
+        // with `panic=abort`, panicking aborts the process before unwinding
+        if !is_nounwind_foreign_abi(abi)
+            || cx.sess().panic_strategy() == PanicStrategy::Abort
+            || in_external_macro(cx.sess(), span)
+        {
+            return;
+        }
+
+        let panics = find_panics(cx, body);
+        if panics.is_empty() {
+            return;
+        }
+        span_lint_and_then(
+            cx,
+            EXTERN_FN_UNWIND_PANIC,
+            cx.tcx.def_span(def_id),
+            &format!("this `extern {abi}` function may panic, but it can't unwind"),
+            |diag| {
+                diag.span_note(panics, "unwinding out of the function from here is undefined behavior");
+                diag.help(format!(
+                    "handle the panics with `std::panic::catch_unwind`, or declare the function as \
+                     `extern \"{}-unwind\"` if the foreign code can handle unwinding",
+                    abi.name()
+                ));
+            },
+        );
+    }
+}
+
+/// Whether `abi` is one of the foreign ABIs which have an `-unwind` variant, but isn't that
+/// variant.
+fn is_nounwind_foreign_abi(abi: Abi) -> bool {
+    matches!(
+        abi,
+        Abi::C { unwind: false }
+            | Abi::Cdecl { unwind: false }
+            | Abi::Stdcall { unwind: false }
+            | Abi::Fastcall { unwind: false }
+            | Abi::Vectorcall { unwind: false }
+            | Abi::Thiscall { unwind: false }
+            | Abi::Aapcs { unwind: false }
+            | Abi::Win64 { unwind: false }
+            | Abi::SysV64 { unwind: false }
+            | Abi::System { unwind: false }
+    )
+}
+
+fn find_panics<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Body<'tcx>) -> Vec<Span> {
+    let mut panics = Vec::new();
+    let _: Option<!> = for_each_expr(body.value, |e| {
+        if let Some(macro_call) = root_macro_call_first_node(cx, e) {
+            if is_panic(cx, macro_call.def_id)
+                || matches!(
+                    cx.tcx.item_name(macro_call.def_id).as_str(),
+                    "unimplemented" | "unreachable" | "todo" | "assert" | "assert_eq" | "assert_ne"
+                )
+            {
+                panics.push(macro_call.span);
+                return ControlFlow::Continue(Descend::No);
+            }
+        } else if !e.span.from_expansion() && may_panic(cx, e) {
+            panics.push(e.span);
+        }
+        ControlFlow::Continue(Descend::Yes)
+    });
+    panics
+}
+
+fn may_panic<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> bool {
+    let typeck = cx.typeck_results();
+    match e.kind {
+        ExprKind::MethodCall(path, recv, ..)
+            if matches!(path.ident.as_str(), "unwrap" | "expect" | "unwrap_err" | "expect_err") =>
+        {
+            let recv_ty = typeck.expr_ty_adjusted(recv).peel_refs();
+            is_type_diagnostic_item(cx, recv_ty, sym::Option) || is_type_diagnostic_item(cx, recv_ty, sym::Result)
+        },
+        ExprKind::Index(base, index) => {
+            if let Some(range) = higher::Range::hir(index) {
+                // `[..]` can't be out of bounds
+                return range.start.is_some() || range.end.is_some();
+            }
+            // rustc already checks constant indices into arrays
+            let is_const_array_index = matches!(typeck.expr_ty_adjusted(base).peel_refs().kind(), ty::Array(..))
+                && constant(cx, typeck, index).is_some();
+            !is_const_array_index && !is_bounds_checked(cx, e, base, index)
+        },
+        _ => false,
+    }
+}
+
+/// Whether `e`, which is `base[index]`, only runs when `index < base.len()`, because it's in
+/// `if i < base.len() { .. }`, in the `else` branch of `if i >= base.len() { .. }`, or after
+/// `if i >= base.len() { return ..; }`.
+fn is_bounds_checked(cx: &LateContext<'_>, e: &Expr<'_>, base: &Expr<'_>, index: &Expr<'_>) -> bool {
+    let Some(id) = path_to_local(index) else {
+        return false;
+    };
+    let mut child = e.hir_id;
+    for (parent_id, node) in cx.tcx.hir().parent_iter(e.hir_id) {
+        match node {
+            Node::Expr(parent) => {
+                if let Some(higher::If { cond, then, r#else }) = higher::If::hir(parent)
+                    && ((then.hir_id == child && checks_in_bounds(cx, cond, base, id))
+                        || (r#else.map_or(false, |r#else| r#else.hir_id == child)
+                            && checks_out_of_bounds(cx, cond, base, id)))
+                {
+                    return true;
+                }
+            },
+            Node::Block(block) => {
+                if block
+                    .stmts
+                    .iter()
+                    .take_while(|stmt| stmt.hir_id != child)
+                    .any(|stmt| is_return_guard(cx, stmt, base, id))
+                {
+                    return true;
+                }
+            },
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => break,
+            _ => {},
+        }
+        child = parent_id;
+    }
+    false
+}
+
+/// Whether `stmt` is `if i >= base.len() { return ..; }`, or a similar check leaving the function.
+fn is_return_guard(cx: &LateContext<'_>, stmt: &Stmt<'_>, base: &Expr<'_>, id: HirId) -> bool {
+    if let StmtKind::Expr(e) | StmtKind::Semi(e) = stmt.kind
+        && let Some(higher::If { cond, then, r#else: None }) = higher::If::hir(e)
+        && let ExprKind::Block(block, _) = then.kind
+        && let Some(last) = block.expr.or_else(|| match block.stmts.last()?.kind {
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+            _ => None,
+        })
+    {
+        (matches!(last.kind, ExprKind::Ret(_)) || cx.typeck_results().expr_ty(last).is_never())
+            && checks_out_of_bounds(cx, cond, base, id)
+    } else {
+        false
+    }
+}
+
+/// Whether `cond` only holds if `i < base.len()`, e.g. `i < base.len() && ..`.
+fn checks_in_bounds(cx: &LateContext<'_>, cond: &Expr<'_>, base: &Expr<'_>, id: HirId) -> bool {
+    let ExprKind::Binary(op, lhs, rhs) = cond.kind else {
+        return false;
+    };
+    match op.node {
+        BinOpKind::And => checks_in_bounds(cx, lhs, base, id) || checks_in_bounds(cx, rhs, base, id),
+        BinOpKind::Lt => is_index_and_len(cx, lhs, rhs, base, id),
+        BinOpKind::Gt => is_index_and_len(cx, rhs, lhs, base, id),
+        _ => false,
+    }
+}
+
+/// Whether `cond` holds if `i >= base.len()`, e.g. `i >= base.len() || ..`.
+fn checks_out_of_bounds(cx: &LateContext<'_>, cond: &Expr<'_>, base: &Expr<'_>, id: HirId) -> bool {
+    let ExprKind::Binary(op, lhs, rhs) = cond.kind else {
+        return false;
+    };
+    match op.node {
+        BinOpKind::Or => checks_out_of_bounds(cx, lhs, base, id) || checks_out_of_bounds(cx, rhs, base, id),
+        BinOpKind::Ge => is_index_and_len(cx, lhs, rhs, base, id),
+        BinOpKind::Le => is_index_and_len(cx, rhs, lhs, base, id),
+        _ => false,
+    }
+}
+
+/// Whether `index` is the local `id` and `len` is `base.len()`.
+fn is_index_and_len(cx: &LateContext<'_>, index: &Expr<'_>, len: &Expr<'_>, base: &Expr<'_>, id: HirId) -> bool {
+    path_to_local_id(index, id)
+        && matches!(len.kind, ExprKind::MethodCall(path, recv, [], _)
+            if path.ident.name == sym::len && SpanlessEq::new(cx).eq_expr(recv, base))
+}
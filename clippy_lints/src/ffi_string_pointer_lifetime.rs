@@ -0,0 +1,224 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use clippy_utils::{def_path_def_ids, path_def_id, path_res};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, HirId, LangItem, Node, PatKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `as_ptr()` and `as_mut_ptr()` called on a temporary `Vec`, `String`,
+    /// `CString`, boxed slice or boxed `str`, when the pointer escapes the statement: it's stored
+    /// in a variable or a field, returned, or passed to one of the functions listed in the
+    /// `pointer-retaining-functions` configuration.
+    ///
+    /// ### Why is this bad?
+    /// The temporary is dropped at the end of the statement, which frees the buffer the pointer
+    /// points into. Any later use of the pointer, for example by foreign code, is a
+    /// use-after-free.
+    ///
+    /// This generalizes rustc's `temporary_cstring_as_ptr` lint to the other owned buffers.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # fn name() -> String { String::new() }
+    /// let ptr = name().as_ptr();
+    /// // `ptr` is dangling here
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn name() -> String { String::new() }
+    /// let name = name();
+    /// let ptr = name.as_ptr();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub FFI_STRING_POINTER_LIFETIME,
+    suspicious,
+    "a pointer into a temporary buffer which is used after the temporary is dropped"
+}
+
+pub struct FfiStringPointerLifetime {
+    conf_pointer_retaining_functions: Vec<String>,
+    pointer_retaining_functions: FxHashSet<DefId>,
+}
+
+impl FfiStringPointerLifetime {
+    pub fn new(conf_pointer_retaining_functions: Vec<String>) -> Self {
+        Self {
+            conf_pointer_retaining_functions,
+            pointer_retaining_functions: FxHashSet::default(),
+        }
+    }
+
+    /// Finds where the pointer produced by `expr` goes, if it outlives the statement containing
+    /// it.
+    fn escape<'tcx>(&self, cx: &LateContext<'tcx>, mut expr: &'tcx Expr<'tcx>) -> Option<Escape> {
+        for (_, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+            match node {
+                Node::Expr(parent) => match parent.kind {
+                    // the tail of the function's body
+                    ExprKind::Block(..) if is_body_value(cx, parent.hir_id) => {
+                        return Some(Escape::Returned(expr.span));
+                    },
+                    ExprKind::Block(..)
+                    | ExprKind::Cast(..)
+                    | ExprKind::Struct(..)
+                    | ExprKind::Tup(_)
+                    | ExprKind::Array(_) => {},
+                    // the pointer is moved into a tuple struct or variant
+                    ExprKind::Call(func, _) if matches!(path_res(cx, func), Res::Def(DefKind::Ctor(..), _)) => {},
+                    // the result still points into the same buffer
+                    ExprKind::MethodCall(path, recv, ..)
+                        if recv.hir_id == expr.hir_id
+                            && matches!(
+                                path.ident.as_str(),
+                                "cast" | "cast_mut" | "cast_const" | "add" | "offset" | "wrapping_add"
+                            ) => {},
+                    ExprKind::Assign(_, rhs, _) if rhs.hir_id == expr.hir_id => {
+                        return Some(Escape::Stored(parent.span));
+                    },
+                    ExprKind::Ret(_) => return Some(Escape::Returned(parent.span)),
+                    ExprKind::Call(func, _) => {
+                        return path_def_id(cx, func)
+                            .filter(|id| self.pointer_retaining_functions.contains(id))
+                            .map(|id| Escape::Retained(id, parent.span));
+                    },
+                    ExprKind::MethodCall(..) => {
+                        return cx
+                            .typeck_results()
+                            .type_dependent_def_id(parent.hir_id)
+                            .filter(|id| self.pointer_retaining_functions.contains(id))
+                            .map(|id| Escape::Retained(id, parent.span));
+                    },
+                    _ => return None,
+                },
+                Node::ExprField(_) => {},
+                Node::Block(block) if block.expr.map_or(false, |tail| tail.hir_id == expr.hir_id) => {},
+                Node::Local(local) if !matches!(local.pat.kind, PatKind::Wild) => {
+                    return Some(Escape::Stored(local.pat.span));
+                },
+                _ => return None,
+            }
+            if let Node::Expr(parent) = node {
+                expr = parent;
+            }
+        }
+        None
+    }
+}
+
+enum Escape {
+    /// Stored in a variable, or in a field of one
+    Stored(Span),
+    Returned(Span),
+    /// Passed to one of the `pointer-retaining-functions`
+    Retained(DefId, Span),
+}
+
+impl_lint_pass!(FfiStringPointerLifetime => [FFI_STRING_POINTER_LIFETIME]);
+
+impl<'tcx> LateLintPass<'tcx> for FfiStringPointerLifetime {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for path in &self.conf_pointer_retaining_functions {
+            let segs: Vec<_> = path.split("::").collect();
+            self.pointer_retaining_functions.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::MethodCall(path, recv, [], _) = expr.kind else {
+            return;
+        };
+        if !matches!(path.ident.as_str(), "as_ptr" | "as_mut_ptr")
+            || recv.is_place_expr(|_| false)
+            || in_external_macro(cx.sess(), expr.span)
+        {
+            return;
+        }
+        let recv_ty = cx.typeck_results().expr_ty(recv);
+        if !is_owned_buffer(cx, recv_ty) || !is_dropped_with_statement(cx, recv) {
+            return;
+        }
+        let buffer = format!("`{recv_ty}`");
+        let Some(escape) = self.escape(cx, expr) else {
+            return;
+        };
+
+        span_lint_and_then(
+            cx,
+            FFI_STRING_POINTER_LIFETIME,
+            expr.span,
+            &format!("this pointer points into a temporary {buffer}, which doesn't live as long as the pointer"),
+            |diag| {
+                diag.span_label(
+                    recv.span,
+                    format!("this {buffer} is dropped before the pointer is used"),
+                );
+                match escape {
+                    Escape::Stored(span) => {
+                        diag.span_note(span, "the pointer is stored here");
+                        diag.help(format!(
+                            "bind the {buffer} to a variable, so that it lives as long as the pointer is used"
+                        ));
+                    },
+                    Escape::Returned(span) => {
+                        diag.span_note(span, "the pointer is returned here");
+                        diag.help(format!(
+                            "return the {buffer} itself, or a pointer from `into_raw` which the caller frees"
+                        ));
+                    },
+                    Escape::Retained(id, span) => {
+                        diag.span_note(
+                            span,
+                            format!(
+                                "the pointer is passed to `{}` here, which keeps it after returning",
+                                cx.tcx.def_path_str(id)
+                            ),
+                        );
+                        diag.help(format!(
+                            "bind the {buffer} to a variable which lives as long as the pointer is kept"
+                        ));
+                    },
+                }
+            },
+        );
+    }
+}
+
+/// Whether `ty` owns the buffer its `as_ptr()` points into.
+fn is_owned_buffer<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    is_type_diagnostic_item(cx, ty, sym::Vec)
+        || is_type_lang_item(cx, ty, LangItem::String)
+        || is_type_diagnostic_item(cx, ty, sym::cstring_type)
+        || (ty.is_box() && matches!(ty.boxed_ty().kind(), ty::Slice(_) | ty::Str))
+}
+
+/// Whether the temporary `e` is dropped at the end of the statement or expression containing
+/// it, rather than living until the end of the block through lifetime extension.
+fn is_dropped_with_statement(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let owner = e.hir_id.owner;
+    let scope_tree = cx.tcx.region_scope_tree(owner.to_def_id());
+    let Some(scope) = cx
+        .typeck_results()
+        .rvalue_scopes
+        .temporary_scope(scope_tree, e.hir_id.local_id)
+    else {
+        return false;
+    };
+    let scope_id = HirId {
+        owner,
+        local_id: scope.item_local_id(),
+    };
+    !matches!(cx.tcx.hir().find(scope_id), Some(Node::Block(_)))
+}
+
+fn is_body_value(cx: &LateContext<'_>, id: HirId) -> bool {
+    cx.enclosing_body
+        .map_or(false, |body| cx.tcx.hir().body(body).value.hir_id == id)
+}
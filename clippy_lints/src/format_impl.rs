@@ -1,20 +1,25 @@
-use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg};
+use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::macros::{find_format_arg_expr, find_format_args, is_format_macro, root_macro_call_first_node};
-use clippy_utils::{get_parent_as_impl, is_diag_trait_item, path_to_local, peel_ref_operators};
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{get_parent_as_impl, is_diag_trait_item, path_def_id, path_to_local};
+use core::ops::ControlFlow;
 use if_chain::if_chain;
 use rustc_ast::{FormatArgsPiece, FormatTrait};
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind, Impl, ImplItem, ImplItemKind, QPath};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, Impl, ImplItem, ImplItemKind, QPath, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::TypeckResults;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
-use rustc_span::Span;
 use rustc_span::{sym, symbol::kw, Symbol};
 
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for format trait implementations (e.g. `Display`) with a recursive call to itself
     /// which uses `self` as a parameter.
-    /// This is typically done indirectly with the `write!` macro or with `to_string()`.
+    /// This is typically done indirectly with the `write!` macro or with `to_string()`, or in
+    /// a method of the type which is called with `self`. Such methods are checked one level
+    /// deep.
     ///
     /// ### Why is this bad?
     /// This will lead to infinite recursion and a stack overflow.
@@ -134,11 +139,23 @@ impl<'tcx> LateLintPass<'tcx> for FormatImpl {
         }
 
         check_self_in_format_args(cx, expr, format_trait_impl);
+        check_self_in_helper_method(cx, expr, format_trait_impl);
         check_print_in_format_impl(cx, expr, format_trait_impl);
     }
 }
 
 fn check_to_string_in_display(cx: &LateContext<'_>, expr: &Expr<'_>) {
+    if is_to_string_on_self(cx, cx.typeck_results(), expr) {
+        span_lint(
+            cx,
+            RECURSIVE_FORMAT_IMPL,
+            expr.span,
+            "using `self.to_string` in `fmt::Display` implementation will cause infinite recursion",
+        );
+    }
+}
+
+fn is_to_string_on_self(cx: &LateContext<'_>, typeck: &TypeckResults<'_>, expr: &Expr<'_>) -> bool {
     if_chain! {
         // Get the hir_id of the object we are calling the method on
         if let ExprKind::MethodCall(path, self_arg, ..) = expr.kind;
@@ -146,24 +163,41 @@ fn check_to_string_in_display(cx: &LateContext<'_>, expr: &Expr<'_>) {
         if path.ident.name == sym::to_string;
         // Is the method a part of the ToString trait? (i.e. not to_string() implemented
         // separately)
-        if let Some(expr_def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id);
+        if let Some(expr_def_id) = typeck.type_dependent_def_id(expr.hir_id);
         if is_diag_trait_item(cx, expr_def_id, sym::ToString);
         // Is the method is called on self
         if let ExprKind::Path(QPath::Resolved(_, path)) = self_arg.kind;
         if let [segment] = path.segments;
         if segment.ident.name == kw::SelfLower;
         then {
-            span_lint(
-                cx,
-                RECURSIVE_FORMAT_IMPL,
-                expr.span,
-                "using `self.to_string` in `fmt::Display` implementation will cause infinite recursion",
-            );
+            true
+        } else {
+            false
         }
     }
 }
 
 fn check_self_in_format_args<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, impl_trait: FormatTraitNames) {
+    let FormatTraitNames { name, .. } = impl_trait;
+    for_each_self_format_arg(cx, cx.typeck_results(), expr, impl_trait, || {
+        span_lint(
+            cx,
+            RECURSIVE_FORMAT_IMPL,
+            expr.span,
+            &format!("using `self` as `{name}` in `impl {name}` will cause infinite recursion"),
+        );
+    });
+}
+
+/// Calls `f` for each argument of the format macro `expr` which formats `self` with the trait
+/// being implemented.
+fn for_each_self_format_arg<'tcx>(
+    cx: &LateContext<'tcx>,
+    typeck: &TypeckResults<'_>,
+    expr: &'tcx Expr<'_>,
+    impl_trait: FormatTraitNames,
+    mut f: impl FnMut(),
+) {
     // Check each arg in format calls - do we ever use Display on self (directly or via deref)?
     if let Some(outer_macro) = root_macro_call_first_node(cx, expr)
         && let macro_def_id = outer_macro.def_id
@@ -187,28 +221,79 @@ fn check_self_in_format_args<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>,
                     && let Ok(index) = placeholder.argument.index
                     && let Some(arg) = format_args.arguments.all_args().get(index)
                     && let Ok(arg_expr) = find_format_arg_expr(expr, arg)
+                    && is_self(cx, typeck, arg_expr)
                 {
-                    check_format_arg_self(cx, expr.span, arg_expr, impl_trait);
+                    f();
                 }
             }
         });
     }
 }
 
-fn check_format_arg_self(cx: &LateContext<'_>, span: Span, arg: &Expr<'_>, impl_trait: FormatTraitNames) {
+fn is_self(cx: &LateContext<'_>, typeck: &TypeckResults<'_>, mut arg: &Expr<'_>) -> bool {
     // Handle multiple dereferencing of references e.g. &&self
     // Handle dereference of &self -> self that is equivalent (i.e. via *self in fmt() impl)
     // Since the argument to fmt is itself a reference: &self
-    let reference = peel_ref_operators(cx, arg);
-    let map = cx.tcx.hir();
+    loop {
+        match arg.kind {
+            ExprKind::AddrOf(_, _, e) => arg = e,
+            ExprKind::Unary(UnOp::Deref, e) if typeck.expr_ty(e).is_ref() => arg = e,
+            _ => break,
+        }
+    }
     // Is the reference self?
-    if path_to_local(reference).map(|x| map.name(x)) == Some(kw::SelfLower) {
+    path_to_local(arg).map(|x| cx.tcx.hir().name(x)) == Some(kw::SelfLower)
+}
+
+/// Checks calls to inherent methods of the type which are passed `self`, and which use
+/// `self.to_string()` or format `self` themselves.
+fn check_self_in_helper_method<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, impl_trait: FormatTraitNames) {
+    let (method_id, self_arg) = match expr.kind {
+        ExprKind::MethodCall(_, self_arg, ..) => (cx.typeck_results().type_dependent_def_id(expr.hir_id), self_arg),
+        ExprKind::Call(func, [self_arg, ..]) => (path_def_id(cx, func), self_arg),
+        _ => return,
+    };
+    let Some(method_id) = method_id.and_then(DefId::as_local) else {
+        return;
+    };
+    if expr.span.from_expansion()
+        || !is_self(cx, cx.typeck_results(), self_arg)
+        || cx
+            .tcx
+            .impl_of_method(method_id.to_def_id())
+            .map_or(true, |impl_id| cx.tcx.trait_id_of_impl(impl_id).is_some())
+    {
+        return;
+    }
+    let Some(body_id) = cx.tcx.hir().maybe_body_owned_by(method_id) else {
+        return;
+    };
+    let body = cx.tcx.hir().body(body_id);
+    let typeck = cx.tcx.typeck_body(body_id);
+    let recursion = for_each_expr(body.value, |e| {
+        if impl_trait.name == sym::Display && is_to_string_on_self(cx, typeck, e) {
+            return ControlFlow::Break((e.span, "calls `self.to_string()`".to_string()));
+        }
+        let mut formats_self = false;
+        for_each_self_format_arg(cx, typeck, e, impl_trait, || formats_self = true);
+        if formats_self {
+            ControlFlow::Break((e.span, format!("formats `self` with `{}`", impl_trait.name)))
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+
+    if let Some((span, what)) = recursion {
         let FormatTraitNames { name, .. } = impl_trait;
-        span_lint(
+        let method = cx.tcx.item_name(method_id.to_def_id());
+        span_lint_and_then(
             cx,
             RECURSIVE_FORMAT_IMPL,
-            span,
-            &format!("using `self` as `{name}` in `impl {name}` will cause infinite recursion"),
+            expr.span,
+            &format!("calling `{method}` in `impl {name}` will cause infinite recursion"),
+            |diag| {
+                diag.span_note(span, format!("`{method}` {what} here"));
+            },
         );
     }
 }
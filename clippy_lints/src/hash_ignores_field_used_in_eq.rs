@@ -0,0 +1,300 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::path_to_local;
+use clippy_utils::visitors::for_each_expr;
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Body, Expr, ExprKind, HirId, Impl, Item, ItemKind, PatKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, AdtDef};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for manual implementations of `Hash` for a struct which don't hash some of the
+    /// fields which are compared by its `PartialEq` implementation.
+    ///
+    /// ### Why is this bad?
+    /// Hashing only some of the compared fields is allowed, as equal values still hash the same.
+    /// But values which only differ in the other fields always collide, which slows down
+    /// `HashMap` and `HashSet` when there are many of them. A field which is compared but not
+    /// hashed is also often one which was added to the struct and to `eq` later, but forgotten in
+    /// `hash`.
+    ///
+    /// ### Known problems
+    /// Leaving out a field is fine if values rarely differ only in that field, e.g. because it's
+    /// derived from the hashed fields.
+    ///
+    /// Implementations which pass `self` to other functions, like `self.key().hash(state)`,
+    /// aren't checked.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::hash::{Hash, Hasher};
+    /// #[derive(PartialEq, Eq)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// impl Hash for Point {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.x.hash(state);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::hash::{Hash, Hasher};
+    /// # #[derive(PartialEq, Eq)]
+    /// # struct Point {
+    /// #     x: i32,
+    /// #     y: i32,
+    /// # }
+    /// impl Hash for Point {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.x.hash(state);
+    ///         self.y.hash(state);
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub HASH_IGNORES_FIELD_USED_IN_EQ,
+    pedantic,
+    "a manual `Hash` implementation which doesn't hash all the fields compared by `PartialEq`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for manual implementations of `Hash` for a struct which hash fields which aren't
+    /// compared by its `PartialEq` implementation.
+    ///
+    /// ### Why is this bad?
+    /// `HashMap` and `HashSet` rely on `k1 == k2 -> hash(k1) == hash(k2)`. Values which only
+    /// differ in a field which isn't compared are equal, but usually hash differently, so a
+    /// lookup doesn't find an equal key, and a set can contain equal values twice.
+    ///
+    /// ### Known problems
+    /// Hashing such a field is fine if it's derived from the compared fields, e.g. a cached hash.
+    ///
+    /// Implementations which pass `self` to other functions, like `self.key().hash(state)`,
+    /// aren't checked.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::hash::{Hash, Hasher};
+    /// struct Entry {
+    ///     key: String,
+    ///     hits: u32,
+    /// }
+    ///
+    /// impl PartialEq for Entry {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self.key == other.key
+    ///     }
+    /// }
+    ///
+    /// impl Hash for Entry {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.key.hash(state);
+    ///         self.hits.hash(state);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::hash::{Hash, Hasher};
+    /// # struct Entry {
+    /// #     key: String,
+    /// #     hits: u32,
+    /// # }
+    /// impl Hash for Entry {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.key.hash(state);
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub HASH_USES_FIELD_IGNORED_BY_EQ,
+    suspicious,
+    "a manual `Hash` implementation which hashes fields that `PartialEq` doesn't compare"
+}
+
+declare_lint_pass!(HashIgnoresFieldUsedInEq => [HASH_IGNORES_FIELD_USED_IN_EQ, HASH_USES_FIELD_IGNORED_BY_EQ]);
+
+impl<'tcx> LateLintPass<'tcx> for HashIgnoresFieldUsedInEq {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        let ItemKind::Impl(Impl {
+            of_trait: Some(ref trait_ref),
+            items,
+            ..
+        }) = item.kind
+        else {
+            return;
+        };
+        if in_external_macro(cx.sess(), item.span)
+            || cx.tcx.has_attr(item.owner_id, sym::automatically_derived)
+            || !trait_ref
+                .trait_def_id()
+                .map_or(false, |id| cx.tcx.is_diagnostic_item(sym::Hash, id))
+        {
+            return;
+        }
+        let ty::Adt(adt, _) = cx.tcx.type_of(item.owner_id).subst_identity().kind() else {
+            return;
+        };
+        if !adt.is_struct() {
+            return;
+        }
+        let Some(eq_impl) = partial_eq_impl(cx, *adt) else {
+            return;
+        };
+        let Some(hash_body) = items
+            .iter()
+            .find(|item| item.ident.name == sym::hash)
+            .and_then(|item| cx.tcx.hir().maybe_body_owned_by(item.id.owner_id.def_id))
+            .map(|id| cx.tcx.hir().body(id))
+        else {
+            return;
+        };
+
+        let Some(compared) = compared_fields(cx, *adt, eq_impl) else {
+            return;
+        };
+        let Some(hashed) = used_fields(hash_body, 1) else {
+            return;
+        };
+
+        let fields_where = |pred: &dyn Fn(&Symbol) -> bool| {
+            adt.non_enum_variant()
+                .fields
+                .iter()
+                .map(|field| field.name)
+                .filter(pred)
+                .map(|name| format!("`{name}`"))
+                .collect::<Vec<_>>()
+        };
+        let impl_span = cx.tcx.def_span(item.owner_id);
+        let eq_span = cx.tcx.def_span(eq_impl);
+
+        let extra = fields_where(&|name| hashed.contains(name) && !compared.contains(name));
+        if !extra.is_empty() {
+            span_lint_and_then(
+                cx,
+                HASH_USES_FIELD_IGNORED_BY_EQ,
+                impl_span,
+                &format!(
+                    "this `Hash` implementation hashes {}, which {} compared by `PartialEq`",
+                    fields_list(&extra),
+                    if extra.len() == 1 { "isn't" } else { "aren't" },
+                ),
+                |diag| {
+                    diag.span_note(eq_span, "`PartialEq` is implemented here");
+                    diag.note("equal values can have different hashes, which breaks `HashMap` and `HashSet`");
+                    diag.help("only hash fields which are compared in `eq`");
+                },
+            );
+        }
+
+        let missing = fields_where(&|name| compared.contains(name) && !hashed.contains(name));
+        if !missing.is_empty() {
+            span_lint_and_then(
+                cx,
+                HASH_IGNORES_FIELD_USED_IN_EQ,
+                impl_span,
+                &format!(
+                    "this `Hash` implementation doesn't hash {}, which {} compared by `PartialEq`",
+                    fields_list(&missing),
+                    if missing.len() == 1 { "is" } else { "are" },
+                ),
+                |diag| {
+                    diag.span_note(eq_span, "`PartialEq` is implemented here");
+                    diag.help("hash the same fields which are compared in `eq`");
+                },
+            );
+        }
+    }
+}
+
+fn fields_list(fields: &[String]) -> String {
+    format!(
+        "{} {}",
+        if fields.len() == 1 { "the field" } else { "the fields" },
+        fields.join(", ")
+    )
+}
+
+/// Collects the names of the fields compared by the `PartialEq` implementation `eq_impl`.
+fn compared_fields(cx: &LateContext<'_>, adt: AdtDef<'_>, eq_impl: DefId) -> Option<FxHashSet<Symbol>> {
+    if cx.tcx.has_attr(eq_impl, sym::automatically_derived) {
+        return Some(adt.non_enum_variant().fields.iter().map(|field| field.name).collect());
+    }
+    let eq_body = cx
+        .tcx
+        .associated_items(eq_impl)
+        .filter_by_name_unhygienic(sym::eq)
+        .next()
+        .and_then(|item| item.def_id.as_local())
+        .and_then(|id| cx.tcx.hir().maybe_body_owned_by(id))
+        .map(|id| cx.tcx.hir().body(id))?;
+    used_fields(eq_body, 2)
+}
+
+/// Finds the local `impl PartialEq for Adt`, ignoring implementations comparing with other types.
+fn partial_eq_impl(cx: &LateContext<'_>, adt: AdtDef<'_>) -> Option<DefId> {
+    let eq_trait = cx.tcx.lang_items().eq_trait()?;
+    let ty = cx.tcx.type_of(adt.did()).subst_identity();
+    let mut eq_impl = None;
+    cx.tcx.for_each_relevant_impl(eq_trait, ty, |impl_id| {
+        if eq_impl.is_none()
+            && impl_id.is_local()
+            && let Some(trait_ref) = cx.tcx.impl_trait_ref(impl_id)
+            && let trait_ref = trait_ref.subst_identity()
+            && trait_ref.substs.type_at(1) == trait_ref.self_ty()
+        {
+            eq_impl = Some(impl_id);
+        }
+    });
+    eq_impl
+}
+
+/// Collects the names of the fields accessed on the first `param_count` parameters of `body`.
+/// Returns `None` if one of them is used in another way, e.g. passed to a function, as the fields
+/// used by that aren't known.
+pub(crate) fn used_fields(body: &Body<'_>, param_count: usize) -> Option<FxHashSet<Symbol>> {
+    let params = body
+        .params
+        .iter()
+        .take(param_count)
+        .map(|param| match param.pat.kind {
+            PatKind::Binding(_, id, ..) => Some(id),
+            _ => None,
+        })
+        .collect::<Option<Vec<HirId>>>()?;
+
+    let mut fields = FxHashSet::default();
+    let mut field_bases = FxHashSet::default();
+    let mut uses = FxHashSet::default();
+    for_each_expr(body.value, |e| {
+        if let ExprKind::Field(base, ident) = e.kind
+            && let base = peel_derefs(base)
+            && path_to_local(base).map_or(false, |id| params.contains(&id))
+        {
+            fields.insert(ident.name);
+            field_bases.insert(base.hir_id);
+        } else if path_to_local(e).map_or(false, |id| params.contains(&id)) {
+            uses.insert(e.hir_id);
+        }
+        ControlFlow::<!, _>::Continue(())
+    });
+    uses.is_subset(&field_bases).then_some(fields)
+}
+
+fn peel_derefs<'tcx>(mut expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    while let ExprKind::Unary(UnOp::Deref, inner) = expr.kind {
+        expr = inner;
+    }
+    expr
+}
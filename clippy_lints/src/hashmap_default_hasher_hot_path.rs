@@ -0,0 +1,154 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::{is_type_diagnostic_item, match_type};
+use clippy_utils::{fn_def_id, paths};
+use rustc_ast::attr;
+use rustc_hir::def::DefKind;
+use rustc_hir::{Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, IntTy, UintTy};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `HashMap`s and `HashSet`s with integer keys and the default hasher, which are
+    /// created in a loop, in an `#[inline]` function or in a function listed in the
+    /// `default-hasher-hot-functions` configuration.
+    ///
+    /// ### Why is this bad?
+    /// The default hasher, SipHash, resists HashDoS attacks, but is slow to hash small keys.
+    /// When the keys can't be chosen by an attacker, a faster hasher, like the one of
+    /// `rustc_hash::FxHashMap`, speeds up every insertion and lookup.
+    ///
+    /// The faster map and set types are set with the `fast-hash-map-type` and
+    /// `fast-hash-set-type` configuration.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// for line in ["1 2", "3 4"] {
+    ///     let mut counts = HashMap::new();
+    ///     for n in line.split(' ') {
+    ///         *counts.entry(n.parse::<u32>().unwrap()).or_insert(0) += 1;
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// use rustc_hash::FxHashMap;
+    ///
+    /// for line in ["1 2", "3 4"] {
+    ///     let mut counts = FxHashMap::default();
+    ///     for n in line.split(' ') {
+    ///         *counts.entry(n.parse::<u32>().unwrap()).or_insert(0) += 1;
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub HASHMAP_DEFAULT_HASHER_HOT_PATH,
+    restriction,
+    "creating a `HashMap` or `HashSet` with integer keys and the default hasher in a hot path"
+}
+
+pub struct HashmapDefaultHasherHotPath {
+    hot_functions: Vec<String>,
+    fast_hash_map_type: String,
+    fast_hash_set_type: String,
+}
+
+impl HashmapDefaultHasherHotPath {
+    #[must_use]
+    pub fn new(hot_functions: Vec<String>, fast_hash_map_type: String, fast_hash_set_type: String) -> Self {
+        Self {
+            hot_functions,
+            fast_hash_map_type,
+            fast_hash_set_type,
+        }
+    }
+}
+
+impl_lint_pass!(HashmapDefaultHasherHotPath => [HASHMAP_DEFAULT_HASHER_HOT_PATH]);
+
+impl<'tcx> LateLintPass<'tcx> for HashmapDefaultHasherHotPath {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let is_constructor = match expr.kind {
+            ExprKind::Call(..) => fn_def_id(cx, expr).map_or(false, |id| {
+                matches!(
+                    cx.tcx.item_name(id).as_str(),
+                    "new" | "with_capacity" | "default" | "from" | "from_iter"
+                )
+            }),
+            ExprKind::MethodCall(path, ..) => path.ident.as_str() == "collect",
+            _ => false,
+        };
+        if !is_constructor || expr.span.from_expansion() {
+            return;
+        }
+        let ty = cx.typeck_results().expr_ty(expr);
+        let ty::Adt(_, substs) = ty.kind() else {
+            return;
+        };
+        let (kind, hasher, fast_type) = if is_type_diagnostic_item(cx, ty, sym::HashMap) {
+            ("HashMap", substs.type_at(2), &self.fast_hash_map_type)
+        } else if is_type_diagnostic_item(cx, ty, sym::HashSet) {
+            ("HashSet", substs.type_at(1), &self.fast_hash_set_type)
+        } else {
+            return;
+        };
+        if !match_type(cx, hasher, &paths::RANDOM_STATE)
+            || !matches!(
+                substs.type_at(0).kind(),
+                ty::Int(IntTy::I8 | IntTy::I16 | IntTy::I32 | IntTy::I64 | IntTy::Isize)
+                    | ty::Uint(UintTy::U8 | UintTy::U16 | UintTy::U32 | UintTy::U64 | UintTy::Usize)
+            )
+        {
+            return;
+        }
+
+        let mut in_loop = false;
+        for (_, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+            match node {
+                Node::Expr(e) if matches!(e.kind, ExprKind::Loop(..)) => {
+                    in_loop = true;
+                    break;
+                },
+                Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => break,
+                _ => {},
+            }
+        }
+        let place = if in_loop {
+            String::from("in a loop")
+        } else {
+            let owner = cx.tcx.hir().enclosing_body_owner(expr.hir_id);
+            let fn_id = cx.tcx.typeck_root_def_id(owner.to_def_id());
+            if !matches!(cx.tcx.def_kind(fn_id), DefKind::Fn | DefKind::AssocFn) {
+                return;
+            }
+            let fn_name = cx.tcx.item_name(fn_id);
+            let inline = cx.tcx.get_attrs(fn_id, sym::inline).any(|attr| {
+                attr.meta_item_list()
+                    .map_or(true, |items| !attr::list_contains_name(&items, sym::never))
+            });
+            if inline {
+                String::from("in an `#[inline]` function")
+            } else if self.hot_functions.iter().any(|name| fn_name.as_str() == name) {
+                format!("in the hot function `{fn_name}`")
+            } else {
+                return;
+            }
+        };
+
+        span_lint_and_then(
+            cx,
+            HASHMAP_DEFAULT_HASHER_HOT_PATH,
+            expr.span,
+            &format!("creating a `{kind}` with integer keys and the default hasher {place}"),
+            |diag| {
+                diag.note("the default hasher resists HashDoS attacks, but is slow to hash small keys");
+                diag.help(format!(
+                    "use `{fast_type}` instead, if the keys can't be chosen by an attacker"
+                ));
+            },
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{get_parent_expr, SpanlessEq};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::{Block, Expr, ExprKind, HirId, MatchSource, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the same `Mutex` or `RwLock` being locked repeatedly in a block, each time only
+    /// to use the temporary guard, like `m.lock().unwrap().len()` followed by
+    /// `m.lock().unwrap().iter()`.
+    ///
+    /// ### Why is this bad?
+    /// Every lock is a synchronization, and another thread may change the data in between, so the
+    /// length checked by the first lock may not hold for the second one. Binding the guard once is
+    /// clearer and locks only once, which matters most in loops.
+    ///
+    /// ### Known problems
+    /// Binding the guard keeps the lock held for longer, which may not be wanted if there's
+    /// unrelated work in between.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// let m = Mutex::new(vec![1, 2, 3]);
+    /// let len = m.lock().unwrap().len();
+    /// let sum: i32 = m.lock().unwrap().iter().sum();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// let m = Mutex::new(vec![1, 2, 3]);
+    /// let guard = m.lock().unwrap();
+    /// let len = guard.len();
+    /// let sum: i32 = guard.iter().sum();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub ITER_ON_LOCKED_COLLECTION,
+    pedantic,
+    "locking the same `Mutex` or `RwLock` repeatedly only to use the temporary guard"
+}
+
+#[derive(Default)]
+pub struct IterOnLockedCollection {
+    /// The locks already linted as part of an outer block.
+    linted: FxHashSet<HirId>,
+}
+
+impl_lint_pass!(IterOnLockedCollection => [ITER_ON_LOCKED_COLLECTION]);
+
+/// A call like `m.lock().unwrap()` whose guard is only used as a temporary.
+struct TempLock<'tcx> {
+    hir_id: HirId,
+    span: Span,
+    /// The `Mutex` or `RwLock`.
+    lock: &'tcx Expr<'tcx>,
+    /// `lock`, `read` or `write`.
+    method: Symbol,
+}
+
+impl<'tcx> LateLintPass<'tcx> for IterOnLockedCollection {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if block.span.from_expansion() {
+            return;
+        }
+        let mut locks = Vec::new();
+        let is_async = for_each_expr(block, |e| {
+            if let ExprKind::Match(_, _, MatchSource::AwaitDesugar) = e.kind {
+                return ControlFlow::Break(());
+            }
+            if let Some(lock) = temp_lock(cx, e) {
+                locks.push(lock);
+            }
+            ControlFlow::Continue(())
+        })
+        .is_some();
+        // the guard can't be held across an `.await`
+        if is_async {
+            return;
+        }
+
+        let mut grouped = vec![false; locks.len()];
+        for (i, first) in locks.iter().enumerate() {
+            if grouped[i] {
+                continue;
+            }
+            let mut group = vec![first];
+            for (j, other) in locks.iter().enumerate().skip(i + 1) {
+                if !grouped[j] && other.method == first.method && SpanlessEq::new(cx).eq_expr(other.lock, first.lock) {
+                    grouped[j] = true;
+                    group.push(other);
+                }
+            }
+            if group.len() < 2 || group.iter().all(|lock| self.linted.contains(&lock.hir_id)) {
+                continue;
+            }
+            self.linted.extend(group.iter().map(|lock| lock.hir_id));
+
+            span_lint_and_then(
+                cx,
+                ITER_ON_LOCKED_COLLECTION,
+                first.span,
+                &format!(
+                    "`{}` is locked repeatedly in this block",
+                    snippet(cx, first.lock.span, "..")
+                ),
+                |diag| {
+                    diag.span_note(
+                        group[1..].iter().map(|lock| lock.span).collect::<Vec<_>>(),
+                        "locked again here",
+                    );
+                    diag.help("lock it once and bind the guard to a variable");
+                },
+            );
+        }
+    }
+}
+
+/// Checks if `e` is `m.lock().unwrap()` or `m.lock().expect(..)`, and the same with `read` and
+/// `write`, whose result is only used as a temporary.
+fn temp_lock<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> Option<TempLock<'tcx>> {
+    let ExprKind::MethodCall(unwrap, lock_call, unwrap_args, _) = e.kind else {
+        return None;
+    };
+    let ExprKind::MethodCall(method, lock, [], _) = lock_call.kind else {
+        return None;
+    };
+    if !matches!((unwrap.ident.as_str(), unwrap_args), ("unwrap", []) | ("expect", [_]))
+        || e.span.from_expansion()
+        || !is_place(lock)
+    {
+        return None;
+    }
+    let impl_ty = cx
+        .typeck_results()
+        .type_dependent_def_id(lock_call.hir_id)
+        .and_then(|id| cx.tcx.impl_of_method(id))
+        .map(|impl_id| cx.tcx.type_of(impl_id).subst_identity())?;
+    let is_lock_method = match method.ident.as_str() {
+        "lock" => is_type_diagnostic_item(cx, impl_ty, sym::Mutex),
+        "read" | "write" => is_type_diagnostic_item(cx, impl_ty, sym::RwLock),
+        _ => false,
+    };
+    let parent = get_parent_expr(cx, e)?;
+    let is_temp = match parent.kind {
+        ExprKind::MethodCall(_, recv, ..) => recv.hir_id == e.hir_id,
+        ExprKind::Field(base, _) | ExprKind::Index(base, _) | ExprKind::Unary(UnOp::Deref, base) => {
+            base.hir_id == e.hir_id
+        },
+        _ => false,
+    };
+    (is_lock_method && is_temp).then_some(TempLock {
+        hir_id: e.hir_id,
+        span: e.span,
+        lock,
+        method: method.ident.name,
+    })
+}
+
+/// Whether `e` is a path, possibly with field accesses and dereferences, which refers to the same
+/// value every time it's evaluated.
+pub(crate) fn is_place(e: &Expr<'_>) -> bool {
+    match e.kind {
+        ExprKind::Path(_) => true,
+        ExprKind::Field(base, _) | ExprKind::Unary(UnOp::Deref, base) | ExprKind::AddrOf(_, _, base) => is_place(base),
+        _ => false,
+    }
+}
@@ -0,0 +1,146 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::match_type;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{match_def_path, paths};
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, Impl, ImplItemKind, Item, ItemKind, Node};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::TypeckResults;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Drop` implementations which join a thread and unwrap the result with
+    /// `.join().unwrap()` or `.join().expect(..)`, or which block on a future with
+    /// `futures::executor::block_on`, `tokio::runtime::Runtime::block_on` or
+    /// `tokio::runtime::Handle::block_on`.
+    ///
+    /// ### Why is this bad?
+    /// `join` returns an error if the thread panicked, and a panic in `drop` while the thread is
+    /// already unwinding aborts the process. Both joining and blocking on a future wait until
+    /// another thread or task is done, which deadlocks if it in turn waits on the value being
+    /// dropped, and `block_on` panics when it's called from within a tokio runtime.
+    ///
+    /// A separate shutdown method can wait for the work to complete and return the errors to
+    /// the caller, leaving `drop` to make a best effort.
+    ///
+    /// ### Example
+    /// ```rust
+    /// struct Worker {
+    ///     thread: Option<std::thread::JoinHandle<()>>,
+    /// }
+    ///
+    /// impl Drop for Worker {
+    ///     fn drop(&mut self) {
+    ///         if let Some(thread) = self.thread.take() {
+    ///             thread.join().unwrap();
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::any::Any;
+    /// struct Worker {
+    ///     thread: Option<std::thread::JoinHandle<()>>,
+    /// }
+    ///
+    /// impl Worker {
+    ///     fn shutdown(mut self) -> Result<(), Box<dyn Any + Send>> {
+    ///         self.thread.take().map_or(Ok(()), |thread| thread.join())
+    ///     }
+    /// }
+    ///
+    /// impl Drop for Worker {
+    ///     fn drop(&mut self) {
+    ///         if let Some(thread) = self.thread.take() {
+    ///             let _ = thread.join();
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub JOIN_HANDLE_UNWRAP_IN_DROP,
+    pedantic,
+    "unwrapping the result of joining a thread, or blocking on a future, in `Drop::drop`"
+}
+
+declare_lint_pass!(JoinHandleUnwrapInDrop => [JOIN_HANDLE_UNWRAP_IN_DROP]);
+
+impl<'tcx> LateLintPass<'tcx> for JoinHandleUnwrapInDrop {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if let ItemKind::Impl(Impl {
+            of_trait: Some(trait_ref),
+            items: [child],
+            ..
+        }) = item.kind
+            && trait_ref.trait_def_id() == cx.tcx.lang_items().drop_trait()
+            && let Some(Node::ImplItem(impl_item)) = cx.tcx.hir().find(child.id.hir_id())
+            && let ImplItemKind::Fn(_, body_id) = impl_item.kind
+            && !in_external_macro(cx.sess(), item.span)
+        {
+            let typeck = cx.tcx.typeck_body(body_id);
+            for_each_expr(cx.tcx.hir().body(body_id).value, |e| {
+                // closures may be run elsewhere
+                if let ExprKind::Closure(_) = e.kind {
+                    return ControlFlow::<(), _>::Continue(Descend::No);
+                }
+                if !e.span.from_expansion() {
+                    check_expr(cx, typeck, e);
+                }
+                ControlFlow::Continue(Descend::Yes)
+            });
+        }
+    }
+}
+
+fn check_expr<'tcx>(cx: &LateContext<'tcx>, typeck: &TypeckResults<'tcx>, e: &Expr<'_>) {
+    let (msg, note) = if is_join_unwrap(cx, typeck, e) {
+        (
+            "unwrapping the result of joining a thread in `Drop::drop`",
+            "this panics if the thread panicked, which aborts the process if the value is dropped while unwinding",
+        )
+    } else if is_block_on(cx, typeck, e) {
+        (
+            "blocking on a future in `Drop::drop`",
+            "this deadlocks if the future waits on the value being dropped, and blocks or panics if the value is \
+             dropped on a thread running an async runtime",
+        )
+    } else {
+        return;
+    };
+    span_lint_and_then(cx, JOIN_HANDLE_UNWRAP_IN_DROP, e.span, msg, |diag| {
+        diag.note(note);
+        diag.help("consider adding a method which shuts down and returns the errors, and ignoring them in `drop`");
+    });
+}
+
+/// Whether `e` is `handle.join().unwrap()` or `handle.join().expect(..)` on a `JoinHandle`.
+fn is_join_unwrap<'tcx>(cx: &LateContext<'tcx>, typeck: &TypeckResults<'tcx>, e: &Expr<'_>) -> bool {
+    if let ExprKind::MethodCall(path, join, ..) = e.kind
+        && matches!(path.ident.as_str(), "unwrap" | "expect")
+        && let ExprKind::MethodCall(path, handle, [], _) = join.kind
+        && path.ident.as_str() == "join"
+    {
+        match_type(cx, typeck.expr_ty(handle).peel_refs(), &paths::STD_THREAD_JOIN_HANDLE)
+    } else {
+        false
+    }
+}
+
+fn is_block_on<'tcx>(cx: &LateContext<'tcx>, typeck: &TypeckResults<'tcx>, e: &Expr<'_>) -> bool {
+    let id = match e.kind {
+        ExprKind::Call(func, [_]) => match func.kind {
+            ExprKind::Path(ref qpath) => typeck.qpath_res(qpath, func.hir_id).opt_def_id(),
+            _ => None,
+        },
+        ExprKind::MethodCall(_, _, [_], _) => typeck.type_dependent_def_id(e.hir_id),
+        _ => None,
+    };
+    id.map_or(false, |id| {
+        match_def_path(cx, id, &paths::FUTURES_EXECUTOR_BLOCK_ON)
+            || match_def_path(cx, id, &paths::TOKIO_RUNTIME_BLOCK_ON)
+            || match_def_path(cx, id, &paths::TOKIO_HANDLE_BLOCK_ON)
+    })
+}
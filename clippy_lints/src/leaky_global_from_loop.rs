@@ -0,0 +1,152 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::fn_def_id;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `Box::leak`, `Vec::leak` and `String::leak` in loops, and in functions
+    /// other than `main` and the functions configured in `leak-initializer-functions`, unless
+    /// they are in the initializer of a `OnceLock`, `OnceCell`, `Once` or `LazyLock`.
+    ///
+    /// ### Why is this bad?
+    /// Leaked memory is never freed. Leaking once to create a global is fine, but leaking in a
+    /// loop, or in a function which is called repeatedly, makes the memory usage grow without
+    /// bounds.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn intern(name: String) -> &'static str {
+    ///     Box::leak(name.into_boxed_str())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::HashSet;
+    /// # use std::sync::{Arc, Mutex};
+    /// fn intern(names: &Mutex<HashSet<Arc<str>>>, name: String) -> Arc<str> {
+    ///     let mut names = names.lock().unwrap();
+    ///     if let Some(name) = names.get(name.as_str()) {
+    ///         return name.clone();
+    ///     }
+    ///     let name: Arc<str> = name.into();
+    ///     names.insert(name.clone());
+    ///     name
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub LEAKY_GLOBAL_FROM_LOOP,
+    pedantic,
+    "leaking memory in a loop or a function which may be called repeatedly"
+}
+
+pub struct LeakyGlobalFromLoop {
+    initializer_functions: Vec<String>,
+}
+
+impl LeakyGlobalFromLoop {
+    pub fn new(initializer_functions: Vec<String>) -> Self {
+        Self { initializer_functions }
+    }
+}
+
+impl_lint_pass!(LeakyGlobalFromLoop => [LEAKY_GLOBAL_FROM_LOOP]);
+
+impl<'tcx> LateLintPass<'tcx> for LeakyGlobalFromLoop {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if !matches!(expr.kind, ExprKind::Call(..) | ExprKind::MethodCall(..))
+            || !fn_def_id(cx, expr).map_or(false, |id| is_leak(cx, id))
+            || in_external_macro(cx.sess(), expr.span)
+        {
+            return;
+        }
+
+        let mut in_loop = false;
+        for (_, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+            match node {
+                Node::Expr(e) => match e.kind {
+                    ExprKind::Loop(..) => in_loop = true,
+                    // everything in the initializer runs once, even loops
+                    ExprKind::Closure(_) if is_once_initializer(cx, e) => return,
+                    _ => {},
+                },
+                Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => break,
+                _ => {},
+            }
+        }
+
+        let owner = cx.tcx.hir().enclosing_body_owner(expr.hir_id);
+        let fn_id = cx.tcx.typeck_root_def_id(owner.to_def_id());
+        if !matches!(cx.tcx.def_kind(fn_id), DefKind::Fn | DefKind::AssocFn) || cx.tcx.def_span(fn_id).from_expansion()
+        {
+            return;
+        }
+        let fn_name = cx.tcx.item_name(fn_id);
+        let is_initializer =
+            fn_name == sym::main || self.initializer_functions.iter().any(|name| fn_name.as_str() == name);
+        if !in_loop && is_initializer {
+            return;
+        }
+
+        let msg = if in_loop {
+            "leaking memory in a loop"
+        } else {
+            "leaking memory in a function which may be called repeatedly"
+        };
+        span_lint_and_then(cx, LEAKY_GLOBAL_FROM_LOOP, expr.span, msg, |diag| {
+            diag.note("leaked memory is never freed, so the memory usage grows every time this runs");
+            if in_loop {
+                diag.help("leak the values once before the loop, or store them in a collection which owns them");
+            } else {
+                diag.help(format!(
+                    "leak once in a `OnceLock` or `LazyLock` initializer, or add `{fn_name}` to \
+                     `leak-initializer-functions` if it's only called once"
+                ));
+            }
+        });
+    }
+}
+
+/// Whether `id` is `Box::leak`, `Vec::leak` or `String::leak`.
+fn is_leak(cx: &LateContext<'_>, id: DefId) -> bool {
+    if cx.tcx.item_name(id).as_str() != "leak" {
+        return false;
+    }
+    let Some(impl_id) = cx.tcx.impl_of_method(id) else {
+        return false;
+    };
+    let self_ty = cx.tcx.type_of(impl_id).subst_identity();
+    self_ty.is_box()
+        || is_type_diagnostic_item(cx, self_ty, sym::Vec)
+        || is_type_diagnostic_item(cx, self_ty, sym::String)
+}
+
+/// Whether `closure` initializes a `OnceLock`, `OnceCell`, `Once` or `LazyLock`, and therefore
+/// runs at most once.
+pub(crate) fn is_once_initializer(cx: &LateContext<'_>, closure: &Expr<'_>) -> bool {
+    let Some(Node::Expr(parent)) = cx.tcx.hir().find_parent(closure.hir_id) else {
+        return false;
+    };
+    match parent.kind {
+        ExprKind::MethodCall(path, ..) => matches!(
+            path.ident.as_str(),
+            "get_or_init" | "get_or_try_init" | "call_once" | "call_once_force"
+        ),
+        ExprKind::Call(..) => fn_def_id(cx, parent)
+            .and_then(|id| cx.tcx.impl_of_method(id))
+            .map_or(false, |impl_id| {
+                matches!(
+                    cx.tcx.type_of(impl_id).subst_identity().kind(),
+                    ty::Adt(adt, _) if matches!(cx.tcx.item_name(adt.did()).as_str(), "LazyLock" | "LazyCell" | "Lazy")
+                )
+            }),
+        _ => false,
+    }
+}
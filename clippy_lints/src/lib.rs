@@ -68,19 +68,25 @@ mod renamed_lints;
 mod allow_attributes;
 mod almost_complete_range;
 mod approx_const;
+mod arc_mutex_of_copy_primitive;
+mod arc_slice_candidate;
 mod arc_with_non_send_sync;
 mod as_conversions;
 mod asm_syntax;
 mod assertions_on_constants;
 mod assertions_on_result_states;
 mod async_yields_async;
+mod atomics_ordering_mismatch;
 mod attrs;
 mod await_holding_invalid;
+mod block_on_inside_async;
+mod blocking_call_in_async_fn;
 mod blocks_in_if_conditions;
 mod bool_assert_comparison;
 mod bool_to_int_with_if;
 mod booleans;
 mod borrow_deref_ref;
+mod box_collection_temporary;
 mod box_default;
 mod cargo;
 mod casts;
@@ -102,15 +108,20 @@ mod default_union_representation;
 mod dereference;
 mod derivable_impls;
 mod derive;
+mod deserialize_skipped_field_without_default;
+mod detached_spawn_result;
 mod disallowed_macros;
 mod disallowed_methods;
 mod disallowed_names;
 mod disallowed_script_idents;
 mod disallowed_types;
 mod doc;
+mod double_lock_same_mutex;
 mod double_parens;
 mod drop_forget_ref;
+mod drop_impl_may_panic;
 mod duplicate_mod;
+mod duration_from_float_seconds_precision;
 mod else_if_without_else;
 mod empty_drop;
 mod empty_enum;
@@ -119,6 +130,7 @@ mod endian_bytes;
 mod entry;
 mod enum_clike;
 mod enum_variants;
+mod env_var_parsed_without_error_context;
 mod equatable_if_let;
 mod escape;
 mod eta_reduction;
@@ -127,8 +139,10 @@ mod excessive_nesting;
 mod exhaustive_items;
 mod exit;
 mod explicit_write;
+mod extern_fn_unwind_panic;
 mod extra_unused_type_parameters;
 mod fallible_impl_from;
+mod ffi_string_pointer_lifetime;
 mod float_literal;
 mod floating_point_arithmetic;
 mod fn_null_check;
@@ -142,6 +156,8 @@ mod from_raw_with_void_ptr;
 mod from_str_radix_10;
 mod functions;
 mod future_not_send;
+mod hash_ignores_field_used_in_eq;
+mod hashmap_default_hasher_hot_path;
 mod if_let_mutex;
 mod if_not_else;
 mod if_then_some_else_none;
@@ -163,12 +179,15 @@ mod invalid_upcast_comparisons;
 mod items_after_statements;
 mod items_after_test_module;
 mod iter_not_returning_iterator;
+mod iter_on_locked_collection;
+mod join_handle_unwrap_in_drop;
 mod large_const_arrays;
 mod large_enum_variant;
 mod large_futures;
 mod large_include_file;
 mod large_stack_arrays;
 mod large_stack_frames;
+mod leaky_global_from_loop;
 mod len_zero;
 mod let_if_seq;
 mod let_underscore;
@@ -182,7 +201,9 @@ mod main_recursion;
 mod manual_assert;
 mod manual_async_fn;
 mod manual_bits;
+mod manual_checked_arith_via_compare;
 mod manual_clamp;
+mod manual_contains_key;
 mod manual_is_ascii_check;
 mod manual_let_else;
 mod manual_main_separator_str;
@@ -233,11 +254,13 @@ mod needless_question_mark;
 mod needless_update;
 mod neg_cmp_op_on_partial_ord;
 mod neg_multiply;
+mod nested_option_result_flattenable;
 mod new_without_default;
 mod no_effect;
 mod no_mangle_with_rust_abi;
 mod non_copy_const;
 mod non_expressive_names;
+mod non_monotonic_time_for_durations;
 mod non_octal_unix_permissions;
 mod non_send_fields_in_send_ty;
 mod nonstandard_macro_braces;
@@ -249,6 +272,7 @@ mod option_if_let_else;
 mod overflow_check_conditional;
 mod panic_in_result_fn;
 mod panic_unimplemented;
+mod partial_ord_inconsistent_with_ord;
 mod partial_pub_fields;
 mod partialeq_ne_impl;
 mod partialeq_to_none;
@@ -263,6 +287,7 @@ mod question_mark;
 mod question_mark_used;
 mod ranges;
 mod rc_clone_in_vec_init;
+mod rc_in_send_context;
 mod read_zero_byte_vec;
 mod redundant_async_block;
 mod redundant_clone;
@@ -277,12 +302,16 @@ mod ref_option_ref;
 mod ref_patterns;
 mod reference;
 mod regex;
+mod repeated_push_str_literal;
+mod repr_c_enum_with_implicit_discriminants_ffi;
 mod return_self_not_must_use;
 mod returns;
 mod same_name_method;
+mod select_without_biased_or_loop_guard;
 mod self_named_constructors;
 mod semicolon_block;
 mod semicolon_if_nothing_returned;
+mod send_non_send_across_spawn;
 mod serde_api;
 mod shadow;
 mod significant_drop_tightening;
@@ -290,7 +319,9 @@ mod single_char_lifetime_names;
 mod single_component_path_imports;
 mod size_of_in_element_count;
 mod size_of_ref;
+mod slice_from_raw_parts_len_overflow;
 mod slow_vector_initialization;
+mod spawn_blocking_async_closure;
 mod std_instead_of_core;
 mod strings;
 mod strlen_on_c_strings;
@@ -304,6 +335,7 @@ mod tabs_in_doc_comments;
 mod temporary_assignment;
 mod tests_outside_test_module;
 mod to_digit_is_some;
+mod to_owned_then_as_ref_argument;
 mod trailing_empty_array;
 mod trait_bounds;
 mod transmute;
@@ -311,6 +343,7 @@ mod types;
 mod undocumented_unsafe_blocks;
 mod unicode;
 mod uninit_vec;
+mod union_field_access_without_cfg_layout;
 mod unit_return_expecting_ord;
 mod unit_types;
 mod unnamed_address;
@@ -320,7 +353,9 @@ mod unnecessary_self_imports;
 mod unnecessary_struct_initialization;
 mod unnecessary_wraps;
 mod unnested_or_patterns;
+mod unpolled_future_created;
 mod unsafe_removed_from_name;
+mod unsound_send_sync_impl_heuristic;
 mod unused_async;
 mod unused_io_amount;
 mod unused_peekable;
@@ -334,6 +369,7 @@ mod use_self;
 mod useless_conversion;
 mod vec;
 mod vec_init_then_push;
+mod volatile_ordinary_read_write;
 mod wildcard_imports;
 mod write;
 mod zero_div_zero;
@@ -644,6 +680,11 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     let avoid_breaking_exported_api = conf.avoid_breaking_exported_api;
     let allow_expect_in_tests = conf.allow_expect_in_tests;
     let allow_unwrap_in_tests = conf.allow_unwrap_in_tests;
+    let chars_rev_collect_ignore_ascii = conf.chars_rev_collect_ignore_ascii;
+    let chained_replace_threshold = conf.chained_replace_threshold;
+    let option_zip_style = conf.option_zip_style;
+    let sort_unstable_candidate_enumerate = conf.sort_unstable_candidate_enumerate;
+    let lock_unwrap_policy = conf.lock_unwrap_policy;
     let suppress_restriction_lint_in_const = conf.suppress_restriction_lint_in_const;
     store.register_late_pass(move |_| Box::new(approx_const::ApproxConstant::new(msrv())));
     store.register_late_pass(move |_| {
@@ -652,6 +693,11 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
             msrv(),
             allow_expect_in_tests,
             allow_unwrap_in_tests,
+            chars_rev_collect_ignore_ascii,
+            chained_replace_threshold,
+            option_zip_style,
+            sort_unstable_candidate_enumerate,
+            lock_unwrap_policy,
         ))
     });
     store.register_late_pass(move |_| Box::new(matches::Matches::new(msrv())));
@@ -669,7 +715,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move |_| Box::new(use_self::UseSelf::new(msrv())));
     store.register_late_pass(move |_| Box::new(missing_const_for_fn::MissingConstForFn::new(msrv())));
     store.register_late_pass(move |_| Box::new(needless_question_mark::NeedlessQuestionMark));
-    store.register_late_pass(move |_| Box::new(casts::Casts::new(msrv())));
+    let min_len_cast_bits = conf.min_len_cast_bits;
+    store.register_late_pass(move |_| Box::new(casts::Casts::new(msrv(), min_len_cast_bits)));
     store.register_early_pass(move || Box::new(unnested_or_patterns::UnnestedOrPatterns::new(msrv())));
     store.register_late_pass(|_| Box::new(size_of_in_element_count::SizeOfInElementCount));
     store.register_late_pass(|_| Box::new(same_name_method::SameNameMethod));
@@ -682,7 +729,15 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     });
     store.register_late_pass(|_| Box::<shadow::Shadow>::default());
     store.register_late_pass(|_| Box::new(unit_types::UnitTypes));
-    store.register_late_pass(move |_| Box::new(loops::Loops::new(msrv())));
+    let sleep_functions = conf.sleep_functions.clone();
+    let float_accumulation_min_iterations = conf.float_accumulation_min_iterations;
+    store.register_late_pass(move |_| {
+        Box::new(loops::Loops::new(
+            msrv(),
+            sleep_functions.clone(),
+            float_accumulation_min_iterations,
+        ))
+    });
     store.register_late_pass(|_| Box::<main_recursion::MainRecursion>::default());
     store.register_late_pass(|_| Box::new(lifetimes::Lifetimes));
     store.register_late_pass(|_| Box::new(entry::HashMapPass));
@@ -716,7 +771,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|_| Box::new(drop_forget_ref::DropForgetRef));
     store.register_late_pass(|_| Box::new(empty_enum::EmptyEnum));
     store.register_late_pass(|_| Box::new(invalid_upcast_comparisons::InvalidUpcastComparisons));
-    store.register_late_pass(|_| Box::new(regex::Regex));
+    store.register_late_pass(|_| Box::<regex::Regex>::default());
     let ignore_interior_mutability = conf.ignore_interior_mutability.clone();
     store.register_late_pass(move |_| Box::new(copies::CopyAndPaste::new(ignore_interior_mutability.clone())));
     store.register_late_pass(|_| Box::new(copy_iterator::CopyIterator));
@@ -905,7 +960,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move |_| Box::new(if_then_some_else_none::IfThenSomeElseNone::new(msrv())));
     store.register_late_pass(|_| Box::new(bool_assert_comparison::BoolAssertComparison));
     store.register_early_pass(move || Box::new(module_style::ModStyle));
-    store.register_late_pass(|_| Box::new(unused_async::UnusedAsync));
+    store.register_late_pass(move |_| Box::new(unused_async::UnusedAsync::new(avoid_breaking_exported_api)));
     let disallowed_types = conf.disallowed_types.clone();
     store.register_late_pass(move |_| Box::new(disallowed_types::DisallowedTypes::new(disallowed_types.clone())));
     let import_renames = conf.enforced_import_renames.clone();
@@ -1045,6 +1100,90 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     });
     let stack_size_threshold = conf.stack_size_threshold;
     store.register_late_pass(move |_| Box::new(large_stack_frames::LargeStackFrames::new(stack_size_threshold)));
+    store.register_late_pass(|_| Box::new(manual_contains_key::ManualContainsKey));
+    store.register_late_pass(|_| Box::new(duration_from_float_seconds_precision::DurationFromFloatSecondsPrecision));
+    store.register_late_pass(|_| Box::new(manual_checked_arith_via_compare::ManualCheckedArithViaCompare));
+    store.register_late_pass(|_| Box::new(to_owned_then_as_ref_argument::ToOwnedThenAsRefArgument));
+    store.register_late_pass(|_| Box::<iter_on_locked_collection::IterOnLockedCollection>::default());
+    store.register_late_pass(|_| Box::new(box_collection_temporary::BoxCollectionTemporary));
+    store.register_late_pass(|_| Box::new(repeated_push_str_literal::RepeatedPushStrLiteral));
+    store.register_late_pass(move |_| {
+        Box::new(nested_option_result_flattenable::NestedOptionResultFlattenable::new(
+            msrv(),
+            avoid_breaking_exported_api,
+        ))
+    });
+    let blocking_call_paths = conf.blocking_call_paths.clone();
+    store.register_late_pass(move |_| {
+        Box::new(blocking_call_in_async_fn::BlockingCallInAsyncFn::new(
+            blocking_call_paths.clone(),
+        ))
+    });
+    let allowed_detached_spawn_wrappers = conf.allowed_detached_spawn_wrappers.clone();
+    store.register_late_pass(move |_| {
+        Box::new(detached_spawn_result::DetachedSpawnResult::new(
+            allowed_detached_spawn_wrappers.clone(),
+        ))
+    });
+    store.register_late_pass(|_| Box::new(select_without_biased_or_loop_guard::SelectWithoutBiasedOrLoopGuard));
+    store.register_late_pass(|_| Box::<block_on_inside_async::BlockOnInsideAsync>::default());
+    store.register_late_pass(|_| Box::new(unpolled_future_created::UnpolledFutureCreated));
+    store.register_late_pass(|_| Box::<atomics_ordering_mismatch::AtomicsOrderingMismatch>::default());
+    store.register_late_pass(|_| Box::<arc_mutex_of_copy_primitive::ArcMutexOfCopyPrimitive>::default());
+    store.register_late_pass(|_| Box::<rc_in_send_context::RcInSendContext>::default());
+    store.register_late_pass(|_| Box::new(join_handle_unwrap_in_drop::JoinHandleUnwrapInDrop));
+    store.register_late_pass(|_| Box::<double_lock_same_mutex::DoubleLockSameMutex>::default());
+    let send_wrapper_types = conf.send_wrapper_types.clone();
+    store.register_late_pass(move |_| {
+        Box::new(send_non_send_across_spawn::SendNonSendAcrossSpawn::new(
+            send_wrapper_types.clone(),
+        ))
+    });
+    store.register_late_pass(|_| Box::new(spawn_blocking_async_closure::SpawnBlockingAsyncClosure));
+    store.register_late_pass(|_| Box::new(slice_from_raw_parts_len_overflow::SliceFromRawPartsLenOverflow));
+    store.register_late_pass(|_| Box::new(unsound_send_sync_impl_heuristic::UnsoundSendSyncImplHeuristic));
+    store.register_late_pass(|_| Box::new(extern_fn_unwind_panic::ExternFnUnwindPanic));
+    let pointer_retaining_functions = conf.pointer_retaining_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(ffi_string_pointer_lifetime::FfiStringPointerLifetime::new(
+            pointer_retaining_functions.clone(),
+        ))
+    });
+    store.register_late_pass(|_| {
+        Box::<repr_c_enum_with_implicit_discriminants_ffi::ReprCEnumWithImplicitDiscriminantsFfi>::default()
+    });
+    store.register_late_pass(|_| Box::<volatile_ordinary_read_write::VolatileOrdinaryReadWrite>::default());
+    store.register_late_pass(|_| Box::new(union_field_access_without_cfg_layout::UnionFieldAccessWithoutCfgLayout));
+    store.register_late_pass(|_| Box::new(partial_ord_inconsistent_with_ord::PartialOrdInconsistentWithOrd));
+    store.register_late_pass(|_| Box::new(hash_ignores_field_used_in_eq::HashIgnoresFieldUsedInEq));
+    let allowed_drop_panic_functions = conf.allowed_drop_panic_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(drop_impl_may_panic::DropImplMayPanic::new(
+            allowed_drop_panic_functions.clone(),
+        ))
+    });
+    store.register_late_pass(|_| {
+        Box::new(deserialize_skipped_field_without_default::DeserializeSkippedFieldWithoutDefault)
+    });
+    let leak_initializer_functions = conf.leak_initializer_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(leaky_global_from_loop::LeakyGlobalFromLoop::new(
+            leak_initializer_functions.clone(),
+        ))
+    });
+    store.register_late_pass(|_| Box::new(non_monotonic_time_for_durations::NonMonotonicTimeForDurations));
+    store.register_late_pass(|_| Box::new(env_var_parsed_without_error_context::EnvVarParsedWithoutErrorContext));
+    store.register_late_pass(|_| Box::<arc_slice_candidate::ArcSliceCandidate>::default());
+    let default_hasher_hot_functions = conf.default_hasher_hot_functions.clone();
+    let fast_hash_map_type = conf.fast_hash_map_type.clone();
+    let fast_hash_set_type = conf.fast_hash_set_type.clone();
+    store.register_late_pass(move |_| {
+        Box::new(hashmap_default_hasher_hot_path::HashmapDefaultHasherHotPath::new(
+            default_hasher_hot_functions.clone(),
+            fast_hash_map_type.clone(),
+            fast_hash_set_type.clone(),
+        ))
+    });
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
@@ -0,0 +1,196 @@
+use super::AWAIT_IN_LOOP_COLLECTABLE;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::path_to_local;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
+use clippy_utils::usage::mutated_variables;
+use clippy_utils::visitors::{for_each_expr, is_local_used};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, HirIdSet, MatchSource, Pat, PatKind, StmtKind};
+use rustc_hir_typeck::expr_use_visitor::{Delegate, ExprUseVisitor, PlaceBase, PlaceWithHirId};
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_lint::LateContext;
+use rustc_middle::hir::place::ProjectionKind;
+use rustc_middle::mir::FakeReadCause;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+/// Checks `for x in iter { f(x).await; }` and `for x in iter { v.push(f(x).await); }`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+) {
+    let ExprKind::Block(block, _) = body.kind else {
+        return;
+    };
+    let (fut, vec) = match (block.stmts, block.expr) {
+        ([], Some(e)) => (awaited(e), None),
+        ([stmt], None) => match stmt.kind {
+            StmtKind::Semi(e) => {
+                if let Some(fut) = awaited(e) {
+                    (Some(fut), None)
+                } else if let Some((vec, pushed)) = pushed_into(cx, e) {
+                    (awaited(pushed), Some(vec))
+                } else {
+                    return;
+                }
+            },
+            _ => return,
+        },
+        // `let x = fut.await; v.push(x);`
+        ([first, second], None) => match (first.kind, second.kind) {
+            (StmtKind::Local(local), StmtKind::Semi(e))
+                if local.els.is_none()
+                    && let PatKind::Binding(_, id, _, None) = local.pat.kind
+                    && let Some(init) = local.init
+                    && let Some((vec, pushed)) = pushed_into(cx, e)
+                    && path_to_local(pushed) == Some(id) =>
+            {
+                (awaited(init), Some(vec))
+            },
+            _ => return,
+        },
+        _ => return,
+    };
+    let Some(fut) = fut else {
+        return;
+    };
+    if body.span.from_expansion() || fut.span.from_expansion() || !is_independent(cx, pat, fut) {
+        return;
+    }
+    if let Some(vec) = vec
+        && let Some(vec_id) = path_to_local(vec)
+        && is_local_used(cx, fut, vec_id)
+    {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        AWAIT_IN_LOOP_COLLECTABLE,
+        expr.span,
+        "the futures of this loop are awaited one after another, but don't depend on each other",
+        |diag| {
+            // the futures run concurrently, so their side effects may happen in a different order
+            let mut app = Applicability::MaybeIncorrect;
+            let ctxt = expr.span.ctxt();
+            let iter = if let ExprKind::AddrOf(BorrowKind::Ref, mutbl, inner) = arg.kind {
+                let inner = Sugg::hir_with_context(cx, inner, ctxt, "..", &mut app).maybe_par();
+                format!("{inner}.{}()", if mutbl.is_mut() { "iter_mut" } else { "iter" })
+            } else {
+                let iter = Sugg::hir_with_context(cx, arg, ctxt, "..", &mut app).maybe_par();
+                let arg_ty = cx.typeck_results().expr_ty(arg);
+                if cx
+                    .tcx
+                    .get_diagnostic_item(sym::Iterator)
+                    .map_or(false, |id| implements_trait(cx, arg_ty, id, &[]))
+                {
+                    iter.to_string()
+                } else {
+                    format!("{iter}.into_iter()")
+                }
+            };
+            let pat = snippet_with_context(cx, pat.span, ctxt, "..", &mut app).0;
+            let fut = snippet_with_context(cx, fut.span, ctxt, "..", &mut app).0;
+            let join = format!("futures::future::join_all({iter}.map(|{pat}| {fut})).await");
+            let sugg = if let Some(vec) = vec {
+                let vec = snippet_with_context(cx, vec.span, ctxt, "..", &mut app).0;
+                format!("{vec}.extend({join});")
+            } else {
+                format!("{join};")
+            };
+            diag.span_suggestion(expr.span, "run them concurrently", sugg, app);
+            diag.note("`join_all` is part of the `futures` crate, which also has `FuturesUnordered`");
+        },
+    );
+}
+
+/// If `e` is `fut.await`, returns `fut`.
+fn awaited<'tcx>(e: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::Match(scrutinee, _, MatchSource::AwaitDesugar) = e.kind
+        && let ExprKind::Call(_, [fut]) = scrutinee.kind
+    {
+        Some(fut)
+    } else {
+        None
+    }
+}
+
+/// If `e` is `v.push(x)` on a local `Vec`, returns `v` and `x`.
+fn pushed_into<'tcx>(cx: &LateContext<'_>, e: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let ExprKind::MethodCall(path, vec, [pushed], _) = e.kind
+        && path.ident.name.as_str() == "push"
+        && path_to_local(vec).is_some()
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(vec), sym::Vec)
+    {
+        Some((vec, pushed))
+    } else {
+        None
+    }
+}
+
+/// Whether `fut` can be created in a closure mapping the loop's items without changing its
+/// meaning, and without depending on the futures created before it.
+fn is_independent<'tcx>(cx: &LateContext<'tcx>, pat: &Pat<'_>, fut: &'tcx Expr<'_>) -> bool {
+    let has_control_flow = for_each_expr(fut, |e| match e.kind {
+        ExprKind::Ret(..)
+        | ExprKind::Break(..)
+        | ExprKind::Continue(..)
+        | ExprKind::Yield(..)
+        | ExprKind::Match(_, _, MatchSource::TryDesugar | MatchSource::AwaitDesugar) => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some();
+    if has_control_flow || !mutated_variables(fut, cx).map_or(false, |mutated| mutated.is_empty()) {
+        return false;
+    }
+
+    // a future borrowing the loop's item can't be returned from the closure
+    let mut delegate = BindingBorrowDelegate {
+        bindings: HirIdSet::default(),
+        borrowed: false,
+    };
+    pat.each_binding(|_, id, _, _| {
+        delegate.bindings.insert(id);
+    });
+    let infcx = cx.tcx.infer_ctxt().build();
+    ExprUseVisitor::new(
+        &mut delegate,
+        &infcx,
+        fut.hir_id.owner.def_id,
+        cx.param_env,
+        cx.typeck_results(),
+    )
+    .walk_expr(fut);
+    !delegate.borrowed
+}
+
+struct BindingBorrowDelegate {
+    bindings: HirIdSet,
+    borrowed: bool,
+}
+
+impl<'tcx> Delegate<'tcx> for BindingBorrowDelegate {
+    fn consume(&mut self, _: &PlaceWithHirId<'tcx>, _: HirId) {}
+
+    fn copy(&mut self, _: &PlaceWithHirId<'tcx>, _: HirId) {}
+
+    fn borrow(&mut self, cmt: &PlaceWithHirId<'tcx>, _: HirId, _: ty::BorrowKind) {
+        // borrowing through a reference the item holds is fine
+        if let PlaceBase::Local(id) = cmt.place.base
+            && self.bindings.contains(&id)
+            && !matches!(cmt.place.projections.first(), Some(proj) if proj.kind == ProjectionKind::Deref)
+        {
+            self.borrowed = true;
+        }
+    }
+
+    fn mutate(&mut self, _: &PlaceWithHirId<'tcx>, _: HirId) {}
+
+    fn fake_read(&mut self, _: &PlaceWithHirId<'tcx>, _: FakeReadCause, _: HirId) {}
+}
@@ -0,0 +1,156 @@
+use super::CHANNEL_RECV_BUSY_LOOP;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::macro_backtrace;
+use clippy_utils::ty::match_type;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{get_parent_expr, higher, is_res_lang_ctor, match_def_path, path_def_id, paths};
+use core::ops::ControlFlow;
+use rustc_hir::{Block, Expr, ExprKind, LangItem, MatchSource, Pat, PatKind, QPath, StmtKind};
+use rustc_lint::LateContext;
+
+/// Checks `loop { match rx.try_recv() { Ok(msg) => .., Err(_) => continue } }` and the like.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, body: &'tcx Block<'_>) {
+    // Only loops which do nothing but handle the messages, loops doing other work between polls,
+    // like an event loop rendering a frame, aren't waiting
+    let handling = match (body.stmts, body.expr) {
+        ([], Some(e)) => e,
+        ([stmt], None) => match stmt.kind {
+            StmtKind::Expr(e) | StmtKind::Semi(e) => e,
+            _ => return,
+        },
+        _ => return,
+    };
+    let try_recv = if let ExprKind::Match(scrutinee, ..) = handling.kind {
+        scrutinee
+    } else if let Some(if_let) = higher::IfLet::hir(cx, handling) {
+        if_let.let_expr
+    } else {
+        return;
+    };
+    if !is_try_recv(cx, try_recv) || !spins_on_empty(cx, try_recv) {
+        return;
+    }
+    let waits = for_each_expr(body, |e| {
+        if let ExprKind::Loop(..) | ExprKind::Closure(_) = e.kind {
+            return ControlFlow::Continue(Descend::No);
+        }
+        if waits(cx, e) {
+            return ControlFlow::Break(());
+        }
+        ControlFlow::Continue(Descend::Yes)
+    })
+    .is_some();
+    if waits {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        CHANNEL_RECV_BUSY_LOOP,
+        expr.span,
+        "this loop busy-waits for messages of a channel",
+        |diag| {
+            diag.span_note(try_recv.span, "`try_recv` returns right away if the channel is empty");
+            diag.help("use the blocking `recv` or `recv_timeout`, or an async channel");
+        },
+    );
+}
+
+/// Whether `e` calls `try_recv` on a `Receiver` of `std` or `crossbeam_channel`.
+fn is_try_recv(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    if let ExprKind::MethodCall(path, recv, [], _) = e.kind
+        && path.ident.as_str() == "try_recv"
+        && !e.span.from_expansion()
+    {
+        let ty = cx.typeck_results().expr_ty(recv).peel_refs();
+        match_type(cx, ty, &paths::STD_MPSC_RECEIVER) || match_type(cx, ty, &paths::CROSSBEAM_CHANNEL_RECEIVER)
+    } else {
+        false
+    }
+}
+
+/// Whether the loop goes on if the `try_recv` call `e` finds the channel empty, because it's
+/// matched by an empty or `continue` arm, or by an `if let Ok(..)` without an `else`.
+fn spins_on_empty(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let Some(parent) = get_parent_expr(cx, e) else {
+        return false;
+    };
+    match parent.kind {
+        ExprKind::Match(_, arms, MatchSource::Normal) => arms
+            .iter()
+            .any(|arm| arm.guard.is_none() && matches_empty(cx, arm.pat) && is_empty_or_continue(arm.body)),
+        ExprKind::Let(let_expr) => {
+            if let_expr.init.hir_id == e.hir_id
+                && let Some(grandparent) = get_parent_expr(cx, parent)
+                && let Some(if_let) = higher::IfLet::hir(cx, grandparent)
+                && !matches_empty(cx, if_let.let_pat)
+            {
+                if_let.if_else.map_or(true, is_empty_or_continue)
+            } else {
+                false
+            }
+        },
+        _ => false,
+    }
+}
+
+/// Whether `pat` may match the error returned for an empty channel.
+fn matches_empty(cx: &LateContext<'_>, pat: &Pat<'_>) -> bool {
+    match pat.kind {
+        PatKind::Wild | PatKind::Binding(_, _, _, None) => true,
+        PatKind::TupleStruct(ref qpath, [inner], _) => {
+            is_res_lang_ctor(cx, cx.qpath_res(qpath, pat.hir_id), LangItem::ResultErr) && matches_empty(cx, inner)
+        },
+        PatKind::Path(QPath::Resolved(_, path)) | PatKind::TupleStruct(QPath::Resolved(_, path), ..) => path
+            .segments
+            .last()
+            .map_or(false, |segment| segment.ident.as_str() == "Empty"),
+        PatKind::Or(pats) => pats.iter().any(|pat| matches_empty(cx, pat)),
+        _ => false,
+    }
+}
+
+fn is_empty_or_continue(e: &Expr<'_>) -> bool {
+    match e.kind {
+        ExprKind::Continue(_) | ExprKind::Tup([]) => true,
+        ExprKind::Block(block, _) => match (block.stmts, block.expr) {
+            ([], None) => true,
+            ([], Some(e)) => is_empty_or_continue(e),
+            ([stmt], None) => matches!(
+                stmt.kind,
+                StmtKind::Semi(Expr {
+                    kind: ExprKind::Continue(_),
+                    ..
+                })
+            ),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Whether `e` sleeps, yields, blocks on a channel or awaits.
+fn waits(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    if macro_backtrace(e.span).any(|macro_call| cx.tcx.item_name(macro_call.def_id).as_str() == "select") {
+        return true;
+    }
+    match e.kind {
+        ExprKind::Call(func, _) => path_def_id(cx, func).map_or(false, |id| {
+            [
+                &paths::STD_THREAD_SLEEP[..],
+                &paths::STD_THREAD_PARK,
+                &paths::STD_THREAD_PARK_TIMEOUT,
+                &paths::STD_THREAD_YIELD_NOW,
+                &paths::CORE_HINT_SPIN_LOOP,
+            ]
+            .into_iter()
+            .any(|path| match_def_path(cx, id, path))
+        }),
+        ExprKind::MethodCall(path, ..) => matches!(
+            path.ident.as_str(),
+            "recv" | "recv_timeout" | "recv_deadline" | "wait" | "wait_timeout" | "park"
+        ),
+        ExprKind::Match(_, _, MatchSource::AwaitDesugar) => true,
+        _ => false,
+    }
+}
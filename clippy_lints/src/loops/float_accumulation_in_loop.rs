@@ -0,0 +1,53 @@
+use super::utils::known_iterations;
+use super::FLOAT_ACCUMULATION_IN_LOOP;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::path_to_local;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use core::ops::ControlFlow;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+
+/// Checks for `sum += x` in a `for` loop, where `sum` is a float declared outside of the loop.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'_>, body: &'tcx Expr<'_>, min_iterations: u64) {
+    if known_iterations(cx, arg).map_or(false, |n| n < u128::from(min_iterations)) {
+        return;
+    }
+
+    let _: Option<!> = for_each_expr(body, |e| {
+        let acc = match e.kind {
+            // nested loops are checked on their own, closures may not run in the loop
+            ExprKind::Loop(..) | ExprKind::Closure(_) => return ControlFlow::Continue(Descend::No),
+            ExprKind::AssignOp(op, acc, _) if matches!(op.node, BinOpKind::Add | BinOpKind::Sub) => acc,
+            ExprKind::Assign(acc, value, _)
+                if let ExprKind::Binary(op, lhs, rhs) = value.kind
+                    && let Some(id) = path_to_local(acc)
+                    && (path_to_local(lhs) == Some(id)
+                        || (op.node == BinOpKind::Add && path_to_local(rhs) == Some(id))) =>
+            {
+                acc
+            },
+            _ => return ControlFlow::Continue(Descend::Yes),
+        };
+        let acc_ty = cx.typeck_results().expr_ty(acc);
+        if !e.span.from_expansion()
+            && acc_ty.is_floating_point()
+            && let Some(id) = path_to_local(acc)
+            && !body.span.contains(cx.tcx.hir().span(id))
+        {
+            span_lint_and_then(
+                cx,
+                FLOAT_ACCUMULATION_IN_LOOP,
+                e.span,
+                &format!("accumulating `{acc_ty}` values in a loop"),
+                |diag| {
+                    diag.note(
+                        "the rounding errors of every addition add up, which loses precision over many iterations \
+                         or with values of very different magnitudes",
+                    );
+                    diag.help("use Kahan summation, or sort the values by magnitude and `sum()` them");
+                },
+            );
+        }
+        ControlFlow::Continue(Descend::Yes)
+    });
+}
@@ -0,0 +1,193 @@
+use super::INDEX_LOOP_OFF_BY_ONE_INCLUSIVE;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{higher, path_to_local, path_to_local_id, SpanlessEq};
+use core::ops::ControlFlow;
+use rustc_ast::ast::RangeLimits;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId, Node, Pat, PatKind, Stmt, StmtKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::{sym, Span};
+
+const GET_HELP: &str = "if the last iteration is needed, use `get` and handle the missing element instead";
+
+/// Checks for `for i in start..=v.len()` loops which index `v` with `i`.
+pub(super) fn check_for<'tcx>(cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>, arg: &'tcx Expr<'_>, body: &'tcx Expr<'_>) {
+    if let Some(higher::Range {
+        start: Some(start),
+        end: Some(end),
+        limits: RangeLimits::Closed,
+    }) = higher::Range::hir(arg)
+        && let PatKind::Binding(_, id, ..) = pat.kind
+        && let Some(seq) = len_receiver(cx, end)
+        && let Some(index) = find_index(cx, body, seq, id)
+    {
+        span_lint_and_then(
+            cx,
+            INDEX_LOOP_OFF_BY_ONE_INCLUSIVE,
+            arg.span,
+            "this range includes the length, which is out of bounds for indexing",
+            |diag| {
+                diag.span_note(index, "this panics in the last iteration");
+                let mut app = Applicability::MaybeIncorrect;
+                let start = snippet_with_context(cx, start.span, arg.span.ctxt(), "..", &mut app).0;
+                let end = snippet_with_context(cx, end.span, arg.span.ctxt(), "..", &mut app).0;
+                diag.span_suggestion(arg.span, "use an exclusive range", format!("{start}..{end}"), app);
+                diag.help(GET_HELP);
+            },
+        );
+    }
+}
+
+/// Checks for `while i <= v.len()` loops which index `v` with `i`.
+pub(super) fn check_while<'tcx>(cx: &LateContext<'tcx>, condition: &'tcx Expr<'_>, body: &'tcx Expr<'_>) {
+    let ExprKind::Binary(op, lhs, rhs) = condition.kind else {
+        return;
+    };
+    let (counter, len, replacement) = match op.node {
+        BinOpKind::Le => (lhs, rhs, "<"),
+        BinOpKind::Ge => (rhs, lhs, ">"),
+        _ => return,
+    };
+    if !condition.span.from_expansion()
+        && let Some(id) = path_to_local(counter)
+        && let Some(seq) = len_receiver(cx, len)
+        && let Some(index) = find_index(cx, body, seq, id)
+    {
+        span_lint_and_then(
+            cx,
+            INDEX_LOOP_OFF_BY_ONE_INCLUSIVE,
+            condition.span,
+            "this condition holds when the index equals the length, which is out of bounds for indexing",
+            |diag| {
+                diag.span_note(index, "this panics in the last iteration");
+                diag.span_suggestion(
+                    op.span,
+                    "use a strict comparison",
+                    replacement,
+                    Applicability::MaybeIncorrect,
+                );
+                diag.help(GET_HELP);
+            },
+        );
+    }
+}
+
+/// Returns `v` for `v.len()`, if `v` is a slice, array, `Vec` or `VecDeque`.
+fn len_receiver<'a>(cx: &LateContext<'_>, e: &'a Expr<'a>) -> Option<&'a Expr<'a>> {
+    if let ExprKind::MethodCall(path, recv, [], _) = e.kind
+        && path.ident.name == sym::len
+    {
+        let ty = cx.typeck_results().expr_ty_adjusted(recv).peel_refs();
+        (matches!(ty.kind(), ty::Slice(_) | ty::Array(..))
+            || is_type_diagnostic_item(cx, ty, sym::Vec)
+            || is_type_diagnostic_item(cx, ty, sym::VecDeque))
+        .then_some(recv)
+    } else {
+        None
+    }
+}
+
+/// Finds `seq[index]` in `body`, where `index` is the local `id`, which isn't bounds checked.
+fn find_index<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Expr<'_>, seq: &Expr<'_>, id: HirId) -> Option<Span> {
+    for_each_expr(body, |e| {
+        if let ExprKind::Index(base, index) = e.kind
+            && path_to_local_id(index, id)
+            && SpanlessEq::new(cx).eq_expr(base, seq)
+            && !is_bounds_checked(cx, body, e, seq, id)
+        {
+            ControlFlow::Break(e.span)
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+}
+
+/// Whether `e` only runs when the index is in bounds, because it's in `if i < seq.len() { .. }`,
+/// in the `else` branch of `if i == seq.len() { .. }`, or after `if i == seq.len() { break; }`.
+fn is_bounds_checked(cx: &LateContext<'_>, body: &Expr<'_>, e: &Expr<'_>, seq: &Expr<'_>, id: HirId) -> bool {
+    let mut child = e.hir_id;
+    for (parent_id, node) in cx.tcx.hir().parent_iter(e.hir_id) {
+        match node {
+            Node::Expr(parent) => {
+                if let Some(higher::If { cond, then, r#else }) = higher::If::hir(parent)
+                    && ((then.hir_id == child && checks_in_bounds(cx, cond, seq, id))
+                        || (r#else.map_or(false, |r#else| r#else.hir_id == child)
+                            && checks_out_of_bounds(cx, cond, seq, id)))
+                {
+                    return true;
+                }
+            },
+            Node::Block(block) => {
+                if block
+                    .stmts
+                    .iter()
+                    .take_while(|stmt| stmt.hir_id != child)
+                    .any(|stmt| is_exit_guard(cx, stmt, seq, id))
+                {
+                    return true;
+                }
+            },
+            _ => {},
+        }
+        if parent_id == body.hir_id {
+            break;
+        }
+        child = parent_id;
+    }
+    false
+}
+
+/// Whether `stmt` is `if i == seq.len() { break; }`, or a similar check leaving the iteration.
+fn is_exit_guard(cx: &LateContext<'_>, stmt: &Stmt<'_>, seq: &Expr<'_>, id: HirId) -> bool {
+    if let StmtKind::Expr(e) | StmtKind::Semi(e) = stmt.kind
+        && let Some(higher::If { cond, then, r#else: None }) = higher::If::hir(e)
+        && let ExprKind::Block(block, _) = then.kind
+        && let Some(last) = block.expr.or_else(|| match block.stmts.last()?.kind {
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+            _ => None,
+        })
+    {
+        (matches!(last.kind, ExprKind::Break(..) | ExprKind::Continue(_) | ExprKind::Ret(_))
+            || cx.typeck_results().expr_ty(last).is_never())
+            && checks_out_of_bounds(cx, cond, seq, id)
+    } else {
+        false
+    }
+}
+
+/// Whether `cond` only holds if `i < seq.len()`, e.g. `i < seq.len() && ..`.
+fn checks_in_bounds(cx: &LateContext<'_>, cond: &Expr<'_>, seq: &Expr<'_>, id: HirId) -> bool {
+    let ExprKind::Binary(op, lhs, rhs) = cond.kind else {
+        return false;
+    };
+    match op.node {
+        BinOpKind::And => checks_in_bounds(cx, lhs, seq, id) || checks_in_bounds(cx, rhs, seq, id),
+        BinOpKind::Lt => is_index_and_len(cx, lhs, rhs, seq, id),
+        BinOpKind::Gt => is_index_and_len(cx, rhs, lhs, seq, id),
+        BinOpKind::Ne => is_index_and_len(cx, lhs, rhs, seq, id) || is_index_and_len(cx, rhs, lhs, seq, id),
+        _ => false,
+    }
+}
+
+/// Whether `cond` holds if `i == seq.len()`, e.g. `i == seq.len() || ..`.
+fn checks_out_of_bounds(cx: &LateContext<'_>, cond: &Expr<'_>, seq: &Expr<'_>, id: HirId) -> bool {
+    let ExprKind::Binary(op, lhs, rhs) = cond.kind else {
+        return false;
+    };
+    match op.node {
+        BinOpKind::Or => checks_out_of_bounds(cx, lhs, seq, id) || checks_out_of_bounds(cx, rhs, seq, id),
+        BinOpKind::Ge => is_index_and_len(cx, lhs, rhs, seq, id),
+        BinOpKind::Le => is_index_and_len(cx, rhs, lhs, seq, id),
+        BinOpKind::Eq => is_index_and_len(cx, lhs, rhs, seq, id) || is_index_and_len(cx, rhs, lhs, seq, id),
+        _ => false,
+    }
+}
+
+/// Whether `index` is the local `id` and `len` is `seq.len()`.
+fn is_index_and_len(cx: &LateContext<'_>, index: &Expr<'_>, len: &Expr<'_>, seq: &Expr<'_>, id: HirId) -> bool {
+    path_to_local_id(index, id) && len_receiver(cx, len).map_or(false, |recv| SpanlessEq::new(cx).eq_expr(recv, seq))
+}
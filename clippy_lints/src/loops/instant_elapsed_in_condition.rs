@@ -0,0 +1,91 @@
+use super::INSTANT_ELAPSED_IN_CONDITION;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::match_type;
+use clippy_utils::{match_def_path, path_def_id, paths};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, UnOp};
+use rustc_lint::LateContext;
+
+/// Checks the equality comparisons with the current time in the condition of a `while` loop.
+pub(super) fn check(cx: &LateContext<'_>, condition: &Expr<'_>) {
+    match condition.kind {
+        ExprKind::Binary(op, lhs, rhs) if matches!(op.node, BinOpKind::And | BinOpKind::Or) => {
+            check(cx, lhs);
+            check(cx, rhs);
+        },
+        ExprKind::Unary(UnOp::Not, inner) | ExprKind::DropTemps(inner) => check(cx, inner),
+        ExprKind::Binary(op, lhs, rhs)
+            if matches!(op.node, BinOpKind::Eq | BinOpKind::Ne) && !condition.span.from_expansion() =>
+        {
+            check_equality(cx, condition, op.node, lhs, rhs);
+        },
+        _ => {},
+    }
+}
+
+/// Checks for `Instant::now() == deadline`, `start.elapsed() != timeout` and similar.
+fn check_equality(cx: &LateContext<'_>, e: &Expr<'_>, op: BinOpKind, lhs: &Expr<'_>, rhs: &Expr<'_>) {
+    let (current, current_on_left) = if let Some(current) = current_time(cx, lhs) {
+        (current, true)
+    } else if let Some(current) = current_time(cx, rhs) {
+        (current, false)
+    } else {
+        return;
+    };
+    span_lint_and_then(
+        cx,
+        INSTANT_ELAPSED_IN_CONDITION,
+        e.span,
+        &format!("comparing {current} for equality in a loop condition"),
+        |diag| {
+            diag.note(format!(
+                "{current} only moves forward, but it is unlikely to ever be exactly equal to another value"
+            ));
+            if op == BinOpKind::Ne {
+                let mut app = Applicability::MaybeIncorrect;
+                let lhs = snippet_with_context(cx, lhs.span, e.span.ctxt(), "..", &mut app).0;
+                let rhs = snippet_with_context(cx, rhs.span, e.span.ctxt(), "..", &mut app).0;
+                let op = if current_on_left { "<" } else { ">" };
+                diag.span_suggestion(e.span, "loop until it has passed", format!("{lhs} {op} {rhs}"), app);
+            }
+        },
+    );
+}
+
+/// If `e` reads the current time, returns a description of what it reads: `Instant::now()` or
+/// the elapsed time, as in `start.elapsed()` or `Instant::now() - start`.
+fn current_time(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<&'static str> {
+    match e.kind {
+        ExprKind::Call(..) if is_instant_now_call(cx, e) => Some("`Instant::now()`"),
+        ExprKind::MethodCall(path, recv, [], _)
+            if path.ident.as_str() == "elapsed"
+                && match_type(
+                    cx,
+                    cx.typeck_results().expr_ty_adjusted(recv).peel_refs(),
+                    &paths::INSTANT,
+                ) =>
+        {
+            Some("the elapsed time")
+        },
+        ExprKind::MethodCall(path, now, [_], _)
+            if path.ident.as_str() == "duration_since" && is_instant_now_call(cx, now) =>
+        {
+            Some("the elapsed time")
+        },
+        ExprKind::Binary(op, now, _) if op.node == BinOpKind::Sub && is_instant_now_call(cx, now) => {
+            Some("the elapsed time")
+        },
+        _ => None,
+    }
+}
+
+fn is_instant_now_call(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    if let ExprKind::Call(func, []) = e.kind
+        && let Some(def_id) = path_def_id(cx, func)
+    {
+        match_def_path(cx, def_id, &paths::INSTANT_NOW)
+    } else {
+        false
+    }
+}
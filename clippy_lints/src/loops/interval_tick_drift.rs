@@ -0,0 +1,97 @@
+use super::INTERVAL_TICK_DRIFT;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::usage::mutated_variables;
+use clippy_utils::{path_def_id, path_to_local};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::hir_id::HirIdSet;
+use rustc_hir::{Block, Expr, ExprKind, MatchSource, QPath, StmtKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+/// Checks `loop { work(); sleep(period); }` and the same with `while` and `.await`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    body: &'tcx Block<'_>,
+    sleep_functions: &FxHashSet<DefId>,
+) {
+    // nothing but sleeping doesn't drift
+    if body.stmts.len() + usize::from(body.expr.is_some()) < 2 {
+        return;
+    }
+    let top_level = body
+        .stmts
+        .iter()
+        .filter_map(|stmt| match stmt.kind {
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+            _ => None,
+        })
+        .chain(body.expr);
+    let Some(mutated) = mutated_variables(expr, cx) else {
+        return;
+    };
+    for e in top_level {
+        let (call, is_async) = match e.kind {
+            ExprKind::Match(scrutinee, _, MatchSource::AwaitDesugar) => match scrutinee.kind {
+                ExprKind::Call(_, [fut]) => (fut, true),
+                _ => continue,
+            },
+            _ => (e, false),
+        };
+        if let ExprKind::Call(func, [period]) = call.kind
+            && !call.span.from_expansion()
+            && path_def_id(cx, func).map_or(false, |id| sleep_functions.contains(&id))
+            && is_fixed_period(cx, expr, &mutated, period)
+        {
+            span_lint_hir_and_then(
+                cx,
+                INTERVAL_TICK_DRIFT,
+                e.hir_id,
+                e.span,
+                "this loop runs periodically by sleeping for a fixed period",
+                |diag| {
+                    diag.note(
+                        "the time spent on the rest of each iteration adds to the period, so the loop drifts \
+                         behind the intended schedule",
+                    );
+                    if is_async {
+                        diag.help("consider using `tokio::time::interval` and awaiting `tick()` at the start of the loop");
+                    } else {
+                        diag.help("consider sleeping until a deadline which is advanced by the period every iteration");
+                    }
+                },
+            );
+            return;
+        }
+    }
+}
+
+/// Whether `period` is the same every iteration of `loop_expr`: a literal, a constant, a local
+/// defined outside the loop and not `mutated` in it, or a `Duration` made from those.
+fn is_fixed_period(cx: &LateContext<'_>, loop_expr: &Expr<'_>, mutated: &HirIdSet, period: &Expr<'_>) -> bool {
+    match period.kind {
+        ExprKind::Lit(_) => true,
+        ExprKind::Path(ref qpath) => {
+            if let Some(id) = path_to_local(period) {
+                !mutated.contains(&id) && !cx.tcx.hir().parent_id_iter(id).any(|id| id == loop_expr.hir_id)
+            } else {
+                matches!(
+                    cx.qpath_res(qpath, period.hir_id),
+                    Res::Def(DefKind::Const | DefKind::AssocConst, _)
+                )
+            }
+        },
+        ExprKind::Binary(_, lhs, rhs) => {
+            is_fixed_period(cx, loop_expr, mutated, lhs) && is_fixed_period(cx, loop_expr, mutated, rhs)
+        },
+        ExprKind::Call(func, args) => {
+            matches!(func.kind, ExprKind::Path(QPath::TypeRelative(..)))
+                && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(period), sym::Duration)
+                && args.iter().all(|arg| is_fixed_period(cx, loop_expr, mutated, arg))
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,222 @@
+use super::MANUAL_WINDOWS_CHUNKS;
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{contains_name, get_parent_expr, higher, is_integer_const, path_to_local, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_ast::ast::RangeLimits;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, HirId, Mutability, Pat, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+use rustc_span::{sym, Span, Symbol};
+
+#[derive(Clone, Copy)]
+enum Kind {
+    /// `for i in 0..v.len() - (n - 1)`, accessing `v[i + o]`
+    Windows,
+    /// `for i in 0..v.len() / n`, accessing `v[i * n + o]`
+    ChunksExact,
+}
+
+/// Checks for looping over the indices of a slice only to access a window or a chunk starting at
+/// each index.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    pat: &'tcx Pat<'_>,
+    arg: &'tcx Expr<'_>,
+    body: &'tcx Expr<'_>,
+    expr: &'tcx Expr<'_>,
+) {
+    let PatKind::Binding(_, index_id, index_ident, None) = pat.kind else {
+        return;
+    };
+    let Some(higher::Range {
+        start: Some(start),
+        end: Some(end),
+        limits,
+    }) = higher::Range::hir(arg)
+    else {
+        return;
+    };
+    if !is_integer_const(cx, start, 0) || arg.span.from_expansion() {
+        return;
+    }
+    let ExprKind::Binary(op, len_call, amount) = end.kind else {
+        return;
+    };
+    let Some(amount) = const_int(cx, amount) else {
+        return;
+    };
+    let (kind, size) = match (op.node, limits) {
+        (BinOpKind::Sub, RangeLimits::HalfOpen) => (Kind::Windows, amount + 1),
+        (BinOpKind::Sub, RangeLimits::Closed) if amount > 0 => (Kind::Windows, amount),
+        (BinOpKind::Div, RangeLimits::HalfOpen) if amount > 0 => (Kind::ChunksExact, amount),
+        _ => return,
+    };
+    let ExprKind::MethodCall(len_path, vec_expr, [], _) = len_call.kind else {
+        return;
+    };
+    let Some(vec_id) = path_to_local(vec_expr) else {
+        return;
+    };
+    let vec_ty = cx.typeck_results().expr_ty_adjusted(vec_expr).peel_refs();
+    if len_path.ident.name != sym::len
+        || !(vec_ty.is_slice() || vec_ty.is_array() || is_type_diagnostic_item(cx, vec_ty, sym::Vec))
+    {
+        return;
+    }
+    let (binding, method) = match kind {
+        Kind::Windows => ("window", "windows"),
+        Kind::ChunksExact => ("chunk", "chunks_exact"),
+    };
+    if contains_name(Symbol::intern(binding), body, cx) {
+        return;
+    }
+
+    let stride = match kind {
+        Kind::Windows => 1,
+        Kind::ChunksExact => size,
+    };
+    let mut app = Applicability::MachineApplicable;
+    let Some(mut sugg) = rewrite_accesses(cx, body, vec_id, index_id, stride, size, binding) else {
+        return;
+    };
+    if sugg.is_empty() {
+        return;
+    }
+    sugg.push((pat.span, binding.to_owned()));
+    sugg.push((
+        arg.span,
+        format!(
+            "{}.{method}({size})",
+            snippet_with_applicability(cx, vec_expr.span, "..", &mut app)
+        ),
+    ));
+
+    span_lint_and_then(
+        cx,
+        MANUAL_WINDOWS_CHUNKS,
+        expr.span.with_hi(arg.span.hi()),
+        &format!("the loop variable `{index_ident}` is only used to access a slice in parts"),
+        |diag| {
+            diag.multipart_suggestion(format!("consider using `{method}`"), sugg, app);
+        },
+    );
+}
+
+fn const_int(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<u128> {
+    match constant(cx, cx.typeck_results(), e) {
+        Some(Constant::Int(value)) => Some(value),
+        _ => None,
+    }
+}
+
+/// Returns `(a, b)` if `e` is `a * i + b` for constants `a` and `b`.
+fn linear(cx: &LateContext<'_>, e: &Expr<'_>, index_id: HirId) -> Option<(u128, u128)> {
+    if path_to_local_id(e, index_id) {
+        return Some((1, 0));
+    }
+    if let Some(value) = const_int(cx, e) {
+        return Some((0, value));
+    }
+    let ExprKind::Binary(op, lhs, rhs) = e.kind else {
+        return None;
+    };
+    let (lhs, rhs) = (linear(cx, lhs, index_id)?, linear(cx, rhs, index_id)?);
+    match op.node {
+        BinOpKind::Add => Some((lhs.0.checked_add(rhs.0)?, lhs.1.checked_add(rhs.1)?)),
+        BinOpKind::Mul if lhs.0 == 0 => Some((rhs.0.checked_mul(lhs.1)?, rhs.1.checked_mul(lhs.1)?)),
+        BinOpKind::Mul if rhs.0 == 0 => Some((lhs.0.checked_mul(rhs.1)?, lhs.1.checked_mul(rhs.1)?)),
+        _ => None,
+    }
+}
+
+/// Computes the replacements for all accesses to `v` in the loop body, which have to be at offsets
+/// `stride * i + o` with `o < size`. Returns `None` if `v` or `i` are used in any other way.
+fn rewrite_accesses(
+    cx: &LateContext<'_>,
+    body: &Expr<'_>,
+    vec_id: HirId,
+    index_id: HirId,
+    stride: u128,
+    size: u128,
+    binding: &str,
+) -> Option<Vec<(Span, String)>> {
+    let offset = |e: &Expr<'_>| match linear(cx, e, index_id) {
+        Some((a, b)) if a == stride => Some(b),
+        _ => None,
+    };
+    let mut sugg = Vec::new();
+    let aborted = for_each_expr(body, |e| {
+        let ExprKind::Index(base, index) = e.kind else {
+            return if path_to_local_id(e, vec_id) || path_to_local_id(e, index_id) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(Descend::Yes)
+            };
+        };
+        if !path_to_local_id(base, vec_id) {
+            return ControlFlow::Continue(Descend::Yes);
+        }
+        let parent = get_parent_expr(cx, e);
+        // writing through the index would conflict with borrowing the slice
+        if let Some(parent) = parent
+            && match parent.kind {
+                ExprKind::Assign(lhs, ..) | ExprKind::AssignOp(_, lhs, _) => lhs.hir_id == e.hir_id,
+                ExprKind::AddrOf(_, Mutability::Mut, _) => true,
+                _ => false,
+            }
+        {
+            return ControlFlow::Break(());
+        }
+        if cx.typeck_results().expr_adjustments(e).iter().any(|adjust| {
+            matches!(
+                adjust.kind,
+                Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Mut { .. }))
+            )
+        }) {
+            return ControlFlow::Break(());
+        }
+        if let Some(range) = higher::Range::hir(index) {
+            let (Some(start), Some(end)) = (range.start, range.end) else {
+                return ControlFlow::Break(());
+            };
+            let (Some(start), Some(end)) = (offset(start), offset(end)) else {
+                return ControlFlow::Break(());
+            };
+            let end = if range.limits == RangeLimits::Closed {
+                end + 1
+            } else {
+                end
+            };
+            if start > end || end > size {
+                return ControlFlow::Break(());
+            }
+            if start == 0 && end == size {
+                match parent {
+                    Some(
+                        parent @ Expr {
+                            kind: ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _),
+                            ..
+                        },
+                    ) => {
+                        sugg.push((parent.span, binding.to_owned()));
+                    },
+                    _ => sugg.push((e.span, format!("{binding}[..]"))),
+                }
+            } else {
+                sugg.push((e.span, format!("{binding}[{start}..{end}]")));
+            }
+        } else {
+            match offset(index) {
+                Some(offset) if offset < size => sugg.push((e.span, format!("{binding}[{offset}]"))),
+                _ => return ControlFlow::Break(()),
+            }
+        }
+        ControlFlow::Continue(Descend::No)
+    });
+    aborted.is_none().then_some(sugg)
+}
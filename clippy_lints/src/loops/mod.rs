@@ -1,31 +1,42 @@
+mod await_in_loop_collectable;
+mod channel_recv_busy_loop;
 mod empty_loop;
 mod explicit_counter_loop;
 mod explicit_into_iter_loop;
 mod explicit_iter_loop;
+mod float_accumulation_in_loop;
 mod for_kv_map;
+mod index_loop_off_by_one_inclusive;
+mod instant_elapsed_in_condition;
+mod interval_tick_drift;
 mod iter_next_loop;
 mod manual_find;
 mod manual_flatten;
 mod manual_memcpy;
 mod manual_while_let_some;
+mod manual_windows_chunks;
 mod missing_spin_loop;
+mod modification_while_iterating_indices;
 mod mut_range_bound;
 mod needless_range_loop;
 mod never_loop;
 mod same_item_push;
 mod single_element_loop;
+mod string_concat_in_loop;
 mod utils;
 mod while_immutable_condition;
 mod while_let_loop;
 mod while_let_on_iterator;
 
-use clippy_utils::higher;
 use clippy_utils::msrvs::Msrv;
+use clippy_utils::{def_path_def_ids, higher};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::DefId;
 use rustc_hir::{Expr, ExprKind, LoopSource, Pat};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Span;
-use utils::{make_iterator_snippet, IncrementVisitor, InitializeVisitor};
+use utils::{known_iterations, make_iterator_snippet, IncrementVisitor, InitializeVisitor};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -607,12 +618,326 @@ declare_clippy_lint! {
     "checking for emptiness of a `Vec` in the loop condition and popping an element in the body"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `for` loops over the indices of a slice that only use the index to access the
+    /// elements of a window or a chunk starting at it, e.g. `v[i]` and `v[i + 1]` in a loop over
+    /// `0..v.len() - 1`, or `v[2 * i]` and `v[2 * i + 1]` in a loop over `0..v.len() / 2`.
+    ///
+    /// ### Why is this bad?
+    /// `windows` and `chunks_exact` state the intent directly and avoid the index arithmetic and its
+    /// bounds checks.
+    ///
+    /// ### Known problems
+    /// `0..v.len() - 1` panics if `v` is empty, while `windows` yields nothing.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let v = vec![1, 2, 3];
+    /// for i in 0..v.len() - 1 {
+    ///     println!("{}", v[i + 1] - v[i]);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let v = vec![1, 2, 3];
+    /// for window in v.windows(2) {
+    ///     println!("{}", window[1] - window[0]);
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_WINDOWS_CHUNKS,
+    complexity,
+    "looping over indices to access windows or chunks of a slice"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `while` loop conditions that compare `Instant::now()` or an elapsed time, like
+    /// `start.elapsed()`, with `==` or `!=`.
+    ///
+    /// ### Why is this bad?
+    /// `Instant::now()` is read anew on every iteration and only guaranteed to not go backwards.
+    /// It can skip over any given `Instant`, so `while Instant::now() != deadline` may never stop.
+    /// The same goes for the elapsed time and a `Duration`.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # use std::time::{Duration, Instant};
+    /// let start = Instant::now();
+    /// let deadline = start + Duration::from_millis(1);
+    /// while start.elapsed() != Duration::from_millis(1) {}
+    /// while Instant::now() != deadline {}
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # use std::time::{Duration, Instant};
+    /// let start = Instant::now();
+    /// let deadline = start + Duration::from_millis(1);
+    /// while start.elapsed() < Duration::from_millis(1) {}
+    /// while Instant::now() < deadline {}
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub INSTANT_ELAPSED_IN_CONDITION,
+    suspicious,
+    "comparing the current time for equality in a loop condition, which may never stop"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `for` loops which only await a future created from the loop's item, possibly
+    /// pushing its output into a `Vec`, where the futures don't depend on each other.
+    ///
+    /// ### Why is this bad?
+    /// Each future is only created after the previous one completed, so they don't run
+    /// concurrently. Joining them with `futures::future::join_all` or collecting them into a
+    /// `FuturesUnordered` lets them make progress at the same time.
+    ///
+    /// ### Known problems
+    /// The futures may depend on each other in ways which aren't detected, e.g. through shared
+    /// state with interior mutability, or the order of their side effects may be important.
+    /// Running many futures at once may also exhaust a resource, like the number of connections.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let mut pages = Vec::new();
+    /// for url in urls {
+    ///     pages.push(fetch(url).await);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let mut pages = Vec::new();
+    /// pages.extend(futures::future::join_all(urls.into_iter().map(|url| fetch(url))).await);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub AWAIT_IN_LOOP_COLLECTABLE,
+    nursery,
+    "awaiting independent futures one after another in a `for` loop"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for loops which do nothing but call `try_recv` on the `Receiver` of a `std` or
+    /// `crossbeam_channel` channel, handle the message and go on if the channel is empty, without
+    /// sleeping, parking, blocking or awaiting anywhere in the loop.
+    ///
+    /// ### Why is this bad?
+    /// The loop busy-waits for messages, using a whole CPU core while the channel is empty.
+    /// `recv` blocks the thread until a message arrives instead.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # let (tx, rx) = std::sync::mpsc::channel::<u32>();
+    /// loop {
+    ///     match rx.try_recv() {
+    ///         Ok(msg) => println!("{msg}"),
+    ///         Err(_) => continue,
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # let (tx, rx) = std::sync::mpsc::channel::<u32>();
+    /// while let Ok(msg) = rx.recv() {
+    ///     println!("{msg}");
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub CHANNEL_RECV_BUSY_LOOP,
+    perf,
+    "busy-waiting for messages of a channel with `try_recv`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `loop`s and `while` loops which do some work and then sleep for a fixed period,
+    /// with `std::thread::sleep`, `tokio::time::sleep` or `async_std::task::sleep`, or the
+    /// functions configured in `sleep-functions`.
+    ///
+    /// ### Why is this bad?
+    /// The time spent on the work adds to the period, so the loop runs less often than intended
+    /// and drifts further behind the schedule with every iteration. An interval, or a deadline
+    /// advanced by the period every iteration, keeps the schedule.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// loop {
+    ///     send_heartbeat().await;
+    ///     tokio::time::sleep(Duration::from_secs(1)).await;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let mut interval = tokio::time::interval(Duration::from_secs(1));
+    /// loop {
+    ///     interval.tick().await;
+    ///     send_heartbeat().await;
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub INTERVAL_TICK_DRIFT,
+    pedantic,
+    "running a loop periodically by sleeping for a fixed period"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `for i in 0..=v.len()` loops and `while i <= v.len()` loops which index `v`
+    /// with `i`.
+    ///
+    /// ### Why is this bad?
+    /// The last index of `v` is `v.len() - 1`, so indexing `v` in the last iteration panics.
+    ///
+    /// ### Known problems
+    /// Indexing which is guarded by a check of the index is linted as well.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # let v = vec![1, 2, 3];
+    /// for i in 0..=v.len() {
+    ///     println!("{}", v[i]);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let v = vec![1, 2, 3];
+    /// for i in 0..v.len() {
+    ///     println!("{}", v[i]);
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub INDEX_LOOP_OFF_BY_ONE_INCLUSIVE,
+    suspicious,
+    "looping up to and including the length of a sequence which is indexed"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `for i in 0..v.len()` loops which insert or remove elements of `v` in the loop,
+    /// e.g. with `v.remove(i)` or `v.insert(i, x)`.
+    ///
+    /// Appending with `push` is not linted, as the appended elements are simply not visited.
+    ///
+    /// ### Why is this bad?
+    /// The range is computed once before the loop. After removing an element, the following
+    /// elements move to lower indices, so the next element is skipped and indexing the last
+    /// indices panics. After inserting an element, the following elements move to higher
+    /// indices and are visited twice.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # let mut v = vec![1, 2, 3];
+    /// for i in 0..v.len() {
+    ///     if v[i] % 2 == 0 {
+    ///         v.remove(i);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let mut v = vec![1, 2, 3];
+    /// v.retain(|x| x % 2 != 0);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MODIFICATION_WHILE_ITERATING_INDICES,
+    suspicious,
+    "adding or removing elements of a `Vec` while looping over its indices"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `for` loops which add `f32` or `f64` values to an accumulator declared
+    /// outside of the loop, like `sum += x`.
+    ///
+    /// ### Why is this bad?
+    /// Every addition rounds the result, and the rounding errors add up. Over many iterations,
+    /// or when the values have very different magnitudes, the sum can lose much of its
+    /// precision.
+    ///
+    /// ### Configuration
+    /// Loops which are known to run fewer than `float-accumulation-min-iterations` times aren't
+    /// linted. Loops over iterators of unknown length are always linted.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let values = vec![1.0_f64; 1000];
+    /// let mut sum = 0.0;
+    /// for x in &values {
+    ///     sum += x;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let values = vec![1.0_f64; 1000];
+    /// // Kahan summation
+    /// let mut sum = 0.0;
+    /// let mut compensation = 0.0;
+    /// for x in &values {
+    ///     let y = x - compensation;
+    ///     let t = sum + y;
+    ///     compensation = (t - sum) - y;
+    ///     sum = t;
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub FLOAT_ACCUMULATION_IN_LOOP,
+    pedantic,
+    "accumulating floating-point values in a loop"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for loops which concatenate to a `String` declared outside of the loop with
+    /// `s = s + x`, or which append `format!(..)` to it.
+    ///
+    /// ### Why is this bad?
+    /// `s = s + x` moves the `String` out and back in every iteration, which is harder to read
+    /// than `push_str`. `format!` allocates a temporary `String` in every iteration, only to
+    /// copy it, while `write!` formats into the `String` directly.
+    ///
+    /// If the number of iterations is known, reserving the capacity before the loop also
+    /// avoids reallocating as the `String` grows.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let names = ["a", "b"];
+    /// let mut s = String::new();
+    /// for name in names {
+    ///     s = s + name;
+    ///     s += &format!("{name}, ");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::fmt::Write;
+    /// # let names = ["a", "b"];
+    /// let mut s = String::new();
+    /// for name in names {
+    ///     s.push_str(name);
+    ///     let _ = write!(s, "{name}, ");
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub STRING_CONCAT_IN_LOOP,
+    pedantic,
+    "concatenating to a `String` with `+` or `format!` in a loop"
+}
+
 pub struct Loops {
     msrv: Msrv,
+    conf_sleep_functions: Vec<String>,
+    sleep_functions: FxHashSet<DefId>,
+    float_accumulation_min_iterations: u64,
 }
 impl Loops {
-    pub fn new(msrv: Msrv) -> Self {
-        Self { msrv }
+    pub fn new(msrv: Msrv, conf_sleep_functions: Vec<String>, float_accumulation_min_iterations: u64) -> Self {
+        Self {
+            msrv,
+            conf_sleep_functions,
+            sleep_functions: FxHashSet::default(),
+            float_accumulation_min_iterations,
+        }
     }
 }
 impl_lint_pass!(Loops => [
@@ -634,10 +959,26 @@ impl_lint_pass!(Loops => [
     SINGLE_ELEMENT_LOOP,
     MISSING_SPIN_LOOP,
     MANUAL_FIND,
-    MANUAL_WHILE_LET_SOME
+    MANUAL_WHILE_LET_SOME,
+    MANUAL_WINDOWS_CHUNKS,
+    INSTANT_ELAPSED_IN_CONDITION,
+    AWAIT_IN_LOOP_COLLECTABLE,
+    CHANNEL_RECV_BUSY_LOOP,
+    INTERVAL_TICK_DRIFT,
+    INDEX_LOOP_OFF_BY_ONE_INCLUSIVE,
+    MODIFICATION_WHILE_ITERATING_INDICES,
+    FLOAT_ACCUMULATION_IN_LOOP,
+    STRING_CONCAT_IN_LOOP,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Loops {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for path in &self.conf_sleep_functions {
+            let segs: Vec<_> = path.split("::").collect();
+            self.sleep_functions.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         let for_loop = higher::ForLoop::hir(expr);
         if let Some(higher::ForLoop {
@@ -655,6 +996,7 @@ impl<'tcx> LateLintPass<'tcx> for Loops {
                 return;
             }
             self.check_for_loop(cx, pat, arg, body, expr, span);
+            modification_while_iterating_indices::check(cx, arg, body, loop_id);
             if let ExprKind::Block(block, _) = body.kind {
                 never_loop::check(cx, block, loop_id, span, for_loop.as_ref());
             }
@@ -677,6 +1019,9 @@ impl<'tcx> LateLintPass<'tcx> for Loops {
             // also check for empty `loop {}` statements, skipping those in #[panic_handler]
             empty_loop::check(cx, expr, block);
             while_let_loop::check(cx, expr, block);
+            channel_recv_busy_loop::check(cx, expr, block);
+            interval_tick_drift::check(cx, expr, block, &self.sleep_functions);
+            string_concat_in_loop::check(cx, block, None);
         }
 
         while_let_on_iterator::check(cx, expr);
@@ -685,6 +1030,13 @@ impl<'tcx> LateLintPass<'tcx> for Loops {
             while_immutable_condition::check(cx, condition, body);
             missing_spin_loop::check(cx, condition, body);
             manual_while_let_some::check(cx, condition, body, span);
+            instant_elapsed_in_condition::check(cx, condition);
+            index_loop_off_by_one_inclusive::check_while(cx, condition, body);
+            if let ExprKind::Block(block, _) = body.kind {
+                channel_recv_busy_loop::check(cx, expr, block);
+                interval_tick_drift::check(cx, expr, block, &self.sleep_functions);
+                string_concat_in_loop::check(cx, block, None);
+            }
         }
     }
 
@@ -713,6 +1065,13 @@ impl Loops {
         same_item_push::check(cx, pat, arg, body, expr);
         manual_flatten::check(cx, pat, arg, body, span);
         manual_find::check(cx, pat, arg, body, span, expr);
+        manual_windows_chunks::check(cx, pat, arg, body, expr);
+        await_in_loop_collectable::check(cx, pat, arg, body, expr);
+        index_loop_off_by_one_inclusive::check_for(cx, pat, arg, body);
+        float_accumulation_in_loop::check(cx, arg, body, self.float_accumulation_min_iterations);
+        if let ExprKind::Block(block, _) = body.kind {
+            string_concat_in_loop::check(cx, block, known_iterations(cx, arg));
+        }
     }
 
     fn check_for_loop_arg(&self, cx: &LateContext<'_>, _: &Pat<'_>, arg: &Expr<'_>) {
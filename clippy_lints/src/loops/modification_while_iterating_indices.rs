@@ -0,0 +1,112 @@
+use super::MODIFICATION_WHILE_ITERATING_INDICES;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{get_parent_node, higher, SpanlessEq};
+use core::ops::ControlFlow;
+use rustc_hir::{Destination, Expr, ExprKind, HirId, Node, StmtKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+/// Checks for `for i in start..v.len()` loops which add or remove elements of `v`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'_>, body: &'tcx Expr<'_>, loop_id: HirId) {
+    let Some(higher::Range {
+        start: Some(_),
+        end: Some(end),
+        ..
+    }) = higher::Range::hir(arg)
+    else {
+        return;
+    };
+    let ExprKind::MethodCall(path, seq, [], _) = end.kind else {
+        return;
+    };
+    let seq_ty = cx.typeck_results().expr_ty_adjusted(seq).peel_refs();
+    if path.ident.name != sym::len
+        || !(is_type_diagnostic_item(cx, seq_ty, sym::Vec) || is_type_diagnostic_item(cx, seq_ty, sym::VecDeque))
+    {
+        return;
+    }
+
+    let mut modifications = Vec::new();
+    for_each_expr(body, |e| {
+        if let ExprKind::MethodCall(path, recv, ..) = e.kind
+            && matches!(
+                path.ident.as_str(),
+                "remove"
+                    | "swap_remove"
+                    | "swap_remove_back"
+                    | "swap_remove_front"
+                    | "insert"
+                    | "pop"
+                    | "pop_back"
+                    | "pop_front"
+                    | "truncate"
+                    | "clear"
+            )
+            && SpanlessEq::new(cx).eq_expr(recv, seq)
+            && !is_followed_by_exit(cx, e, loop_id)
+        {
+            modifications.push(e.span);
+        }
+        ControlFlow::<!>::Continue(())
+    });
+    if modifications.is_empty() {
+        return;
+    }
+
+    let seq = snippet(cx, seq.span, "..");
+    span_lint_and_then(
+        cx,
+        MODIFICATION_WHILE_ITERATING_INDICES,
+        arg.span,
+        &format!("`{seq}` is modified while iterating over its indices"),
+        |diag| {
+            diag.span_note(modifications, "the length of the range was computed before this");
+            diag.note(
+                "removing elements skips the elements after them and makes the last indices out of bounds, \
+                 inserting elements visits elements twice",
+            );
+            diag.help("use `retain`, iterate in reverse, or use a `while` loop which checks the length each time");
+        },
+    );
+}
+
+/// Whether the loop `loop_id` is left right after `e`, because `e` is part of a `break` of it or a
+/// `return`, as in `return Some(v.remove(i));`, or because its statement is directly followed by
+/// one, as in `v.remove(i); break;` or `let x = v.remove(i); return x;`.
+fn is_followed_by_exit(cx: &LateContext<'_>, e: &Expr<'_>, loop_id: HirId) -> bool {
+    let exits = |e: &Expr<'_>| match e.kind {
+        ExprKind::Break(Destination { target_id, .. }, _) => target_id.map_or(false, |id| id == loop_id),
+        ExprKind::Ret(_) => true,
+        _ => false,
+    };
+
+    let mut child = e.hir_id;
+    let stmt = loop {
+        match get_parent_node(cx.tcx, child) {
+            Some(Node::Expr(parent)) => match parent.kind {
+                ExprKind::Break(..) | ExprKind::Ret(_) => return exits(parent),
+                ExprKind::Closure(_) => return false,
+                _ => child = parent.hir_id,
+            },
+            Some(Node::Local(local)) => child = local.hir_id,
+            Some(Node::Stmt(stmt)) => break stmt,
+            _ => return false,
+        }
+    };
+    let Some(Node::Block(block)) = get_parent_node(cx.tcx, stmt.hir_id) else {
+        return false;
+    };
+    let next = block
+        .stmts
+        .iter()
+        .skip_while(|s| s.hir_id != stmt.hir_id)
+        .nth(1)
+        .map_or(block.expr, |s| match s.kind {
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+            _ => None,
+        });
+    next.map_or(false, exits)
+}
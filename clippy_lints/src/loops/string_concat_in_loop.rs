@@ -0,0 +1,104 @@
+use super::STRING_CONCAT_IN_LOOP;
+use crate::format_push_string::FORMAT_PUSH_STRING;
+use crate::repeated_push_str_literal::REPEATED_PUSH_STR_LITERAL;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::root_macro_call_first_node;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{is_lint_allowed, match_def_path, path_to_local, path_to_local_id, paths, peel_hir_expr_refs};
+use core::ops::ControlFlow;
+use rustc_errors::{Applicability, Diagnostic};
+use rustc_hir::{BinOpKind, Block, Expr, ExprKind, LangItem};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+/// Checks for `s = s + x`, `s += &format!(..)` and `s.push_str(&format!(..))` in the body of a
+/// loop, where `s` is a `String` declared outside of the loop.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'_>, iterations: Option<u128>) {
+    let _: Option<!> = for_each_expr(block, |e| {
+        if e.span.from_expansion() {
+            return ControlFlow::Continue(Descend::Yes);
+        }
+        match e.kind {
+            // nested loops are checked on their own, closures may not run in the loop
+            ExprKind::Loop(..) | ExprKind::Closure(_) => return ControlFlow::Continue(Descend::No),
+            ExprKind::Assign(lhs, value, _)
+                if let ExprKind::Binary(op, left, right) = value.kind
+                    && op.node == BinOpKind::Add
+                    && let Some(id) = path_to_local(lhs)
+                    && path_to_local_id(left, id)
+                    && is_outer_string(cx, block, lhs) =>
+            {
+                span_lint_and_then(
+                    cx,
+                    STRING_CONCAT_IN_LOOP,
+                    e.span,
+                    "concatenating a `String` with `+` in a loop",
+                    |diag| {
+                        let mut app = Applicability::MachineApplicable;
+                        let lhs = snippet_with_context(cx, lhs.span, e.span.ctxt(), "..", &mut app).0;
+                        let right = snippet_with_context(cx, right.span, e.span.ctxt(), "..", &mut app).0;
+                        diag.span_suggestion(e.span, "use `push_str`", format!("{lhs}.push_str({right})"), app);
+                        note_capacity(diag, iterations);
+                    },
+                );
+            },
+            ExprKind::AssignOp(op, lhs, rhs)
+                if op.node == BinOpKind::Add && is_format(cx, rhs) && is_outer_string(cx, block, lhs) =>
+            {
+                lint_format(cx, e, iterations);
+            },
+            ExprKind::MethodCall(_, recv, [arg], _)
+                if cx
+                    .typeck_results()
+                    .type_dependent_def_id(e.hir_id)
+                    .map_or(false, |id| match_def_path(cx, id, &paths::PUSH_STR))
+                    && is_format(cx, arg)
+                    && is_outer_string(cx, block, recv) =>
+            {
+                lint_format(cx, e, iterations);
+            },
+            _ => {},
+        }
+        ControlFlow::Continue(Descend::Yes)
+    });
+}
+
+fn lint_format(cx: &LateContext<'_>, e: &Expr<'_>, iterations: Option<u128>) {
+    // `repeated_push_str_literal` and `format_push_string` already lint this
+    if !is_lint_allowed(cx, REPEATED_PUSH_STR_LITERAL, e.hir_id) || !is_lint_allowed(cx, FORMAT_PUSH_STRING, e.hir_id) {
+        return;
+    }
+    span_lint_and_then(
+        cx,
+        STRING_CONCAT_IN_LOOP,
+        e.span,
+        "appending `format!(..)` to a `String` in a loop",
+        |diag| {
+            diag.help("use `write!` from `std::fmt::Write` to format into the `String` directly");
+            note_capacity(diag, iterations);
+        },
+    );
+}
+
+fn note_capacity(diag: &mut Diagnostic, iterations: Option<u128>) {
+    if let Some(n) = iterations {
+        diag.note(format!(
+            "the loop runs {n} times, so reserving the capacity before it with `String::with_capacity` or \
+             `reserve` avoids reallocating"
+        ));
+    }
+}
+
+fn is_format(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    root_macro_call_first_node(cx, peel_hir_expr_refs(e).0).map_or(false, |macro_call| {
+        cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id)
+    })
+}
+
+/// Whether `e` is a `String` which outlives the loop, i.e. not a local declared in the loop.
+fn is_outer_string(cx: &LateContext<'_>, block: &Block<'_>, e: &Expr<'_>) -> bool {
+    is_type_lang_item(cx, cx.typeck_results().expr_ty(e).peel_refs(), LangItem::String)
+        && path_to_local(e).map_or(true, |id| !block.span.contains(cx.tcx.hir().span(id)))
+}
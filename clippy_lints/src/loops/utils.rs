@@ -1,7 +1,8 @@
+use clippy_utils::consts::{constant, Constant};
 use clippy_utils::ty::{has_iter_method, implements_trait};
-use clippy_utils::{get_parent_expr, is_integer_const, path_to_local, path_to_local_id, sugg};
+use clippy_utils::{get_parent_expr, higher, is_integer_const, path_to_local, path_to_local_id, sugg};
 use if_chain::if_chain;
-use rustc_ast::ast::{LitIntType, LitKind};
+use rustc_ast::ast::{LitIntType, LitKind, RangeLimits};
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::{walk_expr, walk_local, walk_pat, walk_stmt, Visitor};
 use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, HirId, HirIdMap, Local, Mutability, Pat, PatKind, Stmt};
@@ -351,3 +352,38 @@ pub(super) fn make_iterator_snippet(cx: &LateContext<'_>, arg: &Expr<'_>, applic
         }
     }
 }
+
+/// Returns the number of iterations of loops over constant ranges and arrays.
+pub(super) fn known_iterations(cx: &LateContext<'_>, arg: &Expr<'_>) -> Option<u128> {
+    if let Some(higher::Range {
+        start: Some(start),
+        end: Some(end),
+        limits,
+    }) = higher::Range::hir(arg)
+    {
+        let typeck = cx.typeck_results();
+        let (Some(Constant::Int(start)), Some(Constant::Int(end))) =
+            (constant(cx, typeck, start), constant(cx, typeck, end))
+        else {
+            return None;
+        };
+        let n = end.saturating_sub(start);
+        return Some(if limits == RangeLimits::Closed {
+            n.saturating_add(1)
+        } else {
+            n
+        });
+    }
+
+    let seq = match arg.kind {
+        ExprKind::MethodCall(path, recv, [], _) if matches!(path.ident.as_str(), "iter" | "iter_mut" | "into_iter") => {
+            recv
+        },
+        _ => arg,
+    };
+    if let ty::Array(_, len) = cx.typeck_results().expr_ty(seq).peel_refs().kind() {
+        len.try_eval_target_usize(cx.tcx, cx.param_env).map(u128::from)
+    } else {
+        None
+    }
+}
@@ -141,7 +141,7 @@ impl<'tcx> LateLintPass<'tcx> for MacroUseImports {
         }
     }
     fn check_crate_post(&mut self, cx: &LateContext<'_>) {
-        let mut used = FxHashMap::default();
+        let mut used: FxHashMap<_, Vec<String>> = FxHashMap::default();
         let mut check_dup = vec![];
         for (import, span, hir_id) in &self.imports {
             let found_idx = self.mac_refs.iter().position(|mac| import.ends_with(&mac.name));
@@ -157,7 +157,7 @@ impl<'tcx> LateLintPass<'tcx> for MacroUseImports {
                     [root, item] => {
                         if !check_dup.contains(&(*item).to_string()) {
                             used.entry(((*root).to_string(), span, hir_id))
-                                .or_insert_with(Vec::new)
+                                .or_default()
                                 .push((*item).to_string());
                             check_dup.push((*item).to_string());
                         }
@@ -175,13 +175,13 @@ impl<'tcx> LateLintPass<'tcx> for MacroUseImports {
                                 })
                                 .collect::<Vec<_>>();
                             used.entry(((*root).to_string(), span, hir_id))
-                                .or_insert_with(Vec::new)
+                                .or_default()
                                 .push(filtered.join("::"));
                             check_dup.extend(filtered);
                         } else {
                             let rest = rest.to_vec();
                             used.entry(((*root).to_string(), span, hir_id))
-                                .or_insert_with(Vec::new)
+                                .or_default()
                                 .push(rest.join("::"));
                             check_dup.extend(rest.iter().map(ToString::to_string));
                         }
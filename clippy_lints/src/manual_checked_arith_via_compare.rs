@@ -0,0 +1,187 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{clip, higher, is_integer_const, is_res_lang_ctor, path_res, peel_blocks, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, LangItem};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, UintTy};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for comparisons of unsigned integers that guard against an overflow manually, like
+    /// `if a > b { a - b } else { 0 }` or `if x > u32::MAX - y { .. } else { x + y }`.
+    ///
+    /// ### Why is this bad?
+    /// `saturating_sub`, `saturating_add` and `checked_add` do the same in a single call, and
+    /// can't get the comparison wrong.
+    ///
+    /// ### Known problems
+    /// Signed integers aren't linted, as the comparisons aren't equivalent to the built-in
+    /// methods for negative values.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let (a, b) = (5u32, 3u32);
+    /// let diff = if a > b { a - b } else { 0 };
+    /// let sum = if a > u32::MAX - b { None } else { Some(a + b) };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let (a, b) = (5u32, 3u32);
+    /// let diff = a.saturating_sub(b);
+    /// let sum = a.checked_add(b);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_CHECKED_ARITH_VIA_COMPARE,
+    complexity,
+    "guarding against an integer overflow with a comparison instead of `checked_*` or `saturating_*`"
+}
+
+declare_lint_pass!(ManualCheckedArithViaCompare => [MANUAL_CHECKED_ARITH_VIA_COMPARE]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualCheckedArithViaCompare {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(higher::If {
+            cond,
+            then,
+            r#else: Some(r#else),
+        }) = higher::If::hir(expr)
+            && let ExprKind::Binary(op, lhs, rhs) = cond.kind
+            && !expr.span.from_expansion()
+            && let ty::Uint(uint_ty) = *cx.typeck_results().expr_ty(lhs).kind()
+        {
+            // normalize the condition to `greater > lesser` or `greater >= lesser`
+            let (greater, lesser, strict) = match op.node {
+                BinOpKind::Gt => (lhs, rhs, true),
+                BinOpKind::Ge => (lhs, rhs, false),
+                BinOpKind::Lt => (rhs, lhs, true),
+                BinOpKind::Le => (rhs, lhs, false),
+                _ => return,
+            };
+            let cmp = Comparison {
+                greater,
+                lesser,
+                strict,
+                uint_ty,
+            };
+            let (then, r#else) = (peel_blocks(then), peel_blocks(r#else));
+            if !cmp.check_saturating_sub(cx, expr, then, r#else) {
+                cmp.check_add(cx, expr, then, r#else);
+            }
+        }
+    }
+}
+
+struct Comparison<'tcx> {
+    greater: &'tcx Expr<'tcx>,
+    lesser: &'tcx Expr<'tcx>,
+    strict: bool,
+    uint_ty: UintTy,
+}
+
+impl<'tcx> Comparison<'tcx> {
+    /// Checks for `if a > b { a - b } else { 0 }` and `if b > a { 0 } else { a - b }`.
+    fn check_saturating_sub(&self, cx: &LateContext<'_>, expr: &Expr<'_>, then: &Expr<'_>, r#else: &Expr<'_>) -> bool {
+        let (lhs, rhs) = if is_sub(cx, then, self.greater, self.lesser) && is_integer_const(cx, r#else, 0) {
+            (self.greater, self.lesser)
+        } else if is_integer_const(cx, then, 0) && is_sub(cx, r#else, self.lesser, self.greater) {
+            (self.lesser, self.greater)
+        } else {
+            return false;
+        };
+        let mut app = Applicability::MachineApplicable;
+        let ctxt = expr.span.ctxt();
+        let lhs = Sugg::hir_with_context(cx, lhs, ctxt, "..", &mut app).maybe_par();
+        let rhs = Sugg::hir_with_context(cx, rhs, ctxt, "..", &mut app);
+        span_lint_and_sugg(
+            cx,
+            MANUAL_CHECKED_ARITH_VIA_COMPARE,
+            expr.span,
+            "manual saturating subtraction",
+            "use `saturating_sub`",
+            format!("{lhs}.saturating_sub({rhs})"),
+            app,
+        );
+        true
+    }
+
+    /// Checks for `if x > MAX - y { .. } else { x + y }` and the inverted condition.
+    fn check_add(&self, cx: &LateContext<'_>, expr: &Expr<'_>, then: &Expr<'_>, r#else: &Expr<'_>) {
+        let (x, y, sum, overflow) = if self.strict
+            && let Some(y) = self.max_minus(cx, self.lesser)
+        {
+            (self.greater, y, r#else, then)
+        } else if !self.strict
+            && let Some(y) = self.max_minus(cx, self.greater)
+        {
+            (self.lesser, y, then, r#else)
+        } else {
+            return;
+        };
+        let (is_option, sum) = match sum.kind {
+            ExprKind::Call(func, [sum]) if is_res_lang_ctor(cx, path_res(cx, func), LangItem::OptionSome) => {
+                (true, sum)
+            },
+            _ => (false, sum),
+        };
+        if !(is_add(cx, sum, x, y) || is_add(cx, sum, y, x)) {
+            return;
+        }
+
+        let method = if is_option && is_res_lang_ctor(cx, path_res(cx, overflow), LangItem::OptionNone) {
+            "checked_add"
+        } else if !is_option && self.is_max(cx, overflow) {
+            "saturating_add"
+        } else {
+            span_lint_and_help(
+                cx,
+                MANUAL_CHECKED_ARITH_VIA_COMPARE,
+                expr.span,
+                "manual overflow check of an addition",
+                None,
+                "use `checked_add` or `overflowing_add` to detect the overflow",
+            );
+            return;
+        };
+        let mut app = Applicability::MachineApplicable;
+        let ctxt = expr.span.ctxt();
+        let x = Sugg::hir_with_context(cx, x, ctxt, "..", &mut app).maybe_par();
+        let y = Sugg::hir_with_context(cx, y, ctxt, "..", &mut app);
+        span_lint_and_sugg(
+            cx,
+            MANUAL_CHECKED_ARITH_VIA_COMPARE,
+            expr.span,
+            "manual overflow check of an addition",
+            &format!("use `{method}`"),
+            format!("{x}.{method}({y})"),
+            app,
+        );
+    }
+
+    /// If `e` is `MAX - y`, returns `y`.
+    fn max_minus<'a>(&self, cx: &LateContext<'_>, e: &'a Expr<'a>) -> Option<&'a Expr<'a>> {
+        match e.kind {
+            ExprKind::Binary(op, max, y) if op.node == BinOpKind::Sub && self.is_max(cx, max) => Some(y),
+            _ => None,
+        }
+    }
+
+    fn is_max(&self, cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+        constant(cx, cx.typeck_results(), e) == Some(Constant::Int(clip(cx.tcx, u128::MAX, self.uint_ty)))
+    }
+}
+
+fn is_sub(cx: &LateContext<'_>, e: &Expr<'_>, lhs: &Expr<'_>, rhs: &Expr<'_>) -> bool {
+    is_bin_op(cx, e, BinOpKind::Sub, lhs, rhs)
+}
+
+fn is_add(cx: &LateContext<'_>, e: &Expr<'_>, lhs: &Expr<'_>, rhs: &Expr<'_>) -> bool {
+    is_bin_op(cx, e, BinOpKind::Add, lhs, rhs)
+}
+
+fn is_bin_op(cx: &LateContext<'_>, e: &Expr<'_>, op: BinOpKind, lhs: &Expr<'_>, rhs: &Expr<'_>) -> bool {
+    matches!(e.kind, ExprKind::Binary(e_op, e_lhs, e_rhs)
+        if e_op.node == op && SpanlessEq::new(cx).eq_expr(e_lhs, lhs) && SpanlessEq::new(cx).eq_expr(e_rhs, rhs))
+}
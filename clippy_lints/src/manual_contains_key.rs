@@ -0,0 +1,128 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{get_parent_expr, is_res_lang_ctor};
+use rustc_errors::Applicability;
+use rustc_hir::LangItem::{OptionNone, OptionSome};
+use rustc_hir::{Expr, ExprKind, Pat, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `map.get(&k).is_some()` and `map.get(&k).is_none()` on a `HashMap` or
+    /// `BTreeMap`, as well as `if let Some(_) = map.get(&k)` and `if let None = map.get(&k)`.
+    ///
+    /// ### Why is this bad?
+    /// The value is looked up only to be thrown away. `contains_key` states the intent
+    /// directly.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// let map: HashMap<u32, u32> = HashMap::new();
+    /// if map.get(&1).is_some() {}
+    /// if let None = map.get(&2) {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// let map: HashMap<u32, u32> = HashMap::new();
+    /// if map.contains_key(&1) {}
+    /// if !map.contains_key(&2) {}
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_CONTAINS_KEY,
+    style,
+    "using `map.get(&k).is_some()` instead of `map.contains_key(&k)`"
+}
+declare_lint_pass!(ManualContainsKey => [MANUAL_CONTAINS_KEY]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualContainsKey {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        match expr.kind {
+            ExprKind::MethodCall(path, recv, [], _) => {
+                let is_some = match path.ident.as_str() {
+                    "is_some" => true,
+                    "is_none" => false,
+                    _ => return,
+                };
+                check_get(cx, expr, recv, is_some, false);
+            },
+            ExprKind::If(cond, ..) => {
+                if let ExprKind::Let(let_expr) = cond.kind
+                    && let Some(is_some) = matched_option_variant(cx, let_expr.pat)
+                {
+                    check_get(cx, cond, let_expr.init, is_some, true);
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+/// Returns `Some(true)` for `Some(_)` and `Some(false)` for `None`.
+fn matched_option_variant(cx: &LateContext<'_>, pat: &Pat<'_>) -> Option<bool> {
+    match pat.kind {
+        PatKind::TupleStruct(ref qpath, [inner], _)
+            if matches!(inner.kind, PatKind::Wild)
+                && is_res_lang_ctor(cx, cx.qpath_res(qpath, pat.hir_id), OptionSome) =>
+        {
+            Some(true)
+        },
+        PatKind::Path(ref qpath) if is_res_lang_ctor(cx, cx.qpath_res(qpath, pat.hir_id), OptionNone) => Some(false),
+        _ => None,
+    }
+}
+
+fn check_get(cx: &LateContext<'_>, expr: &Expr<'_>, get_call: &Expr<'_>, is_some: bool, is_if_let: bool) {
+    if let ExprKind::MethodCall(path, map, [key], _) = get_call.kind
+        && path.ident.as_str() == "get"
+        && let ty = cx.typeck_results().expr_ty_adjusted(map).peel_refs()
+        && let Some(map_name) = [sym::HashMap, sym::BTreeMap]
+            .into_iter()
+            .find(|&name| is_type_diagnostic_item(cx, ty, name))
+    {
+        let mut app = Applicability::MachineApplicable;
+        let ctxt = expr.span.ctxt();
+        let map_snip = snippet_with_context(cx, map.span, ctxt, "..", &mut app).0;
+        let key_snip = snippet_with_context(cx, key.span, ctxt, "..", &mut app).0;
+        // keep an explicit `get::<Q>` turbofish, `contains_key` takes the same type parameter
+        let generics = match path.args {
+            Some(args) if !args.args.is_empty() => {
+                format!("::{}", snippet_with_context(cx, args.span_ext, ctxt, "..", &mut app).0)
+            },
+            _ => String::new(),
+        };
+        let call = Sugg::NonParen(format!("{map_snip}.contains_key{generics}({key_snip})").into());
+        let mut sugg = if is_some { call } else { !call };
+        if let Some(parent) = get_parent_expr(cx, expr)
+            && let ExprKind::MethodCall(_, recv, ..) | ExprKind::Field(recv, _) = parent.kind
+            && recv.hir_id == expr.hir_id
+        {
+            sugg = sugg.maybe_par();
+        }
+        let msg = if is_if_let {
+            format!("matching on `{map_name}::get` only to check whether the key exists")
+        } else {
+            format!(
+                "called `{}()` on the result of `{map_name}::get`",
+                if is_some { "is_some" } else { "is_none" }
+            )
+        };
+        span_lint_and_sugg(
+            cx,
+            MANUAL_CONTAINS_KEY,
+            expr.span,
+            &msg,
+            "use `contains_key` instead",
+            sugg.to_string(),
+            app,
+        );
+    }
+}
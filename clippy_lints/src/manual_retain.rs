@@ -1,16 +1,26 @@
-use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::msrvs::{self, Msrv};
 use clippy_utils::source::snippet;
+use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
-use clippy_utils::{get_parent_expr, match_def_path, paths, SpanlessEq};
+use clippy_utils::usage::local_used_after_expr;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{
+    get_parent_expr, higher, is_integer_literal, match_def_path, path_to_local, path_to_local_id, paths, SpanlessEq,
+};
+use core::ops::ControlFlow;
+use rustc_ast::util::parser::AssocOp;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_hir::def_id::DefId;
 use rustc_hir::ExprKind::Assign;
+use rustc_hir::HirId;
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
 use rustc_semver::RustcVersion;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::symbol::sym;
+use rustc_span::Span;
 
 const ACCEPTABLE_METHODS: [&[&str]; 4] = [
     &paths::HASHSET_ITER,
@@ -49,6 +59,38 @@ declare_clippy_lint! {
     "`retain()` is simpler and the same functionalities"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for loops that remove elements from a `Vec` or `VecDeque` by index while walking
+    /// over it, i.e. `while i < v.len() { if pred { v.remove(i); } else { i += 1; } }`.
+    ///
+    /// ### Why is this bad?
+    /// Every `remove` moves all of the following elements, so the loop takes quadratic time.
+    /// `.retain()` does the same in a single pass and is shorter.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let mut v = vec![1, 2, 3, 4];
+    /// let mut i = 0;
+    /// while i < v.len() {
+    ///     if v[i] % 2 == 0 {
+    ///         v.remove(i);
+    ///     } else {
+    ///         i += 1;
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut v = vec![1, 2, 3, 4];
+    /// v.retain(|x| *x % 2 != 0);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_RETAIN_INDEX_LOOP,
+    perf,
+    "removing elements by index in a loop where `retain()` would do"
+}
+
 pub struct ManualRetain {
     msrv: Msrv,
 }
@@ -60,7 +102,7 @@ impl ManualRetain {
     }
 }
 
-impl_lint_pass!(ManualRetain => [MANUAL_RETAIN]);
+impl_lint_pass!(ManualRetain => [MANUAL_RETAIN, MANUAL_RETAIN_INDEX_LOOP]);
 
 impl<'tcx> LateLintPass<'tcx> for ManualRetain {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
@@ -78,9 +120,268 @@ impl<'tcx> LateLintPass<'tcx> for ManualRetain {
         }
     }
 
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx hir::Block<'_>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            let (loop_expr, needs_semi) = match block.stmts.get(i + 1).map(|stmt| stmt.kind) {
+                Some(hir::StmtKind::Semi(e)) => (e, false),
+                Some(hir::StmtKind::Expr(e)) => (e, true),
+                Some(_) => continue,
+                None => match block.expr {
+                    Some(e) => (e, false),
+                    None => continue,
+                },
+            };
+            check_index_loop(cx, stmt, loop_expr, needs_semi);
+        }
+    }
+
     extract_msrv_attr!(LateContext);
 }
 
+/// Checks `let mut i = 0;` followed by a loop removing the elements at `i` that match a predicate.
+fn check_index_loop<'tcx>(
+    cx: &LateContext<'tcx>,
+    init_stmt: &hir::Stmt<'_>,
+    loop_expr: &'tcx hir::Expr<'tcx>,
+    needs_semi: bool,
+) {
+    let hir::StmtKind::Local(local) = init_stmt.kind else {
+        return;
+    };
+    let hir::PatKind::Binding(hir::BindingAnnotation::MUT, index_id, _, None) = local.pat.kind else {
+        return;
+    };
+    if !local.init.map_or(false, |init| is_integer_literal(init, 0))
+        || local.els.is_some()
+        || init_stmt.span.from_expansion()
+        || loop_expr.span.from_expansion()
+    {
+        return;
+    }
+    let Some(higher::While { condition, body, .. }) = higher::While::hir(loop_expr) else {
+        return;
+    };
+    let len_call = match condition.kind {
+        hir::ExprKind::Binary(op, lhs, rhs) if op.node == hir::BinOpKind::Lt && path_to_local_id(lhs, index_id) => rhs,
+        hir::ExprKind::Binary(op, lhs, rhs) if op.node == hir::BinOpKind::Gt && path_to_local_id(rhs, index_id) => lhs,
+        _ => return,
+    };
+    let hir::ExprKind::MethodCall(len_path, vec_expr, [], _) = len_call.kind else {
+        return;
+    };
+    let Some(vec_id) = path_to_local(vec_expr) else {
+        return;
+    };
+    let vec_ty = cx.typeck_results().expr_ty(vec_expr).peel_refs();
+    if len_path.ident.name != sym::len
+        || !(is_type_diagnostic_item(cx, vec_ty, sym::Vec) || is_type_diagnostic_item(cx, vec_ty, sym::VecDeque))
+    {
+        return;
+    }
+    let Some(if_expr) = single_block_expr(body) else {
+        return;
+    };
+    let Some(higher::If { cond: pred, then, r#else: Some(r#else) }) = higher::If::hir(if_expr) else {
+        return;
+    };
+    let is_remove = |e: &hir::Expr<'_>| {
+        matches!(
+            e.kind,
+            hir::ExprKind::MethodCall(path, recv, [arg], _)
+                if path.ident.as_str() == "remove" && path_to_local_id(recv, vec_id) && path_to_local_id(arg, index_id)
+        )
+    };
+    let is_increment = |e: &hir::Expr<'_>| {
+        matches!(
+            e.kind,
+            hir::ExprKind::AssignOp(op, lhs, rhs)
+                if op.node == hir::BinOpKind::Add && path_to_local_id(lhs, index_id) && is_integer_literal(rhs, 1)
+        )
+    };
+    let remove_if_true = match (single_block_expr(then), single_block_expr(r#else)) {
+        (Some(then), Some(r#else)) if is_remove(then) && is_increment(r#else) => true,
+        (Some(then), Some(r#else)) if is_increment(then) && is_remove(r#else) => false,
+        _ => return,
+    };
+    if local_used_after_expr(cx, index_id, loop_expr) {
+        return;
+    }
+
+    let binding = binding_name(pred);
+    let Some(replacements) = element_uses(cx, pred, vec_id, index_id, binding) else {
+        return;
+    };
+    let retained = retained_pred(cx, pred, &replacements, remove_if_true);
+
+    span_lint_and_then(
+        cx,
+        MANUAL_RETAIN_INDEX_LOOP,
+        init_stmt.span.to(loop_expr.span),
+        "removing elements by index in a loop",
+        |diag| {
+            diag.span_suggestion(
+                init_stmt.span.to(loop_expr.span),
+                "consider calling `.retain()` instead",
+                format!(
+                    "{}.retain(|{binding}| {retained}){}",
+                    snippet(cx, vec_expr.span, ".."),
+                    if needs_semi { ";" } else { "" }
+                ),
+                Applicability::MachineApplicable,
+            );
+            diag.note("each `remove` moves the remaining elements, `retain` only moves each element once");
+        },
+    );
+}
+
+/// Picks a name for the `retain` closure's parameter that isn't already used in `pred`.
+fn binding_name(pred: &hir::Expr<'_>) -> &'static str {
+    ["x", "elem", "item"]
+        .into_iter()
+        .find(|name| {
+            for_each_expr(pred, |e| {
+                if let hir::ExprKind::Path(hir::QPath::Resolved(None, path)) = e.kind
+                    && let [segment] = path.segments
+                    && segment.ident.as_str() == *name
+                {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .is_none()
+        })
+        .unwrap_or("x")
+}
+
+/// Builds the body of the `retain` closure from the loop's predicate, negating it if the loop
+/// removes the elements matching it.
+fn retained_pred(
+    cx: &LateContext<'_>,
+    pred: &hir::Expr<'_>,
+    replacements: &[(Span, String)],
+    remove_if_true: bool,
+) -> String {
+    let rewrite = |span: Span| {
+        let mut text = String::new();
+        let mut pos = span.lo();
+        for (use_span, replacement) in replacements {
+            if span.contains(*use_span) {
+                text.push_str(&snippet(cx, span.with_lo(pos).with_hi(use_span.lo()), ".."));
+                text.push_str(replacement);
+                pos = use_span.hi();
+            }
+        }
+        text.push_str(&snippet(cx, span.with_lo(pos), ".."));
+        text
+    };
+    match pred.kind {
+        hir::ExprKind::Unary(hir::UnOp::Not, inner) if remove_if_true => rewrite(inner.span),
+        _ if !remove_if_true => rewrite(pred.span),
+        hir::ExprKind::Binary(op, lhs, rhs) => (!Sugg::BinOp(
+            AssocOp::from_ast_binop(op.node.into()),
+            rewrite(lhs.span).into(),
+            rewrite(rhs.span).into(),
+        ))
+        .to_string(),
+        hir::ExprKind::Call(..)
+        | hir::ExprKind::MethodCall(..)
+        | hir::ExprKind::Path(..)
+        | hir::ExprKind::Field(..)
+        | hir::ExprKind::Index(..)
+        | hir::ExprKind::Lit(..)
+        | hir::ExprKind::Block(..) => (!Sugg::NonParen(rewrite(pred.span).into())).to_string(),
+        _ => (!Sugg::MaybeParen(rewrite(pred.span).into())).to_string(),
+    }
+}
+
+/// Returns the only expression or statement in the block `e`.
+fn single_block_expr<'tcx>(e: &'tcx hir::Expr<'tcx>) -> Option<&'tcx hir::Expr<'tcx>> {
+    match e.kind {
+        hir::ExprKind::Block(
+            hir::Block {
+                stmts: [],
+                expr: Some(e),
+                ..
+            }
+            | hir::Block {
+                stmts:
+                    [
+                        hir::Stmt {
+                            kind: hir::StmtKind::Expr(e) | hir::StmtKind::Semi(e),
+                            ..
+                        },
+                    ],
+                expr: None,
+                ..
+            },
+            None,
+        ) => Some(e),
+        _ => None,
+    }
+}
+
+/// Finds the uses of `v[i]` in `pred` and what to replace each of them with to refer to the
+/// `retain` closure's parameter instead. Returns `None` if `pred` uses `v` or `i` in any other way,
+/// mutates the element or leaves the loop.
+fn element_uses(
+    cx: &LateContext<'_>,
+    pred: &hir::Expr<'_>,
+    vec_id: HirId,
+    index_id: HirId,
+    binding: &str,
+) -> Option<Vec<(Span, String)>> {
+    let is_element = |e: &hir::Expr<'_>| {
+        matches!(
+            e.kind,
+            hir::ExprKind::Index(base, index) if path_to_local_id(base, vec_id) && path_to_local_id(index, index_id)
+        )
+    };
+    let mut replacements = Vec::new();
+    let aborted = for_each_expr(pred, |e| {
+        match e.kind {
+            hir::ExprKind::AddrOf(hir::BorrowKind::Ref, hir::Mutability::Not, inner) if is_element(inner) => {
+                replacements.push((e.span, binding.to_owned()));
+                return ControlFlow::Continue(Descend::No);
+            },
+            hir::ExprKind::MethodCall(_, recv, ..) | hir::ExprKind::Field(recv, _) if is_element(recv) => {
+                if cx.typeck_results().expr_adjustments(recv).iter().any(|adjust| {
+                    matches!(
+                        adjust.kind,
+                        Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Mut { .. }))
+                    )
+                }) {
+                    return ControlFlow::Break(());
+                }
+                replacements.push((recv.span, binding.to_owned()));
+            },
+            hir::ExprKind::Assign(lhs, ..) | hir::ExprKind::AssignOp(_, lhs, _) if is_element(lhs) => {
+                return ControlFlow::Break(());
+            },
+            hir::ExprKind::AddrOf(_, hir::Mutability::Mut, inner) if is_element(inner) => {
+                return ControlFlow::Break(());
+            },
+            hir::ExprKind::Ret(_) | hir::ExprKind::Break(..) | hir::ExprKind::Continue(_) => {
+                return ControlFlow::Break(());
+            },
+            _ if is_element(e) => {
+                if !replacements.iter().any(|(span, _)| *span == e.span) {
+                    replacements.push((e.span, format!("*{binding}")));
+                }
+                return ControlFlow::Continue(Descend::No);
+            },
+            _ if path_to_local_id(e, vec_id) || path_to_local_id(e, index_id) => return ControlFlow::Break(()),
+            _ => {},
+        }
+        ControlFlow::Continue(Descend::Yes)
+    });
+    if aborted.is_some() {
+        return None;
+    }
+    replacements.sort_by_key(|(span, _)| span.lo());
+    Some(replacements)
+}
+
 fn check_into_iter(
     cx: &LateContext<'_>,
     parent_expr: &hir::Expr<'_>,
@@ -1,20 +1,22 @@
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::{multispan_sugg, span_lint_and_then};
 use clippy_utils::msrvs::{self, Msrv};
-use clippy_utils::source::snippet;
+use clippy_utils::source::{snippet, snippet_with_context};
+use clippy_utils::ty::is_type_lang_item;
 use clippy_utils::usage::mutated_variables;
 use clippy_utils::{eq_expr_value, higher, match_def_path, paths};
 use if_chain::if_chain;
 use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
 use rustc_hir::def::Res;
 use rustc_hir::intravisit::{walk_expr, Visitor};
 use rustc_hir::BinOpKind;
-use rustc_hir::{BorrowKind, Expr, ExprKind};
-use rustc_lint::{LateContext, LateLintPass};
+use rustc_hir::{Block, BorrowKind, Expr, ExprKind, LangItem, Local, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass, Lint};
 use rustc_middle::ty;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Spanned;
-use rustc_span::Span;
+use rustc_span::{sym, Span};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -47,6 +49,42 @@ declare_clippy_lint! {
     "suggests using `strip_{prefix,suffix}` over `str::{starts,ends}_with` and slicing"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `str::{starts,ends}_with` tests followed by stripping the pattern by hand in
+    /// the ways `manual_strip` does not catch: slicing a field, removing the pattern with
+    /// `str::trim_{start,end}_matches`, or returning early and slicing afterwards.
+    ///
+    /// ### Why is this bad?
+    /// `str::strip_{prefix,suffix}` tests and strips in one step, without the index arithmetic.
+    /// `str::trim_{start,end}_matches` removes the pattern as many times as it repeats, which is
+    /// rarely intended right after testing for a single occurrence.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn module_name(file: &str) -> Option<&str> {
+    ///     if !file.ends_with(".rs") {
+    ///         return None;
+    ///     }
+    ///     let name = &file[..file.len() - ".rs".len()];
+    ///     Some(name)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn module_name(file: &str) -> Option<&str> {
+    ///     let Some(name) = file.strip_suffix(".rs") else {
+    ///         return None;
+    ///     };
+    ///     Some(name)
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MANUAL_STRIP_SUFFIX_INDEX,
+    complexity,
+    "stripping a tested prefix or suffix by slicing or trimming where `strip_{prefix,suffix}` would do"
+}
+
 pub struct ManualStrip {
     msrv: Msrv,
 }
@@ -58,7 +96,7 @@ impl ManualStrip {
     }
 }
 
-impl_lint_pass!(ManualStrip => [MANUAL_STRIP]);
+impl_lint_pass!(ManualStrip => [MANUAL_STRIP, MANUAL_STRIP_SUFFIX_INDEX]);
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum StripKind {
@@ -66,6 +104,22 @@ enum StripKind {
     Suffix,
 }
 
+impl StripKind {
+    fn word(self) -> &'static str {
+        match self {
+            Self::Prefix => "prefix",
+            Self::Suffix => "suffix",
+        }
+    }
+
+    fn trim_method(self) -> &'static str {
+        match self {
+            Self::Prefix => "trim_start_matches",
+            Self::Suffix => "trim_end_matches",
+        }
+    }
+}
+
 impl<'tcx> LateLintPass<'tcx> for ManualStrip {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if !self.msrv.meets(msrvs::STR_STRIP_PREFIX) {
@@ -74,18 +128,11 @@ impl<'tcx> LateLintPass<'tcx> for ManualStrip {
 
         if_chain! {
             if let Some(higher::If { cond, then, .. }) = higher::If::hir(expr);
-            if let ExprKind::MethodCall(_, target_arg, [pattern], _) = cond.kind;
-            if let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(cond.hir_id);
-            if let ExprKind::Path(target_path) = &target_arg.kind;
+            if let Some((strip_kind, target_arg, pattern)) = strip_test(cx, cond);
+            if let Some(target_root) = target_root(target_arg);
+            if let ExprKind::Path(target_path) = &target_root.kind;
             then {
-                let strip_kind = if match_def_path(cx, method_def_id, &paths::STR_STARTS_WITH) {
-                    StripKind::Prefix
-                } else if match_def_path(cx, method_def_id, &paths::STR_ENDS_WITH) {
-                    StripKind::Suffix
-                } else {
-                    return;
-                };
-                let target_res = cx.qpath_res(target_path, target_arg.hir_id);
+                let target_res = cx.qpath_res(target_path, target_root.hir_id);
                 if target_res == Res::Err {
                     return;
                 };
@@ -99,27 +146,75 @@ impl<'tcx> LateLintPass<'tcx> for ManualStrip {
                     }
                 }
 
-                let strippings = find_stripping(cx, strip_kind, target_res, pattern, then);
-                if !strippings.is_empty() {
+                let (strippings, trimmings) = find_stripping(cx, strip_kind, target_arg, pattern, then);
+                // Slicing a local is `manual_strip`'s job, anything else is left to
+                // `manual_strip_suffix_index`
+                if target_root.hir_id == target_arg.hir_id && !strippings.is_empty() {
+                    emit_strippings(cx, MANUAL_STRIP, strip_kind, expr, then, target_arg, pattern, strippings);
+                } else if !strippings.is_empty() || !trimmings.is_empty() {
+                    let mut spans = strippings;
+                    spans.extend(trimmings);
+                    spans.sort_by_key(|span| span.lo());
+                    emit_strippings(cx, MANUAL_STRIP_SUFFIX_INDEX, strip_kind, expr, then, target_arg, pattern, spans);
+                }
+            }
+        }
+    }
 
-                    let kind_word = match strip_kind {
-                        StripKind::Prefix => "prefix",
-                        StripKind::Suffix => "suffix",
-                    };
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
+        if !self.msrv.meets(msrvs::STR_STRIP_PREFIX) || !self.msrv.meets(msrvs::LET_ELSE) {
+            return;
+        }
 
-                    let test_span = expr.span.until(then.span);
-                    span_lint_and_then(cx, MANUAL_STRIP, strippings[0], &format!("stripping a {kind_word} manually"), |diag| {
-                        diag.span_note(test_span, format!("the {kind_word} was tested here"));
-                        multispan_sugg(
-                            diag,
-                            &format!("try using the `strip_{kind_word}` method"),
-                            vec![(test_span,
-                                  format!("if let Some(<stripped>) = {}.strip_{kind_word}({}) ",
-                                          snippet(cx, target_arg.span, ".."),
-                                          snippet(cx, pattern.span, "..")))]
-                            .into_iter().chain(strippings.into_iter().map(|span| (span, "<stripped>".into()))),
-                        );
-                    });
+        for [guard, local] in block.stmts.array_windows::<2>() {
+            if_chain! {
+                if let StmtKind::Expr(guard_expr) | StmtKind::Semi(guard_expr) = guard.kind;
+                if let Some(higher::If { cond, then, r#else: None }) = higher::If::hir(guard_expr);
+                if let ExprKind::Unary(UnOp::Not, test) = cond.kind;
+                if let Some((strip_kind, target_arg, pattern)) = strip_test(cx, test);
+                if target_root(target_arg).is_some();
+                if let StmtKind::Local(Local { pat, ty: None, init: Some(init), els: None, .. }) = local.kind;
+                let trimming = is_trimming(cx, strip_kind, target_arg, pattern, init);
+                if trimming || is_stripping(cx, strip_kind, target_arg, pattern, init);
+                if block_diverges(cx, then);
+                if !guard.span.from_expansion() && guard.span.ctxt() == local.span.ctxt();
+                then {
+                    let kind_word = strip_kind.word();
+                    // `trim_{start,end}_matches` removes every repetition of the pattern, while
+                    // `strip_{prefix,suffix}` only removes one
+                    let mut app = if trimming {
+                        Applicability::MaybeIncorrect
+                    } else {
+                        Applicability::MachineApplicable
+                    };
+                    let ctxt = guard.span.ctxt();
+                    span_lint_and_then(
+                        cx,
+                        MANUAL_STRIP_SUFFIX_INDEX,
+                        guard.span.to(local.span),
+                        &format!("stripping a {kind_word} manually"),
+                        |diag| {
+                            let sugg = format!(
+                                "let Some({}) = {}.strip_{kind_word}({}) else {};",
+                                snippet_with_context(cx, pat.span, ctxt, "..", &mut app).0,
+                                snippet_with_context(cx, target_arg.span, ctxt, "..", &mut app).0,
+                                snippet_with_context(cx, pattern.span, ctxt, "..", &mut app).0,
+                                snippet_with_context(cx, then.span, ctxt, "{ .. }", &mut app).0,
+                            );
+                            diag.span_suggestion(
+                                guard.span.to(local.span),
+                                format!("try using the `strip_{kind_word}` method with `let...else`"),
+                                sugg,
+                                app,
+                            );
+                            if trimming {
+                                diag.note(format!(
+                                    "`strip_{kind_word}` removes the {kind_word} once, while `{}` removes it as often as it repeats",
+                                    strip_kind.trim_method(),
+                                ));
+                            }
+                        },
+                    );
                 }
             }
         }
@@ -128,12 +223,99 @@ impl<'tcx> LateLintPass<'tcx> for ManualStrip {
     extract_msrv_attr!(LateContext);
 }
 
-// Returns `Some(arg)` if `expr` matches `arg.len()` and `None` otherwise.
+#[expect(clippy::too_many_arguments)]
+fn emit_strippings(
+    cx: &LateContext<'_>,
+    lint: &'static Lint,
+    strip_kind: StripKind,
+    expr: &Expr<'_>,
+    then: &Expr<'_>,
+    target_arg: &Expr<'_>,
+    pattern: &Expr<'_>,
+    strippings: Vec<Span>,
+) {
+    let kind_word = strip_kind.word();
+    let test_span = expr.span.until(then.span);
+    span_lint_and_then(
+        cx,
+        lint,
+        strippings[0],
+        &format!("stripping a {kind_word} manually"),
+        |diag| {
+            diag.span_note(test_span, format!("the {kind_word} was tested here"));
+            multispan_sugg(
+                diag,
+                &format!("try using the `strip_{kind_word}` method"),
+                vec![(
+                    test_span,
+                    format!(
+                        "if let Some(<stripped>) = {}.strip_{kind_word}({}) ",
+                        snippet(cx, target_arg.span, ".."),
+                        snippet(cx, pattern.span, "..")
+                    ),
+                )]
+                .into_iter()
+                .chain(strippings.into_iter().map(|span| (span, "<stripped>".into()))),
+            );
+        },
+    );
+}
+
+// Returns the kind of stripping, the target and the pattern if `cond` is
+// `target.starts_with(pattern)` or `target.ends_with(pattern)` on a `str`.
+fn strip_test<'tcx>(
+    cx: &LateContext<'tcx>,
+    cond: &'tcx Expr<'tcx>,
+) -> Option<(StripKind, &'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if_chain! {
+        if let ExprKind::MethodCall(_, target_arg, [pattern], _) = cond.kind;
+        if let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(cond.hir_id);
+        then {
+            if match_def_path(cx, method_def_id, &paths::STR_STARTS_WITH) {
+                Some((StripKind::Prefix, target_arg, pattern))
+            } else if match_def_path(cx, method_def_id, &paths::STR_ENDS_WITH) {
+                Some((StripKind::Suffix, target_arg, pattern))
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+}
+
+// Returns the path at the root of a target like `s` or `a.b.c`. Other targets are not looked at,
+// since they may evaluate to a different string each time.
+fn target_root<'a>(mut target: &'a Expr<'a>) -> Option<&'a Expr<'a>> {
+    while let ExprKind::Field(base, _) = target.kind {
+        target = base;
+    }
+    matches!(target.kind, ExprKind::Path(_)).then_some(target)
+}
+
+// Tests if `block` leaves the enclosing scope, as required for the `else` branch of a `let...else`.
+fn block_diverges(cx: &LateContext<'_>, block: &Expr<'_>) -> bool {
+    let ExprKind::Block(block, _) = block.kind else {
+        return false;
+    };
+    let last = block.expr.or_else(|| match block.stmts.last()?.kind {
+        StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+        _ => None,
+    });
+    last.map_or(false, |e| {
+        matches!(e.kind, ExprKind::Ret(_) | ExprKind::Break(..) | ExprKind::Continue(_))
+            || cx.typeck_results().expr_ty(e).is_never()
+    })
+}
+
+// Returns `Some(arg)` if `expr` matches `arg.len()` on a `str` or `String` and `None` otherwise.
 fn len_arg<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
     if_chain! {
-        if let ExprKind::MethodCall(_, arg, [], _) = expr.kind;
+        if let ExprKind::MethodCall(method, arg, [], _) = expr.kind;
         if let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id);
-        if match_def_path(cx, method_def_id, &paths::STR_LEN);
+        if match_def_path(cx, method_def_id, &paths::STR_LEN)
+            || (method.ident.name == sym::len
+                && is_type_lang_item(cx, cx.typeck_results().expr_ty_adjusted(arg).peel_refs(), LangItem::String));
         then {
             Some(arg)
         } else {
@@ -182,60 +364,91 @@ fn peel_ref<'a>(expr: &'a Expr<'_>) -> &'a Expr<'a> {
     }
 }
 
-// Find expressions where `target` is stripped using the length of `pattern`.
-// We'll suggest replacing these expressions with the result of the `strip_{prefix,suffix}`
-// method.
+// Tests if `ex` strips `pattern` from `target` by slicing with the length of `pattern`.
+fn is_stripping<'tcx>(
+    cx: &LateContext<'tcx>,
+    strip_kind: StripKind,
+    target: &Expr<'_>,
+    pattern: &Expr<'_>,
+    ex: &'tcx Expr<'_>,
+) -> bool {
+    if_chain! {
+        if is_ref_str(cx, ex);
+        let unref = peel_ref(ex);
+        if let ExprKind::Index(indexed, index) = &unref.kind;
+        if let Some(higher::Range { start, end, .. }) = higher::Range::hir(index);
+        if eq_expr_value(cx, target, indexed);
+        then {
+            match (strip_kind, start, end) {
+                (StripKind::Prefix, Some(start), None) => eq_pattern_length(cx, pattern, start),
+                (StripKind::Suffix, None, Some(end)) => {
+                    if_chain! {
+                        if let ExprKind::Binary(Spanned { node: BinOpKind::Sub, .. }, left, right) = end.kind;
+                        if let Some(left_arg) = len_arg(cx, left);
+                        if eq_expr_value(cx, target, left_arg);
+                        then {
+                            eq_pattern_length(cx, pattern, right)
+                        } else {
+                            false
+                        }
+                    }
+                },
+                _ => false,
+            }
+        } else {
+            false
+        }
+    }
+}
+
+// Tests if `ex` strips `pattern` from `target` with `trim_{start,end}_matches`.
+fn is_trimming(
+    cx: &LateContext<'_>,
+    strip_kind: StripKind,
+    target: &Expr<'_>,
+    pattern: &Expr<'_>,
+    ex: &Expr<'_>,
+) -> bool {
+    if_chain! {
+        if let ExprKind::MethodCall(method, recv, [arg], _) = ex.kind;
+        if method.ident.as_str() == strip_kind.trim_method();
+        if cx.typeck_results().expr_ty_adjusted(recv).peel_refs().is_str();
+        then {
+            eq_expr_value(cx, target, recv) && eq_expr_value(cx, pattern, arg)
+        } else {
+            false
+        }
+    }
+}
+
+// Find expressions where `target` is stripped using the length of `pattern`, and those where it
+// is trimmed using `pattern`. We'll suggest replacing these expressions with the result of the
+// `strip_{prefix,suffix}` method.
 fn find_stripping<'tcx>(
     cx: &LateContext<'tcx>,
     strip_kind: StripKind,
-    target: Res,
+    target: &'tcx Expr<'_>,
     pattern: &'tcx Expr<'_>,
     expr: &'tcx Expr<'_>,
-) -> Vec<Span> {
+) -> (Vec<Span>, Vec<Span>) {
     struct StrippingFinder<'a, 'tcx> {
         cx: &'a LateContext<'tcx>,
         strip_kind: StripKind,
-        target: Res,
+        target: &'tcx Expr<'tcx>,
         pattern: &'tcx Expr<'tcx>,
-        results: Vec<Span>,
+        strippings: Vec<Span>,
+        trimmings: Vec<Span>,
     }
 
     impl<'a, 'tcx> Visitor<'tcx> for StrippingFinder<'a, 'tcx> {
         fn visit_expr(&mut self, ex: &'tcx Expr<'_>) {
-            if_chain! {
-                if is_ref_str(self.cx, ex);
-                let unref = peel_ref(ex);
-                if let ExprKind::Index(indexed, index) = &unref.kind;
-                if let Some(higher::Range { start, end, .. }) = higher::Range::hir(index);
-                if let ExprKind::Path(path) = &indexed.kind;
-                if self.cx.qpath_res(path, ex.hir_id) == self.target;
-                then {
-                    match (self.strip_kind, start, end) {
-                        (StripKind::Prefix, Some(start), None) => {
-                            if eq_pattern_length(self.cx, self.pattern, start) {
-                                self.results.push(ex.span);
-                                return;
-                            }
-                        },
-                        (StripKind::Suffix, None, Some(end)) => {
-                            if_chain! {
-                                if let ExprKind::Binary(Spanned { node: BinOpKind::Sub, .. }, left, right) = end.kind;
-                                if let Some(left_arg) = len_arg(self.cx, left);
-                                if let ExprKind::Path(left_path) = &left_arg.kind;
-                                if self.cx.qpath_res(left_path, left_arg.hir_id) == self.target;
-                                if eq_pattern_length(self.cx, self.pattern, right);
-                                then {
-                                    self.results.push(ex.span);
-                                    return;
-                                }
-                            }
-                        },
-                        _ => {}
-                    }
-                }
+            if is_stripping(self.cx, self.strip_kind, self.target, self.pattern, ex) {
+                self.strippings.push(ex.span);
+            } else if is_trimming(self.cx, self.strip_kind, self.target, self.pattern, ex) {
+                self.trimmings.push(ex.span);
+            } else {
+                walk_expr(self, ex);
             }
-
-            walk_expr(self, ex);
         }
     }
 
@@ -244,8 +457,9 @@ fn find_stripping<'tcx>(
         strip_kind,
         target,
         pattern,
-        results: vec![],
+        strippings: vec![],
+        trimmings: vec![],
     };
     walk_expr(&mut finder, expr);
-    finder.results
+    (finder.strippings, finder.trimmings)
 }
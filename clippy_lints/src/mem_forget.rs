@@ -1,6 +1,11 @@
-use clippy_utils::diagnostics::span_lint;
-use rustc_hir::{Expr, ExprKind};
-use rustc_lint::{LateContext, LateLintPass};
+use crate::await_holding_invalid::{is_mutex_guard, is_refcell_ref};
+use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{get_attr, is_lint_allowed, match_def_path, paths};
+use rustc_hir::{Expr, ExprKind, LangItem, QPath};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::AdtDef;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::sym;
 
@@ -25,22 +30,97 @@ declare_clippy_lint! {
     "`mem::forget` usage on `Drop` types, likely to cause memory leaks"
 }
 
-declare_lint_pass!(MemForget => [MEM_FORGET]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `std::mem::forget(t)` and `std::mem::ManuallyDrop::new(t)` where `t` is a lock
+    /// guard, a `RefCell` borrow, a `drain` iterator of a `Vec`, `VecDeque` or `String`, or of a
+    /// type marked with `#[clippy::has_significant_drop]`.
+    ///
+    /// ### Why is this bad?
+    /// The destructors of these types restore the state of the value they borrow from. A
+    /// forgotten guard keeps its lock locked forever, so the next attempt to lock it deadlocks,
+    /// a forgotten `RefCell` borrow makes every later borrow panic, and a forgotten `drain`
+    /// iterator leaks the elements and leaves the collection truncated.
+    ///
+    /// Unlike `mem_forget`, which is about leaking in general, this is almost always a bug.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// # let mutex = Mutex::new(0);
+    /// let guard = mutex.lock().unwrap();
+    /// std::mem::forget(guard);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::Mutex;
+    /// # let mutex = Mutex::new(0);
+    /// let guard = mutex.lock().unwrap();
+    /// drop(guard);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MEM_FORGET_GUARD_TYPE,
+    suspicious,
+    "`mem::forget` or `ManuallyDrop::new` on a guard whose destructor must run"
+}
+
+declare_lint_pass!(MemForget => [MEM_FORGET, MEM_FORGET_GUARD_TYPE]);
 
 impl<'tcx> LateLintPass<'tcx> for MemForget {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
         if let ExprKind::Call(path_expr, [ref first_arg, ..]) = e.kind {
             if let ExprKind::Path(ref qpath) = path_expr.kind {
-                if let Some(def_id) = cx.qpath_res(qpath, path_expr.hir_id).opt_def_id() {
-                    if cx.tcx.is_diagnostic_item(sym::mem_forget, def_id) {
-                        let forgot_ty = cx.typeck_results().expr_ty(first_arg);
+                let forgot_ty = cx.typeck_results().expr_ty(first_arg);
+                let is_forget = cx
+                    .qpath_res(qpath, path_expr.hir_id)
+                    .opt_def_id()
+                    .map_or(false, |def_id| cx.tcx.is_diagnostic_item(sym::mem_forget, def_id));
+                let is_manually_drop_new = matches!(qpath, QPath::TypeRelative(_, name) if name.ident.name == sym::new)
+                    && is_type_lang_item(cx, cx.typeck_results().expr_ty(e), LangItem::ManuallyDrop);
 
-                        if forgot_ty.ty_adt_def().map_or(false, |def| def.has_dtor(cx.tcx)) {
-                            span_lint(cx, MEM_FORGET, e.span, "usage of `mem::forget` on `Drop` type");
-                        }
-                    }
+                if (is_forget || is_manually_drop_new)
+                    && !is_lint_allowed(cx, MEM_FORGET_GUARD_TYPE, e.hir_id)
+                    && !in_external_macro(cx.sess(), e.span)
+                    && let Some(adt) = forgot_ty.ty_adt_def()
+                    && let Some(consequence) = guard_consequence(cx, adt)
+                {
+                    let func = if is_forget { "mem::forget" } else { "ManuallyDrop::new" };
+                    span_lint_and_then(
+                        cx,
+                        MEM_FORGET_GUARD_TYPE,
+                        e.span,
+                        &format!("usage of `{func}` on a `{}`", cx.tcx.item_name(adt.did())),
+                        |diag| {
+                            diag.note(format!("its destructor never runs, so {consequence}"));
+                            diag.help("use `drop` instead, or keep the value alive for as long as needed");
+                        },
+                    );
+                } else if is_forget && forgot_ty.ty_adt_def().map_or(false, |def| def.has_dtor(cx.tcx)) {
+                    span_lint(cx, MEM_FORGET, e.span, "usage of `mem::forget` on `Drop` type");
                 }
             }
         }
     }
 }
+
+/// What goes wrong if the destructor of a value of `adt` doesn't run, if it's a guard.
+fn guard_consequence(cx: &LateContext<'_>, adt: AdtDef<'_>) -> Option<&'static str> {
+    let did = adt.did();
+    if is_mutex_guard(cx, did) {
+        Some("the lock stays locked, and locking it again deadlocks")
+    } else if is_refcell_ref(cx, did) {
+        Some("the `RefCell` stays borrowed, and borrowing it mutably panics")
+    } else if match_def_path(cx, did, &paths::VEC_DRAIN)
+        || match_def_path(cx, did, &paths::VEC_DEQUE_DRAIN)
+        || match_def_path(cx, did, &paths::STRING_DRAIN)
+    {
+        Some("the drained elements are leaked, and the collection is left truncated")
+    } else if get_attr(cx.sess(), cx.tcx.get_attrs_unchecked(did), "has_significant_drop")
+        .next()
+        .is_some()
+    {
+        Some("whatever it releases on drop is never released")
+    } else {
+        None
+    }
+}
@@ -0,0 +1,194 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::macros::root_macro_call_first_node;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{get_parent_expr, is_trait_method, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_hir::{BorrowKind, Closure, Expr, ExprKind, LangItem, PatKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+use super::ALLOCATION_INSIDE_HOT_ITERATOR_ADAPTER;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Allocation {
+    /// `to_vec()`
+    ToVec,
+    /// `to_string()` or `to_owned()` on a `str`
+    ToOwned,
+    /// `to_ascii_lowercase()` or `to_ascii_uppercase()`
+    AsciiCase,
+    /// `format!(..)`
+    Format,
+}
+
+#[derive(Clone, Copy)]
+enum Use {
+    Comparison,
+    Hash,
+    Lookup,
+}
+
+/// Checks the closure passed to the iterator adapter `name`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, name: &str, arg: &'tcx Expr<'_>) {
+    if !is_trait_method(cx, expr, sym::Iterator) || expr.span.from_expansion() {
+        return;
+    }
+    let ExprKind::Closure(&Closure { body, .. }) = arg.kind else {
+        return;
+    };
+    let body = cx.tcx.hir().body(body);
+
+    let _: Option<!> = for_each_expr(body.value, |e| {
+        // nested closures are checked on their own
+        if matches!(e.kind, ExprKind::Closure(_)) {
+            return ControlFlow::Continue(Descend::No);
+        }
+        let Some((allocation, span)) = allocation(cx, e) else {
+            return ControlFlow::Continue(Descend::Yes);
+        };
+        match use_of(cx, e) {
+            // `cmp_owned` already lints these
+            Some(Use::Comparison) if allocation == Allocation::ToOwned => {},
+            Some(Use::Comparison) => {
+                let help = match allocation {
+                    Allocation::AsciiCase => "use `eq_ignore_ascii_case` to compare without allocating",
+                    Allocation::Format => "compare the formatted parts directly, e.g. with `starts_with`",
+                    _ => "compare the borrowed slices directly",
+                };
+                lint(
+                    cx,
+                    span,
+                    &format!("allocating in `{name}` only to compare the result"),
+                    help,
+                );
+            },
+            Some(Use::Hash) if matches!(allocation, Allocation::ToVec | Allocation::ToOwned) => {
+                lint(
+                    cx,
+                    span,
+                    &format!("allocating in `{name}` only to hash the result"),
+                    "hash the borrowed value, which hashes the same",
+                );
+            },
+            Some(Use::Lookup) if matches!(allocation, Allocation::ToVec | Allocation::ToOwned) => {
+                lint(
+                    cx,
+                    span,
+                    &format!("allocating in `{name}` only to look up the result"),
+                    "look up the borrowed value, which the collection accepts through `Borrow`",
+                );
+            },
+            _ => {},
+        }
+        ControlFlow::Continue(Descend::No)
+    });
+
+    if name == "map"
+        && let Some((allocation, span)) = allocation(cx, body.value)
+        && allocation != Allocation::Format
+        && let Some(parent) = get_parent_expr(cx, expr)
+        && let ExprKind::MethodCall(consumer, recv, [consumer_arg], _) = parent.kind
+        && recv.hir_id == expr.hir_id
+        && matches!(consumer.ident.as_str(), "all" | "any" | "position")
+        && only_compared(cx, consumer_arg)
+    {
+        let consumer = consumer.ident.as_str();
+        let help = if allocation == Allocation::AsciiCase {
+            format!("use `eq_ignore_ascii_case` in `{consumer}` instead")
+        } else {
+            format!("compare the borrowed values in `{consumer}` instead")
+        };
+        lint(
+            cx,
+            span,
+            &format!("allocating in `map` only to compare the result in `{consumer}`"),
+            &help,
+        );
+    }
+}
+
+fn lint(cx: &LateContext<'_>, span: Span, msg: &str, help: &str) {
+    span_lint_and_help(cx, ALLOCATION_INSIDE_HOT_ITERATOR_ADAPTER, span, msg, None, help);
+}
+
+fn allocation(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<(Allocation, Span)> {
+    if let Some(macro_call) = root_macro_call_first_node(cx, e) {
+        return cx
+            .tcx
+            .is_diagnostic_item(sym::format_macro, macro_call.def_id)
+            .then_some((Allocation::Format, macro_call.span));
+    }
+    let ExprKind::MethodCall(path, recv, [], _) = e.kind else {
+        return None;
+    };
+    if e.span.from_expansion() {
+        return None;
+    }
+    let typeck = cx.typeck_results();
+    let ty = typeck.expr_ty(e);
+    let is_str_recv = || typeck.expr_ty_adjusted(recv).peel_refs().is_str();
+    let allocation = match path.ident.as_str() {
+        "to_vec" if is_type_diagnostic_item(cx, ty, sym::Vec) => Allocation::ToVec,
+        "to_owned" | "to_string" if is_type_lang_item(cx, ty, LangItem::String) && is_str_recv() => Allocation::ToOwned,
+        "to_ascii_lowercase" | "to_ascii_uppercase"
+            if is_type_lang_item(cx, ty, LangItem::String) || is_type_diagnostic_item(cx, ty, sym::Vec) =>
+        {
+            Allocation::AsciiCase
+        },
+        _ => return None,
+    };
+    Some((allocation, e.span))
+}
+
+/// Returns how the value of `e` is used, if it's only compared, hashed or looked up.
+fn use_of<'tcx>(cx: &LateContext<'tcx>, mut e: &'tcx Expr<'tcx>) -> Option<Use> {
+    let mut parent = get_parent_expr(cx, e)?;
+    while let ExprKind::AddrOf(BorrowKind::Ref, _, _) = parent.kind {
+        e = parent;
+        parent = get_parent_expr(cx, e)?;
+    }
+    match parent.kind {
+        ExprKind::Binary(op, ..) if op.node.is_comparison() => Some(Use::Comparison),
+        ExprKind::MethodCall(path, recv, args, _) => match path.ident.as_str() {
+            "eq" | "ne" | "cmp" | "partial_cmp" => Some(Use::Comparison),
+            "hash" if recv.hir_id == e.hir_id => Some(Use::Hash),
+            "contains" | "contains_key" | "get"
+                if args.iter().any(|arg| arg.hir_id == e.hir_id)
+                    && [sym::HashMap, sym::HashSet, sym::BTreeMap, sym::BTreeSet]
+                        .into_iter()
+                        .any(|item| {
+                            is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv).peel_refs(), item)
+                        }) =>
+            {
+                Some(Use::Lookup)
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether the parameter of the closure `arg` is only compared, hashed or looked up.
+fn only_compared(cx: &LateContext<'_>, arg: &Expr<'_>) -> bool {
+    if let ExprKind::Closure(&Closure { body, .. }) = arg.kind
+        && let body = cx.tcx.hir().body(body)
+        && let [param] = body.params
+        && let PatKind::Binding(_, id, _, None) = param.pat.kind
+    {
+        let mut used = false;
+        for_each_expr(body.value, |e| {
+            if path_to_local_id(e, id) {
+                if use_of(cx, e).is_none() {
+                    return ControlFlow::Break(());
+                }
+                used = true;
+            }
+            ControlFlow::Continue(())
+        })
+        .is_none()
+            && used
+    } else {
+        false
+    }
+}
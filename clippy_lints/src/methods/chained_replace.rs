@@ -0,0 +1,53 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{eq_expr_value, get_parent_expr, is_lint_allowed};
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+
+use super::method_call;
+use super::{CHAINED_REPLACE, COLLAPSIBLE_STR_REPLACE};
+
+/// Checks chains of `str::replace` calls, starting from the outermost one.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'tcx>,
+    last_to: &hir::Expr<'_>,
+    threshold: u64,
+) {
+    // Only lint the whole chain, not every call in it
+    if expr.span.from_expansion()
+        || get_parent_expr(cx, expr).map_or(
+            false,
+            |parent| matches!(method_call(parent), Some(("replace", recv, [_, _], _, _)) if recv.hir_id == expr.hir_id),
+        )
+    {
+        return;
+    }
+
+    let mut count = 0;
+    let mut first_span = expr.span;
+    let mut only_chars_with_same_to = true;
+    let mut current = expr;
+    while let Some(("replace", recv, [from, to], method_span, _)) = method_call(current)
+        && cx.typeck_results().expr_ty_adjusted(recv).peel_refs().is_str()
+    {
+        only_chars_with_same_to &=
+            cx.typeck_results().expr_ty(from).peel_refs().is_char() && eq_expr_value(cx, last_to, to);
+        count += 1;
+        first_span = method_span;
+        current = recv;
+    }
+
+    // `collapsible_str_replace` suggests a single call with a char array for these
+    if count < threshold || (only_chars_with_same_to && !is_lint_allowed(cx, COLLAPSIBLE_STR_REPLACE, expr.hir_id)) {
+        return;
+    }
+    span_lint_and_help(
+        cx,
+        CHAINED_REPLACE,
+        expr.span.with_lo(first_span.lo()),
+        &format!("`str::replace` is called {count} times in a row"),
+        None,
+        "each call allocates a new `String` and scans all of it again, consider replacing everything in a \
+        single pass, e.g. by matching on `chars()` or with the `aho-corasick` crate",
+    );
+}
@@ -0,0 +1,88 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::SpanlessEq;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, Node};
+use rustc_lint::LateContext;
+
+use super::{method_call, CHAR_TO_LOWERCASE_NEXT_UNWRAP};
+
+/// Checks `c.to_lowercase().next().unwrap()` and `c.to_uppercase().next().unwrap()`, and the same
+/// with `expect(..)`. `recv` is the receiver of `unwrap` or `expect`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, recv: &Expr<'_>) {
+    let Some(("next", case_call, [], _, _)) = method_call(recv) else {
+        return;
+    };
+    let Some((case @ ("to_lowercase" | "to_uppercase"), c, [], _, _)) = method_call(case_call) else {
+        return;
+    };
+    if !cx.typeck_results().expr_ty_adjusted(c).peel_refs().is_char() || expr.span.from_expansion() {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        CHAR_TO_LOWERCASE_NEXT_UNWRAP,
+        expr.span,
+        &format!("taking only the first `char` of `{case}()`"),
+        |diag| {
+            if is_ascii_literal(c) || is_guarded_by_ascii_check(cx, expr, c) {
+                let mut app = Applicability::MachineApplicable;
+                let c = snippet_with_context(cx, c.span, expr.span.ctxt(), "..", &mut app).0;
+                let ascii = case.replacen("to_", "to_ascii_", 1);
+                diag.span_suggestion(
+                    expr.span,
+                    "the character is ASCII, so it has a single case mapping",
+                    format!("{c}.{ascii}()"),
+                    app,
+                );
+            } else {
+                diag.note(
+                    "some characters map to more than one `char`, e.g. `'ß'.to_uppercase()` is `\"SS\"`, \
+                    so the rest of the mapping is lost",
+                );
+                diag.help(format!(
+                    "use all of them, e.g. with `{case}().collect::<String>()`, \
+                    or `to_ascii_{}()` if the character is known to be ASCII",
+                    &case["to_".len()..]
+                ));
+            }
+        },
+    );
+}
+
+fn is_ascii_literal(e: &Expr<'_>) -> bool {
+    matches!(e.kind, ExprKind::Lit(lit) if matches!(lit.node, LitKind::Char(c) if c.is_ascii()))
+}
+
+/// Whether `expr` is only evaluated in an `if` branch whose condition checks `c.is_ascii()` or
+/// `c.is_ascii_alphabetic()` and similar.
+fn is_guarded_by_ascii_check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, c: &Expr<'_>) -> bool {
+    let mut child = expr.hir_id;
+    for (id, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Expr(Expr {
+                kind: ExprKind::If(cond, then, _),
+                ..
+            }) if then.hir_id == child && checks_ascii(cx, cond, c) => return true,
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => return false,
+            _ => {},
+        }
+        child = id;
+    }
+    false
+}
+
+fn checks_ascii(cx: &LateContext<'_>, cond: &Expr<'_>, c: &Expr<'_>) -> bool {
+    match cond.kind {
+        ExprKind::DropTemps(inner) => checks_ascii(cx, inner, c),
+        ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::And => {
+            checks_ascii(cx, lhs, c) || checks_ascii(cx, rhs, c)
+        },
+        ExprKind::MethodCall(path, recv, [], _) => {
+            path.ident.as_str().starts_with("is_ascii") && SpanlessEq::new(cx).eq_expr(recv, c)
+        },
+        _ => false,
+    }
+}
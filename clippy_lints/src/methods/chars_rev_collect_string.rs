@@ -0,0 +1,33 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::is_type_lang_item;
+use rustc_hir::{Expr, ExprKind, LangItem};
+use rustc_lint::LateContext;
+
+use super::CHARS_REV_COLLECT_STRING;
+
+/// Checks for `s.chars().rev().collect::<String>()`. `rev_recv` is the receiver of `rev`.
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, rev_recv: &Expr<'_>, ignore_ascii: bool) {
+    if let ExprKind::MethodCall(path, str_recv, [], _) = rev_recv.kind
+        && path.ident.name.as_str() == "chars"
+        && cx.typeck_results().expr_ty_adjusted(str_recv).peel_refs().is_str()
+        && is_type_lang_item(cx, cx.typeck_results().expr_ty(expr), LangItem::String)
+        && !expr.span.from_expansion()
+    {
+        if ignore_ascii
+            && let Some(Constant::Str(s)) = constant(cx, cx.typeck_results(), str_recv)
+            && s.is_ascii()
+        {
+            return;
+        }
+        span_lint_and_help(
+            cx,
+            CHARS_REV_COLLECT_STRING,
+            expr.span,
+            "reversing a string with `chars().rev()` does not keep grapheme clusters together",
+            None,
+            "if the string may contain combining characters, consider `graphemes(true).rev()` from the \
+            `unicode-segmentation` crate",
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::higher::ForLoop;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::usage::local_used_after_expr;
+use clippy_utils::visitors::for_each_expr_with_closures;
+use clippy_utils::{get_parent_expr, is_diag_trait_item, is_trait_method, path_to_local, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{BindingAnnotation, BorrowKind, Expr, ExprKind, Mutability, PatKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::{sym, Span};
+
+use super::CLONE_INTO_ITER;
+
+const COLLECTIONS: [rustc_span::Symbol; 6] = [
+    sym::Vec,
+    sym::VecDeque,
+    sym::HashSet,
+    sym::BTreeSet,
+    sym::BinaryHeap,
+    sym::LinkedList,
+];
+
+/// Checks `collection.clone().into_iter()`. `clone_call` is the receiver of `into_iter` and
+/// `recv` the receiver of `clone`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    clone_call: &Expr<'_>,
+    recv: &Expr<'_>,
+    clone_span: Span,
+) {
+    let collection_ty = cx.typeck_results().expr_ty(clone_call);
+    if !is_trait_method(cx, expr, sym::IntoIterator)
+        || !cx
+            .typeck_results()
+            .type_dependent_def_id(clone_call.hir_id)
+            .map_or(false, |id| is_diag_trait_item(cx, id, sym::Clone))
+        || !is_collection(cx, collection_ty)
+        || expr.span.from_expansion()
+    {
+        return;
+    }
+
+    // Iterating by reference borrows the original for as long as the iterator lives. Only do that
+    // if nothing else could need the collection in the meantime: the original is behind a shared
+    // reference, or it's a local which isn't used afterwards.
+    if !is_behind_shared_ref(cx, recv) {
+        match path_to_local(recv) {
+            Some(local_id) if !local_used_after_expr(cx, local_id, expr) => {},
+            _ => return,
+        }
+    }
+
+    let (sugg, app) = if is_only_read_in_loop(cx, expr) {
+        ("iter()", Applicability::MaybeIncorrect)
+    } else {
+        ("iter().cloned()", Applicability::MachineApplicable)
+    };
+    span_lint_and_sugg(
+        cx,
+        CLONE_INTO_ITER,
+        clone_span.with_hi(expr.span.hi()),
+        "cloning a collection only to iterate over it",
+        "iterate over the original instead",
+        sugg.to_string(),
+        app,
+    );
+}
+
+fn is_collection(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    ty.is_array() || COLLECTIONS.iter().any(|&name| is_type_diagnostic_item(cx, ty, name))
+}
+
+/// Whether the place `e` is reached through a shared reference, e.g. `r.items` with `r: &Wrapper`,
+/// so that it can't be mutated while it's borrowed.
+fn is_behind_shared_ref(cx: &LateContext<'_>, mut e: &Expr<'_>) -> bool {
+    loop {
+        if matches!(cx.typeck_results().expr_ty(e).kind(), ty::Ref(_, _, Mutability::Not)) {
+            return true;
+        }
+        match e.kind {
+            ExprKind::Field(base, _) | ExprKind::Unary(UnOp::Deref, base) => e = base,
+            _ => return false,
+        }
+    }
+}
+
+/// Whether `expr` is the iterable of a `for` loop whose item is only ever used by reference.
+fn is_only_read_in_loop(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let Some(for_loop) = get_parent_expr(cx, expr)
+        .and_then(|e| get_parent_expr(cx, e))
+        .and_then(|e| get_parent_expr(cx, e))
+        .and_then(ForLoop::hir)
+    else {
+        return false;
+    };
+    let PatKind::Binding(BindingAnnotation::NONE, binding_id, ..) = for_loop.pat.kind else {
+        return false;
+    };
+    if for_loop.arg.hir_id != expr.hir_id {
+        return false;
+    }
+    for_each_expr_with_closures(cx, for_loop.body, |e| {
+        if !path_to_local_id(e, binding_id) {
+            return ControlFlow::Continue(());
+        }
+        let by_ref = match get_parent_expr(cx, e).map(|parent| parent.kind) {
+            Some(ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _)) => true,
+            Some(ExprKind::MethodCall(_, method_recv, ..)) if method_recv.hir_id == e.hir_id => matches!(
+                cx.typeck_results()
+                    .expr_adjustments(e)
+                    .first()
+                    .map(|adjust| &adjust.kind),
+                Some(Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Not)))
+            ),
+            _ => false,
+        };
+        if by_ref {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    })
+    .is_none()
+}
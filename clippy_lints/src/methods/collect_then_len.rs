@@ -0,0 +1,104 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
+use clippy_utils::path_to_local;
+use clippy_utils::ty::{get_iterator_item_ty, is_type_lang_item};
+use rustc_errors::Applicability;
+use rustc_hir::{BindingAnnotation, Closure, Expr, ExprKind, LangItem, Mutability, Node, Pat, PatKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::Span;
+
+use super::COLLECT_THEN_LEN;
+
+/// Checks for `iter.collect::<String>().len()` and `iter.collect::<String>().is_empty()`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    name: &str,
+    collect_expr: &'tcx Expr<'_>,
+    iter_expr: &'tcx Expr<'_>,
+    collect_span: Span,
+) {
+    let collect_ty = cx.typeck_results().expr_ty(collect_expr);
+    let Some(item_ty) = get_iterator_item_ty(cx, cx.typeck_results().expr_ty(iter_expr)) else {
+        return;
+    };
+    let is_empty = name == "is_empty";
+
+    if !is_type_lang_item(cx, collect_ty, LangItem::String) {
+        return;
+    }
+    // A `String` collected from `&str`s can be empty even if the iterator wasn't, and its length
+    // is in bytes rather than in items.
+    let sugg = match (item_ty.kind(), is_empty) {
+        (ty::Char, true) => "next().is_none()",
+        (ty::Char, false) => "map(char::len_utf8).sum::<usize>()",
+        (ty::Ref(_, inner, _), true) if inner.is_str() => "all(str::is_empty)",
+        (ty::Ref(_, inner, _), false) if inner.is_str() => "map(str::len).sum::<usize>()",
+        _ => return,
+    };
+
+    let short_circuits = is_empty;
+    let side_effects = short_circuits && !is_side_effect_free(cx, iter_expr);
+    // `next` and `all` take the iterator by `&mut`, unlike `collect`
+    let needs_mut = sugg.starts_with("next") || sugg.starts_with("all");
+    span_lint_and_then(
+        cx,
+        COLLECT_THEN_LEN,
+        collect_span.with_hi(expr.span.hi()),
+        &format!("collecting an iterator only to call `{name}` on the result"),
+        |diag| {
+            let app = if side_effects {
+                diag.note("the suggestion no longer drives the iterator to completion, skipping any side effects of later items");
+                Applicability::MaybeIncorrect
+            } else if needs_mut && !is_mutable(cx, iter_expr) {
+                Applicability::MaybeIncorrect
+            } else {
+                Applicability::MachineApplicable
+            };
+            diag.span_suggestion(collect_span.with_hi(expr.span.hi()), "try", sugg, app);
+        },
+    );
+}
+
+/// Whether the closures passed along the iterator chain are known to be free of side effects,
+/// i.e. whether stopping the iteration early is unobservable.
+fn is_side_effect_free<'tcx>(cx: &LateContext<'tcx>, mut iter_expr: &'tcx Expr<'_>) -> bool {
+    while let ExprKind::MethodCall(_, recv, args, _) = iter_expr.kind {
+        for arg in args {
+            match arg.kind {
+                ExprKind::Closure(&Closure { body, .. }) => {
+                    if !switch_to_eager_eval(cx, cx.tcx.hir().body(body).value) {
+                        return false;
+                    }
+                },
+                ExprKind::Lit(_) => {},
+                ExprKind::Path(_) if cx.typeck_results().expr_ty(arg).is_integral() => {},
+                _ => return false,
+            }
+        }
+        iter_expr = recv;
+    }
+    true
+}
+
+/// Whether `iter_expr` can be borrowed mutably, i.e. whether it's a temporary, a `mut` local or
+/// a local `&mut` reference.
+fn is_mutable(cx: &LateContext<'_>, iter_expr: &Expr<'_>) -> bool {
+    match iter_expr.kind {
+        ExprKind::Path(_) => path_to_local(iter_expr).map_or(false, |id| {
+            matches!(
+                cx.tcx.hir().find(id),
+                Some(Node::Pat(Pat {
+                    kind: PatKind::Binding(BindingAnnotation(_, Mutability::Mut), ..),
+                    ..
+                }))
+            ) || matches!(
+                cx.typeck_results().expr_ty(iter_expr).kind(),
+                ty::Ref(_, _, Mutability::Mut)
+            )
+        }),
+        ExprKind::Field(..) | ExprKind::Index(..) | ExprKind::Unary(UnOp::Deref, _) => false,
+        _ => true,
+    }
+}
@@ -0,0 +1,102 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use clippy_utils::{is_lint_allowed, is_trait_method, path_to_local_id, peel_blocks};
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind, LangItem, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::sym;
+
+use super::{method_call, COLLECT_TO_STRING_VIA_VEC, UNNECESSARY_JOIN};
+
+/// Checks `iter.collect::<Vec<_>>().join("")` and `iter.collect::<Vec<_>>().concat()` where the
+/// items are `String`s or `&str`s. `name` is either `join` or `concat`.
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    name: &str,
+    collect_call: &Expr<'_>,
+    join_arg: Option<&Expr<'_>>,
+) {
+    let Some(("collect", iter, [], collect_span, _)) = method_call(collect_call) else {
+        return;
+    };
+    if !is_trait_method(cx, collect_call, sym::Iterator) || expr.span.from_expansion() {
+        return;
+    }
+    // only joining with an empty separator is the same as concatenating
+    if let Some(arg) = join_arg
+        && !matches!(arg.kind, ExprKind::Lit(lit) if matches!(lit.node, LitKind::Str(sep, _) if sep.is_empty()))
+    {
+        return;
+    }
+    let collect_ty = cx.typeck_results().expr_ty(collect_call);
+    let ty::Adt(_, args) = collect_ty.kind() else {
+        return;
+    };
+    if !is_type_diagnostic_item(cx, collect_ty, sym::Vec) {
+        return;
+    }
+    let item_ty = args.type_at(0);
+    let is_string = is_type_lang_item(cx, item_ty, LangItem::String);
+    if !is_string && !is_str_ref(item_ty) {
+        return;
+    }
+
+    if let Some(map_span) = removable_to_string_map(cx, iter) {
+        span_lint_and_sugg(
+            cx,
+            COLLECT_TO_STRING_VIA_VEC,
+            map_span.with_hi(expr.span.hi()),
+            &format!("converting each item to a `String` and collecting them into a `Vec` only to `{name}` them"),
+            "collect the items into a `String` directly",
+            "collect::<String>()".to_owned(),
+            Applicability::MachineApplicable,
+        );
+    } else if !(is_string && name == "join" && !is_lint_allowed(cx, UNNECESSARY_JOIN, expr.hir_id)) {
+        // `unnecessary_join` already suggests this for `Vec<String>` and `join("")`
+        span_lint_and_sugg(
+            cx,
+            COLLECT_TO_STRING_VIA_VEC,
+            collect_span.with_hi(expr.span.hi()),
+            &format!("collecting into a `Vec` only to `{name}` the items into a `String`"),
+            "collect into a `String` directly",
+            "collect::<String>()".to_owned(),
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+fn is_str_ref(ty: Ty<'_>) -> bool {
+    matches!(ty.kind(), ty::Ref(_, inner, _) if inner.is_str())
+}
+
+/// If `iter` is `_.map(|c| c.to_string())` on an iterator of `char`s, `&char`s or `&str`s, which
+/// `String` can be collected from directly, returns the span starting at `map`.
+fn removable_to_string_map(cx: &LateContext<'_>, iter: &Expr<'_>) -> Option<rustc_span::Span> {
+    let Some(("map", _, [map_arg], map_span, _)) = method_call(iter) else {
+        return None;
+    };
+    let ExprKind::Closure(&Closure { body, .. }) = map_arg.kind else {
+        return None;
+    };
+    let body = cx.tcx.hir().body(body);
+    let [param] = body.params else {
+        return None;
+    };
+    let PatKind::Binding(_, param_id, ..) = param.pat.kind else {
+        return None;
+    };
+    let param_ty = cx.typeck_results().node_type(param.hir_id);
+    if let ExprKind::MethodCall(path, recv, [], _) = peel_blocks(body.value).kind
+        && path.ident.name == sym::to_string
+        && path_to_local_id(recv, param_id)
+        && (param_ty.is_char() || is_str_ref(param_ty) || matches!(param_ty.kind(), ty::Ref(_, inner, _) if inner.is_char()))
+        && is_trait_method(cx, iter, sym::Iterator)
+    {
+        Some(map_span)
+    } else {
+        None
+    }
+}
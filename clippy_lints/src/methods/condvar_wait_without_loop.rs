@@ -0,0 +1,35 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::paths;
+use clippy_utils::ty::match_type;
+use rustc_hir::{Expr, ExprKind, LoopSource, Node};
+use rustc_lint::LateContext;
+use rustc_span::Span;
+
+use super::CONDVAR_WAIT_WITHOUT_LOOP;
+
+/// Checks `condvar.wait(guard)` and `condvar.wait_timeout(guard, dur)` outside of a loop.
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, name: &str, name_span: Span) {
+    if !match_type(cx, cx.typeck_results().expr_ty(recv).peel_refs(), &paths::CONDVAR) || expr.span.from_expansion() {
+        return;
+    }
+    for (_, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            // a closure may be called in a loop
+            Node::Expr(&Expr {
+                kind: ExprKind::Loop(_, _, LoopSource::Loop | LoopSource::While, _) | ExprKind::Closure(_),
+                ..
+            }) => return,
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => break,
+            _ => {},
+        }
+    }
+
+    span_lint_and_help(
+        cx,
+        CONDVAR_WAIT_WITHOUT_LOOP,
+        name_span,
+        &format!("`Condvar::{name}` is called without re-checking the condition in a loop"),
+        None,
+        &format!("the condition variable may wake up spuriously, use `{name}_while` to wait until the condition holds"),
+    );
+}
@@ -0,0 +1,81 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::higher::Range;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use clippy_utils::{is_lint_allowed, is_range_full};
+use rustc_ast::ast::RangeLimits;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, LangItem, QPath};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+use super::{DRAIN_COLLECT, ITER_WITH_DRAIN};
+
+/// Checks `v.drain(..).collect()` and `v.drain(n..).collect()`, where the collection has the
+/// same type as `v`.
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg: &Expr<'_>) {
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    let (container_ty, is_ref) = match recv_ty.kind() {
+        ty::Ref(_, inner, _) => (*inner, true),
+        _ => (recv_ty, false),
+    };
+    let Some(ty_name) = (if is_type_lang_item(cx, container_ty, LangItem::String) {
+        Some("String")
+    } else {
+        [(sym::Vec, "Vec"), (sym::VecDeque, "VecDeque")]
+            .into_iter()
+            .find(|&(item, _)| is_type_diagnostic_item(cx, container_ty, item))
+            .map(|(_, name)| name)
+    }) else {
+        return;
+    };
+    // Both replacements leave the original in place, so it has to be a place that can be borrowed
+    // mutably rather than a temporary.
+    if cx.typeck_results().expr_ty(expr) != container_ty || !recv.is_place_expr(|_| true) || expr.span.from_expansion()
+    {
+        return;
+    }
+
+    let mut app = Applicability::MachineApplicable;
+    let ctxt = expr.span.ctxt();
+    let container_path = match recv.kind {
+        ExprKind::Path(QPath::Resolved(None, path)) => Some(path),
+        _ => None,
+    };
+    if is_range_full(cx, arg, container_path) {
+        // `iter_with_drain` suggests `into_iter()` for owned locals
+        if !is_ref && container_path.is_some() && !is_lint_allowed(cx, ITER_WITH_DRAIN, expr.hir_id) {
+            return;
+        }
+        let recv = Sugg::hir_with_context(cx, recv, ctxt, "..", &mut app);
+        let recv = if is_ref { recv } else { recv.mut_addr() };
+        span_lint_and_sugg(
+            cx,
+            DRAIN_COLLECT,
+            expr.span,
+            &format!("`drain` used to move all elements into a new `{ty_name}`"),
+            "consider using `mem::take`",
+            format!("std::mem::take({recv})"),
+            app,
+        );
+    } else if let Some(Range {
+        start: Some(start),
+        end: None,
+        limits: RangeLimits::HalfOpen,
+    }) = Range::hir(arg)
+    {
+        let recv = Sugg::hir_with_context(cx, recv, ctxt, "..", &mut app).maybe_par();
+        let start = snippet_with_context(cx, start.span, ctxt, "..", &mut app).0;
+        span_lint_and_sugg(
+            cx,
+            DRAIN_COLLECT,
+            expr.span,
+            &format!("`drain` used to move the tail into a new `{ty_name}`"),
+            "consider using `split_off`",
+            format!("{recv}.split_off({start})"),
+            app,
+        );
+    }
+}
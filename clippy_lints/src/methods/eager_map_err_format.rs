@@ -0,0 +1,72 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::macros::root_macro_call_first_node;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{get_parent_expr, is_lint_allowed};
+use rustc_hir::{Closure, Expr, ExprKind, LangItem, PatKind, QPath};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+use super::{EAGER_MAP_ERR_FORMAT, MAP_ERR_IGNORE};
+
+/// Checks `res.map_err(|e| format!(..))?`.
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg: &Expr<'_>, name_span: Span) {
+    let ExprKind::Closure(&Closure { body, .. }) = arg.kind else {
+        return;
+    };
+    let body = cx.tcx.hir().body(body);
+    let [param] = body.params else {
+        return;
+    };
+    if !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Result)
+        || !is_format(cx, body.value)
+        || !is_try_operand(cx, expr)
+    {
+        return;
+    }
+    // `map_err_ignore` lints `|_| ..`
+    if let PatKind::Wild = param.pat.kind
+        && !is_lint_allowed(cx, MAP_ERR_IGNORE, expr.hir_id)
+    {
+        return;
+    }
+
+    span_lint_and_help(
+        cx,
+        EAGER_MAP_ERR_FORMAT,
+        name_span.with_hi(expr.span.hi()),
+        "the error is formatted into a `String` before being propagated with `?`",
+        None,
+        "consider an error type which keeps the source error, or attaching the context lazily with a \
+        combinator like `with_context`",
+    );
+}
+
+/// Whether `e` is a `format!` call, possibly as the value of a block like the body of
+/// `|e| { log(&e); format!(..) }`.
+fn is_format(cx: &LateContext<'_>, mut e: &Expr<'_>) -> bool {
+    loop {
+        if let Some(macro_call) = root_macro_call_first_node(cx, e) {
+            return cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id);
+        }
+        match e.kind {
+            ExprKind::Block(block, _) if let Some(value) = block.expr => e = value,
+            _ => return false,
+        }
+    }
+}
+
+/// Whether `expr` is the operand of a `?`.
+fn is_try_operand(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    get_parent_expr(cx, expr).map_or(false, |parent| {
+        matches!(
+            parent.kind,
+            ExprKind::Call(
+                Expr {
+                    kind: ExprKind::Path(QPath::LangItem(LangItem::TryTraitBranch, ..)),
+                    ..
+                },
+                _
+            )
+        )
+    })
+}
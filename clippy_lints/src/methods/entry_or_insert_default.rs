@@ -0,0 +1,100 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::eager_or_lazy::switch_to_lazy_eval;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
+use clippy_utils::{contains_return, is_default_equivalent, is_default_equivalent_call, is_lint_allowed, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_span::source_map::Span;
+use rustc_span::sym;
+
+use super::{ENTRY_OR_INSERT_DEFAULT, OR_FUN_CALL};
+
+/// Checks `entry.or_insert(..)` and `entry.or_insert_with(..)` on the entry types of
+/// `HashMap` and `BTreeMap`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx hir::Expr<'_>,
+    name: &str,
+    method_span: Span,
+    recv: &'tcx hir::Expr<'_>,
+    arg: &'tcx hir::Expr<'_>,
+) {
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    if !is_type_diagnostic_item(cx, recv_ty, sym::HashMapEntry)
+        && !is_type_diagnostic_item(cx, recv_ty, sym::BTreeEntry)
+    {
+        return;
+    }
+
+    let replace_span = method_span.with_hi(expr.span.hi());
+    match name {
+        "or_insert" => {
+            // `or_fun_call` already lints `or_insert(T::new())` and `or_insert(T::default())`
+            let or_fun_call_lints = !is_lint_allowed(cx, OR_FUN_CALL, expr.hir_id)
+                && matches!(peel_blocks(arg).kind, hir::ExprKind::Call(_, []));
+            if or_fun_call_lints {
+                return;
+            }
+            if is_default_equivalent(cx, arg) && value_implements_default(cx, arg) {
+                lint_or_default(cx, replace_span, name);
+            } else if let hir::ExprKind::Call(..) | hir::ExprKind::MethodCall(..) = peel_blocks(arg).kind
+                // `or_fun_call` already suggests the lazy form
+                && is_lint_allowed(cx, OR_FUN_CALL, expr.hir_id)
+                && !arg.span.from_expansion()
+                && switch_to_lazy_eval(cx, arg)
+                && !contains_return(arg)
+            {
+                let mut app = Applicability::MachineApplicable;
+                let sugg = match peel_blocks(arg).kind {
+                    hir::ExprKind::Call(fun, []) => snippet_with_context(cx, fun.span, expr.span.ctxt(), "..", &mut app).0,
+                    _ => format!("|| {}", snippet_with_context(cx, arg.span, expr.span.ctxt(), "..", &mut app).0).into(),
+                };
+                span_lint_and_sugg(
+                    cx,
+                    ENTRY_OR_INSERT_DEFAULT,
+                    replace_span,
+                    "the value passed to `or_insert` is computed even if the entry is occupied",
+                    "use `or_insert_with` instead",
+                    format!("or_insert_with({sugg})"),
+                    app,
+                );
+            }
+        },
+        "or_insert_with" => {
+            let is_default = match arg.kind {
+                hir::ExprKind::Closure(&hir::Closure { body, .. }) => {
+                    let body = cx.tcx.hir().body(body);
+                    body.params.is_empty()
+                        && is_default_equivalent(cx, body.value)
+                        && value_implements_default(cx, body.value)
+                },
+                hir::ExprKind::Path(_) => is_default_equivalent_call(cx, arg),
+                _ => false,
+            };
+            if is_default {
+                lint_or_default(cx, replace_span, name);
+            }
+        },
+        _ => {},
+    }
+}
+
+fn value_implements_default(cx: &LateContext<'_>, value: &hir::Expr<'_>) -> bool {
+    cx.tcx.get_diagnostic_item(sym::Default).map_or(false, |default_trait| {
+        implements_trait(cx, cx.typeck_results().expr_ty(value), default_trait, &[])
+    })
+}
+
+fn lint_or_default(cx: &LateContext<'_>, span: Span, name: &str) {
+    span_lint_and_sugg(
+        cx,
+        ENTRY_OR_INSERT_DEFAULT,
+        span,
+        &format!("`{name}` is called with the default value of the map's value type"),
+        "use `or_default` instead",
+        "or_default()".to_string(),
+        Applicability::MachineApplicable,
+    );
+}
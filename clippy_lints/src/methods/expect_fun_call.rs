@@ -1,5 +1,6 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::macros::{find_format_args, format_args_inputs_span, root_macro_call_first_node};
+use clippy_utils::msrvs::{self, Msrv};
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
 use rustc_errors::Applicability;
@@ -21,6 +22,7 @@ pub(super) fn check<'tcx>(
     name: &str,
     receiver: &'tcx hir::Expr<'tcx>,
     args: &'tcx [hir::Expr<'tcx>],
+    msrv: &Msrv,
 ) {
     // Strip `&`, `as_ref()` and `as_str()` off `arg` until we're left with either a `String` or
     // `&str`
@@ -139,13 +141,30 @@ pub(super) fn check<'tcx>(
         find_format_args(cx, arg_root, macro_call.expn, |format_args| {
             let span = format_args_inputs_span(format_args);
             let sugg = snippet_with_applicability(cx, span, "..", &mut applicability);
+            // `Result::expect` also prints the error after the message, keep doing that
+            let unused_err_name = ["err", "e", "error"].into_iter().find(|&err| {
+                !sugg
+                    .split(|c: char| !c.is_alphanumeric() && c != '_')
+                    .any(|word| word == err)
+            });
+            let panic = if closure_args == "|_|"
+                && let Some(err) = unused_err_name
+            {
+                if msrv.meets(msrvs::FORMAT_ARGS_CAPTURE) {
+                    format!("|{err}| panic!(\"{{}}: {{{err}:?}}\", format_args!({sugg}))")
+                } else {
+                    format!("|{err}| panic!(\"{{}}: {{:?}}\", format_args!({sugg}), {err})")
+                }
+            } else {
+                format!("{closure_args} panic!({sugg})")
+            };
             span_lint_and_sugg(
                 cx,
                 EXPECT_FUN_CALL,
                 span_replace_word,
                 &format!("use of `{name}` followed by a function call"),
                 "try this",
-                format!("unwrap_or_else({closure_args} panic!({sugg}))"),
+                format!("unwrap_or_else({panic})"),
                 applicability,
             );
         });
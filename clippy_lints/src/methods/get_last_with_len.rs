@@ -1,8 +1,8 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_with_applicability;
-use clippy_utils::{is_integer_literal, SpanlessEq};
+use clippy_utils::{find_binding_init, is_integer_literal, path_to_local, SpanlessEq};
 use rustc_errors::Applicability;
-use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_hir::{BinOpKind, BindingAnnotation, Expr, ExprKind, Mutability, Node, Pat, PatKind};
 use rustc_lint::LateContext;
 use rustc_middle::ty;
 use rustc_span::source_map::Spanned;
@@ -20,8 +20,12 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg:
         rhs,
     ) = arg.kind
 
-        // LHS of subtraction is "x.len()"
-        && let ExprKind::MethodCall(lhs_path, lhs_recv, [], _) = &lhs.kind
+        // LHS of subtraction is "x.len()", either directly or through `let len = x.len();`
+        && let (len_call, through_binding) = match path_to_local(lhs).and_then(|id| find_binding_init(cx, id)) {
+            Some(init) => (init, true),
+            None => (lhs, false),
+        }
+        && let ExprKind::MethodCall(lhs_path, lhs_recv, [], _) = &len_call.kind
         && lhs_path.ident.name == sym::len
 
         // RHS of subtraction is 1
@@ -30,6 +34,9 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg:
         // check that recv == lhs_recv `recv.get(lhs_recv.len() - 1)`
         && SpanlessEq::new(cx).eq_expr(recv, lhs_recv)
         && !recv.can_have_side_effects()
+
+        // the length can't have changed since it was stored
+        && (!through_binding || is_immutable_local(cx, recv))
     {
         let method = match cx.typeck_results().expr_ty_adjusted(recv).peel_refs().kind() {
             ty::Adt(def, _) if cx.tcx.is_diagnostic_item(sym::VecDeque, def.did()) => "back",
@@ -39,15 +46,28 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg:
 
         let mut applicability = Applicability::MachineApplicable;
         let recv_snippet = snippet_with_applicability(cx, recv.span, "_", &mut applicability);
+        let arg_snippet = snippet_with_applicability(cx, arg.span, "_", &mut applicability);
 
         span_lint_and_sugg(
             cx,
             GET_LAST_WITH_LEN,
             expr.span,
-            &format!("accessing last element with `{recv_snippet}.get({recv_snippet}.len() - 1)`"),
+            &format!("accessing last element with `{recv_snippet}.get({arg_snippet})`"),
             "try",
             format!("{recv_snippet}.{method}()"),
             applicability,
         );
     }
 }
+
+fn is_immutable_local(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    path_to_local(expr).map_or(false, |id| {
+        matches!(
+            cx.tcx.hir().find(id),
+            Some(Node::Pat(Pat {
+                kind: PatKind::Binding(BindingAnnotation::NONE, ..),
+                ..
+            }))
+        )
+    }) && !matches!(cx.typeck_results().expr_ty(expr).kind(), ty::Ref(_, _, Mutability::Mut))
+}
@@ -1,17 +1,21 @@
 use crate::methods::utils::derefs_to_slice;
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::is_type_diagnostic_item;
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
+use rustc_middle::ty;
 use rustc_span::sym;
 
 use super::ITER_CLONED_COLLECT;
 
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, method_name: &str, expr: &hir::Expr<'_>, recv: &'tcx hir::Expr<'_>) {
+    if !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::Vec) {
+        return;
+    }
     if_chain! {
-        if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::Vec);
         if let Some(slice) = derefs_to_slice(cx, recv, cx.typeck_results().expr_ty(recv));
         if let Some(to_replace) = expr.span.trim_start(slice.span.source_callsite());
 
@@ -26,6 +30,35 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, method_name: &str, expr: &hir:
                 ".to_vec()".to_string(),
                 Applicability::MachineApplicable,
             );
+            return;
+        }
+    }
+    // `(&v).into_iter()` iterates over references like `v.iter()`
+    if_chain! {
+        if let hir::ExprKind::MethodCall(path, iter_recv, [], _) = recv.kind;
+        if path.ident.name == sym::into_iter;
+        let iter_recv_ty = cx.typeck_results().expr_ty(iter_recv);
+        if matches!(iter_recv_ty.kind(), ty::Ref(_, _, hir::Mutability::Not));
+        if let Some(slice) = derefs_to_slice(cx, iter_recv, iter_recv_ty);
+        if !expr.span.from_expansion();
+
+        then {
+            let slice = match slice.kind {
+                hir::ExprKind::AddrOf(hir::BorrowKind::Ref, hir::Mutability::Not, inner) => inner,
+                _ => slice,
+            };
+            let mut app = Applicability::MachineApplicable;
+            let slice = Sugg::hir_with_context(cx, slice, expr.span.ctxt(), "..", &mut app).maybe_par();
+            span_lint_and_sugg(
+                cx,
+                ITER_CLONED_COLLECT,
+                expr.span,
+                &format!("called `into_iter().{method_name}().collect()` on a slice to create a `Vec`. Calling `to_vec()` is \
+                both faster and more readable"),
+                "try",
+                format!("{slice}.to_vec()"),
+                app,
+            );
         }
     }
 }
@@ -0,0 +1,97 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::source::snippet;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{is_integer_literal, is_trait_method, path_to_local};
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{BindingAnnotation, Expr, ExprKind, Node, PatKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+use super::ITER_SKIP_THEN_NTH;
+
+/// Checks `iter.skip(n).nth(m)`. `skip(n).nth(0)` is left to `iter_nth_zero` and
+/// `iter_skip_next`.
+pub(super) fn check_nth(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    skip_call: &Expr<'_>,
+    iter: &Expr<'_>,
+    skip_arg: &Expr<'_>,
+    skip_span: Span,
+    nth_arg: &Expr<'_>,
+) {
+    if !is_trait_method(cx, expr, sym::Iterator)
+        || !is_trait_method(cx, skip_call, sym::Iterator)
+        || is_integer_literal(nth_arg, 0)
+        || expr.span.from_expansion()
+    {
+        return;
+    }
+
+    let mut app = Applicability::MachineApplicable;
+    let ctxt = expr.span.ctxt();
+    let n = match (int_lit(skip_arg), int_lit(nth_arg)) {
+        (Some(skip), Some(nth)) if let Some(sum) = skip.checked_add(nth) => sum.to_string(),
+        _ => {
+            let skip = Sugg::hir_with_context(cx, skip_arg, ctxt, "..", &mut app);
+            let nth = Sugg::hir_with_context(cx, nth_arg, ctxt, "..", &mut app);
+            (skip + nth).to_string()
+        },
+    };
+    span_lint_and_then(
+        cx,
+        ITER_SKIP_THEN_NTH,
+        expr.span,
+        "called `skip(..).nth(..)` on an iterator",
+        |diag| {
+            // `nth` borrows the iterator mutably, while `skip` takes it by value
+            if let Some(id) = path_to_local(iter)
+                && let Node::Pat(pat) = cx.tcx.hir().get(id)
+                && let PatKind::Binding(ann, ..) = pat.kind
+                && ann != BindingAnnotation::MUT
+            {
+                app = Applicability::Unspecified;
+                diag.span_help(
+                    pat.span,
+                    format!("for this change `{}` has to be mutable", snippet(cx, pat.span, "..")),
+                );
+            }
+            diag.span_suggestion(
+                skip_span.with_hi(expr.span.hi()),
+                "use a single `nth`",
+                format!("nth({n})"),
+                app,
+            );
+        },
+    );
+}
+
+/// Checks `iter.take(1).collect::<Vec<_>>()`.
+pub(super) fn check_collect(cx: &LateContext<'_>, expr: &Expr<'_>, take_call: &Expr<'_>, take_arg: &Expr<'_>) {
+    if is_integer_literal(take_arg, 1)
+        && is_trait_method(cx, take_call, sym::Iterator)
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::Vec)
+        && !expr.span.from_expansion()
+    {
+        span_lint_and_help(
+            cx,
+            ITER_SKIP_THEN_NTH,
+            expr.span,
+            "collecting at most one element into a `Vec`",
+            None,
+            "use `next()` to get the first element as an `Option`",
+        );
+    }
+}
+
+fn int_lit(e: &Expr<'_>) -> Option<u128> {
+    if let ExprKind::Lit(lit) = e.kind
+        && let LitKind::Int(value, _) = lit.node
+    {
+        Some(value)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,111 @@
+use clippy_utils::consts::constant;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{find_binding_init, is_trait_method, path_to_local, peel_blocks};
+use core::ops::ControlFlow;
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::{method_call, ITERATOR_STEP_BY_ZERO_RUNTIME};
+
+/// Checks `iter.step_by(n)` where `n` is computed in a way that can produce zero, following
+/// immutable locals back to their initializer.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, arg: &'tcx Expr<'tcx>) {
+    // constants are handled by `iterator_step_by_zero`
+    if !is_trait_method(cx, expr, sym::Iterator)
+        || expr.span.from_expansion()
+        || constant(cx, cx.typeck_results(), arg).is_some()
+    {
+        return;
+    }
+
+    let mut locals = Vec::new();
+    let mut origin = peel_blocks(arg);
+    loop {
+        if let ExprKind::Cast(inner, _) = origin.kind {
+            origin = peel_blocks(inner);
+        } else if let Some(local_id) = path_to_local(origin)
+            && let Some(init) = find_binding_init(cx, local_id)
+        {
+            locals.push(local_id);
+            origin = peel_blocks(init);
+        } else {
+            break;
+        }
+    }
+    let Some(note) = zero_source(cx, origin) else {
+        return;
+    };
+    if origin.span.from_expansion() || is_compared(cx, &locals) {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        ITERATOR_STEP_BY_ZERO_RUNTIME,
+        arg.span,
+        "`Iterator::step_by` is called with a value that may be zero, which panics at runtime",
+        |diag| {
+            if origin.span == arg.span {
+                diag.note(note);
+            } else {
+                diag.span_note(origin.span, note);
+            }
+            diag.help("check that the step is not zero first");
+        },
+    );
+}
+
+/// Returns a description of how `e` can be zero, if it is one of the recognized cases.
+fn zero_source(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<&'static str> {
+    if constant(cx, cx.typeck_results(), e).is_some() {
+        return None;
+    }
+    if let ExprKind::Binary(op, lhs, rhs) = e.kind
+        && op.node == BinOpKind::Sub
+        && is_len_call(lhs)
+        && is_len_call(rhs)
+    {
+        Some("this subtraction results in zero if both lengths are equal")
+    } else if let ExprKind::Binary(op, _, _) = e.kind
+        && op.node == BinOpKind::Rem
+    {
+        Some("this remainder is zero if the left side is a multiple of the right side")
+    } else if let Some((name, recv, _, _, _)) = method_call(e)
+        && matches!(name, "unwrap" | "expect" | "unwrap_or_default")
+        && let Some(("parse", ..)) = method_call(recv)
+    {
+        Some("this value is parsed without checking that it is not zero")
+    } else {
+        None
+    }
+}
+
+fn is_len_call(e: &Expr<'_>) -> bool {
+    matches!(e.kind, ExprKind::MethodCall(path, _, [], _) if path.ident.name == sym::len)
+}
+
+/// Whether any of the locals is compared against something in the enclosing body, which is taken
+/// to mean that zero is checked for.
+fn is_compared(cx: &LateContext<'_>, locals: &[HirId]) -> bool {
+    let Some(body_id) = cx.enclosing_body else {
+        return false;
+    };
+    for_each_expr(cx.tcx.hir().body(body_id).value, |e| {
+        if let ExprKind::Binary(op, lhs, rhs) = e.kind
+            && matches!(
+                op.node,
+                BinOpKind::Eq | BinOpKind::Ne | BinOpKind::Lt | BinOpKind::Le | BinOpKind::Gt | BinOpKind::Ge
+            )
+            && [lhs, rhs]
+                .into_iter()
+                .any(|side| path_to_local(side).map_or(false, |id| locals.contains(&id)))
+        {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
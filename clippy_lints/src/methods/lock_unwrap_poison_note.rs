@@ -0,0 +1,63 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_errors::Applicability;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+use serde::Deserialize;
+
+use super::LOCK_UNWRAP_POISON_NOTE;
+
+/// How `lock_unwrap_poison_note` wants poisoned `Mutex`es to be handled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+pub enum LockUnwrapPolicy {
+    /// `.lock().expect("poisoned")`
+    Expect,
+    /// `parking_lot::Mutex`, which isn't poisoned
+    ParkingLot,
+}
+
+/// Checks `mutex.lock().unwrap()` and, with `LockUnwrapPolicy::ParkingLot`,
+/// `mutex.lock().expect(..)`.
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    lock_recv: &Expr<'_>,
+    name: &str,
+    span: Span,
+    policy: LockUnwrapPolicy,
+) {
+    if expr.span.from_expansion()
+        || !is_type_diagnostic_item(
+            cx,
+            cx.typeck_results().expr_ty_adjusted(lock_recv).peel_refs(),
+            sym::Mutex,
+        )
+    {
+        return;
+    }
+    match policy {
+        LockUnwrapPolicy::Expect if name == "unwrap" => {
+            span_lint_and_sugg(
+                cx,
+                LOCK_UNWRAP_POISON_NOTE,
+                span.with_hi(expr.span.hi()),
+                "called `unwrap` on the result of locking a `Mutex`",
+                "explain that the lock is poisoned if this panics",
+                r#"expect("poisoned")"#.to_owned(),
+                Applicability::MachineApplicable,
+            );
+        },
+        LockUnwrapPolicy::ParkingLot => {
+            span_lint_and_help(
+                cx,
+                LOCK_UNWRAP_POISON_NOTE,
+                span.with_hi(expr.span.hi()),
+                &format!("called `{name}` on the result of locking a `Mutex`"),
+                None,
+                "consider using `parking_lot::Mutex`, which isn't poisoned if a thread panics while holding the lock",
+            );
+        },
+        LockUnwrapPolicy::Expect => {},
+    }
+}
@@ -0,0 +1,58 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::{is_trait_method, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::MIN_MAX_BY_PARTIAL_CMP_UNWRAP;
+
+/// Checks `iter.max_by(|a, b| a.partial_cmp(b).unwrap())` and the `min_by` equivalent on
+/// iterators of floats.
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, name: &str, arg: &Expr<'_>, msrv: &Msrv) {
+    if !is_trait_method(cx, expr, sym::Iterator) || expr.span.from_expansion() {
+        return;
+    }
+    let ExprKind::Closure(&Closure { body, .. }) = arg.kind else {
+        return;
+    };
+    let cmp_expr = peel_blocks(cx.tcx.hir().body(body).value);
+    if let ExprKind::MethodCall(unwrap, cmp_call, _, _) = cmp_expr.kind
+        && matches!(unwrap.ident.as_str(), "unwrap" | "expect")
+        && let ExprKind::MethodCall(cmp, lhs, [rhs], _) = cmp_call.kind
+        && cmp.ident.as_str() == "partial_cmp"
+        && let float_ty = cx.typeck_results().expr_ty_adjusted(lhs).peel_refs()
+        && float_ty.is_floating_point()
+    {
+        let msg = format!("`{name}` is called with a comparison that panics on NaN");
+        if msrv.meets(msrvs::TOTAL_CMP) {
+            let mut app = Applicability::MaybeIncorrect;
+            let ctxt = cmp_expr.span.ctxt();
+            let lhs = snippet_with_context(cx, lhs.span, ctxt, "..", &mut app).0;
+            let rhs = snippet_with_context(cx, rhs.span, ctxt, "..", &mut app).0;
+            span_lint_and_sugg(
+                cx,
+                MIN_MAX_BY_PARTIAL_CMP_UNWRAP,
+                cmp_expr.span,
+                &msg,
+                "use `total_cmp`, which orders NaN instead of panicking",
+                format!("{lhs}.total_cmp({rhs})"),
+                app,
+            );
+        } else {
+            let fold = if name == "max_by" { "max" } else { "min" };
+            span_lint_and_help(
+                cx,
+                MIN_MAX_BY_PARTIAL_CMP_UNWRAP,
+                cmp_expr.span,
+                &msg,
+                None,
+                &format!(
+                    "decide how NaN should be treated, e.g. `fold({float_ty}::NAN, {float_ty}::{fold})` ignores it"
+                ),
+            );
+        }
+    }
+}
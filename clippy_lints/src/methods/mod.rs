@@ -1,19 +1,30 @@
+mod allocation_inside_hot_iterator_adapter;
 mod bind_instead_of_map;
 mod bytecount;
 mod bytes_count_to_len;
 mod bytes_nth;
 mod case_sensitive_file_extension_comparisons;
+mod chained_replace;
+mod char_to_lowercase_next_unwrap;
 mod chars_cmp;
 mod chars_cmp_with_unwrap;
 mod chars_last_cmp;
 mod chars_last_cmp_with_unwrap;
 mod chars_next_cmp;
 mod chars_next_cmp_with_unwrap;
+mod chars_rev_collect_string;
 mod clear_with_drain;
+mod clone_into_iter;
 mod clone_on_copy;
 mod clone_on_ref_ptr;
 mod cloned_instead_of_copied;
 mod collapsible_str_replace;
+mod collect_then_len;
+mod collect_to_string_via_vec;
+mod condvar_wait_without_loop;
+mod drain_collect;
+mod eager_map_err_format;
+mod entry_or_insert_default;
 mod err_expect;
 mod expect_fun_call;
 mod expect_used;
@@ -43,8 +54,11 @@ mod iter_nth_zero;
 mod iter_on_single_or_empty_collections;
 mod iter_overeager_cloned;
 mod iter_skip_next;
+mod iter_skip_then_nth;
 mod iter_with_drain;
 mod iterator_step_by_zero;
+mod iterator_step_by_zero_runtime;
+mod lock_unwrap_poison_note;
 mod manual_next_back;
 mod manual_ok_or;
 mod manual_saturating_arithmetic;
@@ -55,6 +69,7 @@ mod map_err_ignore;
 mod map_flatten;
 mod map_identity;
 mod map_unwrap_or;
+mod min_max_by_partial_cmp_unwrap;
 mod mut_mutex_lock;
 mod needless_collect;
 mod needless_option_as_deref;
@@ -64,13 +79,22 @@ mod obfuscated_if_else;
 mod ok_expect;
 mod open_options;
 mod option_as_ref_deref;
+mod option_as_ref_map_clone_chain;
+mod option_filter_map_bool;
 mod option_map_or_none;
 mod option_map_unwrap_or;
+mod option_zip_then_map_tuple;
 mod or_fun_call;
 mod or_then_unwrap;
 mod path_buf_push_overwrite;
+mod path_join_with_absolute_literal;
+mod pointer_arithmetic_wrapping_offset_misuse;
 mod range_zip_with_len;
+mod read_line_without_clear;
+mod redundant_sort_before_extreme;
 mod repeat_once;
+mod result_ok_then_unwrap_chain;
+mod retain_with_constant_predicate;
 mod search_is_some;
 mod seek_from_current;
 mod seek_to_start_instead_of_rewind;
@@ -79,13 +103,19 @@ mod single_char_insert_string;
 mod single_char_pattern;
 mod single_char_push_string;
 mod skip_while_next;
+mod sort_unstable_candidate;
+mod split_whitespace_collect_count;
 mod stable_sort_primitive;
 mod str_splitn;
 mod string_extend_chars;
+mod string_from_utf8_lossy_into_owned_roundtrip;
 mod suspicious_command_arg_space;
 mod suspicious_map;
 mod suspicious_splitn;
 mod suspicious_to_owned;
+mod sync_mutex_in_async_context;
+mod thread_spawn_join_immediately;
+mod unbuffered_file_io_in_loop;
 mod uninit_assumed_init;
 mod unit_hash;
 mod unnecessary_filter_map;
@@ -101,6 +131,7 @@ mod unwrap_used;
 mod useless_asref;
 mod utils;
 mod vec_resize_to_zero;
+mod vec_resize_zero_then_extend;
 mod verbose_file_reads;
 mod wrong_self_convention;
 mod zst_offset;
@@ -121,6 +152,9 @@ use rustc_middle::ty::{self, TraitRef, Ty};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::{sym, Span};
 
+pub use lock_unwrap_poison_note::LockUnwrapPolicy;
+pub use option_zip_then_map_tuple::OptionZipStyle;
+
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for usage of `cloned()` on an `Iterator` or `Option` where
@@ -933,6 +967,9 @@ declare_clippy_lint! {
     /// ### Why is this bad?
     /// The function will always be called.
     ///
+    /// For a `Result`, the suggested `panic!` still prints the error after a `format!`
+    /// message, like `expect` does.
+    ///
     /// ### Known problems
     /// If the function has side-effects, not calling it will
     /// change the semantics of the program, but you shouldn't rely on that anyway.
@@ -1101,7 +1138,8 @@ declare_clippy_lint! {
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for string methods that receive a single-character
-    /// `str` as an argument, e.g., `_.split("x")`.
+    /// `str` as an argument, e.g., `_.split("x")`, or a `char` converted
+    /// to a string, e.g., `_.split(&c.to_string())`.
     ///
     /// ### Why is this bad?
     /// Performing these methods using a `char` is faster than
@@ -1277,7 +1315,9 @@ declare_clippy_lint! {
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for usage of `x.get(x.len() - 1)` instead of
-    /// `x.last()`.
+    /// `x.last()`. The length may also come from an immutable
+    /// binding like `let len = x.len();`, as long as `x` can't
+    /// have been changed since.
     ///
     /// ### Why is this bad?
     /// Using `x.last()` is easier to read and has the same
@@ -1404,8 +1444,8 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for the use of `.cloned().collect()` on slice to
-    /// create a `Vec`.
+    /// Checks for the use of `.cloned().collect()` or `.copied().collect()` on slice to
+    /// create a `Vec`, with either `iter()` or `into_iter()` on a reference.
     ///
     /// ### Why is this bad?
     /// `.to_vec()` is clearer
@@ -1612,7 +1652,8 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for `MaybeUninit::uninit().assume_init()`.
+    /// Checks for `MaybeUninit::uninit().assume_init()`, also through a variable or an array of
+    /// `MaybeUninit`s, and for `transmute(MaybeUninit::uninit())`.
     ///
     /// ### Why is this bad?
     /// For most types, this is undefined behavior.
@@ -1790,7 +1831,7 @@ declare_clippy_lint! {
 declare_clippy_lint! {
     /// ### What it does
     /// Warns when using `push_str`/`insert_str` with a single-character string literal
-    /// where `push`/`insert` with a `char` would work fine.
+    /// or a `char` converted to a string, where `push`/`insert` with a `char` would work fine.
     ///
     /// ### Why is this bad?
     /// It's less clear that we are pushing a single character.
@@ -2829,11 +2870,16 @@ declare_clippy_lint! {
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for zipping a collection with the range of
-    /// `0.._.len()`.
+    /// `0.._.len()`, or an iterator with `0..`.
     ///
     /// ### Why is this bad?
     /// The code is better expressed with `.enumerate()`.
     ///
+    /// ### Known problems
+    /// `enumerate()` puts the index first. A fix is only suggested when the tuple is
+    /// destructured right away, in a `for` loop or in the closure of an adapter like `map`,
+    /// so that the pattern can be swapped. `iter.zip(0..)` is only linted in that case.
+    ///
     /// ### Example
     /// ```rust
     /// # let x = vec![1];
@@ -2996,10 +3042,11 @@ declare_clippy_lint! {
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Finds occurrences of `Vec::resize(0, an_int)`
+    /// Finds occurrences of `Vec::resize(0, _)`
     ///
     /// ### Why is this bad?
-    /// This is probably an argument inversion mistake.
+    /// With an integer, this is probably an argument inversion mistake.
+    /// Otherwise, `clear()` states the intent directly.
     ///
     /// ### Example
     /// ```rust
@@ -3013,7 +3060,7 @@ declare_clippy_lint! {
     #[clippy::version = "1.46.0"]
     pub VEC_RESIZE_TO_ZERO,
     correctness,
-    "emptying a vector with `resize(0, an_int)` instead of `clear()` is probably an argument inversion mistake"
+    "emptying a vector with `resize(0, _)` instead of `clear()`, which with an integer is probably an argument inversion mistake"
 }
 
 declare_clippy_lint! {
@@ -3247,179 +3294,1191 @@ declare_clippy_lint! {
     "manual reverse iteration of `DoubleEndedIterator`"
 }
 
-pub struct Methods {
-    avoid_breaking_exported_api: bool,
-    msrv: Msrv,
-    allow_expect_in_tests: bool,
-    allow_unwrap_in_tests: bool,
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `entry.or_insert(..)` and `entry.or_insert_with(..)` on the entry of a
+    /// `HashMap` or `BTreeMap` where the inserted value is equivalent to the `Default` of the
+    /// value type, such as `Vec::new()`, `String::new()`, `0` or `vec![]`. It also checks for
+    /// `or_insert` being passed a function call, which is evaluated even if the entry is
+    /// already occupied.
+    ///
+    /// ### Why is this bad?
+    /// `or_default()` is shorter and makes it obvious that the default value is inserted.
+    /// Passing a function call to `or_insert` runs it unconditionally, while
+    /// `or_insert_with` only calls it when the entry is vacant.
+    ///
+    /// ### Known problems
+    /// If the value type of the map is only inferred from the inserted value, replacing it
+    /// with `or_default()` requires a type annotation on the map.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # fn expensive() -> Vec<u32> { vec![1] }
+    /// let mut map: HashMap<u32, Vec<u32>> = HashMap::new();
+    /// map.entry(1).or_insert(Vec::new());
+    /// map.entry(2).or_insert_with(Vec::new);
+    /// map.entry(3).or_insert(expensive());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # fn expensive() -> Vec<u32> { vec![1] }
+    /// let mut map: HashMap<u32, Vec<u32>> = HashMap::new();
+    /// map.entry(1).or_default();
+    /// map.entry(2).or_default();
+    /// map.entry(3).or_insert_with(expensive);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub ENTRY_OR_INSERT_DEFAULT,
+    style,
+    "using `or_insert` with a default value or an eagerly evaluated call on a map entry"
 }
 
-impl Methods {
-    #[must_use]
-    pub fn new(
-        avoid_breaking_exported_api: bool,
-        msrv: Msrv,
-        allow_expect_in_tests: bool,
-        allow_unwrap_in_tests: bool,
-    ) -> Self {
-        Self {
-            avoid_breaking_exported_api,
-            msrv,
-            allow_expect_in_tests,
-            allow_unwrap_in_tests,
-        }
-    }
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for iterators of `char`s or `&str`s that are collected into a `String` only to
+    /// call `len()` or `is_empty()` on the result.
+    ///
+    /// `needless_collect` covers the same for other collections, like `Vec`.
+    ///
+    /// ### Why is this bad?
+    /// The `String` is allocated and immediately thrown away. The same information can be
+    /// computed directly from the iterator by summing the lengths of the items, or by checking
+    /// whether there is any (non-empty) item.
+    ///
+    /// ### Known problems
+    /// `next().is_none()` and `all(..)` stop at the first item, so closures with side effects
+    /// further down the iterator are no longer called. The suggestion is not automatically
+    /// applied in this case.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let words = ["a", "bc"];
+    /// let bytes = words.iter().copied().collect::<String>().len();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let words = ["a", "bc"];
+    /// let bytes = words.iter().copied().map(str::len).sum::<usize>();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub COLLECT_THEN_LEN,
+    perf,
+    "collecting an iterator into a `String` only to get its length"
 }
 
-impl_lint_pass!(Methods => [
-    UNWRAP_USED,
-    EXPECT_USED,
-    SHOULD_IMPLEMENT_TRAIT,
-    WRONG_SELF_CONVENTION,
-    OK_EXPECT,
-    UNWRAP_OR_ELSE_DEFAULT,
-    MAP_UNWRAP_OR,
-    RESULT_MAP_OR_INTO_OPTION,
-    OPTION_MAP_OR_NONE,
-    BIND_INSTEAD_OF_MAP,
-    OR_FUN_CALL,
-    OR_THEN_UNWRAP,
-    EXPECT_FUN_CALL,
-    CHARS_NEXT_CMP,
-    CHARS_LAST_CMP,
-    CLONE_ON_COPY,
-    CLONE_ON_REF_PTR,
-    COLLAPSIBLE_STR_REPLACE,
-    ITER_OVEREAGER_CLONED,
-    CLONED_INSTEAD_OF_COPIED,
-    FLAT_MAP_OPTION,
-    INEFFICIENT_TO_STRING,
-    NEW_RET_NO_SELF,
-    SINGLE_CHAR_PATTERN,
-    SINGLE_CHAR_ADD_STR,
-    SEARCH_IS_SOME,
-    FILTER_NEXT,
-    SKIP_WHILE_NEXT,
-    FILTER_MAP_IDENTITY,
-    MAP_IDENTITY,
-    MANUAL_FILTER_MAP,
-    MANUAL_FIND_MAP,
-    OPTION_FILTER_MAP,
-    FILTER_MAP_NEXT,
-    FLAT_MAP_IDENTITY,
-    MAP_FLATTEN,
-    ITERATOR_STEP_BY_ZERO,
-    ITER_NEXT_SLICE,
-    ITER_COUNT,
-    ITER_NTH,
-    ITER_NTH_ZERO,
-    BYTES_NTH,
-    ITER_SKIP_NEXT,
-    GET_UNWRAP,
-    GET_LAST_WITH_LEN,
-    STRING_EXTEND_CHARS,
-    ITER_CLONED_COLLECT,
-    ITER_WITH_DRAIN,
-    USELESS_ASREF,
-    UNNECESSARY_FOLD,
-    UNNECESSARY_FILTER_MAP,
-    UNNECESSARY_FIND_MAP,
-    INTO_ITER_ON_REF,
-    SUSPICIOUS_MAP,
-    UNINIT_ASSUMED_INIT,
-    MANUAL_SATURATING_ARITHMETIC,
-    ZST_OFFSET,
-    FILETYPE_IS_FILE,
-    OPTION_AS_REF_DEREF,
-    UNNECESSARY_LAZY_EVALUATIONS,
-    MAP_COLLECT_RESULT_UNIT,
-    FROM_ITER_INSTEAD_OF_COLLECT,
-    INSPECT_FOR_EACH,
-    IMPLICIT_CLONE,
-    SUSPICIOUS_TO_OWNED,
-    SUSPICIOUS_SPLITN,
-    MANUAL_STR_REPEAT,
-    EXTEND_WITH_DRAIN,
-    MANUAL_SPLIT_ONCE,
-    NEEDLESS_SPLITN,
-    UNNECESSARY_TO_OWNED,
-    UNNECESSARY_JOIN,
-    ERR_EXPECT,
-    NEEDLESS_OPTION_AS_DEREF,
-    IS_DIGIT_ASCII_RADIX,
-    NEEDLESS_OPTION_TAKE,
-    NO_EFFECT_REPLACE,
-    OBFUSCATED_IF_ELSE,
-    ITER_ON_SINGLE_ITEMS,
-    ITER_ON_EMPTY_COLLECTIONS,
-    NAIVE_BYTECOUNT,
-    BYTES_COUNT_TO_LEN,
-    CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS,
-    GET_FIRST,
-    MANUAL_OK_OR,
-    MAP_CLONE,
-    MAP_ERR_IGNORE,
-    MUT_MUTEX_LOCK,
-    NONSENSICAL_OPEN_OPTIONS,
-    PATH_BUF_PUSH_OVERWRITE,
-    RANGE_ZIP_WITH_LEN,
-    REPEAT_ONCE,
-    STABLE_SORT_PRIMITIVE,
-    UNIT_HASH,
-    UNNECESSARY_SORT_BY,
-    VEC_RESIZE_TO_ZERO,
-    VERBOSE_FILE_READS,
-    ITER_KV_MAP,
-    SEEK_FROM_CURRENT,
-    SEEK_TO_START_INSTEAD_OF_REWIND,
-    NEEDLESS_COLLECT,
-    SUSPICIOUS_COMMAND_ARG_SPACE,
-    CLEAR_WITH_DRAIN,
-    MANUAL_NEXT_BACK,
-    UNNECESSARY_LITERAL_UNWRAP,
-]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `s.chars().rev().collect::<String>()`.
+    ///
+    /// ### Why is this bad?
+    /// `chars()` yields Unicode scalar values, not user-perceived characters. Reversing them
+    /// moves combining marks onto the wrong base character and tears apart emoji sequences,
+    /// e.g. `"e\u{301}"` ("é") turns into an accent followed by an `e`.
+    ///
+    /// There is no fix in the standard library. The `graphemes` iterator of the
+    /// `unicode-segmentation` crate reverses grapheme clusters correctly.
+    ///
+    /// ### Configuration
+    /// Setting `chars-rev-collect-ignore-ascii` to `true` silences the lint for strings that
+    /// are known at compile time to be ASCII-only.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let s = "hello";
+    /// let reversed: String = s.chars().rev().collect();
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// use unicode_segmentation::UnicodeSegmentation;
+    /// let reversed: String = s.graphemes(true).rev().collect();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub CHARS_REV_COLLECT_STRING,
+    pedantic,
+    "reversing a string with `chars().rev()`, which breaks up grapheme clusters"
+}
 
-/// Extracts a method call name, args, and `Span` of the method name.
-fn method_call<'tcx>(
-    recv: &'tcx hir::Expr<'tcx>,
-) -> Option<(&'tcx str, &'tcx hir::Expr<'tcx>, &'tcx [hir::Expr<'tcx>], Span, Span)> {
-    if let ExprKind::MethodCall(path, receiver, args, call_span) = recv.kind {
-        if !args.iter().any(|e| e.span.from_expansion()) && !receiver.span.from_expansion() {
-            let name = path.ident.name.as_str();
-            return Some((name, receiver, args, path.ident.span, call_span));
-        }
-    }
-    None
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `drain(..).collect()` and `drain(n..).collect()` on a `Vec`, `VecDeque` or
+    /// `String` when the result is collected into the same type.
+    ///
+    /// ### Why is this bad?
+    /// `std::mem::take` swaps the whole buffer out without moving every element, and
+    /// `split_off` moves the tail in a single copy. Both are clearer about the intent.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let mut v = vec![1, 2, 3];
+    /// let tail: Vec<i32> = v.drain(1..).collect();
+    /// let all: Vec<i32> = v.drain(..).collect();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut v = vec![1, 2, 3];
+    /// let tail = v.split_off(1);
+    /// let all = std::mem::take(&mut v);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub DRAIN_COLLECT,
+    perf,
+    "calling `drain(..).collect()` instead of `mem::take` or `split_off`"
 }
 
-impl<'tcx> LateLintPass<'tcx> for Methods {
-    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
-        if expr.span.from_expansion() {
-            return;
-        }
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `collection.clone().into_iter()` on standard library collections and arrays.
+    ///
+    /// ### Why is this bad?
+    /// The whole collection is copied into a new allocation just to be consumed.
+    /// `iter().cloned()` clones the items one by one without the intermediate collection, and
+    /// when the items are only used by reference, `iter()` avoids cloning altogether.
+    ///
+    /// ### Known problems
+    /// The suggestion borrows the original collection for as long as the iterator lives, so the
+    /// lint stays silent when the collection is a local that is used again later.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let names = vec![String::from("a")];
+    /// # fn consume(_: String) {}
+    /// for name in names.clone().into_iter() {
+    ///     println!("{}", name.len());
+    /// }
+    /// names.clone().into_iter().for_each(consume);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let names = vec![String::from("a")];
+    /// # fn consume(_: String) {}
+    /// for name in names.iter() {
+    ///     println!("{}", name.len());
+    /// }
+    /// names.iter().cloned().for_each(consume);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub CLONE_INTO_ITER,
+    perf,
+    "cloning a collection only to call `into_iter` on it"
+}
 
-        self.check_methods(cx, expr);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `max_by` and `min_by` on iterators of floats with a comparison closure
+    /// like `|a, b| a.partial_cmp(b).unwrap()`.
+    ///
+    /// ### Why is this bad?
+    /// `partial_cmp` returns `None` as soon as one of the values is NaN, so the `unwrap`
+    /// panics. `total_cmp` always returns an ordering and sorts NaN after all other values.
+    /// If NaN should be ignored instead, `fold` with `f64::max` or `f64::min` does that.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let values = [1.0_f64, 3.0, 2.0];
+    /// let max = values.iter().max_by(|a, b| a.partial_cmp(b).unwrap());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let values = [1.0_f64, 3.0, 2.0];
+    /// let max = values.iter().max_by(|a, b| a.total_cmp(b));
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub MIN_MAX_BY_PARTIAL_CMP_UNWRAP,
+    suspicious,
+    "using `max_by` or `min_by` with `partial_cmp(..).unwrap()` on floats"
+}
 
-        match expr.kind {
-            hir::ExprKind::Call(func, args) => {
-                from_iter_instead_of_collect::check(cx, expr, args, func);
-            },
-            hir::ExprKind::MethodCall(method_call, receiver, args, _) => {
-                let method_span = method_call.ident.span;
-                or_fun_call::check(cx, expr, method_span, method_call.ident.as_str(), receiver, args);
-                expect_fun_call::check(cx, expr, method_span, method_call.ident.as_str(), receiver, args);
-                clone_on_copy::check(cx, expr, method_call.ident.name, receiver, args);
-                clone_on_ref_ptr::check(cx, expr, method_call.ident.name, receiver, args);
-                inefficient_to_string::check(cx, expr, method_call.ident.name, receiver, args);
-                single_char_add_str::check(cx, expr, receiver, args);
-                into_iter_on_ref::check(cx, expr, method_span, method_call.ident.name, receiver);
-                single_char_pattern::check(cx, expr, method_call.ident.name, receiver, args);
-                unnecessary_to_owned::check(cx, expr, method_call.ident.name, receiver, args, &self.msrv);
-            },
-            hir::ExprKind::Binary(op, lhs, rhs) if op.node == hir::BinOpKind::Eq || op.node == hir::BinOpKind::Ne => {
-                let mut info = BinaryExprInfo {
-                    expr,
-                    chain: lhs,
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for chains of three or more `str::replace` calls.
+    ///
+    /// ### Why is this bad?
+    /// Every call allocates a new `String` and goes over the whole text again. For long
+    /// strings or many replacements, doing all of them in a single pass is faster.
+    ///
+    /// ### Known problems
+    /// A single pass is only equivalent if no replacement creates a match for a later
+    /// pattern.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let text = "";
+    /// let escaped = text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let text = "";
+    /// let mut escaped = String::with_capacity(text.len());
+    /// for c in text.chars() {
+    ///     match c {
+    ///         '&' => escaped.push_str("&amp;"),
+    ///         '<' => escaped.push_str("&lt;"),
+    ///         '>' => escaped.push_str("&gt;"),
+    ///         c => escaped.push(c),
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub CHAINED_REPLACE,
+    pedantic,
+    "three or more chained calls to `str::replace`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `opt.filter(|&x| predicate).map(|x| value)` on an `Option` whose result is
+    /// unwrapped or matched on right away.
+    ///
+    /// ### Why is this bad?
+    /// The value goes through two closures that each see only half of the logic.
+    /// `and_then` with `bool::then` or `bool::then_some` tests and maps it in one place.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let opt = Some(2);
+    /// let doubled = opt.filter(|&x| x > 1).map(|x| x * 2).unwrap_or(0);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let opt = Some(2);
+    /// let doubled = opt.and_then(|x| (x > 1).then(|| x * 2)).unwrap_or(0);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub OPTION_FILTER_MAP_BOOL,
+    pedantic,
+    "using `Option::filter` followed by `map` where `and_then` with `bool::then` would do"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for sorting a vector or array that is only used afterwards to take its first or
+    /// last element, and for `sorted().next()` or `sorted().last()` from `itertools`.
+    ///
+    /// ### Why is this bad?
+    /// Sorting takes O(n log n) time, while `min` and `max` find the same element in O(n).
+    ///
+    /// ### Example
+    /// ```rust
+    /// let mut v = vec![3, 1, 2];
+    /// v.sort();
+    /// let smallest = v.first();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let v = vec![3, 1, 2];
+    /// let smallest = v.iter().min();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub REDUNDANT_SORT_BEFORE_EXTREME,
+    perf,
+    "sorting a collection only to take its minimum or maximum"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calling `.step_by(n)` on iterators where `n` is computed in a way that can
+    /// produce zero at runtime: the difference of two lengths, a remainder, or a parsed value
+    /// that is unwrapped. Locals are followed back to their initializer.
+    ///
+    /// ### Why is this bad?
+    /// `step_by` panics if the step is zero. Unlike `step_by(0)`, this only shows up for some
+    /// inputs, e.g. when two lengths happen to be equal.
+    ///
+    /// ### Known problems
+    /// A comparison involving the local anywhere in the function is assumed to check for zero,
+    /// even if it doesn't.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let (a, b) = (vec![1, 2, 3], vec![1]);
+    /// let step = a.len() - b.len();
+    /// for i in (0..10).step_by(step) {
+    ///     // ..
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let (a, b) = (vec![1, 2, 3], vec![1]);
+    /// let step = a.len() - b.len();
+    /// assert!(step != 0, "the step must not be zero");
+    /// for i in (0..10).step_by(step) {
+    ///     // ..
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub ITERATOR_STEP_BY_ZERO_RUNTIME,
+    suspicious,
+    "using `Iterator::step_by` with a step that may be zero at runtime"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for iterators of `String`s or `&str`s that are collected into a `Vec` only to be
+    /// joined with `join("")` or `concat()`, including ones that first convert `char`s or `&str`s
+    /// with `map(|c| c.to_string())`.
+    ///
+    /// ### Why is this bad?
+    /// `String` can be collected from these items directly, without allocating the intermediate
+    /// `Vec` and, for `char`s and `&str`s, without allocating a `String` for every item.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let chars = ['a', 'b'];
+    /// let s = chars.iter().map(|c| c.to_string()).collect::<Vec<_>>().concat();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let chars = ['a', 'b'];
+    /// let s = chars.iter().collect::<String>();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub COLLECT_TO_STRING_VIA_VEC,
+    pedantic,
+    "collecting into a `Vec` only to join the items into a `String`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `opt.as_ref().cloned()`, `opt.as_ref().map(Clone::clone)` and
+    /// `opt.as_deref().map(ToOwned::to_owned)`, as well as their closure forms, where the result
+    /// has the same type as `opt`.
+    ///
+    /// ### Why is this bad?
+    /// `Option` implements `Clone`, so the whole chain is just `opt.clone()`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let opt = Some(String::from("a"));
+    /// let copy = opt.as_ref().map(Clone::clone);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let opt = Some(String::from("a"));
+    /// let copy = opt.clone();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub OPTION_AS_REF_MAP_CLONE_CHAIN,
+    style,
+    "using `as_ref().map(Clone::clone)` or `as_ref().cloned()` where `clone()` would do"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `read`, `read_exact`, `write` and similar calls on a `File` inside of a loop,
+    /// where the file is opened outside of the loop and isn't wrapped in a `BufReader` or
+    /// `BufWriter`.
+    ///
+    /// ### Why is this bad?
+    /// Every call on an unbuffered `File` is a separate system call. Reading or writing in small
+    /// pieces this way is much slower than going through a buffer.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// use std::fs::File;
+    /// use std::io::Write;
+    ///
+    /// let mut file = File::create("out.txt").unwrap();
+    /// for i in 0..100 {
+    ///     file.write_all(&[i]).unwrap();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// use std::fs::File;
+    /// use std::io::{BufWriter, Write};
+    ///
+    /// let mut file = BufWriter::new(File::create("out.txt").unwrap());
+    /// for i in 0..100 {
+    ///     file.write_all(&[i]).unwrap();
+    /// }
+    /// file.flush().unwrap();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub UNBUFFERED_FILE_IO_IN_LOOP,
+    pedantic,
+    "reading from or writing to an unbuffered `File` in a loop"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `read_line` in a loop, where the buffer is declared outside of the loop
+    /// and never cleared in it.
+    ///
+    /// ### Why is this bad?
+    /// `read_line` appends the line to the buffer instead of overwriting it. Without clearing the
+    /// buffer, it contains all lines read so far, which is rarely intended.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// let mut buf = String::new();
+    /// loop {
+    ///     std::io::stdin().read_line(&mut buf).unwrap();
+    ///     if buf.trim() == "quit" {
+    ///         break;
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// let mut buf = String::new();
+    /// loop {
+    ///     buf.clear();
+    ///     std::io::stdin().read_line(&mut buf).unwrap();
+    ///     if buf.trim() == "quit" {
+    ///         break;
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub READ_LINE_WITHOUT_CLEAR,
+    suspicious,
+    "calling `read_line` in a loop without clearing the buffer"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `Path::join` with a constant path starting with a root, like `"/bar"`,
+    /// or a drive letter, like `"C:\\bar"`.
+    ///
+    /// ### Why is this bad?
+    /// Joining an absolute path replaces the base path instead of extending it, so the base path
+    /// is silently discarded.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let path = Path::new("/foo").join("/bar");
+    /// assert_eq!(path, PathBuf::from("/bar"));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let path = Path::new("/foo").join("bar");
+    /// assert_eq!(path, PathBuf::from("/foo/bar"));
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub PATH_JOIN_WITH_ABSOLUTE_LITERAL,
+    suspicious,
+    "calling `Path::join` with an absolute path, which discards the base path"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `c.to_lowercase().next().unwrap()` and `c.to_uppercase().next().unwrap()` on a
+    /// `char`.
+    ///
+    /// ### Why is this bad?
+    /// Some characters map to more than one `char`, e.g. `'ß'` is uppercased to `"SS"`. Taking
+    /// only the first one silently drops the rest. For ASCII characters, `to_ascii_lowercase` and
+    /// `to_ascii_uppercase` return a single `char` directly.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let c = 'a';
+    /// if c.is_ascii_alphabetic() {
+    ///     let upper = c.to_uppercase().next().unwrap();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let c = 'a';
+    /// if c.is_ascii_alphabetic() {
+    ///     let upper = c.to_ascii_uppercase();
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub CHAR_TO_LOWERCASE_NEXT_UNWRAP,
+    suspicious,
+    "taking only the first `char` of `to_lowercase()` or `to_uppercase()`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `retain` with a closure that returns the same constant for every element, and
+    /// for `filter` on an iterator with a closure that always returns `true`.
+    ///
+    /// ### Why is this bad?
+    /// `retain(|_| true)` and `filter(|_| true)` don't do anything, and `retain(|_| false)` is a
+    /// roundabout way to write `clear()`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let mut v = vec![1, 2, 3];
+    /// v.retain(|_| false);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut v = vec![1, 2, 3];
+    /// v.clear();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub RETAIN_WITH_CONSTANT_PREDICATE,
+    complexity,
+    "calling `retain` or `filter` with a closure that always returns the same value"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `vec.truncate(0)`, which can be written as `vec.clear()`, and for
+    /// `Vec::with_capacity(0)`, which can be written as `Vec::new()`.
+    ///
+    /// ### Why is this bad?
+    /// `clear()` and `Vec::new()` state the intent directly.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let mut v: Vec<i32> = Vec::with_capacity(0);
+    /// v.push(1);
+    /// v.truncate(0);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut v: Vec<i32> = Vec::new();
+    /// v.push(1);
+    /// v.clear();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub VEC_RESIZE_ZERO_THEN_EXTEND,
+    style,
+    "emptying a `Vec` with `truncate`, or creating one with `with_capacity(0)`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `s.split(' ')` whose empty items are filtered out, or whose items are counted.
+    ///
+    /// ### Why is this bad?
+    /// Consecutive spaces produce empty items, which is rarely what's wanted when splitting words.
+    /// `split_whitespace()` skips them, and `split_ascii_whitespace()` does the same for
+    /// `split(|c: char| c.is_ascii_whitespace())`.
+    ///
+    /// ### Known problems
+    /// `split_whitespace()` also splits at tabs, newlines and other whitespace, so it isn't
+    /// equivalent to splitting at spaces.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let s = "a  b";
+    /// let words: Vec<_> = s.split(' ').filter(|w| !w.is_empty()).collect();
+    /// let count = s.split(' ').count();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let s = "a  b";
+    /// let words: Vec<_> = s.split_whitespace().collect();
+    /// let count = s.split_whitespace().count();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub SPLIT_WHITESPACE_COLLECT_COUNT,
+    pedantic,
+    "splitting a string at spaces and filtering out or counting the empty items"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for two `Option`s combined in the way that isn't configured as preferred. By
+    /// default, that's `a.and_then(|x| b.map(|y| f(x, y)))`, which can be written as
+    /// `a.zip(b).map(|(x, y)| f(x, y))`. With `option-zip-style = "AndThen"`, it's the other
+    /// way around.
+    ///
+    /// ### Why is this bad?
+    /// Using one form consistently makes the code easier to read. `zip` avoids the nested
+    /// closure, while `and_then` avoids the tuple and only evaluates `b` if `a` is `Some`,
+    /// which is why `zip` is only suggested if `b` is cheap to evaluate.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let (a, b) = (Some(1), Some(2));
+    /// let sum = a.and_then(|x| b.map(|y| x + y));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let (a, b) = (Some(1), Some(2));
+    /// let sum = a.zip(b).map(|(x, y)| x + y);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub OPTION_ZIP_THEN_MAP_TUPLE,
+    pedantic,
+    "combining two `Option`s with `zip` and `map`, or `and_then` and `map`, against the configured style"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `sort`, `sort_by` and `sort_by_key` on slices where the order of elements which
+    /// compare equal can't matter: either equal elements are identical, like integers or types with
+    /// a derived `Ord` made of them, or the sort key is an index from `enumerate`, which is unique.
+    ///
+    /// ### Why is this bad?
+    /// A stable sort keeps equal elements in their original order, which costs extra time and
+    /// allocates a buffer. When there's no way to tell equal elements apart, the unstable variants
+    /// give the same result faster.
+    ///
+    /// ### Configuration
+    /// The check of sorts by indices from `enumerate` can be disabled with
+    /// `sort-unstable-candidate-enumerate = false`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    /// struct Version(u32, u32);
+    ///
+    /// let mut versions = vec![Version(1, 2), Version(1, 0)];
+    /// versions.sort();
+    /// let mut indexed: Vec<_> = ["b", "a"].iter().enumerate().collect();
+    /// indexed.sort_by_key(|&(i, _)| std::cmp::Reverse(i));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[derive(PartialEq, Eq, PartialOrd, Ord)]
+    /// struct Version(u32, u32);
+    ///
+    /// let mut versions = vec![Version(1, 2), Version(1, 0)];
+    /// versions.sort_unstable();
+    /// let mut indexed: Vec<_> = ["b", "a"].iter().enumerate().collect();
+    /// indexed.sort_unstable_by_key(|&(i, _)| std::cmp::Reverse(i));
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub SORT_UNSTABLE_CANDIDATE,
+    pedantic,
+    "using a stable sort where equal elements can't be told apart"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `res.ok().unwrap()` and `res.err().unwrap()` on a `Result`.
+    ///
+    /// ### Why is this bad?
+    /// Converting to an `Option` first throws away the other half of the `Result`, so the panic
+    /// only says that the `Option` was `None`. `unwrap()` and `unwrap_err()` on the `Result`
+    /// itself print the error or the value, which helps figure out what went wrong.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let res: Result<u8, _> = "300".parse();
+    /// let n = res.ok().unwrap();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let res: Result<u8, _> = "300".parse();
+    /// let n = res.unwrap();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub RESULT_OK_THEN_UNWRAP_CHAIN,
+    style,
+    "unwrapping a `Result` after converting it to an `Option` with `ok()` or `err()`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `iter.skip(n).nth(m)` and `iter.take(1).collect::<Vec<_>>()`.
+    ///
+    /// `iter.skip(n).next()` is linted by [`iter_skip_next`](#iter_skip_next).
+    ///
+    /// ### Why is this bad?
+    /// `skip(n).nth(m)` is the same as `nth(n + m)`, with one adapter less. Collecting at most
+    /// one element allocates a `Vec` for what's really an `Option`, which `next()` returns.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let v = vec![1, 2, 3, 4];
+    /// let third = v.iter().skip(1).nth(1);
+    /// let first: Vec<_> = v.iter().take(1).collect();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let v = vec![1, 2, 3, 4];
+    /// let third = v.iter().nth(2);
+    /// let first = v.iter().next();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub ITER_SKIP_THEN_NTH,
+    style,
+    "using `skip(..).nth(..)` or `take(1).collect()` on an iterator"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `String::from_utf8_lossy(..).to_string()`.
+    ///
+    /// ### Why is this bad?
+    /// `from_utf8_lossy` returns a `Cow<str>`, which already owns a new `String` if the bytes
+    /// weren't valid UTF-8. `to_string` copies that `String` once more, while `into_owned` returns
+    /// it as is. If the bytes are an owned `Vec<u8>` which isn't needed afterwards,
+    /// `String::from_utf8` reuses its allocation for valid UTF-8 as well.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let bytes = vec![b'a', 0xff];
+    /// let s = String::from_utf8_lossy(&bytes).to_string();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let bytes = vec![b'a', 0xff];
+    /// let s = String::from_utf8_lossy(&bytes).into_owned();
+    /// // or, to reuse the `Vec` if it's valid UTF-8
+    /// # let bytes = vec![b'a', 0xff];
+    /// let s = String::from_utf8(bytes).unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned());
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub STRING_FROM_UTF8_LOSSY_INTO_OWNED_ROUNDTRIP,
+    perf,
+    "using `to_string` on the `Cow` returned by `String::from_utf8_lossy`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `map_err` with a closure which formats the error into a `String`, followed by
+    /// `?`, like `res.map_err(|e| format!("failed to read config: {e}"))?`.
+    ///
+    /// ### Why is this bad?
+    /// The `String` loses the source error and its type, so callers can neither match on the
+    /// failure nor walk the chain of sources. Libraries should return structured errors, which
+    /// also only format the message if it's displayed.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn parse(s: &str) -> Result<u32, String> {
+    ///     let n = s.parse::<u32>().map_err(|e| format!("invalid number: {e}"))?;
+    ///     Ok(n)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::num::ParseIntError;
+    /// enum Error {
+    ///     InvalidNumber(ParseIntError),
+    /// }
+    ///
+    /// fn parse(s: &str) -> Result<u32, Error> {
+    ///     let n = s.parse::<u32>().map_err(Error::InvalidNumber)?;
+    ///     Ok(n)
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub EAGER_MAP_ERR_FORMAT,
+    restriction,
+    "using `map_err` to turn an error into a formatted `String` before propagating it"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `blocking_lock()`, `blocking_read()` and `blocking_write()` calls on the
+    /// `Mutex` and `RwLock` of `tokio::sync` outside of async functions and blocks.
+    ///
+    /// Holding a `std::sync` lock across an `await` is linted by
+    /// [`await_holding_lock`](#await_holding_lock).
+    ///
+    /// ### Why is this bad?
+    /// The async locks of tokio are only needed if a guard is held across an `await`. A lock
+    /// which is only used in synchronous code is simpler and faster as a `std::sync` lock.
+    ///
+    /// ### Known problems
+    /// The same lock may be used in async code elsewhere, which can need the async lock.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// fn increment(counter: &tokio::sync::Mutex<u32>) {
+    ///     *counter.blocking_lock() += 1;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn increment(counter: &std::sync::Mutex<u32>) {
+    ///     *counter.lock().unwrap() += 1;
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub SYNC_MUTEX_IN_ASYNC_CONTEXT,
+    pedantic,
+    "using the blocking methods of an async lock in synchronous code"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `thread::spawn(|| ..).join().unwrap()`, as well as the same with `expect`.
+    ///
+    /// ### Why is this bad?
+    /// The current thread waits for the spawned one right away, so nothing runs concurrently.
+    /// Running the code of the closure directly is simpler and saves creating a thread.
+    ///
+    /// ### Known problems
+    /// The code runs on a thread with a different stack size and other thread-locals, and a
+    /// panic isn't reported by the spawned thread before being propagated by `unwrap`.
+    ///
+    /// `move` closures which capture variables aren't linted.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # fn compute() -> u32 { 1 }
+    /// let result = std::thread::spawn(|| compute()).join().unwrap();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn compute() -> u32 { 1 }
+    /// let result = compute();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub THREAD_SPAWN_JOIN_IMMEDIATELY,
+    complexity,
+    "spawning a thread and joining it right away"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `Condvar::wait` and `Condvar::wait_timeout` which aren't inside of a
+    /// `while` or `loop` loop.
+    ///
+    /// ### Why is this bad?
+    /// A condition variable may wake up spuriously, without being notified. After waking up, the
+    /// condition has to be checked again, which is what `wait_while` and `wait_timeout_while` do.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::sync::{Condvar, Mutex};
+    /// # let (lock, cvar) = (Mutex::new(true), Condvar::new());
+    /// let mut ready = lock.lock().unwrap();
+    /// if !*ready {
+    ///     ready = cvar.wait(ready).unwrap();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::{Condvar, Mutex};
+    /// # let (lock, cvar) = (Mutex::new(true), Condvar::new());
+    /// let ready = cvar.wait_while(lock.lock().unwrap(), |ready| !*ready).unwrap();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub CONDVAR_WAIT_WITHOUT_LOOP,
+    suspicious,
+    "waiting on a `Condvar` without re-checking the condition in a loop"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `.lock().unwrap()` on a `std::sync::Mutex`. Depending on the
+    /// `lock-unwrap-policy` configuration, suggests `.lock().expect("poisoned")` instead
+    /// (`Expect`, the default), or suggests `parking_lot::Mutex` for both `.lock().unwrap()` and
+    /// `.lock().expect(..)` (`ParkingLot`).
+    ///
+    /// ### Why is this bad?
+    /// Locking a `Mutex` fails if another thread panicked while holding the lock. Whether the
+    /// resulting panic should explain itself, or whether the `Mutex` shouldn't be poisoned at
+    /// all, is a decision which is best made once for the whole code base.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let mutex = std::sync::Mutex::new(0);
+    /// let guard = mutex.lock().unwrap();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let mutex = std::sync::Mutex::new(0);
+    /// let guard = mutex.lock().expect("poisoned");
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub LOCK_UNWRAP_POISON_NOTE,
+    restriction,
+    "handling a poisoned `Mutex` in a way the configured policy doesn't allow"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for pointers computed with `wrapping_add`, `wrapping_sub` or `wrapping_offset`
+    /// which are dereferenced in the same function, without being compared with another pointer
+    /// first, like the end of the buffer.
+    ///
+    /// ### Why is this bad?
+    /// The `wrapping_*` methods are meant for pointers which may leave the bounds of their
+    /// allocation. Dereferencing such a pointer without checking that it's back in bounds is
+    /// undefined behavior. If the pointer is known to stay in bounds, `add`, `sub` and `offset`
+    /// state that, and let the compiler optimize with it.
+    ///
+    /// ### Example
+    /// ```rust
+    /// unsafe fn second(ptr: *const u8) -> u8 {
+    ///     let second = ptr.wrapping_add(1);
+    ///     *second
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// unsafe fn second(ptr: *const u8) -> u8 {
+    ///     // SAFETY: the caller guarantees that `ptr` points to at least two bytes
+    ///     let second = ptr.add(1);
+    ///     *second
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub POINTER_ARITHMETIC_WRAPPING_OFFSET_MISUSE,
+    suspicious,
+    "dereferencing the result of `wrapping_add` or `wrapping_offset` without a bounds check"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `to_vec()`, `to_string()`, `to_ascii_lowercase()` and `format!(..)` in the
+    /// closures of iterator adapters like `filter` and `map`, when the allocated value is only
+    /// compared, hashed or looked up in a map or set.
+    ///
+    /// ### Why is this bad?
+    /// The closure runs for every element, so every element allocates. Slices and `str`s can
+    /// be compared, hashed and looked up directly, and `eq_ignore_ascii_case` compares without
+    /// changing the case first.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let names = ["Alice", "bob"];
+    /// let bobs = names.iter().filter(|name| name.to_ascii_lowercase() == "bob").count();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let names = ["Alice", "bob"];
+    /// let bobs = names.iter().filter(|name| name.eq_ignore_ascii_case("bob")).count();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub ALLOCATION_INSIDE_HOT_ITERATOR_ADAPTER,
+    pedantic,
+    "allocating in an iterator adapter only to compare or hash the result"
+}
+
+#[allow(clippy::struct_excessive_bools)]
+pub struct Methods {
+    avoid_breaking_exported_api: bool,
+    msrv: Msrv,
+    allow_expect_in_tests: bool,
+    allow_unwrap_in_tests: bool,
+    chars_rev_collect_ignore_ascii: bool,
+    chained_replace_threshold: u64,
+    option_zip_style: OptionZipStyle,
+    sort_unstable_candidate_enumerate: bool,
+    lock_unwrap_policy: LockUnwrapPolicy,
+}
+
+impl Methods {
+    #[must_use]
+    #[allow(clippy::fn_params_excessive_bools, clippy::too_many_arguments)]
+    pub fn new(
+        avoid_breaking_exported_api: bool,
+        msrv: Msrv,
+        allow_expect_in_tests: bool,
+        allow_unwrap_in_tests: bool,
+        chars_rev_collect_ignore_ascii: bool,
+        chained_replace_threshold: u64,
+        option_zip_style: OptionZipStyle,
+        sort_unstable_candidate_enumerate: bool,
+        lock_unwrap_policy: LockUnwrapPolicy,
+    ) -> Self {
+        Self {
+            avoid_breaking_exported_api,
+            msrv,
+            allow_expect_in_tests,
+            allow_unwrap_in_tests,
+            chars_rev_collect_ignore_ascii,
+            chained_replace_threshold,
+            option_zip_style,
+            sort_unstable_candidate_enumerate,
+            lock_unwrap_policy,
+        }
+    }
+}
+
+impl_lint_pass!(Methods => [
+    UNWRAP_USED,
+    EXPECT_USED,
+    SHOULD_IMPLEMENT_TRAIT,
+    WRONG_SELF_CONVENTION,
+    OK_EXPECT,
+    UNWRAP_OR_ELSE_DEFAULT,
+    MAP_UNWRAP_OR,
+    RESULT_MAP_OR_INTO_OPTION,
+    OPTION_MAP_OR_NONE,
+    BIND_INSTEAD_OF_MAP,
+    OR_FUN_CALL,
+    OR_THEN_UNWRAP,
+    EXPECT_FUN_CALL,
+    CHARS_NEXT_CMP,
+    CHARS_LAST_CMP,
+    CLONE_ON_COPY,
+    CLONE_ON_REF_PTR,
+    COLLAPSIBLE_STR_REPLACE,
+    ITER_OVEREAGER_CLONED,
+    CLONED_INSTEAD_OF_COPIED,
+    FLAT_MAP_OPTION,
+    INEFFICIENT_TO_STRING,
+    NEW_RET_NO_SELF,
+    SINGLE_CHAR_PATTERN,
+    SINGLE_CHAR_ADD_STR,
+    SEARCH_IS_SOME,
+    FILTER_NEXT,
+    SKIP_WHILE_NEXT,
+    FILTER_MAP_IDENTITY,
+    MAP_IDENTITY,
+    MANUAL_FILTER_MAP,
+    MANUAL_FIND_MAP,
+    OPTION_FILTER_MAP,
+    FILTER_MAP_NEXT,
+    FLAT_MAP_IDENTITY,
+    MAP_FLATTEN,
+    ITERATOR_STEP_BY_ZERO,
+    ITER_NEXT_SLICE,
+    ITER_COUNT,
+    ITER_NTH,
+    ITER_NTH_ZERO,
+    BYTES_NTH,
+    ITER_SKIP_NEXT,
+    GET_UNWRAP,
+    GET_LAST_WITH_LEN,
+    STRING_EXTEND_CHARS,
+    ITER_CLONED_COLLECT,
+    ITER_WITH_DRAIN,
+    USELESS_ASREF,
+    UNNECESSARY_FOLD,
+    UNNECESSARY_FILTER_MAP,
+    UNNECESSARY_FIND_MAP,
+    INTO_ITER_ON_REF,
+    SUSPICIOUS_MAP,
+    UNINIT_ASSUMED_INIT,
+    MANUAL_SATURATING_ARITHMETIC,
+    ZST_OFFSET,
+    FILETYPE_IS_FILE,
+    OPTION_AS_REF_DEREF,
+    UNNECESSARY_LAZY_EVALUATIONS,
+    MAP_COLLECT_RESULT_UNIT,
+    FROM_ITER_INSTEAD_OF_COLLECT,
+    INSPECT_FOR_EACH,
+    IMPLICIT_CLONE,
+    SUSPICIOUS_TO_OWNED,
+    SUSPICIOUS_SPLITN,
+    MANUAL_STR_REPEAT,
+    EXTEND_WITH_DRAIN,
+    MANUAL_SPLIT_ONCE,
+    NEEDLESS_SPLITN,
+    UNNECESSARY_TO_OWNED,
+    UNNECESSARY_JOIN,
+    ERR_EXPECT,
+    NEEDLESS_OPTION_AS_DEREF,
+    IS_DIGIT_ASCII_RADIX,
+    NEEDLESS_OPTION_TAKE,
+    NO_EFFECT_REPLACE,
+    OBFUSCATED_IF_ELSE,
+    ITER_ON_SINGLE_ITEMS,
+    ITER_ON_EMPTY_COLLECTIONS,
+    NAIVE_BYTECOUNT,
+    BYTES_COUNT_TO_LEN,
+    CASE_SENSITIVE_FILE_EXTENSION_COMPARISONS,
+    GET_FIRST,
+    MANUAL_OK_OR,
+    MAP_CLONE,
+    MAP_ERR_IGNORE,
+    MUT_MUTEX_LOCK,
+    NONSENSICAL_OPEN_OPTIONS,
+    PATH_BUF_PUSH_OVERWRITE,
+    RANGE_ZIP_WITH_LEN,
+    REPEAT_ONCE,
+    STABLE_SORT_PRIMITIVE,
+    UNIT_HASH,
+    UNNECESSARY_SORT_BY,
+    VEC_RESIZE_TO_ZERO,
+    VERBOSE_FILE_READS,
+    ITER_KV_MAP,
+    SEEK_FROM_CURRENT,
+    SEEK_TO_START_INSTEAD_OF_REWIND,
+    NEEDLESS_COLLECT,
+    SUSPICIOUS_COMMAND_ARG_SPACE,
+    CLEAR_WITH_DRAIN,
+    MANUAL_NEXT_BACK,
+    UNNECESSARY_LITERAL_UNWRAP,
+    ENTRY_OR_INSERT_DEFAULT,
+    COLLECT_THEN_LEN,
+    CHARS_REV_COLLECT_STRING,
+    DRAIN_COLLECT,
+    CLONE_INTO_ITER,
+    MIN_MAX_BY_PARTIAL_CMP_UNWRAP,
+    CHAINED_REPLACE,
+    OPTION_FILTER_MAP_BOOL,
+    REDUNDANT_SORT_BEFORE_EXTREME,
+    ITERATOR_STEP_BY_ZERO_RUNTIME,
+    COLLECT_TO_STRING_VIA_VEC,
+    OPTION_AS_REF_MAP_CLONE_CHAIN,
+    UNBUFFERED_FILE_IO_IN_LOOP,
+    READ_LINE_WITHOUT_CLEAR,
+    PATH_JOIN_WITH_ABSOLUTE_LITERAL,
+    CHAR_TO_LOWERCASE_NEXT_UNWRAP,
+    RETAIN_WITH_CONSTANT_PREDICATE,
+    VEC_RESIZE_ZERO_THEN_EXTEND,
+    SPLIT_WHITESPACE_COLLECT_COUNT,
+    OPTION_ZIP_THEN_MAP_TUPLE,
+    SORT_UNSTABLE_CANDIDATE,
+    RESULT_OK_THEN_UNWRAP_CHAIN,
+    ITER_SKIP_THEN_NTH,
+    STRING_FROM_UTF8_LOSSY_INTO_OWNED_ROUNDTRIP,
+    EAGER_MAP_ERR_FORMAT,
+    SYNC_MUTEX_IN_ASYNC_CONTEXT,
+    THREAD_SPAWN_JOIN_IMMEDIATELY,
+    CONDVAR_WAIT_WITHOUT_LOOP,
+    LOCK_UNWRAP_POISON_NOTE,
+    POINTER_ARITHMETIC_WRAPPING_OFFSET_MISUSE,
+    ALLOCATION_INSIDE_HOT_ITERATOR_ADAPTER,
+]);
+
+/// Extracts a method call name, args, and `Span` of the method name.
+fn method_call<'tcx>(
+    recv: &'tcx hir::Expr<'tcx>,
+) -> Option<(&'tcx str, &'tcx hir::Expr<'tcx>, &'tcx [hir::Expr<'tcx>], Span, Span)> {
+    if let ExprKind::MethodCall(path, receiver, args, call_span) = recv.kind {
+        if !args.iter().any(|e| e.span.from_expansion()) && !receiver.span.from_expansion() {
+            let name = path.ident.name.as_str();
+            return Some((name, receiver, args, path.ident.span, call_span));
+        }
+    }
+    None
+}
+
+impl<'tcx> LateLintPass<'tcx> for Methods {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+
+        self.check_methods(cx, expr);
+
+        match expr.kind {
+            hir::ExprKind::Call(func, args) => {
+                from_iter_instead_of_collect::check(cx, expr, args, func);
+                vec_resize_zero_then_extend::check_with_capacity(cx, expr, func, args);
+                uninit_assumed_init::check_transmute(cx, expr, func, args);
+            },
+            hir::ExprKind::MethodCall(method_call, receiver, args, _) => {
+                let method_span = method_call.ident.span;
+                or_fun_call::check(cx, expr, method_span, method_call.ident.as_str(), receiver, args);
+                if let [arg] = args {
+                    entry_or_insert_default::check(cx, expr, method_call.ident.as_str(), method_span, receiver, arg);
+                }
+                expect_fun_call::check(
+                    cx,
+                    expr,
+                    method_span,
+                    method_call.ident.as_str(),
+                    receiver,
+                    args,
+                    &self.msrv,
+                );
+                clone_on_copy::check(cx, expr, method_call.ident.name, receiver, args);
+                clone_on_ref_ptr::check(cx, expr, method_call.ident.name, receiver, args);
+                inefficient_to_string::check(cx, expr, method_call.ident.name, receiver, args);
+                single_char_add_str::check(cx, expr, receiver, args);
+                into_iter_on_ref::check(cx, expr, method_span, method_call.ident.name, receiver);
+                single_char_pattern::check(cx, expr, method_call.ident.name, receiver, args);
+                unnecessary_to_owned::check(cx, expr, method_call.ident.name, receiver, args, &self.msrv);
+            },
+            hir::ExprKind::Binary(op, lhs, rhs) if op.node == hir::BinOpKind::Eq || op.node == hir::BinOpKind::Ne => {
+                let mut info = BinaryExprInfo {
+                    expr,
+                    chain: lhs,
                     other: rhs,
                     eq: op.node == hir::BinOpKind::Eq,
                 };
@@ -3572,8 +4631,13 @@ impl Methods {
     fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if let Some((name, recv, args, span, call_span)) = method_call(expr) {
             match (name, args) {
-                ("add" | "offset" | "sub" | "wrapping_offset" | "wrapping_add" | "wrapping_sub", [_arg]) => {
+                ("add" | "offset" | "sub", [_arg]) => zst_offset::check(cx, expr, recv),
+                ("wrapping_offset" | "wrapping_add" | "wrapping_sub", [_arg]) => {
                     zst_offset::check(cx, expr, recv);
+                    pointer_arithmetic_wrapping_offset_misuse::check(cx, expr, recv, name);
+                },
+                ("all" | "any" | "find" | "position" | "skip_while" | "take_while", [arg]) => {
+                    allocation_inside_hot_iterator_adapter::check(cx, expr, name, arg);
                 },
                 ("and_then", [arg]) => {
                     let biom_option_linted = bind_instead_of_map::OptionAndThenSome::check(cx, expr, recv, arg);
@@ -3581,6 +4645,9 @@ impl Methods {
                     if !biom_option_linted && !biom_result_linted {
                         unnecessary_lazy_eval::check(cx, expr, recv, arg, "and");
                     }
+                    if self.option_zip_style == OptionZipStyle::Zip {
+                        option_zip_then_map_tuple::check_and_then(cx, expr, recv, arg, &self.msrv);
+                    }
                 },
                 ("arg", [arg]) => {
                     suspicious_command_arg_space::check(cx, recv, arg, span);
@@ -3591,24 +4658,38 @@ impl Methods {
                 ("as_mut", []) => useless_asref::check(cx, expr, "as_mut", recv),
                 ("as_ref", []) => useless_asref::check(cx, expr, "as_ref", recv),
                 ("assume_init", []) => uninit_assumed_init::check(cx, expr, recv),
-                ("cloned", []) => cloned_instead_of_copied::check(cx, expr, recv, span, &self.msrv),
+                ("blocking_lock" | "blocking_read" | "blocking_write", []) => {
+                    sync_mutex_in_async_context::check(cx, expr, name);
+                },
+                ("cloned", []) => {
+                    cloned_instead_of_copied::check(cx, expr, recv, span, &self.msrv);
+                    option_as_ref_map_clone_chain::check(cx, expr, recv, None);
+                },
                 ("collect", []) if is_trait_method(cx, expr, sym::Iterator) => {
                     needless_collect::check(cx, span, expr, recv, call_span);
                     match method_call(recv) {
                         Some((name @ ("cloned" | "copied"), recv2, [], _, _)) => {
                             iter_cloned_collect::check(cx, name, expr, recv2);
                         },
+                        Some(("drain", drain_recv, [drain_arg], _, _)) => {
+                            drain_collect::check(cx, expr, drain_recv, drain_arg);
+                        },
                         Some(("map", m_recv, [m_arg], _, _)) => {
                             map_collect_result_unit::check(cx, expr, m_recv, m_arg);
                         },
+                        Some(("rev", rev_recv, [], _, _)) => {
+                            chars_rev_collect_string::check(cx, expr, rev_recv, self.chars_rev_collect_ignore_ascii);
+                        },
                         Some(("take", take_self_arg, [take_arg], _, _)) => {
                             if self.msrv.meets(msrvs::STR_REPEAT) {
                                 manual_str_repeat::check(cx, expr, recv, take_self_arg, take_arg);
                             }
+                            iter_skip_then_nth::check_collect(cx, expr, recv, take_arg);
                         },
                         _ => {},
                     }
                 },
+                ("concat", []) => collect_to_string_via_vec::check(cx, expr, name, recv, None),
                 ("count", []) if is_trait_method(cx, expr, sym::Iterator) => match method_call(recv) {
                     Some(("cloned", recv2, [], _, _)) => iter_overeager_cloned::check(cx, expr, recv, recv2, true, false),
                     Some((name2 @ ("into_iter" | "iter" | "iter_mut"), recv2, [], _, _)) => {
@@ -3617,6 +4698,7 @@ impl Methods {
                     Some(("map", _, [arg], _, _)) => suspicious_map::check(cx, expr, recv, arg),
                     Some(("filter", recv2, [arg], _, _)) => bytecount::check(cx, expr, recv2, arg),
                     Some(("bytes", recv2, [], _, _)) => bytes_count_to_len::check(cx, expr, recv, recv2),
+                    Some(("split", ..)) => split_whitespace_collect_count::check(cx, expr, recv, None),
                     _ => {},
                 },
                 ("drain", ..) => {
@@ -3640,7 +4722,12 @@ impl Methods {
                         Some(("err", recv, [], err_span, _)) => err_expect::check(cx, expr, recv, span, err_span, &self.msrv),
                         _ => expect_used::check(cx, expr, recv, false, self.allow_expect_in_tests),
                     }
+                    if let Some(("lock", lock_recv, [], _, _)) = method_call(recv) {
+                        lock_unwrap_poison_note::check(cx, expr, lock_recv, name, span, self.lock_unwrap_policy);
+                    }
                     unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
+                    char_to_lowercase_next_unwrap::check(cx, expr, recv);
+                    thread_spawn_join_immediately::check(cx, expr, recv);
                 },
                 ("expect_err", [_]) => {
                     unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
@@ -3650,11 +4737,18 @@ impl Methods {
                     string_extend_chars::check(cx, expr, recv, arg);
                     extend_with_drain::check(cx, expr, recv, arg);
                 },
+                ("filter", [arg]) => {
+                    allocation_inside_hot_iterator_adapter::check(cx, expr, name, arg);
+                    retain_with_constant_predicate::check_filter(cx, expr, recv, arg);
+                    split_whitespace_collect_count::check(cx, expr, recv, Some(arg));
+                },
                 ("filter_map", [arg]) => {
+                    allocation_inside_hot_iterator_adapter::check(cx, expr, name, arg);
                     unnecessary_filter_map::check(cx, expr, arg, name);
                     filter_map_identity::check(cx, expr, arg, span);
                 },
                 ("find_map", [arg]) => {
+                    allocation_inside_hot_iterator_adapter::check(cx, expr, name, arg);
                     unnecessary_filter_map::check(cx, expr, arg, name);
                 },
                 ("flat_map", [arg]) => {
@@ -3680,17 +4774,29 @@ impl Methods {
                 ("hash", [arg]) => {
                     unit_hash::check(cx, expr, recv, arg);
                 },
+                ("is_empty" | "len", []) => {
+                    if let Some(("collect", iter_recv, [], collect_span, _)) = method_call(recv) {
+                        collect_then_len::check(cx, expr, name, recv, iter_recv, collect_span);
+                    }
+                },
                 ("is_file", []) => filetype_is_file::check(cx, expr, recv),
                 ("is_digit", [radix]) => is_digit_ascii_radix::check(cx, expr, recv, radix, &self.msrv),
                 ("is_none", []) => check_is_some_is_none(cx, expr, recv, false),
                 ("is_some", []) => check_is_some_is_none(cx, expr, recv, true),
                 ("iter" | "iter_mut" | "into_iter", []) => {
                     iter_on_single_or_empty_collections::check(cx, expr, name, recv);
+                    if name == "into_iter"
+                        && let Some(("clone", clone_recv, [], clone_span, _)) = method_call(recv)
+                    {
+                        clone_into_iter::check(cx, expr, recv, clone_recv, clone_span);
+                    }
                 },
                 ("join", [join_arg]) => {
                     if let Some(("collect", _, _, span, _)) = method_call(recv) {
                         unnecessary_join::check(cx, expr, recv, join_arg, span);
+                        collect_to_string_via_vec::check(cx, expr, name, recv, Some(join_arg));
                     }
+                    path_join_with_absolute_literal::check(cx, expr, join_arg);
                 },
                 ("last", []) | ("skip", [_]) => {
                     if let Some((name2, recv2, args2, _span2, _)) = method_call(recv) {
@@ -3698,18 +4804,25 @@ impl Methods {
                             iter_overeager_cloned::check(cx, expr, recv, recv2, false, false);
                         }
                     }
+                    redundant_sort_before_extreme::check_sorted(cx, expr, name, recv);
                 },
                 ("lock", []) => {
                     mut_mutex_lock::check(cx, expr, recv, span);
                 },
                 (name @ ("map" | "map_err"), [m_arg]) => {
                     if name == "map" {
+                        allocation_inside_hot_iterator_adapter::check(cx, expr, name, m_arg);
                         map_clone::check(cx, expr, recv, m_arg, &self.msrv);
+                        option_as_ref_map_clone_chain::check(cx, expr, recv, Some(m_arg));
+                        if self.option_zip_style == OptionZipStyle::AndThen {
+                            option_zip_then_map_tuple::check_zip_map(cx, expr, recv, m_arg);
+                        }
                         if let Some((map_name @ ("iter" | "into_iter"), recv2, _, _, _)) = method_call(recv) {
                             iter_kv_map::check(cx, map_name, expr, recv2, m_arg);
                         }
                     } else {
                         map_err_ignore::check(cx, expr, m_arg);
+                        eager_map_err_format::check(cx, expr, recv, m_arg, span);
                     }
                     if let Some((name, recv2, args, span2,_)) = method_call(recv) {
                         match (name, args) {
@@ -3717,6 +4830,7 @@ impl Methods {
                             ("as_ref", []) => option_as_ref_deref::check(cx, expr, recv2, m_arg, false, &self.msrv),
                             ("filter", [f_arg]) => {
                                 filter_map::check(cx, expr, recv2, f_arg, span2, recv, m_arg, span, false);
+                                option_filter_map_bool::check(cx, expr, recv2, f_arg, span2, m_arg, &self.msrv);
                             },
                             ("find", [f_arg]) => {
                                 filter_map::check(cx, expr, recv2, f_arg, span2, recv, m_arg, span, true);
@@ -3730,6 +4844,9 @@ impl Methods {
                     option_map_or_none::check(cx, expr, recv, def, map);
                     manual_ok_or::check(cx, expr, recv, def, map);
                 },
+                ("max_by" | "min_by", [arg]) => {
+                    min_max_by_partial_cmp_unwrap::check(cx, expr, name, arg, &self.msrv);
+                },
                 ("next", []) => {
                     if let Some((name2, recv2, args2, _, _)) = method_call(recv) {
                         match (name2, args2) {
@@ -3737,6 +4854,9 @@ impl Methods {
                             ("filter", [arg]) => filter_next::check(cx, expr, recv2, arg),
                             ("filter_map", [arg]) => filter_map_next::check(cx, expr, recv2, arg, &self.msrv),
                             ("iter", []) => iter_next_slice::check(cx, expr, recv2),
+                            ("sorted" | "sorted_by" | "sorted_by_key", _) => {
+                                redundant_sort_before_extreme::check_sorted(cx, expr, name, recv);
+                            },
                             ("skip", [arg]) => iter_skip_next::check(cx, expr, recv2, arg),
                             ("skip_while", [_]) => skip_while_next::check(cx, expr),
                             ("rev", [])=> manual_next_back::check(cx, expr, recv, recv2),
@@ -3749,6 +4869,10 @@ impl Methods {
                     Some(("cloned", recv2, [], _, _)) => iter_overeager_cloned::check(cx, expr, recv, recv2, false, false),
                     Some(("iter", recv2, [], _, _)) => iter_nth::check(cx, expr, recv2, recv, n_arg, false),
                     Some(("iter_mut", recv2, [], _, _)) => iter_nth::check(cx, expr, recv2, recv, n_arg, true),
+                    Some(("skip", recv2, [skip_arg], skip_span, _)) => {
+                        iter_nth_zero::check(cx, expr, recv, n_arg);
+                        iter_skip_then_nth::check_nth(cx, expr, recv, recv2, skip_arg, skip_span, n_arg);
+                    },
                     _ => iter_nth_zero::check(cx, expr, recv, n_arg),
                 },
                 ("ok_or_else", [arg]) => unnecessary_lazy_eval::check(cx, expr, recv, arg, "ok_or"),
@@ -3763,11 +4887,20 @@ impl Methods {
                 ("push", [arg]) => {
                     path_buf_push_overwrite::check(cx, expr, arg);
                 },
+                ("read" | "read_exact" | "write" | "write_all", [_]) => {
+                    unbuffered_file_io_in_loop::check(cx, expr, name, recv);
+                },
+                ("read_line", [arg]) => read_line_without_clear::check(cx, expr, recv, arg),
                 ("read_to_end", [_]) => {
                     verbose_file_reads::check(cx, expr, recv, verbose_file_reads::READ_TO_END_MSG);
+                    unbuffered_file_io_in_loop::check(cx, expr, name, recv);
                 },
                 ("read_to_string", [_]) => {
                     verbose_file_reads::check(cx, expr, recv, verbose_file_reads::READ_TO_STRING_MSG);
+                    unbuffered_file_io_in_loop::check(cx, expr, name, recv);
+                },
+                ("retain" | "retain_mut", [arg]) => {
+                    retain_with_constant_predicate::check_retain(cx, expr, recv, arg, span);
                 },
                 ("repeat", [arg]) => {
                     repeat_once::check(cx, expr, recv, arg);
@@ -3782,6 +4915,9 @@ impl Methods {
                     {
                         collapsible_str_replace::check(cx, expr, arg1, arg2);
                     }
+                    if name == "replace" {
+                        chained_replace::check(cx, expr, arg2, self.chained_replace_threshold);
+                    }
                 },
                 ("resize", [count_arg, default_arg]) => {
                     vec_resize_to_zero::check(cx, expr, count_arg, default_arg, span);
@@ -3796,12 +4932,41 @@ impl Methods {
                 },
                 ("sort", []) => {
                     stable_sort_primitive::check(cx, expr, recv);
+                    redundant_sort_before_extreme::check_sort(cx, expr, name, recv, None);
+                    sort_unstable_candidate::check(
+                        cx,
+                        expr,
+                        recv,
+                        name,
+                        None,
+                        span,
+                        self.sort_unstable_candidate_enumerate,
+                    );
+                },
+                ("sort_by" | "sort_by_key", [arg]) => {
+                    if name == "sort_by" {
+                        unnecessary_sort_by::check(cx, expr, recv, arg, false);
+                    }
+                    redundant_sort_before_extreme::check_sort(cx, expr, name, recv, Some(arg));
+                    sort_unstable_candidate::check(
+                        cx,
+                        expr,
+                        recv,
+                        name,
+                        Some(arg),
+                        span,
+                        self.sort_unstable_candidate_enumerate,
+                    );
+                },
+                ("sort_by_cached_key" | "sort_unstable_by_key", [arg]) => {
+                    redundant_sort_before_extreme::check_sort(cx, expr, name, recv, Some(arg));
                 },
-                ("sort_by", [arg]) => {
-                    unnecessary_sort_by::check(cx, expr, recv, arg, false);
+                ("sort_unstable", []) => {
+                    redundant_sort_before_extreme::check_sort(cx, expr, name, recv, None);
                 },
                 ("sort_unstable_by", [arg]) => {
                     unnecessary_sort_by::check(cx, expr, recv, arg, true);
+                    redundant_sort_before_extreme::check_sort(cx, expr, name, recv, Some(arg));
                 },
                 ("splitn" | "rsplitn", [count_arg, pat_arg]) => {
                     if let Some(Constant::Int(count)) = constant(cx, cx.typeck_results(), count_arg) {
@@ -3814,7 +4979,10 @@ impl Methods {
                         suspicious_splitn::check(cx, name, expr, recv, count);
                     }
                 },
-                ("step_by", [arg]) => iterator_step_by_zero::check(cx, expr, arg),
+                ("step_by", [arg]) => {
+                    iterator_step_by_zero::check(cx, expr, arg);
+                    iterator_step_by_zero_runtime::check(cx, expr, arg);
+                },
                 ("take", [_arg]) => {
                     if let Some((name2, recv2, args2, _span2, _)) = method_call(recv) {
                         if let ("cloned", []) = (name2, args2) {
@@ -3837,6 +5005,8 @@ impl Methods {
                 ("to_os_string" | "to_path_buf" | "to_vec", []) => {
                     implicit_clone::check(cx, name, expr, recv);
                 },
+                ("to_string", []) => string_from_utf8_lossy_into_owned_roundtrip::check(cx, expr, recv, span),
+                ("truncate", [arg]) => vec_resize_zero_then_extend::check_truncate(cx, expr, arg, span),
                 ("unwrap", []) => {
                     match method_call(recv) {
                         Some(("get", recv, [get_arg], _, _)) => {
@@ -3848,10 +5018,18 @@ impl Methods {
                         Some(("or", recv, [or_arg], or_span, _)) => {
                             or_then_unwrap::check(cx, expr, recv, or_arg, or_span);
                         },
+                        Some((conv @ ("ok" | "err"), recv, [], conv_span, _)) => {
+                            result_ok_then_unwrap_chain::check(cx, expr, recv, conv, conv_span, span);
+                        },
+                        Some(("lock", lock_recv, [], _, _)) => {
+                            lock_unwrap_poison_note::check(cx, expr, lock_recv, name, span, self.lock_unwrap_policy);
+                        },
                         _ => {},
                     }
                     unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
                     unwrap_used::check(cx, expr, recv, false, self.allow_unwrap_in_tests);
+                    char_to_lowercase_next_unwrap::check(cx, expr, recv);
+                    thread_spawn_join_immediately::check(cx, expr, recv);
                 },
                 ("unwrap_err", []) => {
                     unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
@@ -3886,13 +5064,8 @@ impl Methods {
                     }
                     unnecessary_literal_unwrap::check(cx, expr, recv, name, args);
                 },
-                ("zip", [arg]) => {
-                    if let ExprKind::MethodCall(name, iter_recv, [], _) = recv.kind
-                        && name.ident.name == sym::iter
-                    {
-                        range_zip_with_len::check(cx, expr, iter_recv, arg);
-                    }
-                },
+                ("wait", [_]) | ("wait_timeout", [_, _]) => condvar_wait_without_loop::check(cx, expr, recv, name, span),
+                ("zip", [arg]) => range_zip_with_len::check(cx, expr, recv, arg),
                 _ => {},
             }
         }
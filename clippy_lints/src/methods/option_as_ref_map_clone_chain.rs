@@ -0,0 +1,80 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{is_diag_trait_item, is_lint_allowed, path_def_id, path_to_local_id, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+use super::{method_call, MAP_CLONE, OPTION_AS_REF_MAP_CLONE_CHAIN};
+
+/// Checks `opt.as_ref().cloned()`, `opt.as_ref().map(Clone::clone)` and
+/// `opt.as_deref().map(ToOwned::to_owned)`, and their closure forms. `map_arg` is `None` for
+/// `cloned()`.
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, map_arg: Option<&Expr<'_>>) {
+    let Some((as_ref @ ("as_ref" | "as_deref"), opt, [], _, _)) = method_call(recv) else {
+        return;
+    };
+    let opt_ty = match *cx.typeck_results().expr_ty(opt).kind() {
+        ty::Ref(_, inner, _) => inner,
+        _ => cx.typeck_results().expr_ty(opt),
+    };
+    // `clone()` only gives the same result if it isn't called on a reference to the `Option`
+    if !is_type_diagnostic_item(cx, opt_ty, sym::Option)
+        || !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Option)
+        || cx.typeck_results().expr_ty(expr) != opt_ty
+        || expr.span.from_expansion()
+    {
+        return;
+    }
+    let method = match map_arg {
+        None if as_ref == "as_ref" => "cloned()",
+        None => return,
+        Some(arg) => match clone_fn(cx, arg) {
+            // `map_clone` suggests `cloned()` for this one, which is linted from there
+            Some((sym::Clone, true)) if !is_lint_allowed(cx, MAP_CLONE, expr.hir_id) => return,
+            Some(_) => "map(..)",
+            None => return,
+        },
+    };
+
+    let mut app = Applicability::MachineApplicable;
+    let opt = Sugg::hir_with_context(cx, opt, expr.span.ctxt(), "..", &mut app).maybe_par();
+    span_lint_and_sugg(
+        cx,
+        OPTION_AS_REF_MAP_CLONE_CHAIN,
+        expr.span,
+        &format!("called `{as_ref}().{method}` to clone an `Option`"),
+        "clone the `Option` instead",
+        format!("{opt}.clone()"),
+        app,
+    );
+}
+
+/// If `arg` is a path to `Clone::clone` or `ToOwned::to_owned`, or a closure only calling one of
+/// them on its parameter, returns the trait and whether it is a closure.
+fn clone_fn(cx: &LateContext<'_>, arg: &Expr<'_>) -> Option<(rustc_span::Symbol, bool)> {
+    let trait_of = |def_id| {
+        [sym::Clone, sym::ToOwned]
+            .into_iter()
+            .find(|&name| is_diag_trait_item(cx, def_id, name))
+    };
+    if let ExprKind::Closure(&Closure { body, .. }) = arg.kind {
+        let body = cx.tcx.hir().body(body);
+        if let [param] = body.params
+            && let PatKind::Binding(_, param_id, ..) = param.pat.kind
+            && let call = peel_blocks(body.value)
+            && let ExprKind::MethodCall(_, obj, [], _) = call.kind
+            && path_to_local_id(obj, param_id)
+            && let Some(def_id) = cx.typeck_results().type_dependent_def_id(call.hir_id)
+        {
+            trait_of(def_id).map(|name| (name, true))
+        } else {
+            None
+        }
+    } else {
+        path_def_id(cx, arg).and_then(trait_of).map(|name| (name, false))
+    }
+}
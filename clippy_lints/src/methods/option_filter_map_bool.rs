@@ -0,0 +1,142 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{get_parent_expr, peel_blocks};
+use rustc_errors::Applicability;
+use rustc_hir::{BindingAnnotation, Closure, Expr, ExprKind, Pat, PatKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_span::symbol::Ident;
+use rustc_span::{sym, Span};
+
+use super::OPTION_FILTER_MAP_BOOL;
+
+/// Checks `opt.filter(|&x| pred).map(|x| value)` when the result is unwrapped or matched on right
+/// away.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    filter_recv: &Expr<'_>,
+    filter_arg: &Expr<'_>,
+    filter_span: Span,
+    map_arg: &'tcx Expr<'_>,
+    msrv: &Msrv,
+) {
+    if !msrv.meets(msrvs::BOOL_THEN)
+        || !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(filter_recv), sym::Option)
+        || expr.span.from_expansion()
+        || !is_consumed_right_away(cx, expr)
+    {
+        return;
+    }
+    let ExprKind::Closure(&Closure { body, .. }) = filter_arg.kind else {
+        return;
+    };
+    let filter_body = cx.tcx.hir().body(body);
+    let [filter_param] = filter_body.params else {
+        return;
+    };
+    let pred = peel_blocks(filter_body.value);
+    // A predicate with statements doesn't read well inside of `and_then`
+    if let ExprKind::Block(..) = pred.kind {
+        return;
+    }
+
+    let msg = "called `map` after `filter` on an `Option`";
+    let span = filter_span.with_hi(expr.span.hi());
+    let mut app = Applicability::MachineApplicable;
+    let ctxt = expr.span.ctxt();
+    // `filter` passes the value by reference and `map` by value, only `|&x|` binds the same value
+    // for both
+    let value = if let PatKind::Ref(inner, _) = filter_param.pat.kind
+        && let Some(name) = simple_binding(inner)
+    {
+        match map_arg.kind {
+            ExprKind::Closure(&Closure { body, .. }) => {
+                let map_body = cx.tcx.hir().body(body);
+                if let [map_param] = map_body.params
+                    && simple_binding(map_param.pat).map_or(false, |map_name| map_name.name == name.name)
+                {
+                    let value = snippet_with_context(cx, map_body.value.span, ctxt, "..", &mut app).0;
+                    // the predicate may be what keeps the value from overflowing or panicking, as
+                    // in `x - 1` after `x > 0`, so only plain values are evaluated eagerly
+                    let eager = is_plain_value(map_body.value) && switch_to_eager_eval(cx, map_body.value);
+                    Some((name, value.into_owned(), eager))
+                } else {
+                    None
+                }
+            },
+            ExprKind::Path(_) => {
+                let func = snippet_with_context(cx, map_arg.span, ctxt, "..", &mut app).0;
+                Some((name, format!("{func}({name})"), false))
+            },
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    if let Some((name, value, eager)) = value {
+        let pred = Sugg::hir_with_context(cx, pred, ctxt, "..", &mut app).maybe_par();
+        let then = if eager && msrv.meets(msrvs::BOOL_THEN_SOME) {
+            format!("then_some({value})")
+        } else {
+            format!("then(|| {value})")
+        };
+        span_lint_and_sugg(
+            cx,
+            OPTION_FILTER_MAP_BOOL,
+            span,
+            msg,
+            "try",
+            format!("and_then(|{name}| {pred}.{then})"),
+            app,
+        );
+    } else {
+        span_lint_and_help(
+            cx,
+            OPTION_FILTER_MAP_BOOL,
+            span,
+            msg,
+            None,
+            "use `and_then` with `bool::then` to test and map the value in one closure",
+        );
+    }
+}
+
+/// Whether `e` is made of literals, paths and field accesses, which can't panic.
+fn is_plain_value(e: &Expr<'_>) -> bool {
+    match e.kind {
+        ExprKind::Lit(_) | ExprKind::Path(_) => true,
+        ExprKind::Field(inner, _) | ExprKind::AddrOf(_, _, inner) | ExprKind::Unary(UnOp::Not, inner) => {
+            is_plain_value(inner)
+        },
+        ExprKind::Tup(elems) => elems.iter().all(is_plain_value),
+        _ => false,
+    }
+}
+
+fn simple_binding(pat: &Pat<'_>) -> Option<Ident> {
+    if let PatKind::Binding(BindingAnnotation::NONE, _, name, None) = pat.kind {
+        Some(name)
+    } else {
+        None
+    }
+}
+
+fn is_consumed_right_away(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match get_parent_expr(cx, expr).map(|parent| parent.kind) {
+        Some(ExprKind::MethodCall(path, recv, ..)) => {
+            recv.hir_id == expr.hir_id
+                && matches!(
+                    path.ident.as_str(),
+                    "unwrap" | "expect" | "unwrap_or" | "unwrap_or_else" | "unwrap_or_default"
+                )
+        },
+        Some(ExprKind::Match(scrutinee, ..)) => scrutinee.hir_id == expr.hir_id,
+        Some(ExprKind::Let(let_expr)) => let_expr.init.hir_id == expr.hir_id,
+        _ => false,
+    }
+}
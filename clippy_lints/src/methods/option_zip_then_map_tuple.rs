@@ -0,0 +1,131 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::eager_or_lazy::switch_to_eager_eval;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::peel_blocks;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::is_local_used;
+use rustc_errors::Applicability;
+use rustc_hir::{Body, CaptureBy, Closure, Expr, ExprKind, PatKind, TyKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+use serde::Deserialize;
+
+use super::{method_call, OPTION_ZIP_THEN_MAP_TUPLE};
+
+/// Which of the two ways to combine two `Option`s `option_zip_then_map_tuple` suggests.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+pub enum OptionZipStyle {
+    /// `a.zip(b).map(|(x, y)| ..)`
+    Zip,
+    /// `a.and_then(|x| b.map(|y| ..))`
+    AndThen,
+}
+
+/// Checks `a.zip(b).map(|(x, y)| ..)`, which is linted with `OptionZipStyle::AndThen`.
+pub(super) fn check_zip_map<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, recv: &'tcx Expr<'tcx>, map_arg: &Expr<'_>) {
+    let Some(("zip", a, [b], _, _)) = method_call(recv) else {
+        return;
+    };
+    if !is_option(cx, a) || expr.span.from_expansion() {
+        return;
+    }
+    let Some(body) = closure_body(cx, map_arg) else {
+        return;
+    };
+    let PatKind::Tuple([x, y], dotdot) = body.params[0].pat.kind else {
+        return;
+    };
+    if dotdot.as_opt_usize().is_some() {
+        return;
+    }
+
+    // `b` is only evaluated if `a` is `Some` in `and_then`
+    let mut app = if switch_to_eager_eval(cx, b) {
+        Applicability::MachineApplicable
+    } else {
+        Applicability::MaybeIncorrect
+    };
+    let ctxt = expr.span.ctxt();
+    let x = snippet_with_context(cx, x.span, ctxt, "..", &mut app).0;
+    let y = snippet_with_context(cx, y.span, ctxt, "..", &mut app).0;
+    let b = Sugg::hir_with_context(cx, b, ctxt, "..", &mut app).maybe_par();
+    let value = snippet_with_context(cx, body.value.span, ctxt, "..", &mut app).0;
+    span_lint_and_sugg(
+        cx,
+        OPTION_ZIP_THEN_MAP_TUPLE,
+        expr.span.with_lo(a.span.hi()),
+        "`zip` followed by a `map` which destructures the tuple",
+        "use `and_then` and `map`",
+        format!(".and_then(|{x}| {b}.map(|{y}| {value}))"),
+        app,
+    );
+}
+
+/// Checks `a.and_then(|x| b.map(|y| ..))`, which is linted with `OptionZipStyle::Zip`.
+pub(super) fn check_and_then<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    a: &'tcx Expr<'tcx>,
+    and_then_arg: &'tcx Expr<'tcx>,
+    msrv: &Msrv,
+) {
+    if !is_option(cx, a) || expr.span.from_expansion() || !msrv.meets(msrvs::OPTION_ZIP) {
+        return;
+    }
+    let Some(outer) = closure_body(cx, and_then_arg) else {
+        return;
+    };
+    let Some(("map", b, [map_arg], _, _)) = method_call(peel_blocks(outer.value)) else {
+        return;
+    };
+    let Some(inner) = closure_body(cx, map_arg) else {
+        return;
+    };
+    let x = outer.params[0].pat;
+    // `b` is evaluated before `a` is unwrapped with `zip`, which is only fine if it's cheap and can't
+    // refer to `x`
+    let mut uses_x = false;
+    x.each_binding_or_first(&mut |_, id, _, _| uses_x |= is_local_used(cx, b, id));
+    if !is_option(cx, b) || uses_x || !switch_to_eager_eval(cx, b) {
+        return;
+    }
+
+    let mut app = Applicability::MachineApplicable;
+    let ctxt = expr.span.ctxt();
+    let x = snippet_with_context(cx, x.span, ctxt, "..", &mut app).0;
+    let y = snippet_with_context(cx, inner.params[0].pat.span, ctxt, "..", &mut app).0;
+    let b = snippet_with_context(cx, b.span, ctxt, "..", &mut app).0;
+    let value = snippet_with_context(cx, inner.value.span, ctxt, "..", &mut app).0;
+    span_lint_and_sugg(
+        cx,
+        OPTION_ZIP_THEN_MAP_TUPLE,
+        expr.span.with_lo(a.span.hi()),
+        "`and_then` with a nested `map` on another `Option`",
+        "use `zip`",
+        format!(".zip({b}).map(|({x}, {y})| {value})"),
+        app,
+    );
+}
+
+fn is_option(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(e), sym::Option)
+}
+
+/// Returns the body of a non-`move` closure with a single parameter without a type annotation.
+fn closure_body<'tcx>(cx: &LateContext<'tcx>, arg: &Expr<'_>) -> Option<&'tcx Body<'tcx>> {
+    if let ExprKind::Closure(&Closure {
+        capture_clause: CaptureBy::Ref,
+        fn_decl,
+        body,
+        ..
+    }) = arg.kind
+        && let [input] = fn_decl.inputs
+        && let TyKind::Infer = input.kind
+    {
+        Some(cx.tcx.hir().body(body))
+    } else {
+        None
+    }
+}
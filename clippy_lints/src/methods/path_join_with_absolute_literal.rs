@@ -0,0 +1,62 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::PATH_JOIN_WITH_ABSOLUTE_LITERAL;
+
+/// Checks `path.join("/abs")` and `path.join("C:\\abs")`, where the argument is a constant string.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, arg: &'tcx Expr<'_>) {
+    if let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+        && let Some(impl_id) = cx.tcx.impl_of_method(method_id)
+        && is_type_diagnostic_item(cx, cx.tcx.type_of(impl_id).subst_identity(), sym::Path)
+        && let Some(Constant::Str(joined)) = constant(cx, cx.typeck_results(), arg)
+        && let is_root = joined.starts_with(['/', '\\'])
+        && (is_root || starts_with_drive_letter(&joined))
+        && !expr.span.from_expansion()
+    {
+        span_lint_and_then(
+            cx,
+            PATH_JOIN_WITH_ABSOLUTE_LITERAL,
+            arg.span,
+            &format!(
+                "calling `join` with a path starting with {}",
+                if is_root { "a root" } else { "a drive letter" }
+            ),
+            |diag| {
+                diag.note("the base path is replaced by the joined path instead of being extended");
+                let mut app = Applicability::MaybeIncorrect;
+                diag.span_suggestion(
+                    expr.span,
+                    "if the base path isn't needed, use the joined path directly",
+                    format!(
+                        "PathBuf::from({})",
+                        snippet_with_applicability(cx, arg.span, "..", &mut app)
+                    ),
+                    app,
+                );
+                if let ExprKind::Lit(lit) = arg.kind
+                    && let LitKind::Str(..) = lit.node
+                    && is_root
+                {
+                    diag.span_suggestion(
+                        arg.span,
+                        "if the path is meant to be relative, remove the leading separator",
+                        format!("{:?}", joined.trim_start_matches(['/', '\\'])),
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+            },
+        );
+    }
+}
+
+/// Whether `path` starts with a Windows drive letter, like `C:`.
+fn starts_with_drive_letter(path: &str) -> bool {
+    matches!(path.as_bytes(), [letter, b':', ..] if letter.is_ascii_alphabetic())
+}
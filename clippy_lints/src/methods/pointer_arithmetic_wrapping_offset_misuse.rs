@@ -0,0 +1,194 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{get_parent_expr, path_to_local, path_to_local_id, SpanlessEq};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, HirId, Node, PatKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_span::Span;
+
+use super::POINTER_ARITHMETIC_WRAPPING_OFFSET_MISUSE;
+
+/// Checks `ptr.wrapping_add(n)`, `ptr.wrapping_sub(n)` and `ptr.wrapping_offset(n)`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, recv: &'tcx Expr<'tcx>, name: &str) {
+    if !cx.typeck_results().expr_ty(recv).is_unsafe_ptr() || expr.span.from_expansion() {
+        return;
+    }
+    let Some(body_id) = cx.enclosing_body else {
+        return;
+    };
+    let body = cx.tcx.hir().body(body_id).value;
+
+    let mut derefs = Vec::new();
+    let checked = match follow(cx, expr) {
+        Use::Deref(span) => {
+            derefs.push(span);
+            // e.g. `if ptr.wrapping_add(i) < end { *ptr.wrapping_add(i) }`
+            for_each_expr(body, |e| {
+                if e.hir_id != expr.hir_id
+                    && SpanlessEq::new(cx).eq_expr(e, expr)
+                    && matches!(follow(cx, e), Use::Compared)
+                {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .is_some()
+        },
+        Use::Local(local) => Uses {
+            cx,
+            body,
+            derefs: &mut derefs,
+            seen: FxHashSet::default(),
+        }
+        .is_compared(local),
+        Use::Compared | Use::Other => return,
+    };
+    if checked || derefs.is_empty() {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        POINTER_ARITHMETIC_WRAPPING_OFFSET_MISUSE,
+        expr.span,
+        &format!("the result of `{name}` is dereferenced without checking that it's in bounds"),
+        |diag| {
+            for span in derefs {
+                diag.span_note(span, "the pointer is dereferenced here");
+            }
+            diag.help(format!(
+                "if the pointer stays within the same allocation, use `{}` and explain why in a `// SAFETY:` \
+                 comment, otherwise compare it with the bounds of the allocation before dereferencing it",
+                name.trim_start_matches("wrapping_"),
+            ));
+        },
+    );
+}
+
+/// What happens to the pointer produced by an expression.
+enum Use {
+    /// It's read from, written to or borrowed
+    Deref(Span),
+    /// It's compared with another pointer, e.g. `ptr < end` or `range.contains(&ptr)`
+    Compared,
+    /// It's stored in the local
+    Local(HirId),
+    Other,
+}
+
+/// Finds what happens to the pointer produced by `expr`, looking through casts.
+fn follow<'tcx>(cx: &LateContext<'tcx>, mut expr: &'tcx Expr<'tcx>) -> Use {
+    for (_, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Expr(parent) => match parent.kind {
+                ExprKind::Cast(..) => {},
+                ExprKind::MethodCall(path, recv, ..)
+                    if recv.hir_id == expr.hir_id
+                        && matches!(path.ident.as_str(), "cast" | "cast_mut" | "cast_const") => {},
+                ExprKind::MethodCall(path, recv, ..) if recv.hir_id == expr.hir_id => {
+                    return if matches!(
+                        path.ident.as_str(),
+                        "read"
+                            | "write"
+                            | "read_unaligned"
+                            | "write_unaligned"
+                            | "read_volatile"
+                            | "write_volatile"
+                            | "replace"
+                            | "as_ref"
+                            | "as_mut"
+                    ) {
+                        Use::Deref(parent.span)
+                    } else {
+                        Use::Other
+                    };
+                },
+                ExprKind::Unary(UnOp::Deref, _) => {
+                    return if is_raw_borrowed(cx, parent) {
+                        Use::Other
+                    } else {
+                        Use::Deref(parent.span)
+                    };
+                },
+                ExprKind::Binary(op, ..)
+                    if matches!(
+                        op.node,
+                        BinOpKind::Lt | BinOpKind::Le | BinOpKind::Gt | BinOpKind::Ge | BinOpKind::Eq | BinOpKind::Ne
+                    ) =>
+                {
+                    return Use::Compared;
+                },
+                ExprKind::AddrOf(BorrowKind::Ref, ..) => {
+                    return match get_parent_expr(cx, parent) {
+                        Some(Expr {
+                            kind: ExprKind::MethodCall(path, _, [arg], _),
+                            ..
+                        }) if arg.hir_id == parent.hir_id && path.ident.as_str() == "contains" => Use::Compared,
+                        _ => Use::Other,
+                    };
+                },
+                ExprKind::Assign(lhs, rhs, _) if rhs.hir_id == expr.hir_id => {
+                    return path_to_local(lhs).map_or(Use::Other, Use::Local);
+                },
+                _ => return Use::Other,
+            },
+            Node::Local(local) => {
+                return match local.pat.kind {
+                    PatKind::Binding(_, id, _, None) => Use::Local(id),
+                    _ => Use::Other,
+                };
+            },
+            _ => return Use::Other,
+        }
+        if let Node::Expr(parent) = node {
+            expr = parent;
+        }
+    }
+    Use::Other
+}
+
+/// Whether the place `place` is only used to take its address, e.g. `addr_of!((*ptr).field)`.
+fn is_raw_borrowed<'tcx>(cx: &LateContext<'tcx>, mut place: &'tcx Expr<'tcx>) -> bool {
+    while let Some(parent) = get_parent_expr(cx, place) {
+        match parent.kind {
+            ExprKind::Field(..) => place = parent,
+            ExprKind::AddrOf(BorrowKind::Raw, ..) => return true,
+            _ => return false,
+        }
+    }
+    false
+}
+
+struct Uses<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    body: &'tcx Expr<'tcx>,
+    derefs: &'a mut Vec<Span>,
+    seen: FxHashSet<HirId>,
+}
+
+impl<'tcx> Uses<'_, 'tcx> {
+    /// Whether the pointer in `local`, or in a local it's copied to, is compared with another
+    /// pointer. Collects the places where it's dereferenced.
+    fn is_compared(&mut self, local: HirId) -> bool {
+        if !self.seen.insert(local) {
+            return false;
+        }
+        let mut locals = Vec::new();
+        let compared = for_each_expr(self.body, |e| {
+            if !path_to_local_id(e, local) {
+                return ControlFlow::Continue(());
+            }
+            match follow(self.cx, e) {
+                Use::Deref(span) => self.derefs.push(span),
+                Use::Compared => return ControlFlow::Break(()),
+                Use::Local(id) => locals.push(id),
+                Use::Other => {},
+            }
+            ControlFlow::Continue(())
+        })
+        .is_some();
+        compared || locals.into_iter().any(|id| self.is_compared(id))
+    }
+}
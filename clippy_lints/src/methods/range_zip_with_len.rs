@@ -1,34 +1,160 @@
-use clippy_utils::diagnostics::span_lint;
-use clippy_utils::source::snippet;
-use clippy_utils::{higher, SpanlessEq};
-use clippy_utils::{is_integer_const, is_trait_method};
-use if_chain::if_chain;
-use rustc_hir::{Expr, ExprKind, QPath};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::higher::{ForLoop, Range};
+use clippy_utils::source::{snippet, snippet_with_context};
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{get_parent_expr, is_integer_const, is_trait_method, SpanlessEq};
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind, Pat, PatKind};
 use rustc_lint::LateContext;
-use rustc_span::sym;
-
-use super::RANGE_ZIP_WITH_LEN;
-
-pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>, zip_arg: &'tcx Expr<'_>) {
-    if_chain! {
-        if is_trait_method(cx, expr, sym::Iterator);
-        // range expression in `.zip()` call: `0..x.len()`
-        if let Some(higher::Range { start: Some(start), end: Some(end), .. }) = higher::Range::hir(zip_arg);
-        if is_integer_const(cx, start, 0);
-        // `.len()` call
-        if let ExprKind::MethodCall(len_path, len_recv, [], _) = end.kind;
-        if len_path.ident.name == sym::len;
-        // `.iter()` and `.len()` called on same `Path`
-        if let ExprKind::Path(QPath::Resolved(_, iter_path)) = recv.kind;
-        if let ExprKind::Path(QPath::Resolved(_, len_path)) = len_recv.kind;
-        if SpanlessEq::new(cx).eq_path_segments(iter_path.segments, len_path.segments);
-        then {
-            span_lint(cx,
-                RANGE_ZIP_WITH_LEN,
-                expr.span,
-                &format!("it is more idiomatic to use `{}.iter().enumerate()`",
-                    snippet(cx, recv.span, "_"))
-            );
+use rustc_middle::ty;
+use rustc_span::{sym, Span};
+
+use super::{method_call, RANGE_ZIP_WITH_LEN};
+
+/// Adapters whose closure consumes the zipped item, so swapping the tuple in its pattern is enough.
+const CONSUMERS: [&str; 9] = [
+    "map",
+    "for_each",
+    "filter_map",
+    "flat_map",
+    "find_map",
+    "map_while",
+    "any",
+    "all",
+    "position",
+];
+
+/// A range of indices, `0..` or `0..v.len()`.
+enum IndexRange<'tcx> {
+    Unbounded,
+    Len(&'tcx Expr<'tcx>),
+}
+
+impl<'tcx> IndexRange<'tcx> {
+    /// Whether `iter` yields as many items as the range, if the range is bounded.
+    fn matches_len_of(&self, cx: &LateContext<'_>, iter: &Expr<'_>) -> bool {
+        match self {
+            Self::Unbounded => true,
+            Self::Len(v) => iterates_over(cx, iter, v),
+        }
+    }
+}
+
+/// Checks `iter.zip(0..)`, `v.iter().zip(0..v.len())` and `(0..v.len()).zip(v.iter())`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, recv: &Expr<'_>, arg: &Expr<'_>) {
+    if !is_trait_method(cx, expr, sym::Iterator) || expr.span.from_expansion() {
+        return;
+    }
+    let mut app = Applicability::MachineApplicable;
+    let ctxt = expr.span.ctxt();
+    if let Some(range) = index_range(cx, arg) {
+        if !range.matches_len_of(cx, recv) {
+            return;
+        }
+        let pat = consuming_pat(cx, expr)
+            .filter(|pat| matches!(pat.kind, PatKind::Tuple([_, _], dotdot) if dotdot.as_opt_usize().is_none()));
+        let sugg = if let Some(pat) = pat
+            && let PatKind::Tuple([item, index], _) = pat.kind
+        {
+            let item = snippet_with_context(cx, item.span, ctxt, "..", &mut app).0;
+            let index = snippet_with_context(cx, index.span, ctxt, "..", &mut app).0;
+            let zip_span = method_call(expr).map_or(expr.span, |(_, _, _, span, _)| span);
+            vec![
+                (zip_span.with_hi(expr.span.hi()), "enumerate()".to_owned()),
+                (pat.span, format!("({index}, {item})")),
+            ]
+        } else if matches!(range, IndexRange::Len(_)) {
+            // the items are swapped, which has to be fixed up by hand
+            Vec::new()
+        } else {
+            // `zip(0..)` is also used for other things than indices
+            return;
+        };
+        emit(cx, expr, recv, sugg, app);
+    } else if let Some(range) = index_range(cx, recv) {
+        let arg_ty = cx.typeck_results().expr_ty(arg);
+        if !range.matches_len_of(cx, arg) {
+            return;
+        }
+        if !cx
+            .tcx
+            .get_diagnostic_item(sym::Iterator)
+            .map_or(false, |id| implements_trait(cx, arg_ty, id, &[]))
+        {
+            return;
         }
+        let iter = Sugg::hir_with_context(cx, arg, ctxt, "..", &mut app).maybe_par();
+        emit(cx, expr, arg, vec![(expr.span, format!("{iter}.enumerate()"))], app);
+    }
+}
+
+fn emit(cx: &LateContext<'_>, expr: &Expr<'_>, iter: &Expr<'_>, sugg: Vec<(Span, String)>, app: Applicability) {
+    span_lint_and_then(
+        cx,
+        RANGE_ZIP_WITH_LEN,
+        expr.span,
+        &format!(
+            "it is more idiomatic to use `{}.enumerate()`",
+            snippet(cx, iter.span, "_")
+        ),
+        |diag| {
+            if !sugg.is_empty() {
+                diag.multipart_suggestion("use `enumerate()` instead", sugg, app);
+            }
+        },
+    );
+}
+
+/// Checks for `0..` or `0..v.len()` over `usize`.
+fn index_range<'tcx>(cx: &LateContext<'_>, e: &'tcx Expr<'tcx>) -> Option<IndexRange<'tcx>> {
+    let Range {
+        start: Some(start), end, ..
+    } = Range::hir(e)?
+    else {
+        return None;
+    };
+    if !is_integer_const(cx, start, 0) || *cx.typeck_results().expr_ty(start).kind() != ty::Uint(ty::UintTy::Usize) {
+        return None;
+    }
+    match end {
+        None => Some(IndexRange::Unbounded),
+        Some(end) => match method_call(end) {
+            Some(("len", len_recv, [], _, _)) => Some(IndexRange::Len(len_recv)),
+            _ => None,
+        },
+    }
+}
+
+/// Whether `iter` is `v.iter()`, `v.iter_mut()` or `v.into_iter()`, which yield `v.len()` items.
+fn iterates_over(cx: &LateContext<'_>, iter: &Expr<'_>, v: &Expr<'_>) -> bool {
+    matches!(
+        method_call(iter),
+        Some(("iter" | "iter_mut" | "into_iter", iter_recv, [], _, _)) if SpanlessEq::new(cx).eq_expr(iter_recv, v)
+    )
+}
+
+/// Returns the pattern that destructures the items of `expr`, either in a `for` loop or in the
+/// closure of an adapter that consumes them.
+fn consuming_pat<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Pat<'tcx>> {
+    if let Some(for_loop) = get_parent_expr(cx, expr)
+        .and_then(|e| get_parent_expr(cx, e))
+        .and_then(|e| get_parent_expr(cx, e))
+        .and_then(ForLoop::hir)
+        && for_loop.arg.hir_id == expr.hir_id
+    {
+        return Some(for_loop.pat);
+    }
+    let parent = get_parent_expr(cx, expr)?;
+    if let Some((name, consumer_recv, [closure], _, _)) = method_call(parent)
+        && consumer_recv.hir_id == expr.hir_id
+        && CONSUMERS.contains(&name)
+        && is_trait_method(cx, parent, sym::Iterator)
+        && let ExprKind::Closure(&Closure { body, .. }) = closure.kind
+        && let [param] = cx.tcx.hir().body(body).params
+    {
+        Some(param.pat)
+    } else {
+        None
     }
 }
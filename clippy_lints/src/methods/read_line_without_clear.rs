@@ -0,0 +1,81 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::{is_type_lang_item, match_type};
+use clippy_utils::visitors::for_each_expr_with_closures;
+use clippy_utils::{
+    get_enclosing_loop_or_multi_call_closure, get_parent_expr, match_trait_method, path_to_local, paths,
+};
+use core::ops::ControlFlow;
+use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, LangItem, Mutability};
+use rustc_lint::LateContext;
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+
+use super::{method_call, READ_LINE_WITHOUT_CLEAR};
+
+/// Checks `stdin.read_line(&mut buf)` in a loop, where `buf` is declared outside of the loop and
+/// never cleared or otherwise modified in it.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, recv: &Expr<'_>, arg: &Expr<'_>) {
+    if !match_trait_method(cx, expr, &paths::STD_IO_BUFREAD)
+        && !match_type(cx, cx.typeck_results().expr_ty(recv).peel_refs(), &paths::STD_IO_STDIN)
+    {
+        return;
+    }
+    let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, buf) = arg.kind else {
+        return;
+    };
+    let Some(buf_id) = path_to_local(buf) else {
+        return;
+    };
+    if !is_type_lang_item(cx, cx.typeck_results().expr_ty(buf), LangItem::String) || expr.span.from_expansion() {
+        return;
+    }
+    let Some(enclosing_loop) = get_enclosing_loop_or_multi_call_closure(cx, expr) else {
+        return;
+    };
+    if enclosing_loop.span.contains(cx.tcx.hir().span(buf_id)) || is_modified_in(cx, enclosing_loop, buf_id) {
+        return;
+    }
+
+    let buf = snippet(cx, buf.span, "..");
+    span_lint_and_help(
+        cx,
+        READ_LINE_WITHOUT_CLEAR,
+        expr.span,
+        &format!("calling `read_line` in a loop without clearing `{buf}`"),
+        None,
+        &format!(
+            "`read_line` appends to the buffer, so the lines accumulate; \
+            consider calling `{buf}.clear()` before reading the next line, or iterating over `lines()`"
+        ),
+    );
+}
+
+/// Whether `buf` is assigned to or borrowed mutably anywhere in `e`, other than by `read_line`.
+fn is_modified_in<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>, buf_id: HirId) -> bool {
+    for_each_expr_with_closures(cx, e, |e| {
+        if path_to_local(e) != Some(buf_id) {
+            return ControlFlow::Continue(());
+        }
+        let Some(parent) = get_parent_expr(cx, e) else {
+            return ControlFlow::Continue(());
+        };
+        let modified = match parent.kind {
+            ExprKind::Assign(lhs, ..) | ExprKind::AssignOp(_, lhs, _) => lhs.hir_id == e.hir_id,
+            ExprKind::AddrOf(_, Mutability::Mut, _) => {
+                !matches!(get_parent_expr(cx, parent).and_then(method_call), Some(("read_line", _, [arg], _, _)) if arg.hir_id == parent.hir_id)
+            },
+            _ => false,
+        } || cx.typeck_results().expr_adjustments(e).iter().any(|adjust| {
+            matches!(
+                adjust.kind,
+                Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Mut { .. }))
+            )
+        });
+        if modified {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
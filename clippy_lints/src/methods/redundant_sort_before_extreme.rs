@@ -0,0 +1,198 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_local_use_after_expr;
+use clippy_utils::{
+    get_enclosing_loop_or_multi_call_closure, get_parent_expr, is_integer_literal, match_def_path, path_to_local,
+    path_to_local_id, paths,
+};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, HirId, Mutability, Node, StmtKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+use super::{method_call, REDUNDANT_SORT_BEFORE_EXTREME};
+
+const NOTE: &str = "sorting takes O(n log n) time, while finding the minimum or maximum only takes O(n)";
+
+#[derive(Clone, Copy)]
+enum Extreme {
+    Min,
+    Max,
+}
+
+impl Extreme {
+    fn method(self) -> &'static str {
+        match self {
+            Self::Min => "min",
+            Self::Max => "max",
+        }
+    }
+}
+
+/// Checks `v.sort()` and its `_by` and `_by_key` variants when `v` is only used afterwards to take
+/// its first or last element.
+pub(super) fn check_sort<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    name: &str,
+    recv: &Expr<'_>,
+    arg: Option<&Expr<'_>>,
+) {
+    let Some(local_id) = path_to_local(recv) else {
+        return;
+    };
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    if !(recv_ty.is_array() || is_type_diagnostic_item(cx, recv_ty, sym::Vec)) || expr.span.from_expansion() {
+        return;
+    }
+    let Some(Node::Stmt(stmt)) = cx.tcx.hir().find_parent(expr.hir_id) else {
+        return;
+    };
+    if !matches!(stmt.kind, StmtKind::Semi(_)) {
+        return;
+    }
+    // Inside of a loop, the uses before the sort see the sorted elements on the next iteration
+    if let Some(enclosing_loop) = get_enclosing_loop_or_multi_call_closure(cx, expr)
+        && !enclosing_loop.span.contains(cx.tcx.hir().span(local_id))
+    {
+        return;
+    }
+
+    // The closures of `min_by` and `max_by` take `&&T` rather than `&T`, which may not fit the
+    // patterns of the closure
+    let mut app = if arg.is_some() {
+        Applicability::MaybeIncorrect
+    } else {
+        Applicability::MachineApplicable
+    };
+    let ctxt = expr.span.ctxt();
+    let local = snippet_with_context(cx, recv.span, ctxt, "..", &mut app).0;
+    let arg = arg.map_or(String::new(), |arg| {
+        snippet_with_context(cx, arg.span, ctxt, "..", &mut app).0.into_owned()
+    });
+    let suffix = if name.ends_with("_key") {
+        "_by_key"
+    } else if name.ends_with("_by") {
+        "_by"
+    } else {
+        ""
+    };
+    let search = |extreme: Extreme| format!("{local}.iter().{}{suffix}({arg})", extreme.method());
+
+    let mut replacements: Vec<(Span, String)> = Vec::new();
+    let res = for_each_local_use_after_expr(cx, local_id, expr.hir_id, |e| {
+        // `v[v.len() - 1]` uses `v` twice
+        if replacements.iter().any(|(span, _)| span.contains(e.span)) {
+            return ControlFlow::Continue(());
+        }
+        let Some(parent) = get_parent_expr(cx, e) else {
+            return ControlFlow::Break(());
+        };
+        match parent.kind {
+            ExprKind::MethodCall(path, _, [], _) if path.ident.as_str() == "first" => {
+                replacements.push((parent.span, search(Extreme::Min)));
+            },
+            ExprKind::MethodCall(path, _, [], _) if path.ident.as_str() == "last" => {
+                replacements.push((parent.span, search(Extreme::Max)));
+            },
+            ExprKind::Index(base, index) if base.hir_id == e.hir_id => {
+                let Some(extreme) = indexed_extreme(index, local_id) else {
+                    return ControlFlow::Break(());
+                };
+                let value = format!("{}.unwrap()", search(extreme));
+                replacements.push(match get_parent_expr(cx, parent) {
+                    Some(grandparent)
+                        if let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _) = grandparent.kind =>
+                    {
+                        (grandparent.span, value)
+                    },
+                    Some(Expr {
+                        kind: ExprKind::MethodCall(_, method_recv, ..) | ExprKind::Field(method_recv, _),
+                        ..
+                    }) if method_recv.hir_id == parent.hir_id => (parent.span, format!("(*{value})")),
+                    _ => (parent.span, format!("*{value}")),
+                });
+            },
+            _ => return ControlFlow::Break(()),
+        }
+        ControlFlow::Continue(())
+    });
+    if res.is_break() || replacements.is_empty() {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        REDUNDANT_SORT_BEFORE_EXTREME,
+        expr.span,
+        &format!("`{local}` is sorted only to take its minimum or maximum"),
+        |diag| {
+            diag.note(NOTE);
+            replacements.push((stmt.span, String::new()));
+            diag.multipart_suggestion("search for it instead of sorting", replacements, app);
+        },
+    );
+}
+
+/// Checks `iter.sorted().next()` and `iter.sorted().last()` from `itertools`, and their `_by` and
+/// `_by_key` variants.
+pub(super) fn check_sorted(cx: &LateContext<'_>, expr: &Expr<'_>, name: &str, recv: &Expr<'_>) {
+    let extreme = match name {
+        "next" => Extreme::Min,
+        "last" => Extreme::Max,
+        _ => return,
+    };
+    if let Some((sorted, _, args, sorted_span, _)) = method_call(recv)
+        && let Some(did) = cx.typeck_results().type_dependent_def_id(recv.hir_id)
+        && [
+            &paths::ITERTOOLS_SORTED,
+            &paths::ITERTOOLS_SORTED_BY,
+            &paths::ITERTOOLS_SORTED_BY_KEY,
+        ]
+        .into_iter()
+        .any(|path| match_def_path(cx, did, path))
+        && !expr.span.from_expansion()
+    {
+        let mut app = Applicability::MachineApplicable;
+        let args = args
+            .first()
+            .map_or(String::new(), |arg| {
+                snippet_with_context(cx, arg.span, expr.span.ctxt(), "..", &mut app).0.into_owned()
+            });
+        span_lint_and_then(
+            cx,
+            REDUNDANT_SORT_BEFORE_EXTREME,
+            sorted_span.with_hi(expr.span.hi()),
+            &format!("the iterator is sorted only to take its {}imum", extreme.method()),
+            |diag| {
+                diag.note(NOTE);
+                diag.span_suggestion(
+                    sorted_span.with_hi(expr.span.hi()),
+                    "search for it instead of sorting",
+                    format!("{}{}({args})", extreme.method(), &sorted["sorted".len()..]),
+                    app,
+                );
+            },
+        );
+    }
+}
+
+/// Returns the element `index` takes from a sorted slice, if it's `0` or `v.len() - 1`.
+fn indexed_extreme(index: &Expr<'_>, local_id: HirId) -> Option<Extreme> {
+    if is_integer_literal(index, 0) {
+        return Some(Extreme::Min);
+    }
+    if let ExprKind::Binary(op, lhs, rhs) = index.kind
+        && op.node == BinOpKind::Sub
+        && is_integer_literal(rhs, 1)
+        && let ExprKind::MethodCall(len, len_recv, [], _) = lhs.kind
+        && len.ident.name == sym::len
+        && path_to_local_id(len_recv, local_id)
+    {
+        Some(Extreme::Max)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,54 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::{has_debug_impl, is_type_diagnostic_item};
+use rustc_errors::Applicability;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::{sym, Span};
+
+use super::RESULT_OK_THEN_UNWRAP_CHAIN;
+
+/// Checks `res.ok().unwrap()` and `res.err().unwrap()`, where `conv` is `ok` or `err`.
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    res: &Expr<'_>,
+    conv: &str,
+    conv_span: Span,
+    name_span: Span,
+) {
+    let res_ty = cx.typeck_results().expr_ty(res);
+    let ty::Adt(_, substs) = res_ty.kind() else {
+        return;
+    };
+    if !is_type_diagnostic_item(cx, res_ty, sym::Result) || expr.span.from_expansion() {
+        return;
+    }
+    let (method, discarded, discarded_ty) = if conv == "ok" {
+        ("unwrap", "error", substs.type_at(1))
+    } else {
+        ("unwrap_err", "value", substs.type_at(0))
+    };
+    // the suggested method shows the discarded part in the panic message
+    if !has_debug_impl(cx, discarded_ty) {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        RESULT_OK_THEN_UNWRAP_CHAIN,
+        conv_span.to(name_span),
+        &format!("called `{conv}().unwrap()` on a `Result`"),
+        |diag| {
+            diag.span_suggestion(
+                conv_span.to(name_span),
+                format!("call `{method}()` on the `Result` directly"),
+                method,
+                Applicability::MachineApplicable,
+            );
+            diag.note(format!(
+                "`{conv}()` discards the {discarded}, which `{method}()` would show when panicking"
+            ));
+        },
+    );
+}
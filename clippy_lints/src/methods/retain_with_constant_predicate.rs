@@ -0,0 +1,102 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::is_trait_method;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind, LangItem, Node, StmtKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span, Symbol};
+
+use super::RETAIN_WITH_CONSTANT_PREDICATE;
+
+/// The collections with a `retain` method, all of which also have `clear`.
+const RETAIN_TYPES: [Symbol; 7] = [
+    sym::Vec,
+    sym::VecDeque,
+    sym::HashMap,
+    sym::HashSet,
+    sym::BTreeMap,
+    sym::BTreeSet,
+    sym::BinaryHeap,
+];
+
+/// Checks `retain(|_| true)` and `retain(|_| false)`, and the same with `retain_mut`.
+pub(super) fn check_retain(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg: &Expr<'_>, span: Span) {
+    let recv_ty = cx.typeck_results().expr_ty_adjusted(recv).peel_refs();
+    if !RETAIN_TYPES.iter().any(|&ty| is_type_diagnostic_item(cx, recv_ty, ty))
+        && !is_type_lang_item(cx, recv_ty, LangItem::String)
+    {
+        return;
+    }
+    let Some(keep) = constant_predicate(cx, arg) else {
+        return;
+    };
+    if expr.span.from_expansion() {
+        return;
+    }
+
+    if keep {
+        if let Node::Stmt(stmt) = cx.tcx.hir().get_parent(expr.hir_id)
+            && let StmtKind::Semi(_) = stmt.kind
+        {
+            span_lint_and_sugg(
+                cx,
+                RETAIN_WITH_CONSTANT_PREDICATE,
+                stmt.span,
+                "this `retain` keeps all elements",
+                "remove the call",
+                String::new(),
+                Applicability::MachineApplicable,
+            );
+        } else {
+            span_lint_and_help(
+                cx,
+                RETAIN_WITH_CONSTANT_PREDICATE,
+                expr.span,
+                "this `retain` keeps all elements",
+                None,
+                "remove the call",
+            );
+        }
+    } else {
+        span_lint_and_sugg(
+            cx,
+            RETAIN_WITH_CONSTANT_PREDICATE,
+            span.with_hi(expr.span.hi()),
+            "this `retain` removes all elements",
+            "use `clear()` instead",
+            "clear()".to_owned(),
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+/// Checks `iter.filter(|_| true)`.
+pub(super) fn check_filter(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg: &Expr<'_>) {
+    if is_trait_method(cx, expr, sym::Iterator)
+        && constant_predicate(cx, arg) == Some(true)
+        && !expr.span.from_expansion()
+    {
+        span_lint_and_sugg(
+            cx,
+            RETAIN_WITH_CONSTANT_PREDICATE,
+            expr.span.with_lo(recv.span.hi()),
+            "this `filter` keeps all elements",
+            "remove the call",
+            String::new(),
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+/// If `arg` is a closure returning the same `bool` constant for every element, returns it.
+fn constant_predicate(cx: &LateContext<'_>, arg: &Expr<'_>) -> Option<bool> {
+    let ExprKind::Closure(&Closure { body, .. }) = arg.kind else {
+        return None;
+    };
+    let body = cx.tcx.hir().body(body);
+    match constant(cx, cx.tcx.typeck_body(body.id()), body.value)? {
+        Constant::Bool(keep) => Some(keep),
+        _ => None,
+    }
+}
@@ -6,7 +6,7 @@ use clippy_utils::{is_trait_method, strip_pat_refs};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
-use rustc_hir::PatKind;
+use rustc_hir::{PatKind, TyKind};
 use rustc_lint::LateContext;
 use rustc_span::source_map::Span;
 use rustc_span::symbol::sym;
@@ -37,12 +37,12 @@ pub(super) fn check<'tcx>(
             let mut applicability = Applicability::MachineApplicable;
             let any_search_snippet = if_chain! {
                 if search_method == "find";
-                if let hir::ExprKind::Closure(&hir::Closure { body, .. }) = search_arg.kind;
-                let closure_body = cx.tcx.hir().body(body);
-                if let Some(closure_arg) = closure_body.params.get(0);
+                if let hir::ExprKind::Closure(closure) = search_arg.kind;
+                let closure_body = cx.tcx.hir().body(closure.body);
+                if let Some(closure_arg) = closure_body.params.first();
                 then {
-                    if let hir::PatKind::Ref(..) = closure_arg.pat.kind {
-                        Some(search_snippet.replacen('&', "", 1))
+                    if let hir::PatKind::Ref(inner, _) = closure_arg.pat.kind {
+                        Some(strip_ref_param(cx, search_arg, closure, closure_arg, inner, &mut applicability))
                     } else if let PatKind::Binding(..) = strip_pat_refs(closure_arg.pat).kind {
                         // `find()` provides a reference to the item, but `any` does not,
                         // so we should fix item usages for suggestion
@@ -149,3 +149,35 @@ pub(super) fn check<'tcx>(
         }
     }
 }
+
+/// Rewrites the closure `search_arg`, whose parameter `param` is the pattern `&inner`, to take
+/// the item by value, e.g. `|&x: &i32| x == 0` to `|x: i32| x == 0`.
+fn strip_ref_param(
+    cx: &LateContext<'_>,
+    search_arg: &hir::Expr<'_>,
+    closure: &hir::Closure<'_>,
+    param: &hir::Param<'_>,
+    inner: &hir::Pat<'_>,
+    applicability: &mut Applicability,
+) -> String {
+    let before = snippet_with_applicability(cx, search_arg.span.until(param.pat.span), "..", applicability);
+    let inner = snippet_with_applicability(cx, inner.span, "..", applicability);
+    // the parameter type of an unannotated closure is spanned inside of the pattern
+    let (ty, rest) = match closure.fn_decl.inputs.first() {
+        Some(ty) if !param.pat.span.contains(ty.span) => {
+            let ty_sugg = if let TyKind::Ref(_, mut_ty) = ty.kind {
+                format!(
+                    ": {}",
+                    snippet_with_applicability(cx, mut_ty.ty.span, "..", applicability)
+                )
+            } else {
+                // `any` infers the type of the item
+                String::new()
+            };
+            (ty_sugg, ty.span.hi())
+        },
+        _ => (String::new(), param.pat.span.hi()),
+    };
+    let after = snippet_with_applicability(cx, search_arg.span.with_lo(rest), "..", applicability);
+    format!("{before}{inner}{ty}{after}")
+}
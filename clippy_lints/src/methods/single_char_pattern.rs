@@ -1,4 +1,4 @@
-use super::utils::get_hint_if_single_char_arg;
+use super::utils::{get_char_if_to_string_arg, get_hint_if_single_char_arg};
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use if_chain::if_chain;
 use rustc_errors::Applicability;
@@ -36,7 +36,7 @@ const PATTERN_METHODS: [(&str, usize); 24] = [
     ("replacen", 0),
 ];
 
-/// lint for length-1 `str`s for methods in `PATTERN_METHODS`
+/// lint for length-1 `str`s and `char`s converted to strings for methods in `PATTERN_METHODS`
 pub(super) fn check(
     cx: &LateContext<'_>,
     _expr: &hir::Expr<'_>,
@@ -51,17 +51,28 @@ pub(super) fn check(
             if method_name.as_str() == method && args.len() > pos;
             let arg = &args[pos];
             let mut applicability = Applicability::MachineApplicable;
-            if let Some(hint) = get_hint_if_single_char_arg(cx, arg, &mut applicability);
             then {
-                span_lint_and_sugg(
-                    cx,
-                    SINGLE_CHAR_PATTERN,
-                    arg.span,
-                    "single-character string constant used as pattern",
-                    "try using a `char` instead",
-                    hint,
-                    applicability,
-                );
+                if let Some(hint) = get_hint_if_single_char_arg(cx, arg, &mut applicability) {
+                    span_lint_and_sugg(
+                        cx,
+                        SINGLE_CHAR_PATTERN,
+                        arg.span,
+                        "single-character string constant used as pattern",
+                        "try using a `char` instead",
+                        hint,
+                        applicability,
+                    );
+                } else if let Some(ch) = get_char_if_to_string_arg(cx, arg, &mut applicability) {
+                    span_lint_and_sugg(
+                        cx,
+                        SINGLE_CHAR_PATTERN,
+                        arg.span,
+                        "`char` converted to a string used as pattern",
+                        "use the `char` directly",
+                        ch,
+                        applicability,
+                    );
+                }
             }
         }
     }
@@ -1,4 +1,4 @@
-use super::utils::get_hint_if_single_char_arg;
+use super::utils::{get_char_if_to_string_arg, get_hint_if_single_char_arg};
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_with_applicability;
 use rustc_errors::Applicability;
@@ -7,7 +7,7 @@ use rustc_lint::LateContext;
 
 use super::SINGLE_CHAR_ADD_STR;
 
-/// lint for length-1 `str`s as argument for `push_str`
+/// lint for length-1 `str`s and `char`s converted to strings as argument for `push_str`
 pub(super) fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, receiver: &hir::Expr<'_>, args: &[hir::Expr<'_>]) {
     let mut applicability = Applicability::MachineApplicable;
     if let Some(extension_string) = get_hint_if_single_char_arg(cx, &args[0], &mut applicability) {
@@ -23,5 +23,17 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, receiver: &hir::
             sugg,
             applicability,
         );
+    } else if let Some(ch) = get_char_if_to_string_arg(cx, &args[0], &mut applicability) {
+        let base_string_snippet =
+            snippet_with_applicability(cx, receiver.span.source_callsite(), "..", &mut applicability);
+        span_lint_and_sugg(
+            cx,
+            SINGLE_CHAR_ADD_STR,
+            expr.span,
+            "calling `push_str()` using a `char` converted to a string",
+            "consider using `push` with the `char`",
+            format!("{base_string_snippet}.push({ch})"),
+            applicability,
+        );
     }
 }
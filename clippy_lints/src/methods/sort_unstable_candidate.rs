@@ -0,0 +1,257 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{
+    get_parent_node, is_lint_allowed, is_slice_of_primitives, is_trait_method, match_def_path, path_def_id,
+    path_to_local, path_to_local_id,
+};
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind, HirId, Node, Pat, PatKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::{sym, Span};
+
+use super::{method_call, SORT_UNSTABLE_CANDIDATE, STABLE_SORT_PRIMITIVE, UNNECESSARY_SORT_BY};
+
+/// What a closure passed to `sort_by` or `sort_by_key` compares.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Key {
+    /// The whole element, e.g. `|x| *x`.
+    Whole,
+    /// The first field of a tuple element, e.g. `|&(i, _)| i`.
+    Index,
+}
+
+/// Checks `sort()`, `sort_by(..)` and `sort_by_key(..)` calls on slices. `check_enumerate` enables
+/// the check of sorts by indices from `enumerate`.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    recv: &'tcx Expr<'tcx>,
+    name: &str,
+    arg: Option<&'tcx Expr<'tcx>>,
+    name_span: Span,
+    check_enumerate: bool,
+) {
+    let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) else {
+        return;
+    };
+    let ty::Slice(elem_ty) = cx.typeck_results().expr_ty_adjusted(recv).peel_refs().kind() else {
+        return;
+    };
+    if !cx
+        .tcx
+        .impl_of_method(method_id)
+        .map_or(false, |impl_id| cx.tcx.type_of(impl_id).subst_identity().is_slice())
+        || expr.span.from_expansion()
+    {
+        return;
+    }
+
+    let key = match (name, arg) {
+        // `stable_sort_primitive` lints the primitive types
+        ("sort", None)
+            if is_slice_of_primitives(cx, recv).is_none()
+                || is_lint_allowed(cx, STABLE_SORT_PRIMITIVE, expr.hir_id) =>
+        {
+            Key::Whole
+        },
+        // `unnecessary_sort_by` suggests `sort` or `sort_by_key` instead
+        ("sort_by", Some(arg)) if is_lint_allowed(cx, UNNECESSARY_SORT_BY, expr.hir_id) => {
+            let Some(key) = compared_key(cx, arg) else {
+                return;
+            };
+            key
+        },
+        ("sort_by_key", Some(arg)) => {
+            let Some(key) = sort_key(cx, arg) else {
+                return;
+            };
+            key
+        },
+        _ => return,
+    };
+    let note = match key {
+        Key::Whole if equal_means_identical(cx, *elem_ty) => "elements which compare equal are identical",
+        Key::Index if check_enumerate && is_from_enumerate(cx, expr, recv) => "the indices from `enumerate` are unique",
+        _ => return,
+    };
+
+    let unstable = name.replacen("sort", "sort_unstable", 1);
+    span_lint_and_then(
+        cx,
+        SORT_UNSTABLE_CANDIDATE,
+        expr.span,
+        &format!("used `{name}` where the stability of the sort can't matter"),
+        |diag| {
+            diag.span_suggestion(
+                name_span,
+                format!("use `{unstable}`"),
+                unstable.clone(),
+                Applicability::MachineApplicable,
+            );
+            diag.note(format!("{note}, so an unstable sort has the same result"));
+        },
+    );
+}
+
+/// Whether two values of `ty` which compare equal with `Ord` can't be told apart.
+fn equal_means_identical<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    match *ty.kind() {
+        ty::Bool | ty::Char | ty::Int(_) | ty::Uint(_) | ty::Str => true,
+        ty::Ref(_, inner, _) | ty::Array(inner, _) | ty::Slice(inner) => equal_means_identical(cx, inner),
+        ty::Tuple(tys) => tys.iter().all(|ty| equal_means_identical(cx, ty)),
+        // a derived `Ord` compares all fields
+        ty::Adt(adt, substs) => {
+            !adt.is_union()
+                && has_derived_ord(cx, ty)
+                && adt
+                    .all_fields()
+                    .all(|field| equal_means_identical(cx, field.ty(cx.tcx, substs)))
+        },
+        _ => false,
+    }
+}
+
+fn has_derived_ord<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    let Some(ord_id) = cx.tcx.get_diagnostic_item(sym::Ord) else {
+        return false;
+    };
+    let mut derived = false;
+    cx.tcx.for_each_relevant_impl(ord_id, ty, |impl_id| {
+        derived |= cx.tcx.has_attr(impl_id, sym::automatically_derived);
+    });
+    derived
+}
+
+/// Returns what a `sort_by` comparator like `|a, b| a.cmp(b)` or `|a, b| b.0.cmp(&a.0)` compares.
+fn compared_key(cx: &LateContext<'_>, arg: &Expr<'_>) -> Option<Key> {
+    let ExprKind::Closure(&Closure { body, .. }) = arg.kind else {
+        return None;
+    };
+    let body = cx.tcx.hir().body(body);
+    let [a, b] = body.params else {
+        return None;
+    };
+    let ExprKind::MethodCall(path, left, [right], _) = body.value.kind else {
+        return None;
+    };
+    if path.ident.name != sym::cmp || !is_trait_method(cx, body.value, sym::Ord) {
+        return None;
+    }
+    let (left, right) = (peel_ref_ops(left), peel_ref_ops(right));
+    match (key_of(left, a.pat), key_of(right, b.pat)) {
+        (Some(l), Some(r)) if l == r => Some(l),
+        _ => match (key_of(left, b.pat), key_of(right, a.pat)) {
+            (Some(l), Some(r)) if l == r => Some(l),
+            _ => None,
+        },
+    }
+}
+
+/// Returns what a `sort_by_key` key like `|x| *x` or `|&(i, _)| Reverse(i)` is.
+fn sort_key(cx: &LateContext<'_>, arg: &Expr<'_>) -> Option<Key> {
+    let ExprKind::Closure(&Closure { body, .. }) = arg.kind else {
+        return None;
+    };
+    let body = cx.tcx.hir().body(body);
+    let [param] = body.params else {
+        return None;
+    };
+    let mut key = peel_ref_ops(body.value);
+    if let ExprKind::Call(func, [inner]) = key.kind
+        && path_def_id(cx, func).map_or(false, |id| match_def_path(cx, id, &["core", "cmp", "Reverse"]))
+    {
+        key = peel_ref_ops(inner);
+    }
+    key_of(key, param.pat)
+}
+
+/// Returns what `e` is in terms of the closure parameter `pat`.
+fn key_of(e: &Expr<'_>, pat: &Pat<'_>) -> Option<Key> {
+    match peel_ref_pats(pat).kind {
+        PatKind::Binding(_, id, _, None) => {
+            if path_to_local_id(e, id) {
+                Some(Key::Whole)
+            } else if let ExprKind::Field(base, field) = e.kind
+                && field.name.as_str() == "0"
+                && path_to_local_id(peel_ref_ops(base), id)
+            {
+                Some(Key::Index)
+            } else {
+                None
+            }
+        },
+        PatKind::Tuple([first, ..], _)
+            if let PatKind::Binding(_, id, _, None) = peel_ref_pats(first).kind
+                && path_to_local_id(e, id) =>
+        {
+            Some(Key::Index)
+        },
+        _ => None,
+    }
+}
+
+fn peel_ref_ops<'a>(mut e: &'a Expr<'a>) -> &'a Expr<'a> {
+    while let ExprKind::AddrOf(_, _, inner) | ExprKind::Unary(UnOp::Deref, inner) = e.kind {
+        e = inner;
+    }
+    e
+}
+
+fn peel_ref_pats<'a>(mut pat: &'a Pat<'a>) -> &'a Pat<'a> {
+    while let PatKind::Ref(inner, _) = pat.kind {
+        pat = inner;
+    }
+    pat
+}
+
+/// Whether `recv` is a local initialized with `iter.enumerate().collect()`, which isn't used
+/// between its declaration and the sort in `expr`.
+fn is_from_enumerate<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, recv: &Expr<'_>) -> bool {
+    let Some(local_id) = path_to_local(recv) else {
+        return false;
+    };
+    let Some(Node::Local(local)) = cx.tcx.hir().find_parent(local_id) else {
+        return false;
+    };
+    let Some(init) = local.init else {
+        return false;
+    };
+    if !matches!(method_call(init), Some(("collect", enumerate, [], _, _))
+        if matches!(method_call(enumerate), Some(("enumerate", _, [], _, _))) && is_trait_method(cx, enumerate, sym::Iterator))
+    {
+        return false;
+    }
+    let Some(Node::Stmt(let_stmt)) = get_parent_node(cx.tcx, local.hir_id) else {
+        return false;
+    };
+    let Some(Node::Block(block)) = get_parent_node(cx.tcx, let_stmt.hir_id) else {
+        return false;
+    };
+    let Some(sort_stmt) = enclosing_stmt_in(cx, expr.hir_id, block.hir_id) else {
+        return false;
+    };
+    let Some(start) = block.stmts.iter().position(|stmt| stmt.hir_id == let_stmt.hir_id) else {
+        return false;
+    };
+    // pushing to or reordering the `Vec` before the sort may make the indices no longer unique
+    block.stmts[start + 1..]
+        .iter()
+        .take_while(|stmt| stmt.hir_id != sort_stmt)
+        .all(|stmt| !is_local_used(cx, stmt, local_id))
+}
+
+/// Returns the statement of `block` which contains `id`.
+fn enclosing_stmt_in(cx: &LateContext<'_>, id: HirId, block: HirId) -> Option<HirId> {
+    let mut child = id;
+    for (parent_id, node) in cx.tcx.hir().parent_iter(id) {
+        if parent_id == block {
+            return Some(child);
+        }
+        if let Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) = node {
+            return None;
+        }
+        child = parent_id;
+    }
+    None
+}
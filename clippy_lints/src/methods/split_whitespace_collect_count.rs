@@ -0,0 +1,110 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{is_integer_const, match_def_path, path_def_id, path_to_local_id, peel_blocks};
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Closure, Expr, ExprKind, PatKind, UnOp};
+use rustc_lint::LateContext;
+
+use super::{method_call, SPLIT_WHITESPACE_COLLECT_COUNT};
+
+/// Checks `s.split(' ').filter(|x| !x.is_empty())` and `s.split(' ').count()`. `filter_arg` is
+/// the closure passed to `filter`, if any.
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, split_call: &Expr<'_>, filter_arg: Option<&Expr<'_>>) {
+    let Some(("split", s, [pat], split_span, _)) = method_call(split_call) else {
+        return;
+    };
+    if !cx.typeck_results().expr_ty_adjusted(s).peel_refs().is_str() || expr.span.from_expansion() {
+        return;
+    }
+    let Some((method, exact)) = whitespace_method(cx, pat) else {
+        return;
+    };
+    let (msg, replacement) = match filter_arg {
+        Some(arg) if is_non_empty_filter(cx, arg) => {
+            ("filtering out the empty items of `split`", format!("{method}()"))
+        },
+        Some(_) => return,
+        // an exact pattern still keeps the empty items, so counting them isn't the same
+        None if exact => return,
+        None => ("counting the items of `split` at spaces", format!("{method}().count()")),
+    };
+
+    span_lint_and_then(cx, SPLIT_WHITESPACE_COLLECT_COUNT, expr.span, msg, |diag| {
+        let app = if exact {
+            Applicability::MachineApplicable
+        } else {
+            diag.note(format!(
+                "consecutive spaces produce empty items, while `{method}` also splits at other whitespace, like tabs and newlines"
+            ));
+            Applicability::MaybeIncorrect
+        };
+        diag.span_suggestion(
+            split_span.with_hi(expr.span.hi()),
+            format!("use `{method}()`"),
+            replacement,
+            app,
+        );
+    });
+}
+
+/// Returns the whitespace-aware method to split at `pat`, and whether it splits at exactly the same
+/// characters.
+fn whitespace_method(cx: &LateContext<'_>, pat: &Expr<'_>) -> Option<(&'static str, bool)> {
+    if let Some(def_id) = path_def_id(cx, pat) {
+        return match_def_path(cx, def_id, &["core", "char", "methods", "<impl char>", "is_whitespace"])
+            .then_some(("split_whitespace", true));
+    }
+    if let ExprKind::Closure(&Closure { body, .. }) = pat.kind
+        && let body = cx.tcx.hir().body(body)
+        && let [param] = body.params
+        && let PatKind::Binding(_, param_id, ..) = param.pat.kind
+        && let Some((name, recv, [], _, _)) = method_call(peel_blocks(body.value))
+        && path_to_local_id(recv, param_id)
+    {
+        return match name {
+            "is_whitespace" => Some(("split_whitespace", true)),
+            "is_ascii_whitespace" => Some(("split_ascii_whitespace", true)),
+            _ => None,
+        };
+    }
+    match constant(cx, cx.typeck_results(), pat)? {
+        Constant::Char(' ') => Some(("split_whitespace", false)),
+        Constant::Str(s) if s == " " => Some(("split_whitespace", false)),
+        _ => None,
+    }
+}
+
+/// Whether `arg` is `|x| !x.is_empty()`, `|x| x.len() > 0`, `|x| x.len() != 0` or `|x| *x != ""`.
+fn is_non_empty_filter(cx: &LateContext<'_>, arg: &Expr<'_>) -> bool {
+    let ExprKind::Closure(&Closure { body, .. }) = arg.kind else {
+        return false;
+    };
+    let body = cx.tcx.hir().body(body);
+    let [param] = body.params else {
+        return false;
+    };
+    let PatKind::Binding(_, param_id, ..) = param.pat.kind else {
+        return false;
+    };
+    let is_param = |e: &Expr<'_>| {
+        let e = match e.kind {
+            ExprKind::Unary(UnOp::Deref, inner) => inner,
+            _ => e,
+        };
+        path_to_local_id(e, param_id)
+    };
+    let is_call_on_param = |e: &Expr<'_>, name: &str| matches!(method_call(e), Some((method, recv, [], _, _)) if method == name && is_param(recv));
+    match peel_blocks(body.value).kind {
+        ExprKind::Unary(UnOp::Not, inner) => is_call_on_param(inner, "is_empty"),
+        ExprKind::Binary(op, lhs, rhs) => match op.node {
+            BinOpKind::Gt | BinOpKind::Ne if is_call_on_param(lhs, "len") => is_integer_const(cx, rhs, 0),
+            BinOpKind::Ne => {
+                is_param(lhs)
+                    && matches!(rhs.kind, ExprKind::Lit(lit) if matches!(lit.node, LitKind::Str(s, _) if s.is_empty()))
+            },
+            _ => false,
+        },
+        _ => false,
+    }
+}
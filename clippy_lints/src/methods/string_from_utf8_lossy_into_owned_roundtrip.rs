@@ -0,0 +1,78 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use clippy_utils::usage::local_used_after_expr;
+use clippy_utils::{get_parent_expr, is_lint_allowed, is_trait_method, path_def_id, path_to_local};
+use rustc_errors::Applicability;
+use rustc_hir::{BorrowKind, Expr, ExprKind, LangItem, Mutability};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+use super::{STRING_FROM_UTF8_LOSSY_INTO_OWNED_ROUNDTRIP, UNNECESSARY_TO_OWNED};
+
+/// Checks `String::from_utf8_lossy(..).to_string()`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, recv: &'tcx Expr<'tcx>, name_span: Span) {
+    let ExprKind::Call(func, [bytes]) = recv.kind else {
+        return;
+    };
+    if !is_trait_method(cx, expr, sym::ToString) || !is_from_utf8_lossy(cx, func) || recv.span.from_expansion() {
+        return;
+    }
+    // `unnecessary_to_owned` lints `&String::from_utf8_lossy(..).to_string()`
+    if let Some(parent) = get_parent_expr(cx, expr)
+        && let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _) = parent.kind
+        && !is_lint_allowed(cx, UNNECESSARY_TO_OWNED, expr.hir_id)
+    {
+        return;
+    }
+
+    let owned_vec = match bytes.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, vec)
+            if let Some(local_id) = path_to_local(vec)
+                && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(vec), sym::Vec)
+                && !local_used_after_expr(cx, local_id, expr) =>
+        {
+            Some(vec)
+        },
+        _ => None,
+    };
+    span_lint_and_then(
+        cx,
+        STRING_FROM_UTF8_LOSSY_INTO_OWNED_ROUNDTRIP,
+        expr.span,
+        "`to_string` called on the result of `String::from_utf8_lossy`",
+        |diag| {
+            if let Some(vec) = owned_vec {
+                // other borrows of the `Vec` which are still alive would conflict with the move
+                let mut app = Applicability::MaybeIncorrect;
+                let ctxt = expr.span.ctxt();
+                let func = snippet_with_context(cx, func.span, ctxt, "..", &mut app).0;
+                let func = func.strip_suffix("_lossy").unwrap_or("String::from_utf8");
+                let vec = snippet_with_context(cx, vec.span, ctxt, "..", &mut app).0;
+                diag.span_suggestion(
+                    expr.span,
+                    "the `Vec` isn't used afterwards, so convert it without copying valid UTF-8",
+                    format!("{func}({vec}).unwrap_or_else(|e| {func}_lossy(e.as_bytes()).into_owned())"),
+                    app,
+                );
+                diag.help("or use `into_owned` to reuse the `String` allocated for invalid UTF-8");
+            } else {
+                diag.span_suggestion(
+                    name_span,
+                    "use `into_owned` to reuse the `String` allocated for invalid UTF-8",
+                    "into_owned",
+                    Applicability::MachineApplicable,
+                );
+            }
+        },
+    );
+}
+
+fn is_from_utf8_lossy(cx: &LateContext<'_>, func: &Expr<'_>) -> bool {
+    path_def_id(cx, func).map_or(false, |id| {
+        cx.tcx.item_name(id).as_str() == "from_utf8_lossy"
+            && cx.tcx.impl_of_method(id).map_or(false, |impl_id| {
+                is_type_lang_item(cx, cx.tcx.type_of(impl_id).subst_identity(), LangItem::String)
+            })
+    })
+}
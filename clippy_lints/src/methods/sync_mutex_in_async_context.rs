@@ -0,0 +1,37 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{match_def_path, paths};
+use rustc_hir::{Expr, GeneratorKind};
+use rustc_lint::LateContext;
+
+use super::SYNC_MUTEX_IN_ASYNC_CONTEXT;
+
+/// Checks `blocking_lock()`, `blocking_read()` and `blocking_write()` calls on the locks of
+/// `tokio::sync` outside of async bodies.
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, name: &str) {
+    let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) else {
+        return;
+    };
+    let (lock, std_lock) = if match_def_path(cx, method_id, &paths::TOKIO_MUTEX_BLOCKING_LOCK) {
+        ("tokio::sync::Mutex", "std::sync::Mutex")
+    } else if match_def_path(cx, method_id, &paths::TOKIO_RWLOCK_BLOCKING_READ)
+        || match_def_path(cx, method_id, &paths::TOKIO_RWLOCK_BLOCKING_WRITE)
+    {
+        ("tokio::sync::RwLock", "std::sync::RwLock")
+    } else {
+        return;
+    };
+    if cx.enclosing_body.map_or(true, |body| {
+        matches!(cx.tcx.hir().body(body).generator_kind, Some(GeneratorKind::Async(_)))
+    }) {
+        return;
+    }
+
+    span_lint_and_help(
+        cx,
+        SYNC_MUTEX_IN_ASYNC_CONTEXT,
+        expr.span,
+        &format!("`{lock}` locked with `{name}` in synchronous code"),
+        None,
+        &format!("if the lock is only used in synchronous code, consider using `{std_lock}` instead"),
+    );
+}
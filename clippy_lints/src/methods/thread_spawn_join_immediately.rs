@@ -0,0 +1,73 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{match_def_path, path_def_id, paths};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{CaptureBy, Closure, Expr, ExprKind, MatchSource};
+use rustc_lint::LateContext;
+
+use super::{method_call, THREAD_SPAWN_JOIN_IMMEDIATELY};
+
+/// Checks `thread::spawn(|| ..).join().unwrap()` and the same with `expect`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, recv: &'tcx Expr<'tcx>) {
+    let Some(("join", spawn_call, [], _, _)) = method_call(recv) else {
+        return;
+    };
+    let ExprKind::Call(func, [closure]) = spawn_call.kind else {
+        return;
+    };
+    let ExprKind::Closure(&Closure {
+        def_id,
+        capture_clause,
+        body,
+        ..
+    }) = closure.kind
+    else {
+        return;
+    };
+    if !path_def_id(cx, func).map_or(false, |id| match_def_path(cx, id, &paths::STD_THREAD_SPAWN))
+        || expr.span.from_expansion()
+    {
+        return;
+    }
+    // moving the captured values into the closure may be the point of it
+    if capture_clause == CaptureBy::Value
+        && cx
+            .typeck_results()
+            .closure_min_captures_flattened(def_id)
+            .next()
+            .is_some()
+    {
+        return;
+    }
+    let body = cx.tcx.hir().body(body);
+    // `return` and `?` would leave the enclosing function instead of the closure
+    let leaves_closure = for_each_expr(body.value, |e| match e.kind {
+        ExprKind::Ret(_) | ExprKind::Match(_, _, MatchSource::TryDesugar) => ControlFlow::Break(()),
+        ExprKind::Closure(_) => ControlFlow::Continue(Descend::No),
+        _ => ControlFlow::Continue(Descend::Yes),
+    })
+    .is_some();
+    if leaves_closure {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        THREAD_SPAWN_JOIN_IMMEDIATELY,
+        expr.span,
+        "spawning a thread and joining it right away",
+        |diag| {
+            // a panic in the closure isn't reported by the spawned thread anymore
+            let mut app = Applicability::MaybeIncorrect;
+            let sugg = Sugg::hir_with_context(cx, body.value, expr.span.ctxt(), "..", &mut app);
+            diag.span_suggestion(
+                expr.span,
+                "the code doesn't run concurrently, run it directly",
+                sugg,
+                app,
+            );
+        },
+    );
+}
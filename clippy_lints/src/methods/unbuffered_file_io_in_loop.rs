@@ -0,0 +1,51 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{get_enclosing_loop_or_multi_call_closure, is_trait_method, path_to_local};
+use rustc_hir::{Expr, Node};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::UNBUFFERED_FILE_IO_IN_LOOP;
+
+/// Checks `file.read(..)`, `file.write(..)` and similar calls on a `File` inside of a loop,
+/// where the file was opened outside of it.
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, name: &str, recv: &Expr<'_>) {
+    let (is_read, wrapper) = if is_trait_method(cx, expr, sym::IoRead) {
+        (true, "BufReader")
+    } else if is_trait_method(cx, expr, sym::IoWrite) {
+        (false, "BufWriter")
+    } else {
+        return;
+    };
+    let Some(local_id) = path_to_local(recv) else {
+        return;
+    };
+    if !is_type_diagnostic_item(cx, cx.typeck_results().expr_ty_adjusted(recv).peel_refs(), sym::File) {
+        return;
+    }
+    // a file opened in each iteration is only accessed a few times
+    let Some(enclosing_loop) = get_enclosing_loop_or_multi_call_closure(cx, expr) else {
+        return;
+    };
+    if enclosing_loop.span.contains(cx.tcx.hir().span(local_id)) {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        UNBUFFERED_FILE_IO_IN_LOOP,
+        expr.span,
+        &format!("calling `File::{name}` in a loop without buffering"),
+        |diag| {
+            if let Some(Node::Local(local)) = cx.tcx.hir().find_parent(local_id)
+                && let Some(init) = local.init
+            {
+                diag.span_note(init.span, "the file is opened here");
+            }
+            diag.help(format!(
+                "every call is a separate system call, consider wrapping the file in a `{wrapper}`{}",
+                if is_read { "" } else { " and flushing it after the loop" }
+            ));
+        },
+    );
+}
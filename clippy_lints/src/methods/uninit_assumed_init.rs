@@ -1,26 +1,77 @@
-use clippy_utils::diagnostics::span_lint;
-use clippy_utils::{is_path_diagnostic_item, ty::is_uninit_value_valid_for_ty};
-use if_chain::if_chain;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{expr_or_init, is_path_diagnostic_item, ty::is_uninit_value_valid_for_ty};
 use rustc_hir as hir;
 use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
 use rustc_span::sym;
 
 use super::UNINIT_ASSUMED_INIT;
 
 /// lint for `MaybeUninit::uninit().assume_init()` (we already have the latter)
 pub(super) fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>) {
-    if_chain! {
-        if let hir::ExprKind::Call(callee, args) = recv.kind;
-        if args.is_empty();
-        if is_path_diagnostic_item(cx, callee, sym::maybe_uninit_uninit);
-        if !is_uninit_value_valid_for_ty(cx, cx.typeck_results().expr_ty_adjusted(expr));
-        then {
-            span_lint(
-                cx,
-                UNINIT_ASSUMED_INIT,
-                expr.span,
-                "this call for this type may be undefined behavior"
-            );
-        }
+    let recv = match expr_or_init(cx, recv).kind {
+        // an element of `[MaybeUninit::uninit(); N]`
+        hir::ExprKind::Index(base, _) => expr_or_init(cx, base),
+        _ => recv,
+    };
+    if is_uninit(cx, expr_or_init(cx, recv)) {
+        lint(cx, expr, cx.typeck_results().expr_ty_adjusted(expr));
+    }
+}
+
+/// lint for `transmute(MaybeUninit::uninit())`
+pub(super) fn check_transmute(
+    cx: &LateContext<'_>,
+    expr: &hir::Expr<'_>,
+    func: &hir::Expr<'_>,
+    args: &[hir::Expr<'_>],
+) {
+    if let [arg] = args
+        && is_path_diagnostic_item(cx, func, sym::transmute)
+        && is_uninit(cx, expr_or_init(cx, arg))
+    {
+        lint(cx, expr, cx.typeck_results().expr_ty(expr));
+    }
+}
+
+/// Whether `e` is `MaybeUninit::uninit()`, or an array of them.
+fn is_uninit(cx: &LateContext<'_>, e: &hir::Expr<'_>) -> bool {
+    match e.kind {
+        hir::ExprKind::Call(callee, []) => is_path_diagnostic_item(cx, callee, sym::maybe_uninit_uninit),
+        hir::ExprKind::Repeat(elem, _) => is_uninit(cx, elem),
+        hir::ExprKind::Array(elems) => !elems.is_empty() && elems.iter().all(|elem| is_uninit(cx, elem)),
+        _ => false,
+    }
+}
+
+fn lint<'tcx>(cx: &LateContext<'tcx>, expr: &hir::Expr<'_>, ty: Ty<'tcx>) {
+    if is_uninit_value_valid_for_ty(cx, ty) {
+        return;
+    }
+    span_lint_and_then(
+        cx,
+        UNINIT_ASSUMED_INIT,
+        expr.span,
+        "this call for this type may be undefined behavior",
+        |diag| {
+            if let Some(reason) = reason(ty) {
+                diag.note(reason);
+            }
+        },
+    );
+}
+
+/// Explains why `ty` doesn't allow being uninitialized, for the less obvious cases.
+fn reason(ty: Ty<'_>) -> Option<String> {
+    match ty.kind() {
+        ty::Param(_) => Some(format!(
+            "`{ty}` is generic, so it may be a type which doesn't allow being uninitialized"
+        )),
+        ty::Ref(..) => Some("references must always point to valid memory".to_owned()),
+        ty::Adt(adt, _) if adt.is_box() => Some("`Box`es must always point to valid memory".to_owned()),
+        ty::Adt(adt, _) if adt.is_enum() => Some(format!("the discriminant of `{ty}` must always be valid")),
+        ty::Array(elem, _) => reason(*elem),
+        ty::Tuple(tys) => tys.iter().find_map(reason),
+        _ => None,
     }
 }
@@ -84,6 +84,29 @@ pub(super) fn get_hint_if_single_char_arg(
     }
 }
 
+/// Returns the snippet of `c` if `arg` is `&c.to_string()` or `c.to_string().as_str()` for a
+/// `c` of type `char`.
+pub(super) fn get_char_if_to_string_arg(
+    cx: &LateContext<'_>,
+    arg: &hir::Expr<'_>,
+    applicability: &mut Applicability,
+) -> Option<String> {
+    let to_string_call = match arg.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, inner) => inner,
+        ExprKind::MethodCall(path, inner, [], _) if path.ident.name == sym::as_str => inner,
+        _ => return None,
+    };
+    if let ExprKind::MethodCall(path, ch, [], _) = to_string_call.kind
+        && path.ident.name == sym::to_string
+        && cx.typeck_results().expr_ty(ch).is_char()
+        && !arg.span.from_expansion()
+    {
+        Some(snippet_with_applicability(cx, ch.span, "..", applicability).into_owned())
+    } else {
+        None
+    }
+}
+
 /// The core logic of `check_for_loop_iter` in `unnecessary_iter_cloned.rs`, this function wraps a
 /// use of `CloneOrCopyVisitor`.
 pub(super) fn clone_or_copy_needed<'tcx>(
@@ -1,4 +1,5 @@
 use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::eager_or_lazy::switch_to_lazy_eval;
 use clippy_utils::ty::is_type_diagnostic_item;
 use if_chain::if_chain;
 use rustc_ast::LitKind;
@@ -22,7 +23,6 @@ pub(super) fn check<'tcx>(
         if let Some(impl_id) = cx.tcx.impl_of_method(method_id);
         if is_type_diagnostic_item(cx, cx.tcx.type_of(impl_id).subst_identity(), sym::Vec);
         if let ExprKind::Lit(Spanned { node: LitKind::Int(0, _), .. }) = count_arg.kind;
-        if let ExprKind::Lit(Spanned { node: LitKind::Int(..), .. }) = default_arg.kind;
         then {
             let method_call_span = expr.span.with_lo(name_span.lo());
             span_lint_and_then(
@@ -31,13 +31,23 @@ pub(super) fn check<'tcx>(
                 expr.span,
                 "emptying a vector with `resize`",
                 |db| {
-                    db.help("the arguments may be inverted...");
-                    db.span_suggestion(
-                        method_call_span,
-                        "...or you can empty the vector with",
-                        "clear()".to_string(),
-                        Applicability::MaybeIncorrect,
-                    );
+                    if let ExprKind::Lit(Spanned { node: LitKind::Int(..), .. }) = default_arg.kind {
+                        db.help("the arguments may be inverted...");
+                        db.span_suggestion(
+                            method_call_span,
+                            "...or you can empty the vector with",
+                            "clear()".to_string(),
+                            Applicability::MaybeIncorrect,
+                        );
+                    } else {
+                        // the default value would not be evaluated anymore
+                        let app = if switch_to_lazy_eval(cx, default_arg) {
+                            Applicability::MaybeIncorrect
+                        } else {
+                            Applicability::MachineApplicable
+                        };
+                        db.span_suggestion(method_call_span, "empty the vector with", "clear()".to_string(), app);
+                    }
                 },
             );
         }
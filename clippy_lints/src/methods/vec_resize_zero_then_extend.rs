@@ -0,0 +1,62 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::is_integer_const;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, HirId, QPath};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+use super::VEC_RESIZE_ZERO_THEN_EXTEND;
+
+/// Checks `vec.truncate(0)`.
+pub(super) fn check_truncate(cx: &LateContext<'_>, expr: &Expr<'_>, arg: &Expr<'_>, name_span: Span) {
+    if is_vec_method(cx, expr.hir_id) && is_integer_const(cx, arg, 0) {
+        emit(
+            cx,
+            name_span.with_hi(expr.span.hi()),
+            "truncate(0)",
+            "clear()",
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+/// Checks `Vec::with_capacity(0)`.
+pub(super) fn check_with_capacity(cx: &LateContext<'_>, expr: &Expr<'_>, func: &Expr<'_>, args: &[Expr<'_>]) {
+    if let ExprKind::Path(QPath::TypeRelative(_, segment)) = func.kind
+        && segment.ident.name == sym!(with_capacity)
+        && let [arg] = args
+        && is_vec_method(cx, func.hir_id)
+        && is_integer_const(cx, arg, 0)
+    {
+        emit(
+            cx,
+            segment.ident.span.with_hi(expr.span.hi()),
+            "with_capacity(0)",
+            "new()",
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+/// Whether `hir_id` is a call of a method defined on `Vec`.
+fn is_vec_method(cx: &LateContext<'_>, hir_id: HirId) -> bool {
+    cx.typeck_results()
+        .type_dependent_def_id(hir_id)
+        .and_then(|id| cx.tcx.impl_of_method(id))
+        .map_or(false, |impl_id| {
+            is_type_diagnostic_item(cx, cx.tcx.type_of(impl_id).subst_identity(), sym::Vec)
+        })
+}
+
+fn emit(cx: &LateContext<'_>, span: Span, call: &str, replacement: &str, app: Applicability) {
+    span_lint_and_sugg(
+        cx,
+        VEC_RESIZE_ZERO_THEN_EXTEND,
+        span,
+        &format!("calling `{call}` on a `Vec`"),
+        &format!("use `{replacement}` instead"),
+        replacement.to_owned(),
+        app,
+    );
+}
@@ -1,15 +1,16 @@
-use clippy_utils::diagnostics::span_lint;
+use crate::hash_ignores_field_used_in_eq::used_fields;
+use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
 use clippy_utils::ty::is_interior_mut_ty;
 use clippy_utils::{def_path_def_ids, trait_ref_of_method};
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::query::Key;
-use rustc_middle::ty::{Adt, Ty};
+use rustc_middle::ty::{self, Adt, FieldDef, Ty, TypeVisitableExt};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::def_id::LocalDefId;
 use rustc_span::source_map::Span;
-use rustc_span::symbol::sym;
+use rustc_span::symbol::{sym, Symbol};
 use std::iter;
 
 declare_clippy_lint! {
@@ -81,13 +82,64 @@ declare_clippy_lint! {
     "Check for mutable `Map`/`Set` key type"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for sets/maps with key types whose `Hash` (for `HashMap`/`HashSet`) or `Ord` (for
+    /// `BTreeMap`/`BTreeSet`) implementation reads a field with interior mutability behind a
+    /// reference or smart pointer, like `Arc<Mutex<T>>`, `&AtomicUsize` or `Rc<Cell<T>>`.
+    ///
+    /// ### Why is this bad?
+    /// This is the case [`mutable_key_type`] can't see: the key itself doesn't have interior
+    /// mutability, but its hash or order can still change while it's in the collection, which
+    /// then can't find it anymore.
+    ///
+    /// ### Known problems
+    /// Only the fields used by the implementation are checked, not how they are used. Hashing
+    /// just the address of an `Arc` is fine, but is linted as well.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::collections::HashSet;
+    /// # use std::hash::{Hash, Hasher};
+    /// # use std::sync::{Arc, Mutex};
+    /// #[derive(PartialEq, Eq)]
+    /// struct Session {
+    ///     user: Arc<Mutex<String>>,
+    /// }
+    ///
+    /// impl Hash for Session {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.user.lock().unwrap().hash(state);
+    ///     }
+    /// }
+    ///
+    /// let sessions: HashSet<Session> = HashSet::new();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::HashSet;
+    /// # use std::hash::{Hash, Hasher};
+    /// # use std::sync::{Arc, Mutex};
+    /// #[derive(PartialEq, Eq, Hash)]
+    /// struct Session {
+    ///     id: u64,
+    /// }
+    ///
+    /// let sessions: HashSet<Session> = HashSet::new();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub INTERIOR_MUTABLE_KEY_EXTENDED,
+    pedantic,
+    "map/set key type whose `Hash` or `Ord` implementation reads interior mutable data behind a pointer"
+}
+
 #[derive(Clone)]
 pub struct MutableKeyType {
     ignore_interior_mutability: Vec<String>,
     ignore_mut_def_ids: FxHashSet<hir::def_id::DefId>,
 }
 
-impl_lint_pass!(MutableKeyType => [ MUTABLE_KEY_TYPE ]);
+impl_lint_pass!(MutableKeyType => [MUTABLE_KEY_TYPE, INTERIOR_MUTABLE_KEY_EXTENDED]);
 
 impl<'tcx> LateLintPass<'tcx> for MutableKeyType {
     fn check_crate(&mut self, cx: &LateContext<'tcx>) {
@@ -151,12 +203,11 @@ impl MutableKeyType {
     fn check_ty_<'tcx>(&self, cx: &LateContext<'tcx>, span: Span, ty: Ty<'tcx>) {
         let ty = ty.peel_refs();
         if let Adt(def, substs) = ty.kind() {
-            let is_keyed_type = [sym::HashMap, sym::BTreeMap, sym::HashSet, sym::BTreeSet]
-                .iter()
-                .any(|diag_item| cx.tcx.is_diagnostic_item(*diag_item, def.did()));
-            if !is_keyed_type {
-                return;
-            }
+            let (trait_item, method) = match cx.tcx.get_diagnostic_name(def.did()) {
+                Some(sym::HashMap | sym::HashSet) => (sym::Hash, sym::hash),
+                Some(sym::BTreeMap | sym::BTreeSet) => (sym::Ord, sym::cmp),
+                _ => return,
+            };
 
             let subst_ty = substs.type_at(0);
             // Determines if a type contains interior mutability which would affect its implementation of
@@ -165,7 +216,83 @@ impl MutableKeyType {
                 && !matches!(subst_ty.ty_adt_id(), Some(adt_id) if self.ignore_mut_def_ids.contains(&adt_id))
             {
                 span_lint(cx, MUTABLE_KEY_TYPE, span, "mutable key type");
+            } else if let Some(field) = self.interior_mut_field_read_by(cx, subst_ty, trait_item, method) {
+                span_lint_and_then(cx, INTERIOR_MUTABLE_KEY_EXTENDED, span, "mutable key type", |diag| {
+                    diag.span_note(
+                        cx.tcx.def_span(field.did),
+                        format!(
+                            "`{trait_item}` for `{subst_ty}` reads this field, which has interior mutability \
+                                 behind a pointer"
+                        ),
+                    );
+                });
             }
         }
     }
+
+    /// Finds a field of the local struct `ty` which is read by its implementation of
+    /// `trait_item`, and has interior mutability behind a reference or smart pointer.
+    fn interior_mut_field_read_by<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        ty: Ty<'tcx>,
+        trait_item: Symbol,
+        method: Symbol,
+    ) -> Option<&'tcx FieldDef> {
+        let Adt(def, substs) = ty.kind() else {
+            return None;
+        };
+        if !def.is_struct() || !def.did().is_local() {
+            return None;
+        }
+        let trait_id = cx.tcx.get_diagnostic_item(trait_item)?;
+        let mut impl_id = None;
+        cx.tcx.for_each_relevant_impl(trait_id, ty, |id| {
+            if id.is_local() {
+                impl_id = impl_id.or(Some(id));
+            }
+        });
+        let impl_id = impl_id?;
+        // derived implementations read all the fields
+        let read_fields = if cx.tcx.has_attr(impl_id, sym::automatically_derived) {
+            None
+        } else {
+            let body = cx
+                .tcx
+                .associated_items(impl_id)
+                .filter_by_name_unhygienic(method)
+                .next()
+                .and_then(|item| item.def_id.as_local())
+                .and_then(|id| cx.tcx.hir().maybe_body_owned_by(id))?;
+            Some(used_fields(cx.tcx.hir().body(body), 1)?)
+        };
+        def.non_enum_variant().fields.iter().find(|field| {
+            read_fields.as_ref().map_or(true, |fields| fields.contains(&field.name))
+                && self.has_interior_mut_behind_pointer(cx, field.ty(cx.tcx, substs))
+        })
+    }
+
+    fn has_interior_mut_behind_pointer<'tcx>(&self, cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+        match *ty.kind() {
+            ty::Ref(_, inner_ty, _) | ty::Slice(inner_ty) | ty::Array(inner_ty, _) => {
+                self.has_interior_mut_behind_pointer(cx, inner_ty)
+            },
+            ty::Tuple(fields) => fields.iter().any(|ty| self.has_interior_mut_behind_pointer(cx, ty)),
+            Adt(def, substs)
+                if def.is_box()
+                    || [sym::Option, sym::Vec, sym::Rc, sym::Arc]
+                        .iter()
+                        .any(|diag_item| cx.tcx.is_diagnostic_item(*diag_item, def.did())) =>
+            {
+                substs.types().any(|ty| self.has_interior_mut_behind_pointer(cx, ty))
+            },
+            Adt(def, _) => {
+                !self.ignore_mut_def_ids.contains(&def.did())
+                    && !ty.has_escaping_bound_vars()
+                    && cx.tcx.layout_of(cx.param_env.and(ty)).is_ok()
+                    && !ty.is_freeze(cx.tcx, cx.param_env)
+            },
+            _ => false,
+        }
+    }
 }
@@ -0,0 +1,298 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::higher::IfLet;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{is_res_lang_ctor, return_ty};
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::LangItem::{OptionSome, ResultOk};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, FnRetTy, Impl, ItemKind, MatchSource, Node, Pat, PatKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for values of type `Option<Option<T>>` or `Result<Result<T, E>, E>` which are
+    /// unwrapped twice or matched against `Some(Some(..))` or `Ok(Ok(..))` right away, and for
+    /// functions returning `Result<Result<T, E>, E>`.
+    ///
+    /// ### Why is this bad?
+    /// Both layers mean the same thing, so they can be flattened into one with
+    /// `Option::flatten` or `Result::and_then(std::convert::identity)`. A single layer is
+    /// easier to handle by the callers.
+    ///
+    /// ### Known problems
+    /// Sometimes the layers do mean different things, e.g. a failed connection and a failed
+    /// request, even though they share an error type.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let x = Some(Some(1));
+    /// if let Some(Some(y)) = x {
+    ///     println!("{y}");
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = Some(Some(1));
+    /// if let Some(y) = x.flatten() {
+    ///     println!("{y}");
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub NESTED_OPTION_RESULT_FLATTENABLE,
+    pedantic,
+    "nested `Option`s or `Result`s which could be flattened into one"
+}
+
+pub struct NestedOptionResultFlattenable {
+    msrv: Msrv,
+    avoid_breaking_exported_api: bool,
+}
+
+impl NestedOptionResultFlattenable {
+    #[must_use]
+    pub fn new(msrv: Msrv, avoid_breaking_exported_api: bool) -> Self {
+        Self {
+            msrv,
+            avoid_breaking_exported_api,
+        }
+    }
+}
+
+impl_lint_pass!(NestedOptionResultFlattenable => [NESTED_OPTION_RESULT_FLATTENABLE]);
+
+#[derive(Clone, Copy)]
+enum Nested {
+    Option,
+    Result,
+}
+
+impl Nested {
+    /// Checks if `ty` is `Option<Option<T>>` or `Result<Result<T, E>, E>`.
+    fn of_ty<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<Self> {
+        let ty::Adt(_, substs) = ty.kind() else {
+            return None;
+        };
+        let inner = substs.types().next()?;
+        if is_type_diagnostic_item(cx, ty, sym::Option) && is_type_diagnostic_item(cx, inner, sym::Option) {
+            Some(Self::Option)
+        } else if is_type_diagnostic_item(cx, ty, sym::Result)
+            && is_type_diagnostic_item(cx, inner, sym::Result)
+            && let ty::Adt(_, inner_substs) = inner.kind()
+            && substs.type_at(1) == inner_substs.type_at(1)
+        {
+            Some(Self::Result)
+        } else {
+            None
+        }
+    }
+
+    fn flatten(self) -> &'static str {
+        match self {
+            Self::Option => "flatten()",
+            Self::Result => "and_then(std::convert::identity)",
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Option => "Option",
+            Self::Result => "Result",
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for NestedOptionResultFlattenable {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        if let Some(if_let) = IfLet::hir(cx, expr) {
+            self.check_nested_pat(cx, expr, if_let.let_expr, if_let.let_pat);
+        } else if let ExprKind::Match(scrutinee, [first, second], MatchSource::Normal) = expr.kind
+            && first.guard.is_none()
+            && let PatKind::Wild = second.pat.kind
+        {
+            self.check_nested_pat(cx, expr, scrutinee, first.pat);
+        } else {
+            self.check_unwraps(cx, expr);
+        }
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'tcx>,
+        _: &'tcx Body<'tcx>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        // `option_option` lints `Option<Option<T>>` in signatures
+        let FnRetTy::Return(ret_ty) = decl.output else {
+            return;
+        };
+        if matches!(kind, FnKind::Closure)
+            || in_external_macro(cx.sess(), span)
+            || (self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(def_id))
+        {
+            return;
+        }
+        let hir_id = cx.tcx.hir().local_def_id_to_hir_id(def_id);
+        if let Some(Node::Item(item)) = cx.tcx.hir().find_parent(hir_id)
+            && matches!(item.kind, ItemKind::Impl(Impl { of_trait: Some(_), .. }))
+        {
+            return;
+        }
+        if let Some(Nested::Result) = Nested::of_ty(cx, return_ty(cx, hir_id.expect_owner())) {
+            span_lint_and_help(
+                cx,
+                NESTED_OPTION_RESULT_FLATTENABLE,
+                ret_ty.span,
+                "this function returns a `Result` nested in a `Result` with the same error type",
+                None,
+                "consider returning a single `Result`",
+            );
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+impl NestedOptionResultFlattenable {
+    /// Checks `if let Some(Some(x)) = e` and `match e { Some(Some(x)) => .., _ => .. }`.
+    fn check_nested_pat(&self, cx: &LateContext<'_>, expr: &Expr<'_>, scrutinee: &Expr<'_>, pat: &Pat<'_>) {
+        let Some(nested) = Nested::of_ty(cx, cx.typeck_results().expr_ty(scrutinee)) else {
+            return;
+        };
+        let Some(inner) = nested_pat(cx, pat).and_then(|inner| nested_pat(cx, inner).map(|_| inner)) else {
+            return;
+        };
+        if expr.span.from_expansion() || !self.meets_msrv(nested) {
+            return;
+        }
+
+        let mut app = Applicability::MachineApplicable;
+        let ctxt = expr.span.ctxt();
+        let inner_snip = snippet_with_context(cx, inner.span, ctxt, "..", &mut app).0;
+        // `e.map(f).flatten()` is better written as `e.and_then(f)`
+        let (flatten, scrutinee_sugg) = if let Some(map_span) = map_call(cx, scrutinee, nested) {
+            ("and_then", (map_span, "and_then".to_owned()))
+        } else {
+            let scrutinee_sugg = Sugg::hir_with_context(cx, scrutinee, ctxt, "..", &mut app).maybe_par();
+            (
+                nested.flatten(),
+                (scrutinee.span, format!("{scrutinee_sugg}.{}", nested.flatten())),
+            )
+        };
+        span_lint_and_then(
+            cx,
+            NESTED_OPTION_RESULT_FLATTENABLE,
+            pat.span,
+            &format!("matching both layers of a nested `{}`", nested.name()),
+            |diag| {
+                diag.multipart_suggestion(
+                    format!("use `{flatten}` and match only one layer"),
+                    vec![(pat.span, inner_snip.into_owned()), scrutinee_sugg],
+                    app,
+                );
+            },
+        );
+    }
+
+    /// Checks `e.unwrap().unwrap()`, as well as the same with `expect`.
+    fn check_unwraps(&self, cx: &LateContext<'_>, expr: &Expr<'_>) {
+        let ExprKind::MethodCall(outer, inner_call, _, _) = expr.kind else {
+            return;
+        };
+        let ExprKind::MethodCall(inner, recv, inner_args, _) = inner_call.kind else {
+            return;
+        };
+        if !matches!(outer.ident.as_str(), "unwrap" | "expect") || !matches!(inner.ident.as_str(), "unwrap" | "expect")
+        {
+            return;
+        }
+        let Some(nested) = Nested::of_ty(cx, cx.typeck_results().expr_ty(recv)) else {
+            return;
+        };
+        if expr.span.from_expansion() || inner_call.span.from_expansion() || !self.meets_msrv(nested) {
+            return;
+        }
+
+        // the message of the inner `expect` is lost
+        let app = if inner_args.is_empty() {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
+        let (flatten, suggs) = if let Some(map_span) = map_call(cx, recv, nested) {
+            (
+                "and_then",
+                vec![
+                    (map_span, "and_then".to_owned()),
+                    (recv.span.shrink_to_hi().with_hi(inner_call.span.hi()), String::new()),
+                ],
+            )
+        } else {
+            (
+                nested.flatten(),
+                vec![(
+                    inner.ident.span.with_hi(inner_call.span.hi()),
+                    nested.flatten().to_owned(),
+                )],
+            )
+        };
+        span_lint_and_then(
+            cx,
+            NESTED_OPTION_RESULT_FLATTENABLE,
+            expr.span,
+            &format!("unwrapping both layers of a nested `{}`", nested.name()),
+            |diag| {
+                diag.multipart_suggestion(format!("use `{flatten}` and unwrap only once"), suggs, app);
+            },
+        );
+    }
+
+    fn meets_msrv(&self, nested: Nested) -> bool {
+        match nested {
+            Nested::Option => self.msrv.meets(msrvs::OPTION_FLATTEN),
+            Nested::Result => true,
+        }
+    }
+}
+
+/// If `e` is `x.map(f)` on the outer `Option` or `Result`, returns the span of `map`.
+fn map_call(cx: &LateContext<'_>, e: &Expr<'_>, nested: Nested) -> Option<Span> {
+    if let ExprKind::MethodCall(path, recv, [_], _) = e.kind
+        && path.ident.name == sym::map
+        && !path.ident.span.from_expansion()
+    {
+        let recv_ty = cx.typeck_results().expr_ty(recv);
+        let item = match nested {
+            Nested::Option => sym::Option,
+            Nested::Result => sym::Result,
+        };
+        is_type_diagnostic_item(cx, recv_ty, item).then_some(path.ident.span)
+    } else {
+        None
+    }
+}
+
+/// If `pat` is `Some(inner)` or `Ok(inner)`, returns `inner`.
+fn nested_pat<'a>(cx: &LateContext<'_>, pat: &'a Pat<'a>) -> Option<&'a Pat<'a>> {
+    if let PatKind::TupleStruct(ref qpath, [inner], dotdot) = pat.kind
+        && dotdot.as_opt_usize().is_none()
+    {
+        let res = cx.qpath_res(qpath, pat.hir_id);
+        (is_res_lang_ctor(cx, res, OptionSome) || is_res_lang_ctor(cx, res, ResultOk)).then_some(inner)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,98 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::match_type;
+use clippy_utils::{expr_or_init, match_def_path, path_def_id, path_to_local, paths};
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `SystemTime`s which are taken with `SystemTime::now()` and stored to measure
+    /// elapsed time, with `elapsed`, `duration_since` or by comparing them with a later
+    /// `SystemTime::now()`.
+    ///
+    /// ### Why is this bad?
+    /// `SystemTime` follows the wall clock, which can jump backwards or forwards when it's
+    /// adjusted, for example by NTP. `elapsed` and `duration_since` then return an error (and
+    /// often panic on the `unwrap`), and comparisons give the wrong result. `Instant` is
+    /// monotonic and meant for measuring durations.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::time::SystemTime;
+    /// let start = SystemTime::now();
+    /// // ...
+    /// let elapsed = start.elapsed().unwrap();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::time::Instant;
+    /// let start = Instant::now();
+    /// // ...
+    /// let elapsed = start.elapsed();
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub NON_MONOTONIC_TIME_FOR_DURATIONS,
+    suspicious,
+    "measuring elapsed time with `SystemTime` instead of `Instant`"
+}
+
+declare_lint_pass!(NonMonotonicTimeForDurations => [NON_MONOTONIC_TIME_FOR_DURATIONS]);
+
+impl<'tcx> LateLintPass<'tcx> for NonMonotonicTimeForDurations {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        let msg = match expr.kind {
+            ExprKind::MethodCall(path, recv, args, _)
+                if match_type(
+                    cx,
+                    cx.typeck_results().expr_ty_adjusted(recv).peel_refs(),
+                    &paths::SYSTEM_TIME,
+                ) =>
+            {
+                let start = match (path.ident.as_str(), args) {
+                    ("elapsed", []) => recv,
+                    ("duration_since", [start]) if is_now(cx, recv) => start,
+                    _ => return,
+                };
+                if !is_stored_now(cx, start) {
+                    return;
+                }
+                "measuring elapsed time with `SystemTime`"
+            },
+            ExprKind::Binary(op, lhs, rhs)
+                if matches!(op.node, BinOpKind::Lt | BinOpKind::Le | BinOpKind::Gt | BinOpKind::Ge)
+                    && match_type(cx, cx.typeck_results().expr_ty(lhs), &paths::SYSTEM_TIME)
+                    && is_now(cx, lhs)
+                    && is_now(cx, rhs)
+                    && (is_stored_now(cx, lhs) || is_stored_now(cx, rhs)) =>
+            {
+                "comparing `SystemTime`s to measure elapsed time"
+            },
+            _ => return,
+        };
+
+        span_lint_and_then(cx, NON_MONOTONIC_TIME_FOR_DURATIONS, expr.span, msg, |diag| {
+            diag.note("`SystemTime` can go backwards or jump forwards when the system clock is adjusted");
+            diag.help("use `Instant`, which is monotonic");
+        });
+    }
+}
+
+/// Whether `e` is `SystemTime::now()`, possibly offset by a `Duration` or stored in a local.
+fn is_now(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    match expr_or_init(cx, e).kind {
+        ExprKind::Call(func, []) => {
+            path_def_id(cx, func).map_or(false, |id| match_def_path(cx, id, &paths::SYSTEM_TIME_NOW))
+        },
+        ExprKind::Binary(op, time, _) if matches!(op.node, BinOpKind::Add | BinOpKind::Sub) => is_now(cx, time),
+        _ => false,
+    }
+}
+
+/// Whether `e` is a local holding an earlier `SystemTime::now()`.
+fn is_stored_now(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    path_to_local(e).is_some() && is_now(cx, e)
+}
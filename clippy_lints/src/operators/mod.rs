@@ -17,6 +17,7 @@ mod modulo_one;
 mod needless_bitwise_bool;
 mod numeric_arithmetic;
 mod op_ref;
+mod osstr_lossy_comparison;
 mod ptr_eq;
 mod self_assignment;
 mod verbose_bit_mask;
@@ -754,6 +755,34 @@ declare_clippy_lint! {
     "explicit self-assignment"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for comparisons of a `Path` or an `OsStr` with a string, where the `Path` is
+    /// converted with `to_string_lossy()` or `to_str().unwrap()` first.
+    ///
+    /// ### Why is this bad?
+    /// `OsStr` can be compared with a `str` directly. This doesn't allocate, doesn't panic on
+    /// paths that aren't valid UTF-8 and doesn't replace any of their characters.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::path::Path;
+    /// let path = Path::new("foo.txt");
+    /// if path.to_string_lossy() == "foo.txt" {}
+    /// if path.to_str().unwrap() == "foo.txt" {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::path::Path;
+    /// let path = Path::new("foo.txt");
+    /// if path.as_os_str() == "foo.txt" {}
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub OSSTR_LOSSY_COMPARISON,
+    pedantic,
+    "converting a `Path` or an `OsStr` to a `str` only to compare it with a string"
+}
+
 pub struct Operators {
     arithmetic_context: numeric_arithmetic::Context,
     verbose_bit_mask_threshold: u64,
@@ -784,6 +813,7 @@ impl_lint_pass!(Operators => [
     NEEDLESS_BITWISE_BOOL,
     PTR_EQ,
     SELF_ASSIGNMENT,
+    OSSTR_LOSSY_COMPARISON,
 ]);
 impl Operators {
     pub fn new(verbose_bit_mask_threshold: u64) -> Self {
@@ -808,6 +838,7 @@ impl<'tcx> LateLintPass<'tcx> for Operators {
                     identity_op::check(cx, e, op.node, lhs, rhs);
                     needless_bitwise_bool::check(cx, e, op.node, lhs, rhs);
                     ptr_eq::check(cx, e, op.node, lhs, rhs);
+                    osstr_lossy_comparison::check(cx, e, op.node, lhs, rhs);
                 }
                 self.arithmetic_context.check_binary(cx, e, op.node, lhs, rhs);
                 bit_mask::check(cx, e, op.node, lhs, rhs);
@@ -0,0 +1,90 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::sym;
+
+use super::OSSTR_LOSSY_COMPARISON;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    op: BinOpKind,
+    left: &'tcx Expr<'_>,
+    right: &'tcx Expr<'_>,
+) {
+    if !matches!(op, BinOpKind::Eq | BinOpKind::Ne) {
+        return;
+    }
+    let conversion = if is_str_const(cx, right) {
+        left
+    } else if is_str_const(cx, left) {
+        right
+    } else {
+        return;
+    };
+    let Some((recv, method)) = lossy_conversion(cx, conversion) else {
+        return;
+    };
+    let mut app = Applicability::MachineApplicable;
+    let recv_sugg = Sugg::hir_with_context(cx, recv, expr.span.ctxt(), "..", &mut app).maybe_par();
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    let os_str = match *recv_ty.kind() {
+        ty::Ref(_, inner, _) if is_type_diagnostic_item(cx, inner, sym::OsStr) => recv_sugg.to_string(),
+        _ if is_type_diagnostic_item(cx, recv_ty.peel_refs(), sym::OsStr) => return,
+        _ => format!("{recv_sugg}.as_os_str()"),
+    };
+    span_lint_and_sugg(
+        cx,
+        OSSTR_LOSSY_COMPARISON,
+        conversion.span,
+        &format!("converting with `{method}` only to compare with a string"),
+        "compare the `OsStr` directly",
+        os_str,
+        app,
+    );
+}
+
+/// Whether `e` is a constant string, which can be compared with an `OsStr` directly. Strings
+/// containing the replacement character could also be equal to a lossily converted string.
+fn is_str_const(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    matches!(constant(cx, cx.typeck_results(), e), Some(Constant::Str(s)) if !s.contains('\u{FFFD}'))
+}
+
+/// If `e` is `x.to_string_lossy()`, `x.to_str().unwrap()` or `x.to_str().expect(..)` on an
+/// `OsStr` or a `Path`, returns `x` and the name of the conversion.
+fn lossy_conversion<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'static str)> {
+    let ExprKind::MethodCall(path, recv, args, _) = e.kind else {
+        return None;
+    };
+    match (path.ident.as_str(), args) {
+        ("to_string_lossy", []) if is_os_str_method(cx, e) => Some((recv, "to_string_lossy()")),
+        ("unwrap", []) | ("expect", [_]) => {
+            let ExprKind::MethodCall(to_str, to_str_recv, [], _) = recv.kind else {
+                return None;
+            };
+            let method = if args.is_empty() {
+                "to_str().unwrap()"
+            } else {
+                "to_str().expect(..)"
+            };
+            (to_str.ident.as_str() == "to_str" && is_os_str_method(cx, recv)).then_some((to_str_recv, method))
+        },
+        _ => None,
+    }
+}
+
+/// Whether the method called by `e` is defined on `OsStr` or `Path`.
+fn is_os_str_method(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    cx.typeck_results()
+        .type_dependent_def_id(e.hir_id)
+        .and_then(|id| cx.tcx.impl_of_method(id))
+        .map_or(false, |impl_id| {
+            let self_ty = cx.tcx.type_of(impl_id).subst_identity();
+            is_type_diagnostic_item(cx, self_ty, sym::OsStr) || is_type_diagnostic_item(cx, self_ty, sym::Path)
+        })
+}
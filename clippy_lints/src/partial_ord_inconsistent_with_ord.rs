@@ -0,0 +1,215 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{is_res_lang_ctor, last_path_segment, path_to_local, path_to_local_id, peel_blocks};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Body, Expr, ExprKind, ImplItem, ImplItemKind, LangItem, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::TypeckResults;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::symbol::kw;
+use rustc_span::{sym, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for manual implementations of `PartialOrd` for a type which also implements `Ord`
+    /// manually, where `partial_cmp` doesn't return `Some(self.cmp(other))`.
+    ///
+    /// ### Why is this bad?
+    /// `Ord` requires `partial_cmp(a, b) == Some(cmp(a, b))`. Two separate implementations
+    /// easily drift apart, for example by comparing the fields in a different order, and then
+    /// sorting and the ordered collections behave inconsistently.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// #[derive(PartialEq, Eq)]
+    /// struct Version {
+    ///     major: u32,
+    ///     minor: u32,
+    /// }
+    ///
+    /// impl PartialOrd for Version {
+    ///     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    ///         (self.minor, self.major).partial_cmp(&(other.minor, other.major))
+    ///     }
+    /// }
+    ///
+    /// impl Ord for Version {
+    ///     fn cmp(&self, other: &Self) -> Ordering {
+    ///         (self.major, self.minor).cmp(&(other.major, other.minor))
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # #[derive(PartialEq, Eq)]
+    /// # struct Version {
+    /// #     major: u32,
+    /// #     minor: u32,
+    /// # }
+    /// impl PartialOrd for Version {
+    ///     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    ///         Some(self.cmp(other))
+    ///     }
+    /// }
+    /// # impl Ord for Version {
+    /// #     fn cmp(&self, other: &Self) -> Ordering {
+    /// #         (self.major, self.minor).cmp(&(other.major, other.minor))
+    /// #     }
+    /// # }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub PARTIAL_ORD_INCONSISTENT_WITH_ORD,
+    suspicious,
+    "a manual `PartialOrd` implementation which doesn't delegate to the manual `Ord` implementation"
+}
+
+declare_lint_pass!(PartialOrdInconsistentWithOrd => [PARTIAL_ORD_INCONSISTENT_WITH_ORD]);
+
+impl<'tcx> LateLintPass<'tcx> for PartialOrdInconsistentWithOrd {
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, impl_item: &'tcx ImplItem<'tcx>) {
+        let ImplItemKind::Fn(_, body_id) = impl_item.kind else {
+            return;
+        };
+        if impl_item.ident.name != sym::partial_cmp || in_external_macro(cx.sess(), impl_item.span) {
+            return;
+        }
+        let impl_id = cx.tcx.local_parent(impl_item.owner_id.def_id).to_def_id();
+        let (Some(trait_ref), Some(ord_id)) = (cx.tcx.impl_trait_ref(impl_id), cx.tcx.get_diagnostic_item(sym::Ord))
+        else {
+            return;
+        };
+        let trait_ref = trait_ref.subst_identity();
+        // `PartialOrd<Other>` isn't related to `Ord`
+        if cx.tcx.lang_items().partial_ord_trait() != Some(trait_ref.def_id)
+            || trait_ref.substs.type_at(1) != trait_ref.self_ty()
+            || cx.tcx.has_attr(impl_id, sym::automatically_derived)
+        {
+            return;
+        }
+        // the other relevant impls are blanket impls of other crates, like the one for function pointers
+        let mut ord_impl = None;
+        cx.tcx.for_each_relevant_impl(ord_id, trait_ref.self_ty(), |id| {
+            if id.is_local() {
+                ord_impl = ord_impl.or(Some(id));
+            }
+        });
+        // a derived `Ord` is linted by `derive_ord_xor_partial_ord`
+        let Some(ord_impl) = ord_impl.filter(|&id| !cx.tcx.has_attr(id, sym::automatically_derived)) else {
+            return;
+        };
+
+        let body = cx.tcx.hir().body(body_id);
+        let typeck = cx.tcx.typeck_body(body_id);
+        if delegates_to_cmp(cx, typeck, body, ord_id, ord_impl) || cmp_calls_partial_cmp(cx, ord_impl) {
+            return;
+        }
+
+        span_lint_and_then(
+            cx,
+            PARTIAL_ORD_INCONSISTENT_WITH_ORD,
+            body.value.span,
+            "`partial_cmp` doesn't return the result of `cmp` of the `Ord` implementation",
+            |diag| {
+                diag.span_note(cx.tcx.def_span(ord_impl), "`Ord` is implemented here");
+                if let Some(other) = body.params.get(1).and_then(|param| param.pat.simple_ident()) {
+                    diag.span_suggestion(
+                        body.value.span,
+                        "return the result of `cmp`",
+                        format!("{{ Some(self.cmp({other})) }}"),
+                        Applicability::MaybeIncorrect,
+                    );
+                } else {
+                    diag.help("return `Some(self.cmp(other))`");
+                }
+            },
+        );
+    }
+}
+
+/// Whether the body of `partial_cmp` is `Some(self.cmp(other))`, or `Some(Ord::cmp(self, other))`.
+fn delegates_to_cmp<'tcx>(
+    cx: &LateContext<'tcx>,
+    typeck: &TypeckResults<'tcx>,
+    body: &'tcx Body<'tcx>,
+    ord_id: DefId,
+    ord_impl: DefId,
+) -> bool {
+    let ExprKind::Call(some, [cmp]) = peel_blocks(body.value).kind else {
+        return false;
+    };
+    if !is_res_lang_ctor(cx, callee_res(typeck, some), LangItem::OptionSome) {
+        return false;
+    }
+    let (cmp_id, this, other) = match cmp.kind {
+        ExprKind::MethodCall(_, this, [other], _) => (typeck.type_dependent_def_id(cmp.hir_id), this, other),
+        ExprKind::Call(func, [this, other]) => (callee_res(typeck, func).opt_def_id(), this, other),
+        _ => return false,
+    };
+    let Some(cmp_id) = cmp_id else {
+        return false;
+    };
+    let [self_param, other_param] = body.params else {
+        return false;
+    };
+    (cx.tcx.trait_of_item(cmp_id) == Some(ord_id) || cx.tcx.impl_of_method(cmp_id) == Some(ord_impl))
+        && path_to_local_id(peel_refs(this), self_param.pat.hir_id)
+        && path_to_local_id(peel_refs(other), other_param.pat.hir_id)
+}
+
+/// Whether `cmp` of the `Ord` implementation calls `self.partial_cmp(..)`, which would recurse
+/// if `partial_cmp` returned the result of `cmp`.
+fn cmp_calls_partial_cmp(cx: &LateContext<'_>, ord_impl: DefId) -> bool {
+    let Some(cmp) = cx
+        .tcx
+        .associated_items(ord_impl)
+        .filter_by_name_unhygienic(sym::cmp)
+        .next()
+        .and_then(|item| item.def_id.as_local())
+        .and_then(|id| cx.tcx.hir().maybe_body_owned_by(id))
+    else {
+        return false;
+    };
+    let is_self = |e: &Expr<'_>| path_to_local(peel_refs(e)).map(|id| cx.tcx.hir().name(id)) == Some(kw::SelfLower);
+    for_each_expr(cx.tcx.hir().body(cmp).value, |e| {
+        let calls_partial_cmp = match e.kind {
+            ExprKind::MethodCall(path, this, ..) => path.ident.name == sym::partial_cmp && is_self(this),
+            // e.g. `PartialOrd::partial_cmp(self, other)`
+            ExprKind::Call(func, [this, ..]) => last_path_segment_name(func) == Some(sym::partial_cmp) && is_self(this),
+            _ => false,
+        };
+        if calls_partial_cmp {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+fn callee_res(typeck: &TypeckResults<'_>, func: &Expr<'_>) -> Res {
+    match func.kind {
+        ExprKind::Path(ref qpath) => typeck.qpath_res(qpath, func.hir_id),
+        _ => Res::Err,
+    }
+}
+
+fn last_path_segment_name(func: &Expr<'_>) -> Option<Symbol> {
+    match func.kind {
+        ExprKind::Path(ref qpath) => Some(last_path_segment(qpath).ident.name),
+        _ => None,
+    }
+}
+
+/// Peels `&` and `*`, like in `Ord::cmp(&*self, &other)`.
+fn peel_refs<'tcx>(mut expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    while let ExprKind::AddrOf(_, _, inner) | ExprKind::Unary(UnOp::Deref, inner) = expr.kind {
+        expr = inner;
+    }
+    expr
+}
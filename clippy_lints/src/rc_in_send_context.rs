@@ -0,0 +1,194 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{match_def_path, path_def_id, paths};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Closure, Expr, ExprKind, ItemKind, Unsafety};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Rc`s and `RefCell`s which are created:
+    /// * in a closure passed to `std::thread::spawn` or in a future passed to `tokio::spawn`
+    /// * in a struct expression of a type which implements `Send` with an `unsafe impl`
+    ///   (only `Rc`s, since a `RefCell` of a `Send` type is `Send`)
+    ///
+    /// ### Why is this bad?
+    /// A future holding an `Rc` or a borrow of a `RefCell` across an `.await` isn't `Send`,
+    /// which `tokio::spawn` requires. The resulting error points at the spawn rather than at the
+    /// value, and is hard to read when the value is hidden behind a trait object. Code run on
+    /// another thread is often meant to share values between threads, which needs `Arc` and
+    /// `Mutex` or `RwLock`.
+    ///
+    /// An `unsafe impl Send` turns the same mistake into undefined behavior, as the reference
+    /// count of an `Rc` isn't updated atomically. See also `non_send_fields_in_send_ty`, which
+    /// checks the types of the fields.
+    ///
+    /// ### Known problems
+    /// The `Rc` or `RefCell` may be used on the spawned thread only, in which case it's fine.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::rc::Rc;
+    /// std::thread::spawn(|| {
+    ///     let config = Rc::new(String::from("config"));
+    ///     // ..
+    /// });
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// std::thread::spawn(|| {
+    ///     let config = Arc::new(String::from("config"));
+    ///     // ..
+    /// });
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub RC_IN_SEND_CONTEXT,
+    nursery,
+    "creating an `Rc` or a `RefCell` in code which runs on, or is sent to, another thread"
+}
+
+#[derive(Default)]
+pub struct RcInSendContext {
+    /// The span of the `unsafe impl Send` of each type of the crate which has one.
+    unsafe_send_impls: FxHashMap<DefId, Span>,
+}
+
+impl_lint_pass!(RcInSendContext => [RC_IN_SEND_CONTEXT]);
+
+impl<'tcx> LateLintPass<'tcx> for RcInSendContext {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let Some(send_trait) = cx.tcx.get_diagnostic_item(sym::Send) else {
+            return;
+        };
+        for id in cx.tcx.hir().items() {
+            if let ItemKind::Impl(imp) = cx.tcx.hir().item(id).kind
+                && imp.unsafety == Unsafety::Unsafe
+                && let Some(trait_ref) = imp.of_trait
+                && trait_ref.trait_def_id() == Some(send_trait)
+                && let ty::Adt(adt, _) = cx.tcx.type_of(id.owner_id).subst_identity().kind()
+            {
+                self.unsafe_send_impls.insert(adt.did(), cx.tcx.def_span(id.owner_id));
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        if let Some(spawn) = spawn_fn(cx, expr)
+            && let ExprKind::Call(_, [arg]) = expr.kind
+            && let ExprKind::Closure(&Closure { body, .. }) = arg.kind
+        {
+            let (context, note) = if spawn == "tokio::spawn" {
+                (
+                    "a future passed to `tokio::spawn`",
+                    "the future isn't `Send` if this is held across an `.await`, and `tokio::spawn` requires it to be",
+                )
+            } else {
+                (
+                    "a closure passed to `std::thread::spawn`",
+                    "this can't be shared with or sent to other threads",
+                )
+            };
+            for_each_created_value(cx, cx.tcx.hir().body(body).value, true, |e, name| {
+                span_lint_and_then(
+                    cx,
+                    RC_IN_SEND_CONTEXT,
+                    e.span,
+                    &format!("`{name}` created in {context}"),
+                    |diag| {
+                        diag.note(note);
+                        diag.help(thread_safe_alternative(name));
+                    },
+                );
+            });
+        } else if let ExprKind::Struct(_, fields, _) = expr.kind
+            && let ty::Adt(adt, _) = cx.typeck_results().expr_ty(expr).kind()
+            && let Some(&impl_span) = self.unsafe_send_impls.get(&adt.did())
+        {
+            let ty_name = cx.tcx.item_name(adt.did());
+            for field in fields {
+                for_each_created_value(cx, field.expr, false, |e, name| {
+                    span_lint_and_then(
+                        cx,
+                        RC_IN_SEND_CONTEXT,
+                        e.span,
+                        &format!("`{name}` stored in `{ty_name}`, which implements `Send` with an `unsafe impl`"),
+                        |diag| {
+                            diag.span_note(
+                                impl_span,
+                                format!(
+                                    "sending a `{ty_name}` to another thread can cause data races on the reference count"
+                                ),
+                            );
+                            diag.help(thread_safe_alternative(name));
+                        },
+                    );
+                });
+            }
+        }
+    }
+}
+
+/// If `expr` is a call to `std::thread::spawn` or `tokio::spawn`, returns the name of the
+/// function.
+fn spawn_fn(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<&'static str> {
+    let ExprKind::Call(func, [_]) = expr.kind else {
+        return None;
+    };
+    let id = path_def_id(cx, func)?;
+    if match_def_path(cx, id, &paths::STD_THREAD_SPAWN) {
+        Some("std::thread::spawn")
+    } else if match_def_path(cx, id, &paths::TOKIO_SPAWN) {
+        Some("tokio::spawn")
+    } else {
+        None
+    }
+}
+
+/// Calls `f` with each call to an associated function in `e`, like `Rc::new`, which creates an
+/// `Rc` (or a `RefCell`, if `with_ref_cell` is set), along with the name of the type. Nested
+/// spawns are skipped, as they are checked on their own.
+fn for_each_created_value<'tcx>(
+    cx: &LateContext<'tcx>,
+    e: &'tcx Expr<'tcx>,
+    with_ref_cell: bool,
+    mut f: impl FnMut(&'tcx Expr<'tcx>, &'static str),
+) {
+    for_each_expr(e, |e| {
+        if spawn_fn(cx, e).is_some() {
+            return ControlFlow::<(), _>::Continue(Descend::No);
+        }
+        if let ExprKind::Call(func, _) = e.kind
+            && let ExprKind::Path(ref qpath) = func.kind
+            && !e.span.from_expansion()
+            && let Some(id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+            && cx.tcx.opt_associated_item(id).is_some()
+        {
+            let ty = cx.typeck_results().expr_ty(e);
+            if is_type_diagnostic_item(cx, ty, sym::Rc) {
+                f(e, "Rc");
+            } else if with_ref_cell && is_type_diagnostic_item(cx, ty, sym::RefCell) {
+                f(e, "RefCell");
+            }
+        }
+        ControlFlow::Continue(Descend::Yes)
+    });
+}
+
+fn thread_safe_alternative(name: &str) -> &'static str {
+    if name == "Rc" {
+        "consider using an `Arc` instead"
+    } else {
+        "consider using a `Mutex` or an `RwLock` instead"
+    }
+}
@@ -1,15 +1,20 @@
 use std::fmt::Display;
 
+use crate::leaky_global_from_loop::is_once_initializer;
 use clippy_utils::consts::{constant, Constant};
-use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
+use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_hir_and_then};
 use clippy_utils::source::snippet_opt;
-use clippy_utils::{match_def_path, paths};
+use clippy_utils::{fn_def_id, is_trait_method, match_def_path, path_def_id, paths};
 use if_chain::if_chain;
 use rustc_ast::ast::{LitKind, StrStyle};
-use rustc_hir::{BorrowKind, Expr, ExprKind};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, Node};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::{BytePos, Span};
+use rustc_span::sym;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -55,10 +60,58 @@ declare_clippy_lint! {
     "trivial regular expressions"
 }
 
-declare_lint_pass!(Regex => [INVALID_REGEX, TRIVIAL_REGEX]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for [regex](https://crates.io/crates/regex) creation (with `Regex::new` or
+    /// `RegexSet::new`) from constant patterns in loops, in closures passed to iterator
+    /// adapters, and in functions of the same crate which are called in loops.
+    ///
+    /// ### Why is this bad?
+    /// Compiling a regex is expensive, much more so than matching it. Compiling the same
+    /// pattern again in every iteration wastes most of the time of the loop.
+    ///
+    /// ### Known problems
+    /// Calls through trait objects and generic functions aren't followed.
+    ///
+    /// ### Example
+    /// ```ignore
+    /// fn is_id(s: &str) -> bool {
+    ///     Regex::new("^[a-z]+[0-9]*$").unwrap().is_match(s)
+    /// }
+    ///
+    /// for line in lines {
+    ///     if is_id(line) { .. }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```ignore
+    /// static ID: LazyLock<Regex> = LazyLock::new(|| Regex::new("^[a-z]+[0-9]*$").unwrap());
+    ///
+    /// fn is_id(s: &str) -> bool {
+    ///     ID.is_match(s)
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub REGEX_COMPILED_IN_LOOP_OR_HOT_FN,
+    perf,
+    "compiling a constant regex in a loop, or in a function which is called in a loop"
+}
+
+#[derive(Default)]
+pub struct Regex {
+    /// Regexes compiled outside of loops, by the function compiling them.
+    compiled_in_fns: FxHashMap<LocalDefId, Vec<(HirId, Span)>>,
+    /// Calls of local functions outside of loops, by the calling function.
+    calls: FxHashMap<LocalDefId, Vec<LocalDefId>>,
+    /// Local functions which are called in loops, with the span of the call.
+    called_in_loops: Vec<(LocalDefId, Span)>,
+}
+
+impl_lint_pass!(Regex => [INVALID_REGEX, TRIVIAL_REGEX, REGEX_COMPILED_IN_LOOP_OR_HOT_FN]);
 
 impl<'tcx> LateLintPass<'tcx> for Regex {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        self.record_compilation_or_call(cx, expr);
         if_chain! {
             if let ExprKind::Call(fun, [arg]) = expr.kind;
             if let ExprKind::Path(ref qpath) = fun.kind;
@@ -78,6 +131,131 @@ impl<'tcx> LateLintPass<'tcx> for Regex {
             }
         }
     }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        let mut visited = FxHashSet::default();
+        // in reverse, to lint in the order of the calls
+        let mut stack: Vec<_> = std::mem::take(&mut self.called_in_loops).into_iter().rev().collect();
+        while let Some((f, call_span)) = stack.pop() {
+            if !visited.insert(f) {
+                continue;
+            }
+            for &(hir_id, span) in self.compiled_in_fns.get(&f).into_iter().flatten() {
+                span_lint_hir_and_then(
+                    cx,
+                    REGEX_COMPILED_IN_LOOP_OR_HOT_FN,
+                    hir_id,
+                    span,
+                    "compiling a regex in a function which is called in a loop",
+                    |diag| {
+                        diag.span_note(call_span, "the function is called in a loop here");
+                        diag.help("compile it once in a `static` with `LazyLock` or `once_cell::sync::Lazy`");
+                    },
+                );
+            }
+            stack.extend(
+                self.calls
+                    .get(&f)
+                    .into_iter()
+                    .flatten()
+                    .map(|&callee| (callee, call_span)),
+            );
+        }
+    }
+}
+
+impl Regex {
+    fn record_compilation_or_call<'tcx>(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        if let ExprKind::Call(fun, [arg]) = expr.kind
+            && let Some(def_id) = path_def_id(cx, fun)
+            && [
+                &paths::REGEX_NEW[..],
+                &paths::REGEX_BYTES_NEW,
+                &paths::REGEX_SET_NEW,
+                &paths::REGEX_BYTES_SET_NEW,
+            ]
+            .iter()
+            .any(|path| match_def_path(cx, def_id, path))
+        {
+            if !is_const_pattern(cx, arg) {
+                return;
+            }
+            match execution_context(cx, expr) {
+                ExecutionContext::Loop => span_lint_and_help(
+                    cx,
+                    REGEX_COMPILED_IN_LOOP_OR_HOT_FN,
+                    expr.span,
+                    "compiling a regex in a loop",
+                    None,
+                    "compile it once before the loop, or in a `static` with `LazyLock` or `once_cell::sync::Lazy`",
+                ),
+                ExecutionContext::Fn(f) => self.compiled_in_fns.entry(f).or_default().push((expr.hir_id, expr.span)),
+                ExecutionContext::Once => {},
+            }
+        } else if let Some(callee) = fn_def_id(cx, expr).and_then(DefId::as_local)
+            && matches!(cx.tcx.def_kind(callee), DefKind::Fn | DefKind::AssocFn)
+        {
+            match execution_context(cx, expr) {
+                ExecutionContext::Loop => self.called_in_loops.push((callee, expr.span)),
+                ExecutionContext::Fn(f) => self.calls.entry(f).or_default().push(callee),
+                ExecutionContext::Once => {},
+            }
+        }
+    }
+}
+
+enum ExecutionContext {
+    /// In a loop, or in a closure passed to an iterator adapter.
+    Loop,
+    /// Evaluated once, e.g. in a `LazyLock` initializer or a `static`.
+    Once,
+    /// Outside of loops in the function.
+    Fn(LocalDefId),
+}
+
+fn execution_context(cx: &LateContext<'_>, e: &Expr<'_>) -> ExecutionContext {
+    for (_, node) in cx.tcx.hir().parent_iter(e.hir_id) {
+        match node {
+            Node::Expr(parent) => match parent.kind {
+                ExprKind::Loop(..) => return ExecutionContext::Loop,
+                ExprKind::Closure(_) if is_once_initializer(cx, parent) => return ExecutionContext::Once,
+                ExprKind::Closure(_)
+                    if let Some(Node::Expr(call)) = cx.tcx.hir().find_parent(parent.hir_id)
+                        && let ExprKind::MethodCall(..) = call.kind
+                        && is_trait_method(cx, call, sym::Iterator) =>
+                {
+                    return ExecutionContext::Loop;
+                },
+                _ => {},
+            },
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => break,
+            _ => {},
+        }
+    }
+    let owner = cx.tcx.hir().enclosing_body_owner(e.hir_id);
+    let root = cx.tcx.typeck_root_def_id(owner.to_def_id());
+    match root.as_local() {
+        Some(f) if matches!(cx.tcx.def_kind(f), DefKind::Fn | DefKind::AssocFn) => ExecutionContext::Fn(f),
+        // `const`s and `static`s are evaluated once
+        _ => ExecutionContext::Once,
+    }
+}
+
+/// Whether the pattern, or all patterns of a `RegexSet`, are constant.
+fn is_const_pattern<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'_>) -> bool {
+    let arg = if let ExprKind::AddrOf(BorrowKind::Ref, _, inner) = arg.kind {
+        inner
+    } else {
+        arg
+    };
+    if let ExprKind::Array(exprs) = arg.kind {
+        exprs.iter().all(|e| const_str(cx, e).is_some())
+    } else {
+        const_str(cx, arg).is_some()
+    }
 }
 
 fn lint_syntax_error(cx: &LateContext<'_>, error: &regex_syntax::Error, unescaped: &str, base: Span, offset: u8) {
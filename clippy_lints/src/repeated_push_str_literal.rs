@@ -0,0 +1,221 @@
+use crate::format_push_string::FORMAT_PUSH_STRING;
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::source::{snippet_opt, snippet_with_context};
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{is_lint_allowed, match_def_path, paths, peel_hir_expr_refs, span_contains_comment, SpanlessEq};
+use rustc_ast::ast::{LitKind, StrStyle};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Block, Expr, ExprKind, LangItem, Stmt, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for consecutive `s.push_str("..")` statements with string literals, and for
+    /// `s += &format!(..)` and `s.push_str(&format!(..))`.
+    ///
+    /// ### Why is this bad?
+    /// The pushed literals can be merged into one, which is shorter and copies the text in one
+    /// go. Appending a `format!` allocates a temporary `String` only to copy it, while `write!`
+    /// formats into the `String` directly.
+    ///
+    /// ### Known problems
+    /// `write!` needs the `std::fmt::Write` trait in scope, and returns a `Result` which is
+    /// ignored in the suggestion, since writing to a `String` can't fail.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let mut s = String::new();
+    /// s.push_str("Hello, ");
+    /// s.push_str("world");
+    /// s += &format!("{}!", 1);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::fmt::Write as _;
+    ///
+    /// let mut s = String::new();
+    /// s.push_str("Hello, world");
+    /// let _ = write!(s, "{}!", 1);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub REPEATED_PUSH_STR_LITERAL,
+    style,
+    "pushing several string literals or a `format!` onto a `String` one at a time"
+}
+
+declare_lint_pass!(RepeatedPushStrLiteral => [REPEATED_PUSH_STR_LITERAL]);
+
+/// A statement like `s.push_str("..");`.
+struct PushLiteral<'tcx> {
+    span: Span,
+    recv: &'tcx Expr<'tcx>,
+    /// The source of the literal without the quotes.
+    contents: String,
+}
+
+impl<'tcx> LateLintPass<'tcx> for RepeatedPushStrLiteral {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if in_external_macro(cx.sess(), block.span) {
+            return;
+        }
+        let mut run: Vec<PushLiteral<'tcx>> = Vec::new();
+        for stmt in block.stmts {
+            match push_literal(cx, stmt) {
+                Some(push)
+                    if run
+                        .last()
+                        .map_or(true, |last| SpanlessEq::new(cx).eq_expr(last.recv, push.recv)) =>
+                {
+                    run.push(push);
+                },
+                push => {
+                    lint_run(cx, &run);
+                    run.clear();
+                    run.extend(push);
+                },
+            }
+            check_format_push(cx, stmt);
+        }
+        lint_run(cx, &run);
+    }
+}
+
+fn lint_run(cx: &LateContext<'_>, run: &[PushLiteral<'_>]) {
+    let [first, .., last] = run else {
+        return;
+    };
+    let span = first.span.to(last.span);
+    let mut app = if span_contains_comment(cx.sess().source_map(), span) {
+        Applicability::MaybeIncorrect
+    } else {
+        Applicability::MachineApplicable
+    };
+    let recv = snippet_with_context(cx, first.recv.span, span.ctxt(), "..", &mut app).0;
+    let merged = run.iter().map(|push| push.contents.as_str()).collect::<String>();
+    span_lint_and_sugg(
+        cx,
+        REPEATED_PUSH_STR_LITERAL,
+        span,
+        "consecutive `push_str` calls with string literals",
+        "merge the literals into one",
+        format!("{recv}.push_str(\"{merged}\");"),
+        app,
+    );
+}
+
+/// Checks if `stmt` is `s.push_str("..");` with a literal without a prefix, where `s` is a
+/// `String` which refers to the same value every time it's evaluated.
+fn push_literal<'tcx>(cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'tcx>) -> Option<PushLiteral<'tcx>> {
+    let StmtKind::Semi(e) = stmt.kind else {
+        return None;
+    };
+    let ExprKind::MethodCall(_, recv, [arg], _) = e.kind else {
+        return None;
+    };
+    let ExprKind::Lit(lit) = arg.kind else {
+        return None;
+    };
+    if stmt.span.from_expansion()
+        || arg.span.from_expansion()
+        || !matches!(lit.node, LitKind::Str(_, StrStyle::Cooked))
+        || !is_place(recv)
+        || !cx
+            .typeck_results()
+            .type_dependent_def_id(e.hir_id)
+            .map_or(false, |id| match_def_path(cx, id, &paths::PUSH_STR))
+    {
+        return None;
+    }
+    let snip = snippet_opt(cx, arg.span)?;
+    let contents = snip.strip_prefix('"')?.strip_suffix('"')?;
+    Some(PushLiteral {
+        span: stmt.span,
+        recv,
+        contents: contents.to_owned(),
+    })
+}
+
+/// Checks `s += &format!(..);` and `s.push_str(&format!(..));`.
+fn check_format_push(cx: &LateContext<'_>, stmt: &Stmt<'_>) {
+    let StmtKind::Semi(e) = stmt.kind else {
+        return;
+    };
+    let (dst, arg) = match e.kind {
+        ExprKind::MethodCall(_, recv, [arg], _)
+            if cx
+                .typeck_results()
+                .type_dependent_def_id(e.hir_id)
+                .map_or(false, |id| match_def_path(cx, id, &paths::PUSH_STR)) =>
+        {
+            (recv, arg)
+        },
+        ExprKind::AssignOp(op, left, arg)
+            if op.node == BinOpKind::Add
+                && is_type_lang_item(cx, cx.typeck_results().expr_ty(left), LangItem::String) =>
+        {
+            (left, arg)
+        },
+        _ => return,
+    };
+    // `format_push_string` lints the same without a suggestion
+    if stmt.span.from_expansion() || !is_lint_allowed(cx, FORMAT_PUSH_STRING, e.hir_id) {
+        return;
+    }
+    let (arg, _) = peel_hir_expr_refs(arg);
+    let expn = arg.span.ctxt().outer_expn_data();
+    if !expn
+        .macro_def_id
+        .map_or(false, |id| cx.tcx.is_diagnostic_item(sym::format_macro, id))
+        || expn.call_site.ctxt() != stmt.span.ctxt()
+    {
+        return;
+    }
+    let Some(format_args) = snippet_opt(cx, expn.call_site).and_then(|snip| macro_args(&snip).map(str::to_owned))
+    else {
+        return;
+    };
+
+    let mut app = Applicability::MaybeIncorrect;
+    let dst = snippet_with_context(cx, dst.span, stmt.span.ctxt(), "..", &mut app).0;
+    span_lint_and_then(
+        cx,
+        REPEATED_PUSH_STR_LITERAL,
+        stmt.span,
+        "`format!(..)` appended to a `String`",
+        |diag| {
+            diag.span_suggestion(
+                stmt.span,
+                "use `write!` to format into the `String` directly",
+                format!("let _ = write!({dst}, {format_args});"),
+                app,
+            );
+            diag.note("`write!` needs `std::fmt::Write` to be in scope");
+        },
+    );
+}
+
+/// Returns the arguments of a macro call like `format!(..)` from its source.
+fn macro_args(call: &str) -> Option<&str> {
+    let (_, rest) = call.split_once('!')?;
+    let rest = rest.trim_start();
+    let close = match rest.chars().next()? {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        _ => return None,
+    };
+    rest[1..].strip_suffix(close).map(str::trim)
+}
+
+/// Whether `e` is a path, possibly with field accesses and dereferences, which refers to the same
+/// value every time it's evaluated.
+fn is_place(e: &Expr<'_>) -> bool {
+    match e.kind {
+        ExprKind::Path(_) => true,
+        ExprKind::Field(base, _) | ExprKind::Unary(UnOp::Deref, base) => is_place(base),
+        _ => false,
+    }
+}
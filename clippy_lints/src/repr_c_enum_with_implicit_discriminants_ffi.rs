@@ -0,0 +1,194 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use rustc_data_structures::fx::{FxHashSet, FxIndexMap};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::{FnDecl, FnRetTy, ForeignItemKind, Item, ItemKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty, VariantDiscr};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+use rustc_target::spec::abi::Abi;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for enums of the crate which are used in the signature of an `extern` function,
+    /// directly or through the fields of a struct, and which are either
+    /// - `#[repr(C)]` with variants that don't have an explicit discriminant, or
+    /// - in a field of such a struct without any `#[repr]`.
+    ///
+    /// rustc's `improper_ctypes` lints already check enums without a `#[repr]` which are used
+    /// directly as a parameter or return type.
+    ///
+    /// ### Why is this bad?
+    /// The C side of the interface defines the value of each variant. Implicit discriminants
+    /// only match if the variants are declared in the same order, with the same gaps, which
+    /// silently breaks when either side changes. The layout and discriminants of an enum
+    /// without a `#[repr]` aren't specified at all.
+    ///
+    /// ### Example
+    /// ```rust
+    /// #[repr(C)]
+    /// pub enum Status {
+    ///     Ok,
+    ///     Retry,
+    ///     Failed,
+    /// }
+    ///
+    /// extern "C" {
+    ///     fn poll_device() -> Status;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[repr(C)]
+    /// pub enum Status {
+    ///     Ok = 0,
+    ///     Retry = 1,
+    ///     Failed = 2,
+    /// }
+    ///
+    /// extern "C" {
+    ///     fn poll_device() -> Status;
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub REPR_C_ENUM_WITH_IMPLICIT_DISCRIMINANTS_FFI,
+    suspicious,
+    "an enum in an FFI signature whose discriminants aren't spelled out"
+}
+
+#[derive(Default)]
+pub struct ReprCEnumWithImplicitDiscriminantsFfi {
+    /// The first use of each enum in an `extern` signature, and the field it's used through,
+    /// if any.
+    enums: FxIndexMap<LocalDefId, (Span, Option<DefId>)>,
+}
+
+impl_lint_pass!(ReprCEnumWithImplicitDiscriminantsFfi => [REPR_C_ENUM_WITH_IMPLICIT_DISCRIMINANTS_FFI]);
+
+impl<'tcx> LateLintPass<'tcx> for ReprCEnumWithImplicitDiscriminantsFfi {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if in_external_macro(cx.sess(), item.span) {
+            return;
+        }
+        match item.kind {
+            ItemKind::Fn(sig, ..) if sig.header.abi != Abi::Rust => {
+                self.check_fn_sig(cx, item.owner_id.to_def_id(), sig.decl);
+            },
+            ItemKind::ForeignMod { abi, items } if abi != Abi::Rust => {
+                for foreign_item in items {
+                    let def_id = foreign_item.id.owner_id.to_def_id();
+                    match cx.tcx.hir().foreign_item(foreign_item.id).kind {
+                        ForeignItemKind::Fn(decl, ..) => self.check_fn_sig(cx, def_id, decl),
+                        ForeignItemKind::Static(ty, _) => {
+                            self.check_ty(cx, cx.tcx.type_of(def_id).subst_identity(), ty.span);
+                        },
+                        ForeignItemKind::Type => {},
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (&enum_id, &(use_span, field)) in &self.enums {
+            let adt = cx.tcx.adt_def(enum_id);
+            let name = cx.tcx.item_name(enum_id.to_def_id());
+            let hir_id = cx.tcx.hir().local_def_id_to_hir_id(enum_id);
+            let span = cx.tcx.def_span(enum_id);
+            if adt.repr().c() {
+                let implicit: Vec<_> = adt
+                    .variants()
+                    .iter()
+                    .filter(|variant| matches!(variant.discr, VariantDiscr::Relative(_)))
+                    .map(|variant| cx.tcx.def_span(variant.def_id))
+                    .collect();
+                span_lint_hir_and_then(
+                    cx,
+                    REPR_C_ENUM_WITH_IMPLICIT_DISCRIMINANTS_FFI,
+                    hir_id,
+                    span,
+                    &format!("`#[repr(C)]` enum `{name}` is used in an FFI signature, but has implicit discriminants"),
+                    |diag| {
+                        diag.span_note(implicit, "these variants don't have an explicit discriminant");
+                        diag.span_note(use_span, "the enum is used in this `extern` signature");
+                        diag.help("give each variant the value the C side of the interface expects, like `= 0`");
+                    },
+                );
+            } else if let Some(field) = field {
+                span_lint_hir_and_then(
+                    cx,
+                    REPR_C_ENUM_WITH_IMPLICIT_DISCRIMINANTS_FFI,
+                    hir_id,
+                    span,
+                    &format!("enum `{name}` is used in an FFI signature, but doesn't have a `#[repr]`"),
+                    |diag| {
+                        diag.span_note(cx.tcx.def_span(field), "the enum is used in this field");
+                        diag.span_note(use_span, "which is part of a type used in this `extern` signature");
+                        diag.help(
+                            "add `#[repr(C)]`, or `#[repr]` with the integer type of the C enum, and give each \
+                             variant the value the C side expects",
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+impl ReprCEnumWithImplicitDiscriminantsFfi {
+    fn check_fn_sig<'tcx>(&mut self, cx: &LateContext<'tcx>, def_id: DefId, decl: &'tcx FnDecl<'tcx>) {
+        let sig = cx.tcx.fn_sig(def_id).subst_identity().skip_binder();
+        for (ty, hir_ty) in sig.inputs().iter().zip(decl.inputs) {
+            self.check_ty(cx, *ty, hir_ty.span);
+        }
+        if let FnRetTy::Return(hir_ty) = decl.output {
+            self.check_ty(cx, sig.output(), hir_ty.span);
+        }
+    }
+
+    fn check_ty<'tcx>(&mut self, cx: &LateContext<'tcx>, ty: Ty<'tcx>, use_span: Span) {
+        self.walk_ty(cx, ty, use_span, None, &mut FxHashSet::default());
+    }
+
+    /// Records the enums of the crate in `ty`, looking through pointers, arrays and the fields
+    /// of structs and unions. `field` is the field whose type contains `ty`, if any.
+    fn walk_ty<'tcx>(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        ty: Ty<'tcx>,
+        use_span: Span,
+        field: Option<DefId>,
+        seen: &mut FxHashSet<DefId>,
+    ) {
+        match *ty.kind() {
+            ty::Adt(adt, _) if adt.is_enum() => {
+                let Some(enum_id) = adt.did().as_local() else {
+                    return;
+                };
+                let repr = adt.repr();
+                let implicit_discriminants = repr.c()
+                    && adt.is_payloadfree()
+                    && adt
+                        .variants()
+                        .iter()
+                        .any(|variant| matches!(variant.discr, VariantDiscr::Relative(_)));
+                // without a `#[repr]`, only the uses through a field aren't linted by rustc
+                let unspecified = !repr.c() && repr.int.is_none() && field.is_some();
+                if implicit_discriminants || unspecified {
+                    self.enums.entry(enum_id).or_insert((use_span, field));
+                }
+            },
+            ty::Adt(adt, substs) if adt.did().is_local() && seen.insert(adt.did()) => {
+                for adt_field in adt.all_fields() {
+                    self.walk_ty(cx, adt_field.ty(cx.tcx, substs), use_span, Some(adt_field.did), seen);
+                }
+            },
+            ty::Array(ty, _) | ty::Slice(ty) | ty::Ref(_, ty, _) | ty::RawPtr(ty::TypeAndMut { ty, .. }) => {
+                self.walk_ty(cx, ty, use_span, field, seen);
+            },
+            _ => {},
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::macro_backtrace;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{get_parent_expr, match_def_path, paths};
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, HirId, LangItem, QPath};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `select!` calls in loops which race a future that isn't cancel safe, like
+    /// `reader.read_exact(&mut buf)`, and create that future anew in every iteration.
+    ///
+    /// ### Why is this bad?
+    /// When another branch of the `select!` completes first, the future is dropped. A future
+    /// which isn't cancel safe may already have read some data at that point, which is lost, and
+    /// the next iteration starts over with a new future.
+    ///
+    /// The futures checked are the ones returned by `read_exact`, `read_to_end`,
+    /// `read_to_string`, `read_line`, `read_until` and `write_all` of tokio's extension traits.
+    ///
+    /// ### Known problems
+    /// Any macro called `select` is checked, which covers the ones of tokio and futures, but may
+    /// include unrelated ones.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// loop {
+    ///     tokio::select! {
+    ///         res = reader.read_exact(&mut buf) => handle(res?, &buf),
+    ///         _ = shutdown.recv() => break,
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let read = reader.read_exact(&mut buf);
+    /// tokio::pin!(read);
+    /// tokio::select! {
+    ///     res = &mut read => handle(res?),
+    ///     _ = shutdown.recv() => {},
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub SELECT_WITHOUT_BIASED_OR_LOOP_GUARD,
+    suspicious,
+    "racing a future which isn't cancel safe in a `select!` in a loop"
+}
+
+declare_lint_pass!(SelectWithoutBiasedOrLoopGuard => [SELECT_WITHOUT_BIASED_OR_LOOP_GUARD]);
+
+impl<'tcx> LateLintPass<'tcx> for SelectWithoutBiasedOrLoopGuard {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::Loop(block, ..) = expr.kind else {
+            return;
+        };
+        if in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        // nested loops are checked on their own
+        for_each_expr(block, |e| {
+            if let ExprKind::Loop(..) = e.kind {
+                return ControlFlow::<(), _>::Continue(Descend::No);
+            }
+            if let ExprKind::MethodCall(path, ..) = e.kind
+                && !e.span.from_expansion()
+                && is_not_cancel_safe(cx, e)
+                && !is_awaited(cx, e)
+                && let Some(select_span) = enclosing_select(cx, e, expr.hir_id)
+            {
+                span_lint_and_then(
+                    cx,
+                    SELECT_WITHOUT_BIASED_OR_LOOP_GUARD,
+                    e.span,
+                    &format!("the future returned by `{}` isn't cancel safe", path.ident),
+                    |diag| {
+                        diag.span_note(
+                            select_span,
+                            "it's dropped, together with the data it has read or written so far, if this \
+                            `select!` completes with another branch",
+                        );
+                        diag.help(
+                            "consider creating the future once before the loop and polling it with `&mut`, \
+                            or using a cancel safe method",
+                        );
+                    },
+                );
+            }
+            ControlFlow::Continue(Descend::Yes)
+        });
+    }
+}
+
+fn is_not_cancel_safe(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let Some(id) = cx.typeck_results().type_dependent_def_id(e.hir_id) else {
+        return false;
+    };
+    let Some(trait_id) = cx.tcx.trait_of_item(id) else {
+        return false;
+    };
+    let name = cx.tcx.item_name(id);
+    match name.as_str() {
+        "read_exact" | "read_to_end" | "read_to_string" => match_def_path(cx, trait_id, &paths::TOKIO_IO_ASYNCREADEXT),
+        "read_line" | "read_until" => match_def_path(cx, trait_id, &paths::TOKIO_IO_ASYNCBUFREADEXT),
+        "write_all" => match_def_path(cx, trait_id, &paths::TOKIO_IO_ASYNCWRITEEXT),
+        _ => false,
+    }
+}
+
+/// Whether `e` is the operand of an `.await` written by the user, which runs it to completion.
+fn is_awaited(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    get_parent_expr(cx, e).map_or(false, |parent| {
+        macro_backtrace(parent.span).next().is_none()
+            && matches!(
+                parent.kind,
+                ExprKind::Call(
+                    Expr {
+                        kind: ExprKind::Path(QPath::LangItem(LangItem::IntoFutureIntoFuture, ..)),
+                        ..
+                    },
+                    _
+                )
+            )
+    })
+}
+
+/// Returns the span of the `select!` call within the loop `loop_id` which `e` is passed to.
+fn enclosing_select(cx: &LateContext<'_>, e: &Expr<'_>, loop_id: HirId) -> Option<Span> {
+    for id in cx.tcx.hir().parent_id_iter(e.hir_id) {
+        if id == loop_id {
+            return None;
+        }
+        if let Some(macro_call) = macro_backtrace(cx.tcx.hir().span(id))
+            .find(|macro_call| cx.tcx.item_name(macro_call.def_id).as_str() == "select")
+        {
+            return Some(macro_call.span);
+        }
+    }
+    None
+}
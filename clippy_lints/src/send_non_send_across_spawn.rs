@@ -0,0 +1,178 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{def_path_def_ids, match_def_path, path_def_id, paths};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Closure, Expr, ExprKind, ItemKind, Unsafety};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, AdtDef, Ty};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for closures passed to `std::thread::spawn` and futures passed to `tokio::spawn`
+    /// which capture a value of a type that only implements `Send` through an `unsafe impl`,
+    /// and which is either listed in the `send-wrapper-types` configuration, or is a type of the
+    /// crate with a field containing an `Rc` or a `RefCell`.
+    ///
+    /// ### Why is this bad?
+    /// Capturing an `Rc`, a `RefCell` or a raw pointer directly fails to compile with an error
+    /// about the bounds of the spawn function. Wrapping it in a type with an `unsafe impl Send`
+    /// silences that error, but using the value on another thread is still unsound, and the
+    /// problem is hard to see at the spawn. This lint names the captured variable and the
+    /// reason its type isn't safe to send.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::rc::Rc;
+    /// struct Shared(Rc<String>);
+    /// unsafe impl Send for Shared {}
+    ///
+    /// let shared = Shared(Rc::new(String::new()));
+    /// std::thread::spawn(move || shared.0.len());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::Arc;
+    /// struct Shared(Arc<String>);
+    ///
+    /// let shared = Shared(Arc::new(String::new()));
+    /// std::thread::spawn(move || shared.0.len());
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub SEND_NON_SEND_ACROSS_SPAWN,
+    suspicious,
+    "capturing a value which isn't safe to send in a closure or future passed to a spawn function"
+}
+
+pub struct SendNonSendAcrossSpawn {
+    conf_send_wrapper_types: Vec<String>,
+    send_wrapper_types: FxHashSet<DefId>,
+    /// The span of the field containing an `Rc` or a `RefCell` of each type of the crate which
+    /// has an `unsafe impl Send`.
+    unsafe_send_fields: FxHashMap<DefId, Span>,
+}
+
+impl SendNonSendAcrossSpawn {
+    pub fn new(conf_send_wrapper_types: Vec<String>) -> Self {
+        Self {
+            conf_send_wrapper_types,
+            send_wrapper_types: FxHashSet::default(),
+            unsafe_send_fields: FxHashMap::default(),
+        }
+    }
+
+    /// Explains why values of `adt` aren't safe to send, if they aren't.
+    fn reason(&self, cx: &LateContext<'_>, adt: AdtDef<'_>) -> Option<(Option<Span>, String)> {
+        let name = cx.tcx.item_name(adt.did());
+        if self.send_wrapper_types.contains(&adt.did()) {
+            Some((
+                None,
+                format!(
+                    "`{name}` is listed in `send-wrapper-types`, as it only implements `Send` with an `unsafe impl`"
+                ),
+            ))
+        } else {
+            self.unsafe_send_fields.get(&adt.did()).map(|&span| {
+                (
+                    Some(span),
+                    format!(
+                        "`{name}` implements `Send` with an `unsafe impl`, but this field can't be used from \
+                         multiple threads"
+                    ),
+                )
+            })
+        }
+    }
+}
+
+impl_lint_pass!(SendNonSendAcrossSpawn => [SEND_NON_SEND_ACROSS_SPAWN]);
+
+impl<'tcx> LateLintPass<'tcx> for SendNonSendAcrossSpawn {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for path in &self.conf_send_wrapper_types {
+            let segs: Vec<_> = path.split("::").collect();
+            self.send_wrapper_types.extend(def_path_def_ids(cx, &segs));
+        }
+        let Some(send_trait) = cx.tcx.get_diagnostic_item(sym::Send) else {
+            return;
+        };
+        for id in cx.tcx.hir().items() {
+            if let ItemKind::Impl(imp) = cx.tcx.hir().item(id).kind
+                && imp.unsafety == Unsafety::Unsafe
+                && let Some(trait_ref) = imp.of_trait
+                && trait_ref.trait_def_id() == Some(send_trait)
+                && let ty::Adt(adt, substs) = cx.tcx.type_of(id.owner_id).subst_identity().kind()
+                && let Some(field) = adt
+                    .all_fields()
+                    .find(|field| contains_rc_or_ref_cell(cx, field.ty(cx.tcx, substs)))
+            {
+                self.unsafe_send_fields.insert(adt.did(), cx.tcx.def_span(field.did));
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let ExprKind::Call(func, [arg]) = expr.kind else {
+            return;
+        };
+        let ExprKind::Closure(&Closure { def_id, .. }) = arg.kind else {
+            return;
+        };
+        let Some(id) = path_def_id(cx, func) else {
+            return;
+        };
+        let context = if match_def_path(cx, id, &paths::STD_THREAD_SPAWN) {
+            "the closure passed to `std::thread::spawn`"
+        } else if match_def_path(cx, id, &paths::TOKIO_SPAWN) {
+            "the future passed to `tokio::spawn`"
+        } else {
+            return;
+        };
+        if in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+
+        for captured in cx.typeck_results().closure_min_captures_flattened(def_id) {
+            let ty = captured.place.ty();
+            let Some((adt, (field_span, reason))) = ty.walk().find_map(|arg| match arg.unpack() {
+                ty::GenericArgKind::Type(ty) => match ty.kind() {
+                    ty::Adt(adt, _) => self.reason(cx, *adt).map(|reason| (*adt, reason)),
+                    _ => None,
+                },
+                _ => None,
+            }) else {
+                continue;
+            };
+            let name = cx.tcx.hir().name(captured.get_root_variable());
+            span_lint_and_then(
+                cx,
+                SEND_NON_SEND_ACROSS_SPAWN,
+                captured.get_path_span(cx.tcx),
+                &format!("`{name}` is captured by {context}, but it isn't safe to send to another thread"),
+                |diag| {
+                    if let Some(field_span) = field_span {
+                        diag.span_note(field_span, reason);
+                    } else {
+                        diag.note(reason);
+                    }
+                    diag.help(format!(
+                        "create the `{}` on the spawned thread, or make it thread-safe, for example with `Arc` and `Mutex`",
+                        cx.tcx.item_name(adt.did())
+                    ));
+                },
+            );
+        }
+    }
+}
+
+fn contains_rc_or_ref_cell<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    ty.walk().any(|arg| match arg.unpack() {
+        ty::GenericArgKind::Type(ty) => {
+            is_type_diagnostic_item(cx, ty, sym::Rc) || is_type_diagnostic_item(cx, ty, sym::RefCell)
+        },
+        _ => false,
+    })
+}
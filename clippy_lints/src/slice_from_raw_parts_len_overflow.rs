@@ -0,0 +1,154 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{expr_or_init, match_def_path, paths, SpanlessEq};
+use core::ops::ControlFlow;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks the length passed to `slice::from_raw_parts` and `slice::from_raw_parts_mut` for
+    /// two mistakes:
+    /// - it's a number of bytes divided by `size_of::<T>()`, without checking the remainder of
+    ///   the division anywhere in the function
+    /// - it's the `len()` of the buffer the pointer comes from, but the elements of that buffer
+    ///   are smaller than `T`
+    ///
+    /// ### Why is this bad?
+    /// If the number of bytes isn't a multiple of the size of `T`, the division silently drops
+    /// the trailing bytes, which usually means the input is malformed. In the second case the
+    /// slice covers more memory than the buffer, which is undefined behavior.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::mem::size_of;
+    /// fn as_words(bytes: &[u8]) -> &[u32] {
+    ///     let len = bytes.len() / size_of::<u32>();
+    ///     unsafe { std::slice::from_raw_parts(bytes.as_ptr().cast(), len) }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn as_words(bytes: &[u8]) -> Option<&[u32]> {
+    ///     match unsafe { bytes.align_to::<u32>() } {
+    ///         ([], words, []) => Some(words),
+    ///         _ => None,
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub SLICE_FROM_RAW_PARTS_LEN_OVERFLOW,
+    suspicious,
+    "passing a length to `slice::from_raw_parts` which doesn't match the memory behind the pointer"
+}
+
+declare_lint_pass!(SliceFromRawPartsLenOverflow => [SLICE_FROM_RAW_PARTS_LEN_OVERFLOW]);
+
+impl<'tcx> LateLintPass<'tcx> for SliceFromRawPartsLenOverflow {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(func, [ptr, len]) = expr.kind
+            && let ExprKind::Path(ref qpath) = func.kind
+            && let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+            && (match_def_path(cx, def_id, &paths::SLICE_FROM_RAW_PARTS)
+                || match_def_path(cx, def_id, &paths::SLICE_FROM_RAW_PARTS_MUT))
+            && let Some(elem_ty) = cx.typeck_results().node_substs(func.hir_id).types().next()
+            && !in_external_macro(cx.sess(), expr.span)
+        {
+            let len_init = expr_or_init(cx, len);
+            if let ExprKind::Binary(op, bytes, size) = len_init.kind
+                && op.node == BinOpKind::Div
+                && size_of_ty(cx, size) == Some(elem_ty)
+                && !has_remainder_check(cx, bytes)
+            {
+                span_lint_and_help(
+                    cx,
+                    SLICE_FROM_RAW_PARTS_LEN_OVERFLOW,
+                    len.span,
+                    &format!(
+                        "the length is a number of bytes divided by the size of `{elem_ty}`, but the remainder \
+                         isn't checked"
+                    ),
+                    None,
+                    &format!(
+                        "check that `{} % size_of::<{elem_ty}>()` is zero first, or use `align_to`",
+                        snippet(cx, bytes.span, "..")
+                    ),
+                );
+            } else if let Some((buf, buf_elem_ty)) = ptr_source(cx, ptr)
+                && let ExprKind::MethodCall(path, len_recv, [], _) = len_init.kind
+                && path.ident.name == sym::len
+                && SpanlessEq::new(cx).eq_expr(buf, len_recv)
+                && let Ok(buf_elem) = cx.layout_of(buf_elem_ty)
+                && let Ok(elem) = cx.layout_of(elem_ty)
+                && buf_elem.size < elem.size
+            {
+                span_lint_and_help(
+                    cx,
+                    SLICE_FROM_RAW_PARTS_LEN_OVERFLOW,
+                    len.span,
+                    &format!(
+                        "the length is the number of `{buf_elem_ty}`s in `{}`, but it's used as the number of \
+                         `{elem_ty}`s, which are larger",
+                        snippet(cx, buf.span, "..")
+                    ),
+                    None,
+                    "the slice covers more memory than the buffer, consider using `align_to`",
+                );
+            }
+        }
+    }
+}
+
+/// If `e` is `size_of::<T>()`, returns `T`.
+fn size_of_ty<'tcx>(cx: &LateContext<'tcx>, e: &Expr<'_>) -> Option<Ty<'tcx>> {
+    if let ExprKind::Call(func, []) = e.kind
+        && let ExprKind::Path(ref qpath) = func.kind
+        && let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+        && cx.tcx.is_diagnostic_item(sym::mem_size_of, def_id)
+    {
+        cx.typeck_results().node_substs(func.hir_id).types().next()
+    } else {
+        None
+    }
+}
+
+/// Whether the enclosing body also computes `bytes % ..`.
+fn has_remainder_check(cx: &LateContext<'_>, bytes: &Expr<'_>) -> bool {
+    let Some(body) = cx.enclosing_body else {
+        return true;
+    };
+    for_each_expr(cx.tcx.hir().body(body).value, |e| {
+        if let ExprKind::Binary(op, lhs, _) = e.kind
+            && op.node == BinOpKind::Rem
+            && SpanlessEq::new(cx).eq_expr(lhs, bytes)
+        {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+/// If `ptr` is `buf.as_ptr()` or `buf.as_mut_ptr()`, possibly cast, returns `buf` and the
+/// pointee type of the method's result.
+fn ptr_source<'tcx>(cx: &LateContext<'tcx>, ptr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, Ty<'tcx>)> {
+    let ptr = expr_or_init(cx, ptr);
+    match ptr.kind {
+        ExprKind::Cast(inner, _) => ptr_source(cx, inner),
+        ExprKind::MethodCall(path, recv, [], _) if path.ident.name == sym!(cast) => ptr_source(cx, recv),
+        ExprKind::MethodCall(path, buf, [], _) if matches!(path.ident.as_str(), "as_ptr" | "as_mut_ptr") => {
+            match cx.typeck_results().expr_ty(ptr).kind() {
+                ty::RawPtr(ty::TypeAndMut { ty, .. }) => Some((buf, *ty)),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
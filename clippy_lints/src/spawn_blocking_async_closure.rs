@@ -0,0 +1,119 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::{match_def_path, path_def_id, paths};
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for closures and functions passed to `tokio::task::spawn_blocking`, `Runtime::spawn_blocking` or
+    /// `Handle::spawn_blocking` which return a future, like `spawn_blocking(|| async { .. })`.
+    ///
+    /// ### Why is this bad?
+    /// The blocking thread only runs the closure, which creates the future without polling it.
+    /// None of the code in the async block runs there; the future is returned through the
+    /// `JoinHandle` instead, and is dropped without running if nothing awaits it.
+    ///
+    /// Passing the async block itself, as in `spawn_blocking(async move { .. })`, doesn't compile,
+    /// since a future isn't a closure.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// tokio::task::spawn_blocking(|| async {
+    ///     send_report().await;
+    /// });
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// tokio::spawn(async {
+    ///     send_report().await;
+    /// });
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub SPAWN_BLOCKING_ASYNC_CLOSURE,
+    suspicious,
+    "passing a closure which returns a future to `spawn_blocking`"
+}
+
+declare_lint_pass!(SpawnBlockingAsyncClosure => [SPAWN_BLOCKING_ASYNC_CLOSURE]);
+
+impl<'tcx> LateLintPass<'tcx> for SpawnBlockingAsyncClosure {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        // the span to replace with the name of the function spawning a future
+        let (spawn_span, spawn, arg) = match expr.kind {
+            ExprKind::Call(func, [arg])
+                if path_def_id(cx, func).map_or(false, |id| match_def_path(cx, id, &paths::TOKIO_SPAWN_BLOCKING)) =>
+            {
+                (func.span, "tokio::spawn", arg)
+            },
+            ExprKind::MethodCall(path, _, [arg], _)
+                if cx
+                    .typeck_results()
+                    .type_dependent_def_id(expr.hir_id)
+                    .map_or(false, |id| {
+                        match_def_path(cx, id, &paths::TOKIO_RUNTIME_SPAWN_BLOCKING)
+                            || match_def_path(cx, id, &paths::TOKIO_HANDLE_SPAWN_BLOCKING)
+                    }) =>
+            {
+                (path.ident.span, "spawn", arg)
+            },
+            _ => return,
+        };
+        if in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        // the function's output is the type parameter of the returned `JoinHandle`
+        let ty::Adt(_, substs) = cx.typeck_results().expr_ty(expr).kind() else {
+            return;
+        };
+        let Some(output) = substs.types().next() else {
+            return;
+        };
+        let Some(future_trait) = cx.tcx.lang_items().future_trait() else {
+            return;
+        };
+        if !implements_trait(cx, output, future_trait, &[]) {
+            return;
+        }
+
+        span_lint_and_then(
+            cx,
+            SPAWN_BLOCKING_ASYNC_CLOSURE,
+            arg.span,
+            "this function returns a future, which `spawn_blocking` doesn't poll",
+            |diag| {
+                diag.note("the future is returned through the `JoinHandle` without running any of its code");
+                if let ExprKind::Closure(&Closure { body, .. }) = arg.kind
+                    && let ret = cx.tcx.hir().body(body).value
+                    && is_async_block(ret)
+                    && !ret.span.from_expansion()
+                {
+                    let mut applicability = Applicability::MaybeIncorrect;
+                    let future = snippet_with_applicability(cx, ret.span, "..", &mut applicability);
+                    diag.multipart_suggestion(
+                        "spawn the async block as a task instead",
+                        vec![(spawn_span, spawn.to_owned()), (arg.span, future.into_owned())],
+                        applicability,
+                    );
+                } else {
+                    diag.help("consider spawning the future with `tokio::spawn`, or making the function synchronous");
+                }
+            },
+        );
+    }
+}
+
+fn is_async_block(e: &Expr<'_>) -> bool {
+    matches!(
+        e.kind,
+        ExprKind::Closure(&Closure {
+            movability: Some(_),
+            ..
+        })
+    )
+}
@@ -0,0 +1,210 @@
+use crate::methods::UNNECESSARY_TO_OWNED;
+use crate::unnecessary_owned_empty_strings::UNNECESSARY_OWNED_EMPTY_STRINGS;
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{expr_sig, is_type_diagnostic_item, is_type_lang_item, peel_mid_ty_refs, ExprFnSig};
+use clippy_utils::visitors::{for_each_expr, is_local_used};
+use clippy_utils::{is_lint_allowed, is_trait_item, is_trait_method, path_to_local};
+use core::ops::ControlFlow;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{BorrowKind, Expr, ExprKind, LangItem, Mutability};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Binder, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for arguments like `&x.to_string()`, `&x.to_owned()` or `String::from(x).as_str()`
+    /// passed to a parameter of type `&str` or `&[T]`, where `x` already dereferences to it.
+    ///
+    /// ### Why is this bad?
+    /// The allocated copy is only borrowed and dropped right after the call. Passing `x` itself
+    /// does the same without allocating.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn greet(name: &str) {}
+    ///
+    /// let name = String::from("Ferris");
+    /// greet(&name.to_string());
+    /// greet(String::from(&name).as_str());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn greet(name: &str) {}
+    ///
+    /// let name = String::from("Ferris");
+    /// greet(&name);
+    /// greet(&name);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub TO_OWNED_THEN_AS_REF_ARGUMENT,
+    perf,
+    "allocating a `String` or `Vec` only to borrow it as an argument"
+}
+
+declare_lint_pass!(ToOwnedThenAsRefArgument => [TO_OWNED_THEN_AS_REF_ARGUMENT]);
+
+impl<'tcx> LateLintPass<'tcx> for ToOwnedThenAsRefArgument {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        // `args` includes the receiver of a method call, matching the inputs of the signature
+        let (sig, args) = match expr.kind {
+            ExprKind::Call(func, args) => (expr_sig(cx, func), args.iter().collect::<Vec<_>>()),
+            ExprKind::MethodCall(_, recv, args, _) => (
+                cx.typeck_results()
+                    .type_dependent_def_id(expr.hir_id)
+                    .map(|id| ExprFnSig::Sig(cx.tcx.fn_sig(id).subst_identity(), Some(id))),
+                [recv].into_iter().chain(args).collect(),
+            ),
+            _ => return,
+        };
+        let Some(sig) = sig else {
+            return;
+        };
+        let inputs = (0..args.len())
+            .map(|i| sig.input(i).map(Binder::skip_binder))
+            .collect::<Vec<_>>();
+
+        for (i, arg) in args.iter().enumerate() {
+            if let Some(Some(param_ty)) = inputs.get(i)
+                && let Some(target) = Target::of_param(*param_ty)
+                && let Some((x, is_addr_of_method)) = borrowed_copy(cx, arg, target)
+            {
+                let (x_ty, n_refs) = peel_mid_ty_refs(cx.typeck_results().expr_ty(x));
+                let is_unsized = x_ty.is_str() || x_ty.is_slice();
+                if !(is_unsized || target.is_owned(cx, x_ty))
+                    // `unnecessary_to_owned` lints `&x.to_owned()` when `x` isn't already owned, and
+                    // `unnecessary_owned_empty_strings` lints `&String::from("")`
+                    || (is_unsized && is_addr_of_method && !is_lint_allowed(cx, UNNECESSARY_TO_OWNED, arg.hir_id))
+                    || (is_empty_str_lit(x) && !is_lint_allowed(cx, UNNECESSARY_OWNED_EMPTY_STRINGS, arg.hir_id))
+                    || conflicts_with_mut_arg(cx, x, &args, &inputs, i)
+                {
+                    continue;
+                }
+
+                let mut app = Applicability::MachineApplicable;
+                let x = Sugg::hir_with_context(cx, x, expr.span.ctxt(), "..", &mut app);
+                let sugg = if n_refs == 0 { x.addr() } else { x };
+                span_lint_and_sugg(
+                    cx,
+                    TO_OWNED_THEN_AS_REF_ARGUMENT,
+                    arg.span,
+                    &format!(
+                        "allocating a `{}` only to borrow it as `{}`",
+                        target.owned_name(),
+                        target.borrowed_name()
+                    ),
+                    "pass the original value",
+                    sugg.to_string(),
+                    app,
+                );
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Target {
+    Str,
+    Slice,
+}
+
+impl Target {
+    fn of_param(ty: Ty<'_>) -> Option<Self> {
+        match ty.kind() {
+            ty::Ref(_, inner, Mutability::Not) if inner.is_str() => Some(Self::Str),
+            ty::Ref(_, inner, Mutability::Not) if inner.is_slice() => Some(Self::Slice),
+            _ => None,
+        }
+    }
+
+    /// Whether `ty` is the owned version of the target, i.e. `String` or `Vec`.
+    fn is_owned(self, cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+        match self {
+            Self::Str => is_type_lang_item(cx, ty, LangItem::String),
+            Self::Slice => is_type_diagnostic_item(cx, ty, sym::Vec),
+        }
+    }
+
+    fn owned_name(self) -> &'static str {
+        match self {
+            Self::Str => "String",
+            Self::Slice => "Vec",
+        }
+    }
+
+    fn borrowed_name(self) -> &'static str {
+        match self {
+            Self::Str => "&str",
+            Self::Slice => "&[T]",
+        }
+    }
+}
+
+/// If `arg` is `&copy` or `copy.as_str()`/`copy.as_slice()`, where `copy` is a new `String` or
+/// `Vec` created from `x`, returns `x` and whether `arg` is `&x.to_owned()` or a similar method.
+fn borrowed_copy<'tcx>(
+    cx: &LateContext<'_>,
+    arg: &'tcx Expr<'tcx>,
+    target: Target,
+) -> Option<(&'tcx Expr<'tcx>, bool)> {
+    let (copy, is_addr_of) = match arg.kind {
+        ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, copy) => (copy, true),
+        ExprKind::MethodCall(path, copy, [], _) => match (target, path.ident.as_str()) {
+            (Target::Str, "as_str") | (Target::Slice, "as_slice") => (copy, false),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    if copy.span.from_expansion() || !target.is_owned(cx, cx.typeck_results().expr_ty(copy)) {
+        return None;
+    }
+    match copy.kind {
+        ExprKind::MethodCall(path, x, [], _)
+            if match path.ident.as_str() {
+                "to_string" => is_trait_method(cx, copy, sym::ToString),
+                "to_owned" => is_trait_method(cx, copy, sym::ToOwned),
+                "to_vec" => cx.typeck_results().expr_ty_adjusted(x).peel_refs().is_slice(),
+                _ => false,
+            } =>
+        {
+            Some((x, is_addr_of))
+        },
+        ExprKind::Call(func, [x]) if is_trait_item(cx, func, sym::From) => Some((x, false)),
+        _ => None,
+    }
+}
+
+/// Whether `x` uses a local that's also used by one of the `&mut` arguments, in which case
+/// borrowing `x` for the call wouldn't compile.
+fn conflicts_with_mut_arg<'tcx>(
+    cx: &LateContext<'tcx>,
+    x: &'tcx Expr<'tcx>,
+    args: &[&'tcx Expr<'tcx>],
+    inputs: &[Option<Ty<'_>>],
+    arg_index: usize,
+) -> bool {
+    args.iter().zip(inputs).enumerate().any(|(i, (arg, input))| {
+        i != arg_index
+            && input.map_or(true, |ty| matches!(ty.kind(), ty::Ref(_, _, Mutability::Mut)))
+            && for_each_expr(*arg, |e| {
+                if let Some(id) = path_to_local(e)
+                    && is_local_used(cx, x, id)
+                {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .is_some()
+    })
+}
+
+fn is_empty_str_lit(e: &Expr<'_>) -> bool {
+    matches!(e.kind, ExprKind::Lit(lit) if matches!(lit.node, LitKind::Str(s, _) if s.is_empty()))
+}
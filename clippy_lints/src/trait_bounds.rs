@@ -248,6 +248,8 @@ impl TraitBounds {
         if gen.span.from_expansion() {
             return;
         }
+        // `cx` is only used to hash `ty`, its interior mutability doesn't change the hash
+        #[allow(clippy::interior_mutable_key_extended)]
         let mut map: UnhashMap<SpanlessTy<'_, '_>, Vec<&GenericBound<'_>>> = UnhashMap::default();
         let mut applicability = Applicability::MaybeIncorrect;
         for bound in gen.predicates {
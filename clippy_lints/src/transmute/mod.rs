@@ -1,4 +1,5 @@
 mod crosspointer_transmute;
+mod transmute_between_repr_rust_types;
 mod transmute_float_to_int;
 mod transmute_int_to_bool;
 mod transmute_int_to_char;
@@ -464,6 +465,37 @@ declare_clippy_lint! {
     "transmute results in a null function pointer, which is undefined behavior"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for transmutes between two different structs which both have the default
+    /// representation, i.e. neither is `#[repr(C)]`, `#[repr(transparent)]` or `#[repr(packed)]`.
+    ///
+    /// ### Why is this bad?
+    /// The layout of the default representation is unspecified. The compiler may reorder and pad
+    /// the fields of each struct differently, even if they're declared the same way, so the
+    /// fields of the result don't need to line up with those of the argument.
+    ///
+    /// ### Example
+    /// ```rust
+    /// struct Point { x: u16, y: u32 }
+    /// struct Pair { a: u16, b: u32 }
+    ///
+    /// let pair: Pair = unsafe { std::mem::transmute(Point { x: 1, y: 2 }) };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Point { x: u16, y: u32 }
+    /// struct Pair { a: u16, b: u32 }
+    ///
+    /// let point = Point { x: 1, y: 2 };
+    /// let pair = Pair { a: point.x, b: point.y };
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub TRANSMUTE_BETWEEN_REPR_RUST_TYPES,
+    suspicious,
+    "transmute between two structs whose layout isn't specified"
+}
+
 pub struct Transmute {
     msrv: Msrv,
 }
@@ -485,6 +517,7 @@ impl_lint_pass!(Transmute => [
     TRANSMUTE_UNDEFINED_REPR,
     TRANSMUTING_NULL,
     TRANSMUTE_NULL_TO_FN,
+    TRANSMUTE_BETWEEN_REPR_RUST_TYPES,
 ]);
 impl Transmute {
     #[must_use]
@@ -532,7 +565,8 @@ impl<'tcx> LateLintPass<'tcx> for Transmute {
                     | transmute_float_to_int::check(cx, e, from_ty, to_ty, arg, const_context)
                     | transmute_num_to_bytes::check(cx, e, from_ty, to_ty, arg, const_context)
                     | (
-                        unsound_collection_transmute::check(cx, e, from_ty, to_ty)
+                        transmute_between_repr_rust_types::check(cx, e, from_ty, to_ty)
+                        || unsound_collection_transmute::check(cx, e, from_ty, to_ty)
                         || transmute_undefined_repr::check(cx, e, from_ty, to_ty)
                     );
 
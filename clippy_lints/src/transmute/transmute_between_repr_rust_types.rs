@@ -0,0 +1,75 @@
+use super::TRANSMUTE_BETWEEN_REPR_RUST_TYPES;
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::{self, AdtDef, Ty};
+
+/// Checks for `transmute_between_repr_rust_types` lint.
+/// Returns `true` if it's triggered, otherwise returns `false`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>, from_ty: Ty<'tcx>, to_ty: Ty<'tcx>) -> bool {
+    let from_inner = peel_wrappers(cx, from_ty);
+    let to_inner = peel_wrappers(cx, to_ty);
+    let (ty::Adt(from_adt, _), ty::Adt(to_adt, _)) = (from_inner.kind(), to_inner.kind()) else {
+        return false;
+    };
+    if from_adt.did() == to_adt.did()
+        || !has_unspecified_layout(cx, *from_adt, from_inner)
+        || !has_unspecified_layout(cx, *to_adt, to_inner)
+    {
+        return false;
+    }
+
+    span_lint_and_then(
+        cx,
+        TRANSMUTE_BETWEEN_REPR_RUST_TYPES,
+        e.span,
+        &format!("transmute from `{from_ty}` to `{to_ty}`, which both have the default representation"),
+        |diag| {
+            diag.note(
+                "the compiler may order and pad the fields of each type differently, so the fields don't line up",
+            );
+            let local: Vec<_> = [from_adt, to_adt]
+                .iter()
+                .filter(|adt| adt.did().is_local())
+                .map(|adt| format!("`{}`", cx.tcx.item_name(adt.did())))
+                .collect();
+            if local.is_empty() {
+                diag.help("consider converting between the types field by field");
+            } else {
+                diag.help(format!(
+                    "consider adding `#[repr(C)]` to {}, or converting between the types field by field",
+                    local.join(" and ")
+                ));
+            }
+        },
+    );
+    true
+}
+
+/// Peels structs with a single field which isn't zero-sized, as they're laid out like that field.
+fn peel_wrappers<'tcx>(cx: &LateContext<'tcx>, mut ty: Ty<'tcx>) -> Ty<'tcx> {
+    while let ty::Adt(adt, substs) = ty.kind()
+        && adt.is_struct()
+        && let mut fields = adt
+            .all_fields()
+            .map(|field| field.ty(cx.tcx, substs))
+            .filter(|ty| cx.layout_of(*ty).map_or(true, |layout| !layout.is_zst()))
+        && let Some(field) = fields.next()
+        && fields.next().is_none()
+    {
+        ty = field;
+    }
+    ty
+}
+
+/// Whether `ty` is a struct with fields whose layout isn't specified by an attribute.
+fn has_unspecified_layout<'tcx>(cx: &LateContext<'tcx>, adt: AdtDef<'tcx>, ty: Ty<'tcx>) -> bool {
+    let repr = adt.repr();
+    adt.is_struct()
+        && !repr.c()
+        && !repr.transparent()
+        && !repr.simd()
+        && repr.pack.is_none()
+        && cx.layout_of(ty).map_or(false, |layout| !layout.is_zst())
+}
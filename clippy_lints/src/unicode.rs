@@ -98,6 +98,9 @@ fn escape<T: Iterator<Item = char>>(s: T) -> String {
     result
 }
 
+// Only strings that contain invisible characters are escaped, which is rare enough not to need a
+// single pass.
+#[allow(clippy::chained_replace)]
 fn check_str(cx: &LateContext<'_>, span: Span, id: HirId) {
     if !span_is_local(span) {
         return;
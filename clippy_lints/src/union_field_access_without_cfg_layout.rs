@@ -0,0 +1,251 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{get_parent_expr, path_to_local};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::intravisit::{walk_expr, walk_local, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, HirId, Local, Mutability, PatKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::Ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for reads of a field of a local union, when the field written last is another
+    /// field of a different size.
+    ///
+    /// ### Why is this bad?
+    /// This reinterprets the bytes of one type as another type, which is easy to get wrong: if
+    /// the field read is larger than the field written, some of its bytes are uninitialized,
+    /// and if it's smaller, which bytes it gets depends on the layout of the union and the
+    /// endianness of the target.
+    ///
+    /// ### Known problems
+    /// The fields are tracked in the order they appear in the function, ignoring branches and
+    /// loops.
+    ///
+    /// ### Example
+    /// ```rust
+    /// union Bits {
+    ///     byte: u8,
+    ///     word: u32,
+    /// }
+    ///
+    /// let bits = Bits { byte: 1 };
+    /// let word = unsafe { bits.word };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let word = u32::from(1u8);
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub UNION_FIELD_ACCESS_WITHOUT_CFG_LAYOUT,
+    suspicious,
+    "reading a union field of a different size than the one written last"
+}
+
+declare_lint_pass!(UnionFieldAccessWithoutCfgLayout => [UNION_FIELD_ACCESS_WITHOUT_CFG_LAYOUT]);
+
+impl<'tcx> LateLintPass<'tcx> for UnionFieldAccessWithoutCfgLayout {
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &'tcx Body<'tcx>) {
+        if in_external_macro(cx.sess(), body.value.span) {
+            return;
+        }
+        LastWrites {
+            cx,
+            writes: FxHashMap::default(),
+        }
+        .visit_expr(body.value);
+    }
+}
+
+struct Write<'tcx> {
+    field: Symbol,
+    ty: Ty<'tcx>,
+    span: Span,
+}
+
+/// Tracks the field of each local union which was written last.
+struct LastWrites<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    writes: FxHashMap<HirId, Write<'tcx>>,
+}
+
+impl<'tcx> LastWrites<'_, 'tcx> {
+    /// If `expr` is a local of a union type, returns its id.
+    fn union_local(&self, expr: &Expr<'_>) -> Option<HirId> {
+        path_to_local(expr).filter(|_| self.cx.typeck_results().expr_ty(expr).is_union())
+    }
+
+    /// Records the field written by `union`, if it's a union expression like `U { a: 1 }`.
+    fn union_init(&mut self, local: HirId, union: &Expr<'_>) {
+        if let ExprKind::Struct(_, [field], None) = union.kind
+            && self.cx.typeck_results().expr_ty(union).is_union()
+        {
+            self.writes.insert(
+                local,
+                Write {
+                    field: field.ident.name,
+                    ty: self.cx.typeck_results().expr_ty(field.expr),
+                    span: field.span,
+                },
+            );
+        } else {
+            self.writes.remove(&local);
+        }
+    }
+
+    fn check_read(&mut self, expr: &Expr<'tcx>, local: HirId, field: Symbol) {
+        // the field may be written through the reference
+        let borrowed_mut = matches!(
+            get_parent_expr(self.cx, expr),
+            Some(Expr {
+                kind: ExprKind::AddrOf(_, Mutability::Mut, _),
+                ..
+            })
+        ) || self.cx.typeck_results().expr_adjustments(expr).iter().any(|adjust| {
+            matches!(
+                adjust.kind,
+                Adjust::Borrow(
+                    AutoBorrow::Ref(_, AutoBorrowMutability::Mut { .. }) | AutoBorrow::RawPtr(Mutability::Mut)
+                )
+            )
+        });
+        if borrowed_mut {
+            self.writes.remove(&local);
+            return;
+        }
+
+        let Some(write) = self.writes.get(&local) else {
+            return;
+        };
+        if write.field == field || expr.span.from_expansion() {
+            return;
+        }
+        let ty = self.cx.typeck_results().expr_ty(expr);
+        let (Ok(read_layout), Ok(write_layout)) = (self.cx.layout_of(ty), self.cx.layout_of(write.ty)) else {
+            return;
+        };
+        let (read_size, write_size) = (read_layout.size.bytes(), write_layout.size.bytes());
+        if read_size == write_size {
+            return;
+        }
+
+        span_lint_and_then(
+            self.cx,
+            UNION_FIELD_ACCESS_WITHOUT_CFG_LAYOUT,
+            expr.span,
+            &format!(
+                "reading union field `{field}` ({}), but the field written last has a different size",
+                bytes(read_size)
+            ),
+            |diag| {
+                diag.span_note(
+                    write.span,
+                    format!("field `{}` ({}) is written here", write.field, bytes(write_size)),
+                );
+                if read_size > write_size {
+                    diag.note("the bytes which weren't written are uninitialized");
+                } else {
+                    diag.note("which bytes are read depends on the layout of the union and the target");
+                }
+                diag.help(format!(
+                    "if reinterpreting the bytes is intended, use `transmute` between types of the same size, \
+                     or a conversion which checks the layout like the ones of `zerocopy`, otherwise convert \
+                     the `{}` to `{ty}`",
+                    write.ty
+                ));
+            },
+        );
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for LastWrites<'_, 'tcx> {
+    fn visit_local(&mut self, local: &'tcx Local<'tcx>) {
+        walk_local(self, local);
+        if let PatKind::Binding(_, id, _, None) = local.pat.kind
+            && let Some(init) = local.init
+        {
+            self.union_init(id, init);
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        match expr.kind {
+            ExprKind::Assign(lhs, rhs, _) => {
+                if let Some(local) = self.union_local(lhs) {
+                    self.visit_expr(rhs);
+                    self.union_init(local, rhs);
+                    return;
+                }
+                if let ExprKind::Field(base, field) = lhs.kind
+                    && let Some(local) = self.union_local(base)
+                {
+                    self.visit_expr(rhs);
+                    self.writes.insert(
+                        local,
+                        Write {
+                            field: field.name,
+                            ty: self.cx.typeck_results().expr_ty(lhs),
+                            span: lhs.span,
+                        },
+                    );
+                    return;
+                }
+                // a part of a field is written, e.g. `u.pair.0 = 1`
+                if let Some(local) = self.union_local(peel_fields(lhs)) {
+                    self.visit_expr(rhs);
+                    self.writes.remove(&local);
+                    return;
+                }
+            },
+            ExprKind::AssignOp(_, lhs, _) => {
+                if let ExprKind::Field(base, field) = lhs.kind
+                    && let Some(local) = self.union_local(base)
+                {
+                    walk_expr(self, expr);
+                    self.writes.insert(
+                        local,
+                        Write {
+                            field: field.name,
+                            ty: self.cx.typeck_results().expr_ty(lhs),
+                            span: lhs.span,
+                        },
+                    );
+                    return;
+                }
+            },
+            ExprKind::Field(base, field) => {
+                if let Some(local) = self.union_local(base) {
+                    self.check_read(expr, local, field.name);
+                    return;
+                }
+            },
+            // the union is used in another way, e.g. borrowed or partially written
+            ExprKind::Path(_) => {
+                if let Some(local) = path_to_local(expr) {
+                    self.writes.remove(&local);
+                }
+            },
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+}
+
+fn peel_fields<'tcx>(mut expr: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    while let ExprKind::Field(base, _) | ExprKind::Index(base, _) = expr.kind {
+        expr = base;
+    }
+    expr
+}
+
+fn bytes(size: u64) -> String {
+    if size == 1 {
+        "1 byte".into()
+    } else {
+        format!("{size} bytes")
+    }
+}
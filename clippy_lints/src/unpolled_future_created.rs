@@ -0,0 +1,96 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::implements_trait;
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{get_enclosing_block, is_path_diagnostic_item};
+use rustc_hir::{Expr, ExprKind, Local, PatKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for futures returned by an `async fn` or another function returning
+    /// `impl Future` which are dropped right away, by passing them to `drop`, or by binding them
+    /// to an unused variable starting with `_`.
+    ///
+    /// ### Why is this bad?
+    /// A future does nothing until it's polled, so none of the code of the function is run.
+    /// The `must_use` attribute of `Future` and `let_underscore_future` don't catch these
+    /// cases. Most likely, the future was meant to be awaited or spawned.
+    ///
+    /// ### Example
+    /// ```rust
+    /// async fn flush() {}
+    /// # async fn context() {
+    /// let _flushed = flush();
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// async fn flush() {}
+    /// # async fn context() {
+    /// flush().await;
+    /// # }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub UNPOLLED_FUTURE_CREATED,
+    suspicious,
+    "creating a future and dropping it without polling it"
+}
+
+declare_lint_pass!(UnpolledFutureCreated => [UNPOLLED_FUTURE_CREATED]);
+
+impl<'tcx> LateLintPass<'tcx> for UnpolledFutureCreated {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Call(func, [arg]) = expr.kind
+            && is_path_diagnostic_item(cx, func, sym::mem_drop)
+            && !in_external_macro(cx.sess(), expr.span)
+            && is_created_future(cx, arg)
+        {
+            emit(cx, arg, "this future is dropped right away without being polled");
+        }
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'tcx>) {
+        if let PatKind::Binding(_, id, ident, None) = local.pat.kind
+            && ident.as_str().starts_with('_')
+            && let Some(init) = local.init
+            && !in_external_macro(cx.sess(), local.span)
+            && is_created_future(cx, init)
+            && let Some(block) = get_enclosing_block(cx, local.hir_id)
+            && !is_local_used(cx, block, id)
+        {
+            emit(
+                cx,
+                init,
+                &format!("this future is bound to `{ident}`, which isn't used, and dropped without being polled"),
+            );
+        }
+    }
+}
+
+/// Whether `e` is a call to a function returning an opaque `impl Future`, like an `async fn`.
+fn is_created_future(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let ty = cx.typeck_results().expr_ty(e);
+    matches!(e.kind, ExprKind::Call(..) | ExprKind::MethodCall(..))
+        && !e.span.from_expansion()
+        && matches!(ty.kind(), ty::Alias(ty::Opaque, _))
+        && cx
+            .tcx
+            .lang_items()
+            .future_trait()
+            .map_or(false, |id| implements_trait(cx, ty, id, &[]))
+}
+
+fn emit(cx: &LateContext<'_>, fut: &Expr<'_>, msg: &str) {
+    span_lint_and_help(
+        cx,
+        UNPOLLED_FUTURE_CREATED,
+        fut.span,
+        msg,
+        None,
+        "futures do nothing unless polled, consider `.await`ing or spawning it",
+    );
+}
@@ -0,0 +1,193 @@
+use crate::undocumented_unsafe_blocks::{item_has_safety_comment, HasSafetyComment};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::paths;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item, match_type};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Item, ItemKind, Mutability, Unsafety};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `unsafe impl Send` and `unsafe impl Sync` for types with a field which doesn't
+    /// implement the trait because it contains an `Rc`, a `Cell` or a `RefCell`, a `*mut` pointer,
+    /// or a generic parameter without the bound, unless the `// SAFETY:` comment of the impl
+    /// mentions each of these fields.
+    ///
+    /// ### Why is this bad?
+    /// These types aren't thread-safe for a reason: the reference count of an `Rc` and the
+    /// borrow flag of a `RefCell` aren't atomic, and a generic parameter may be a type which
+    /// mustn't be sent or shared. The impl is only sound if the rest of the type makes sure
+    /// these fields are never used from two threads, which should be explained for each of them.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::rc::Rc;
+    /// struct Cache<T> {
+    ///     shared: Rc<String>,
+    ///     value: T,
+    /// }
+    ///
+    /// unsafe impl<T> Send for Cache<T> {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::rc::Rc;
+    /// struct Cache<T> {
+    ///     shared: Rc<String>,
+    ///     value: T,
+    /// }
+    ///
+    /// // SAFETY: `shared` is never cloned, so its only reference is sent along with the `Cache`.
+    /// unsafe impl<T: Send> Send for Cache<T> {}
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub UNSOUND_SEND_SYNC_IMPL_HEURISTIC,
+    suspicious,
+    "`unsafe impl Send` or `Sync` for a type with a field which isn't thread-safe, without explaining why"
+}
+
+declare_lint_pass!(UnsoundSendSyncImplHeuristic => [UNSOUND_SEND_SYNC_IMPL_HEURISTIC]);
+
+impl<'tcx> LateLintPass<'tcx> for UnsoundSendSyncImplHeuristic {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        let ItemKind::Impl(imp) = item.kind else {
+            return;
+        };
+        let Some(trait_id) = imp.of_trait.and_then(|trait_ref| trait_ref.trait_def_id()) else {
+            return;
+        };
+        let Some(trait_name) = cx.tcx.get_diagnostic_name(trait_id) else {
+            return;
+        };
+        if !matches!(trait_name, sym::Send | sym::Sync) {
+            return;
+        }
+        if imp.unsafety != Unsafety::Unsafe || in_external_macro(cx.sess(), item.span) {
+            return;
+        }
+        let ty::Adt(adt, substs) = cx.tcx.type_of(item.owner_id).subst_identity().kind() else {
+            return;
+        };
+
+        let mut fields = Vec::new();
+        for field in adt.all_fields() {
+            let field_ty = field.ty(cx.tcx, substs);
+            if !implements_trait(cx, field_ty, trait_id, &[])
+                && let Some(culprit) = culprit(cx, field_ty, trait_id, &mut FxHashSet::default())
+            {
+                fields.push((field.name, cx.tcx.def_span(field.did), culprit));
+            }
+        }
+        let comment = match item_has_safety_comment(cx, item) {
+            HasSafetyComment::Yes(pos) => snippet_opt(cx, Span::new(pos, item.span.lo(), item.span.ctxt(), None)),
+            HasSafetyComment::No => None,
+            HasSafetyComment::Maybe => return,
+        };
+        fields.retain(|(name, ..)| {
+            comment
+                .as_ref()
+                .map_or(true, |comment| !comment.contains(name.as_str()))
+        });
+        if fields.is_empty() {
+            return;
+        }
+
+        span_lint_and_then(
+            cx,
+            UNSOUND_SEND_SYNC_IMPL_HEURISTIC,
+            item.span,
+            &format!(
+                "`unsafe impl {trait_name}` for a type with fields which don't implement `{trait_name}`, and aren't \
+                 explained"
+            ),
+            |diag| {
+                for (name, span, culprit) in &fields {
+                    diag.span_note(*span, reason(*name, *culprit));
+                }
+                diag.help(format!(
+                    "explain why the impl is sound in a `// SAFETY:` comment which mentions each of these fields, \
+                     or make them implement `{trait_name}`"
+                ));
+            },
+        );
+    }
+}
+
+/// Finds the `Rc`, the `Cell` or `RefCell`, the `*mut` pointer or the generic parameter which
+/// causes `ty` to not implement the trait, if it's one of these.
+fn culprit<'tcx>(
+    cx: &LateContext<'tcx>,
+    ty: Ty<'tcx>,
+    trait_id: DefId,
+    seen: &mut FxHashSet<DefId>,
+) -> Option<Ty<'tcx>> {
+    if is_listed(cx, ty) {
+        return Some(ty);
+    }
+    let inner = match ty.kind() {
+        ty::Adt(adt, substs) => {
+            let fields = if adt.did().is_local() && seen.insert(adt.did()) {
+                adt.all_fields().map(|field| field.ty(cx.tcx, substs)).collect()
+            } else {
+                Vec::new()
+            };
+            substs
+                .types()
+                .chain(fields)
+                .find_map(|ty| failing_culprit(cx, ty, trait_id, seen))
+        },
+        ty::Array(ty, _) | ty::Slice(ty) | ty::Ref(_, ty, _) => failing_culprit(cx, *ty, trait_id, seen),
+        ty::Tuple(tys) => tys.iter().find_map(|ty| failing_culprit(cx, ty, trait_id, seen)),
+        _ => None,
+    };
+    // e.g. `RefCell<u8>` is `Send`, but `Arc<RefCell<u8>>` isn't
+    inner.or_else(|| {
+        ty.walk().skip(1).find_map(|arg| match arg.unpack() {
+            ty::GenericArgKind::Type(ty) if is_listed(cx, ty) => Some(ty),
+            _ => None,
+        })
+    })
+}
+
+fn failing_culprit<'tcx>(
+    cx: &LateContext<'tcx>,
+    ty: Ty<'tcx>,
+    trait_id: DefId,
+    seen: &mut FxHashSet<DefId>,
+) -> Option<Ty<'tcx>> {
+    if implements_trait(cx, ty, trait_id, &[]) {
+        None
+    } else {
+        culprit(cx, ty, trait_id, seen)
+    }
+}
+
+fn is_listed<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    match ty.kind() {
+        ty::Param(_)
+        | ty::RawPtr(ty::TypeAndMut {
+            mutbl: Mutability::Mut, ..
+        }) => true,
+        ty::Adt(..) => {
+            is_type_diagnostic_item(cx, ty, sym::Rc)
+                || is_type_diagnostic_item(cx, ty, sym::RefCell)
+                || match_type(cx, ty, &paths::WEAK_RC)
+                || match_type(cx, ty, &paths::CELL)
+        },
+        _ => false,
+    }
+}
+
+fn reason(field: Symbol, culprit: Ty<'_>) -> String {
+    match culprit.kind() {
+        ty::Param(_) => format!("`{field}` contains the generic parameter `{culprit}`, which may not be thread-safe"),
+        ty::RawPtr(_) => format!("`{field}` contains the raw pointer `{culprit}`"),
+        _ => format!("`{field}` contains `{culprit}`, which isn't thread-safe"),
+    }
+}
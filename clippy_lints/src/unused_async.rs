@@ -3,7 +3,7 @@ use rustc_hir::intravisit::{walk_body, walk_expr, walk_fn, FnKind, Visitor};
 use rustc_hir::{Body, Expr, ExprKind, FnDecl, YieldSource};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::hir::nested_filter;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::def_id::LocalDefId;
 use rustc_span::Span;
 
@@ -16,6 +16,9 @@ declare_clippy_lint! {
     /// Callers of async methods either need to be calling from an async function themselves or run it on an executor, both of which
     /// causes runtime overhead and hassle for the caller.
     ///
+    /// Exported functions aren't linted if `avoid-breaking-exported-api` is set, since removing
+    /// the `async` changes their signature.
+    ///
     /// ### Example
     /// ```rust
     /// async fn get_random_number() -> i64 {
@@ -37,7 +40,20 @@ declare_clippy_lint! {
     "finds async functions with no await statements"
 }
 
-declare_lint_pass!(UnusedAsync => [UNUSED_ASYNC]);
+pub struct UnusedAsync {
+    avoid_breaking_exported_api: bool,
+}
+
+impl UnusedAsync {
+    #[must_use]
+    pub fn new(avoid_breaking_exported_api: bool) -> Self {
+        Self {
+            avoid_breaking_exported_api,
+        }
+    }
+}
+
+impl_lint_pass!(UnusedAsync => [UNUSED_ASYNC]);
 
 struct AsyncFnVisitor<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
@@ -91,7 +107,10 @@ impl<'tcx> LateLintPass<'tcx> for UnusedAsync {
         span: Span,
         def_id: LocalDefId,
     ) {
-        if !span.from_expansion() && fn_kind.asyncness().is_async() {
+        if !span.from_expansion()
+            && fn_kind.asyncness().is_async()
+            && !(self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(def_id))
+        {
             let mut visitor = AsyncFnVisitor {
                 cx,
                 found_await: false,
@@ -56,6 +56,8 @@ impl UpperCaseAcronyms {
 
 impl_lint_pass!(UpperCaseAcronyms => [UPPER_CASE_ACRONYMS]);
 
+// Every fragment is reversed back, so grapheme clusters end up in their original order.
+#[allow(clippy::chars_rev_collect_string)]
 fn correct_ident(ident: &str) -> String {
     let ident = ident.chars().rev().collect::<String>();
     let fragments = ident
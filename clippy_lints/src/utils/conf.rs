@@ -289,11 +289,11 @@ define_Conf! {
     /// arithmetic-side-effects-allowed-unary = ["SomeType", "AnotherType"]
     /// ```
     (arithmetic_side_effects_allowed_unary: rustc_data_structures::fx::FxHashSet<String> = <_>::default()),
-    /// Lint: ENUM_VARIANT_NAMES, LARGE_TYPES_PASSED_BY_VALUE, TRIVIALLY_COPY_PASS_BY_REF, UNNECESSARY_WRAPS, UNUSED_SELF, UPPER_CASE_ACRONYMS, WRONG_SELF_CONVENTION, BOX_COLLECTION, REDUNDANT_ALLOCATION, RC_BUFFER, VEC_BOX, OPTION_OPTION, LINKEDLIST, RC_MUTEX, UNNECESSARY_BOX_RETURNS.
+    /// Lint: ENUM_VARIANT_NAMES, LARGE_TYPES_PASSED_BY_VALUE, TRIVIALLY_COPY_PASS_BY_REF, UNNECESSARY_WRAPS, UNUSED_SELF, UPPER_CASE_ACRONYMS, WRONG_SELF_CONVENTION, BOX_COLLECTION, REDUNDANT_ALLOCATION, RC_BUFFER, VEC_BOX, OPTION_OPTION, LINKEDLIST, RC_MUTEX, UNNECESSARY_BOX_RETURNS, NESTED_OPTION_RESULT_FLATTENABLE, UNUSED_ASYNC.
     ///
     /// Suppress lints whenever the suggested change would cause breakage for other crates.
     (avoid_breaking_exported_api: bool = true),
-    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN.
+    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN, EXPECT_FUN_CALL, MIN_MAX_BY_PARTIAL_CMP_UNWRAP, MANUAL_STRIP_SUFFIX_INDEX, OPTION_FILTER_MAP_BOOL, NESTED_OPTION_RESULT_FLATTENABLE.
     ///
     /// The minimum rust version that the project supports
     (msrv: Option<String> = None),
@@ -538,6 +538,85 @@ define_Conf! {
     ///
     /// Minimum chars an ident can have, anything below or equal to this will be linted.
     (min_ident_chars_threshold: u64 = 1),
+    /// Lint: CHARS_REV_COLLECT_STRING.
+    ///
+    /// Whether to skip strings that are known at compile time to only contain ASCII characters.
+    (chars_rev_collect_ignore_ascii: bool = false),
+    /// Lint: CHAINED_REPLACE.
+    ///
+    /// The minimum number of chained `str::replace` calls that triggers the lint
+    (chained_replace_threshold: u64 = 3),
+    /// Lint: OPTION_ZIP_THEN_MAP_TUPLE.
+    ///
+    /// Which way of combining two `Option`s is preferred: `a.zip(b).map(|(x, y)| ..)` with `Zip`,
+    /// or `a.and_then(|x| b.map(|y| ..))` with `AndThen`
+    (option_zip_style: crate::methods::OptionZipStyle = crate::methods::OptionZipStyle::Zip),
+    /// Lint: SORT_UNSTABLE_CANDIDATE.
+    ///
+    /// Whether to lint stable sorts by indices from `enumerate`, which are unique as long as the
+    /// `Vec` isn't changed between collecting and sorting it
+    (sort_unstable_candidate_enumerate: bool = true),
+    /// Lint: BLOCKING_CALL_IN_ASYNC_FN.
+    ///
+    /// Additional blocking functions which shouldn't be called in async contexts, written as fully
+    /// qualified paths
+    (blocking_call_paths: Vec<crate::utils::conf::DisallowedPath> = Vec::new()),
+    /// Lint: DETACHED_SPAWN_RESULT.
+    ///
+    /// Fully qualified paths of functions which are meant to detach the tasks they spawn
+    (allowed_detached_spawn_wrappers: Vec<String> = Vec::new()),
+    /// Lint: LOCK_UNWRAP_POISON_NOTE.
+    ///
+    /// How poisoned `Mutex`es should be handled: with `.lock().expect("poisoned")` with `Expect`,
+    /// or by using `parking_lot::Mutex` with `ParkingLot`
+    (lock_unwrap_policy: crate::methods::LockUnwrapPolicy = crate::methods::LockUnwrapPolicy::Expect),
+    /// Lint: SEND_NON_SEND_ACROSS_SPAWN.
+    ///
+    /// Fully qualified paths of types which only implement `Send` with an `unsafe impl`, like
+    /// wrappers of raw pointers, and shouldn't be sent to spawned threads or tasks
+    (send_wrapper_types: Vec<String> = Vec::new()),
+    /// Lint: INTERVAL_TICK_DRIFT.
+    ///
+    /// Fully qualified paths of the functions which sleep for the `Duration` they're passed
+    (sleep_functions: Vec<String> = vec![
+        "std::thread::sleep".to_owned(),
+        "tokio::time::sleep".to_owned(),
+        "async_std::task::sleep".to_owned(),
+    ]),
+    /// Lint: FFI_STRING_POINTER_LIFETIME.
+    ///
+    /// Fully qualified paths of functions which keep a pointer they're passed after returning,
+    /// like foreign functions registering a callback's user data
+    (pointer_retaining_functions: Vec<String> = Vec::new()),
+    /// Lint: DROP_IMPL_MAY_PANIC.
+    ///
+    /// Fully qualified paths of functions which may be called in `Drop::drop` even though they
+    /// could panic, e.g. because they only panic on bugs
+    (allowed_drop_panic_functions: Vec<String> = Vec::new()),
+    /// Lint: LEAKY_GLOBAL_FROM_LOOP.
+    ///
+    /// Names of functions which are only called once, and may leak memory to create globals
+    (leak_initializer_functions: Vec<String> = vec!["init".to_owned(), "initialize".to_owned(), "setup".to_owned()]),
+    /// Lint: TRUNCATING_CAST_OF_LEN.
+    ///
+    /// The minimum size in bits of the integer types which lengths can be cast to without linting
+    (min_len_cast_bits: u64 = 64),
+    /// Lint: FLOAT_ACCUMULATION_IN_LOOP.
+    ///
+    /// The minimum number of iterations of loops with a known length which are linted
+    (float_accumulation_min_iterations: u64 = 100),
+    /// Lint: HASHMAP_DEFAULT_HASHER_HOT_PATH.
+    ///
+    /// Names of functions which are called often enough to create their maps and sets with a faster hasher
+    (default_hasher_hot_functions: Vec<String> = Vec::new()),
+    /// Lint: HASHMAP_DEFAULT_HASHER_HOT_PATH.
+    ///
+    /// The map type with a faster hasher to suggest instead of `HashMap`
+    (fast_hash_map_type: String = "rustc_hash::FxHashMap".to_owned()),
+    /// Lint: HASHMAP_DEFAULT_HASHER_HOT_PATH.
+    ///
+    /// The set type with a faster hasher to suggest instead of `HashSet`
+    (fast_hash_set_type: String = "rustc_hash::FxHashSet".to_owned()),
 }
 
 /// Search for the configuration file.
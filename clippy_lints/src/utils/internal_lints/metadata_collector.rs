@@ -213,6 +213,7 @@ and lints affected.
 impl Drop for MetadataCollector {
     /// You might ask: How hacky is this?
     /// My answer:     YES
+    #[allow(clippy::drop_impl_may_panic)]
     fn drop(&mut self) {
         // The metadata collector gets dropped twice, this makes sure that we only write
         // when the list is full
@@ -0,0 +1,261 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::{expr_or_init, get_parent_expr, match_def_path, path_def_id, paths};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, HirId, Mutability, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for ordinary reads and writes of a `static mut`, or of a fixed address like
+    /// `0x4000_0000 as *mut u32`, when the same place is accessed with `read_volatile` or
+    /// `write_volatile` somewhere else in the crate.
+    ///
+    /// ### Why is this bad?
+    /// Using volatile accesses for a place usually means it's a memory-mapped register, or
+    /// memory shared with an interrupt handler or some hardware. The compiler may remove,
+    /// merge or reorder the ordinary accesses, so they may never reach the device.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// const STATUS: *mut u32 = 0x4000_0000 as *mut u32;
+    ///
+    /// unsafe fn wait_ready() {
+    ///     while STATUS.read_volatile() & 1 == 0 {}
+    /// }
+    ///
+    /// unsafe fn reset() {
+    ///     *STATUS = 0;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// unsafe fn reset() {
+    ///     STATUS.write_volatile(0);
+    /// }
+    /// ```
+    #[clippy::version = "1.72.0"]
+    pub VOLATILE_ORDINARY_READ_WRITE,
+    suspicious,
+    "reading or writing a place with an ordinary access, when it's accessed with volatile operations elsewhere"
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Place {
+    Static(DefId),
+    Address(u128),
+}
+
+struct OrdinaryAccess {
+    place: Place,
+    hir_id: HirId,
+    span: Span,
+    kind: AccessKind,
+    /// The replacement of the span with a volatile access
+    sugg: Option<(Span, String, Applicability)>,
+}
+
+#[derive(Clone, Copy)]
+enum AccessKind {
+    Read,
+    Write,
+    /// A compound assignment, or an access to a field or an element of the place
+    Other,
+}
+
+#[derive(Default)]
+pub struct VolatileOrdinaryReadWrite {
+    /// The first volatile access of each place, and the name of the function used for it.
+    volatile: FxHashMap<Place, (Span, &'static str)>,
+    ordinary: Vec<OrdinaryAccess>,
+}
+
+impl_lint_pass!(VolatileOrdinaryReadWrite => [VOLATILE_ORDINARY_READ_WRITE]);
+
+impl<'tcx> LateLintPass<'tcx> for VolatileOrdinaryReadWrite {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        if let Some((ptr, name)) = volatile_access(cx, expr) {
+            if let Some(place) = pointee_place(cx, ptr) {
+                self.volatile.entry(place).or_insert((expr.span, name));
+            }
+            return;
+        }
+
+        let (place, ptr) = match expr.kind {
+            ExprKind::Unary(UnOp::Deref, ptr) if cx.typeck_results().expr_ty(ptr).is_unsafe_ptr() => {
+                let Some(place) = pointee_place(cx, ptr) else {
+                    return;
+                };
+                (place, Some(ptr))
+            },
+            ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, expr.hir_id) {
+                Res::Def(DefKind::Static(Mutability::Mut), id) => (Place::Static(id), None),
+                _ => return,
+            },
+            _ => return,
+        };
+        // taking the address isn't an access, e.g. for `&mut *ptr` or `STATIC.len()`
+        if cx
+            .typeck_results()
+            .expr_adjustments(expr)
+            .iter()
+            .any(|adjust| matches!(adjust.kind, Adjust::Borrow(_)))
+        {
+            return;
+        }
+        let parent = get_parent_expr(cx, expr);
+        let kind = match parent.map(|parent| parent.kind) {
+            Some(ExprKind::AddrOf(..)) => return,
+            Some(ExprKind::Assign(lhs, ..)) if lhs.hir_id == expr.hir_id => AccessKind::Write,
+            Some(ExprKind::AssignOp(_, lhs, _) | ExprKind::Field(lhs, _) | ExprKind::Index(lhs, _))
+                if lhs.hir_id == expr.hir_id =>
+            {
+                AccessKind::Other
+            },
+            Some(ExprKind::MethodCall(_, recv, ..)) if recv.hir_id == expr.hir_id => AccessKind::Other,
+            _ => AccessKind::Read,
+        };
+        let sugg = if expr.span.from_expansion() {
+            None
+        } else {
+            sugg(cx, expr, ptr, parent, kind)
+        };
+        self.ordinary.push(OrdinaryAccess {
+            place,
+            hir_id: expr.hir_id,
+            span: expr.span,
+            kind,
+            sugg,
+        });
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for access in &self.ordinary {
+            let Some(&(volatile_span, volatile_name)) = self.volatile.get(&access.place) else {
+                continue;
+            };
+            let place = match access.place {
+                Place::Static(id) => format!("`static mut {}`", cx.tcx.item_name(id)),
+                Place::Address(addr) => format!("the address `{addr:#x}`"),
+            };
+            let (access_name, volatile) = match access.kind {
+                AccessKind::Read => ("read", "`read_volatile`"),
+                AccessKind::Write => ("write", "`write_volatile`"),
+                AccessKind::Other => ("access", "`read_volatile` and `write_volatile` on the whole value"),
+            };
+            span_lint_hir_and_then(
+                cx,
+                VOLATILE_ORDINARY_READ_WRITE,
+                access.hir_id,
+                access.span,
+                &format!("ordinary {access_name} of {place}, which is accessed with volatile operations elsewhere"),
+                |diag| {
+                    diag.span_note(
+                        volatile_span,
+                        format!("{place} is accessed with `{volatile_name}` here"),
+                    );
+                    if let Some((span, sugg, applicability)) = &access.sugg {
+                        diag.span_suggestion(*span, format!("use {volatile}"), sugg, *applicability);
+                    } else {
+                        diag.help(format!("use {volatile}"));
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// If `expr` is a volatile read or write, returns the pointer argument and the name of the
+/// function.
+fn volatile_access<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'static str)> {
+    match expr.kind {
+        ExprKind::Call(func, [ptr, ..]) => {
+            let id = path_def_id(cx, func)?;
+            if match_def_path(cx, id, &paths::PTR_READ_VOLATILE) {
+                Some((ptr, "read_volatile"))
+            } else if match_def_path(cx, id, &paths::PTR_WRITE_VOLATILE) {
+                Some((ptr, "write_volatile"))
+            } else {
+                None
+            }
+        },
+        ExprKind::MethodCall(path, ptr, ..) if cx.typeck_results().expr_ty(ptr).is_unsafe_ptr() => {
+            match path.ident.as_str() {
+                "read_volatile" => Some((ptr, "read_volatile")),
+                "write_volatile" => Some((ptr, "write_volatile")),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Finds the place `ptr` points to, if it's a `static mut` or a constant address.
+fn pointee_place<'tcx>(cx: &LateContext<'tcx>, ptr: &'tcx Expr<'tcx>) -> Option<Place> {
+    let ptr = expr_or_init(cx, ptr);
+    match ptr.kind {
+        ExprKind::Cast(inner, _) if cx.typeck_results().expr_ty(inner).is_unsafe_ptr() => pointee_place(cx, inner),
+        ExprKind::MethodCall(path, recv, [], _)
+            if matches!(path.ident.as_str(), "cast" | "cast_mut" | "cast_const") =>
+        {
+            pointee_place(cx, recv)
+        },
+        ExprKind::AddrOf(_, _, inner) => match inner.kind {
+            ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, inner.hir_id) {
+                Res::Def(DefKind::Static(Mutability::Mut), id) => Some(Place::Static(id)),
+                _ => None,
+            },
+            _ => None,
+        },
+        ExprKind::Cast(inner, _) => match constant(cx, cx.typeck_results(), inner) {
+            Some(Constant::Int(addr)) => Some(Place::Address(addr)),
+            _ => None,
+        },
+        _ => match constant(cx, cx.typeck_results(), ptr) {
+            Some(Constant::RawPtr(addr)) => Some(Place::Address(addr)),
+            _ => None,
+        },
+    }
+}
+
+fn sugg<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    ptr: Option<&'tcx Expr<'tcx>>,
+    parent: Option<&'tcx Expr<'tcx>>,
+    kind: AccessKind,
+) -> Option<(Span, String, Applicability)> {
+    let mut applicability = Applicability::MachineApplicable;
+    let ptr = if let Some(ptr) = ptr {
+        Sugg::hir_with_applicability(cx, ptr, "..", &mut applicability)
+            .maybe_par()
+            .to_string()
+    } else {
+        let name = snippet_with_applicability(cx, expr.span, "..", &mut applicability);
+        if matches!(kind, AccessKind::Write) {
+            format!("core::ptr::addr_of_mut!({name})")
+        } else {
+            format!("core::ptr::addr_of!({name})")
+        }
+    };
+    match (kind, parent.map(|parent| (parent, parent.kind))) {
+        (AccessKind::Write, Some((parent, ExprKind::Assign(_, value, _)))) => {
+            let value = snippet_with_applicability(cx, value.span, "..", &mut applicability);
+            Some((parent.span, format!("{ptr}.write_volatile({value})"), applicability))
+        },
+        (AccessKind::Read, _) => Some((expr.span, format!("{ptr}.read_volatile()"), applicability)),
+        _ => None,
+    }
+}
@@ -90,9 +90,9 @@ use rustc_hir::intravisit::{walk_expr, FnKind, Visitor};
 use rustc_hir::LangItem::{OptionNone, ResultErr, ResultOk};
 use rustc_hir::{
     self as hir, def, Arm, ArrayLen, BindingAnnotation, Block, BlockCheckMode, Body, Closure, Destination, Expr,
-    ExprKind, FnDecl, HirId, Impl, ImplItem, ImplItemKind, ImplItemRef, IsAsync, Item, ItemKind, LangItem, Local,
-    MatchSource, Mutability, Node, OwnerId, Param, Pat, PatKind, Path, PathSegment, PrimTy, QPath, Stmt, StmtKind,
-    TraitItem, TraitItemRef, TraitRef, TyKind, UnOp,
+    ExprKind, FnDecl, ForeignItemRef, HirId, Impl, ImplItem, ImplItemKind, ImplItemRef, IsAsync, Item, ItemKind,
+    LangItem, Local, MatchSource, Mutability, Node, OwnerId, Param, Pat, PatKind, Path, PathSegment, PrimTy, QPath,
+    Stmt, StmtKind, TraitItem, TraitItemRef, TraitRef, TyKind, UnOp,
 };
 use rustc_lexer::{tokenize, TokenKind};
 use rustc_lint::{LateContext, Level, Lint, LintContext};
@@ -577,7 +577,19 @@ fn local_item_children_by_name(tcx: TyCtxt<'_>, local_id: LocalDefId, name: Symb
         ItemKind::Mod(r#mod) => r#mod
             .item_ids
             .iter()
-            .filter_map(|&item_id| res(hir.item(item_id).ident, item_id.owner_id))
+            .flat_map(|&item_id| {
+                let item = hir.item(item_id);
+                // the items of an `extern` block are in the enclosing module's namespace
+                let foreign_items = match item.kind {
+                    ItemKind::ForeignMod { items, .. } => items,
+                    _ => &[],
+                };
+                res(item.ident, item_id.owner_id).into_iter().chain(
+                    foreign_items
+                        .iter()
+                        .filter_map(|&ForeignItemRef { ident, id, .. }| res(ident, id.owner_id)),
+                )
+            })
             .collect(),
         ItemKind::Impl(r#impl) => r#impl
             .items
@@ -828,8 +840,9 @@ pub fn is_default_equivalent_call(cx: &LateContext<'_>, repl_func: &Expr<'_>) ->
 pub fn is_default_equivalent(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
     match &e.kind {
         ExprKind::Lit(lit) => match lit.node {
-            LitKind::Bool(false) | LitKind::Int(0, _) => true,
+            LitKind::Bool(false) | LitKind::Int(0, _) | LitKind::Char('\0') => true,
             LitKind::Str(s, _) => s.is_empty(),
+            LitKind::Float(s, _) => s.as_str().replace('_', "").parse::<f64>() == Ok(0.0),
             _ => false,
         },
         ExprKind::Tup(items) | ExprKind::Array(items) => items.iter().all(|x| is_default_equivalent(cx, x)),
@@ -16,14 +16,23 @@ pub const APPLICABILITY_VALUES: [[&str; 3]; 4] = [
 #[cfg(feature = "internal")]
 pub const DIAGNOSTIC_BUILDER: [&str; 3] = ["rustc_errors", "diagnostic_builder", "DiagnosticBuilder"];
 pub const ARC_PTR_EQ: [&str; 4] = ["alloc", "sync", "Arc", "ptr_eq"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const ASYNC_STD_TASK_SPAWN: [&str; 4] = ["async_std", "task", "spawn", "spawn"];
+pub const ATOMIC_FENCE: [&str; 4] = ["core", "sync", "atomic", "fence"];
+pub const ATOMIC_ORDERING: [&str; 4] = ["core", "sync", "atomic", "Ordering"];
 pub const BTREEMAP_CONTAINS_KEY: [&str; 6] = ["alloc", "collections", "btree", "map", "BTreeMap", "contains_key"];
 pub const BTREEMAP_INSERT: [&str; 6] = ["alloc", "collections", "btree", "map", "BTreeMap", "insert"];
 pub const BTREESET_ITER: [&str; 6] = ["alloc", "collections", "btree", "set", "BTreeSet", "iter"];
+pub const CELL: [&str; 3] = ["core", "cell", "Cell"];
 pub const CLONE_TRAIT_METHOD: [&str; 4] = ["core", "clone", "Clone", "clone"];
+pub const CONDVAR: [&str; 4] = ["std", "sync", "condvar", "Condvar"];
+pub const CORE_HINT_SPIN_LOOP: [&str; 3] = ["core", "hint", "spin_loop"];
 pub const CORE_ITER_CLONED: [&str; 6] = ["core", "iter", "traits", "iterator", "Iterator", "cloned"];
 pub const CORE_ITER_COPIED: [&str; 6] = ["core", "iter", "traits", "iterator", "Iterator", "copied"];
 pub const CORE_ITER_FILTER: [&str; 6] = ["core", "iter", "traits", "iterator", "Iterator", "filter"];
 pub const CORE_RESULT_OK_METHOD: [&str; 4] = ["core", "result", "Result", "ok"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const CROSSBEAM_CHANNEL_RECEIVER: [&str; 3] = ["crossbeam_channel", "channel", "Receiver"];
 pub const CSTRING_AS_C_STR: [&str; 5] = ["alloc", "ffi", "c_str", "CString", "as_c_str"];
 pub const DEFAULT_TRAIT_METHOD: [&str; 4] = ["core", "default", "Default", "default"];
 pub const DEREF_MUT_TRAIT_METHOD: [&str; 5] = ["core", "ops", "deref", "DerefMut", "deref_mut"];
@@ -37,6 +46,8 @@ pub const F64_EPSILON: [&str; 4] = ["core", "f64", "<impl f64>", "EPSILON"];
 pub const FROM_ITERATOR_METHOD: [&str; 6] = ["core", "iter", "traits", "collect", "FromIterator", "from_iter"];
 pub const FROM_STR_METHOD: [&str; 5] = ["core", "str", "traits", "FromStr", "from_str"];
 #[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const FUTURES_EXECUTOR_BLOCK_ON: [&str; 3] = ["futures_executor", "local_pool", "block_on"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
 pub const FUTURES_IO_ASYNCREADEXT: [&str; 3] = ["futures_util", "io", "AsyncReadExt"];
 #[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
 pub const FUTURES_IO_ASYNCWRITEEXT: [&str; 3] = ["futures_util", "io", "AsyncWriteExt"];
@@ -50,6 +61,9 @@ pub const IDENT_AS_STR: [&str; 4] = ["rustc_span", "symbol", "Ident", "as_str"];
 pub const INSERT_STR: [&str; 4] = ["alloc", "string", "String", "insert_str"];
 pub const ITER_EMPTY: [&str; 5] = ["core", "iter", "sources", "empty", "Empty"];
 pub const ITERTOOLS_NEXT_TUPLE: [&str; 3] = ["itertools", "Itertools", "next_tuple"];
+pub const ITERTOOLS_SORTED: [&str; 3] = ["itertools", "Itertools", "sorted"];
+pub const ITERTOOLS_SORTED_BY: [&str; 3] = ["itertools", "Itertools", "sorted_by"];
+pub const ITERTOOLS_SORTED_BY_KEY: [&str; 3] = ["itertools", "Itertools", "sorted_by_key"];
 #[cfg(feature = "internal")]
 pub const KW_MODULE: [&str; 3] = ["rustc_span", "symbol", "kw"];
 #[cfg(feature = "internal")]
@@ -64,8 +78,14 @@ pub const MSRV: [&str; 3] = ["clippy_utils", "msrvs", "Msrv"];
 pub const OPEN_OPTIONS: [&str; 3] = ["std", "fs", "OpenOptions"];
 pub const OS_STRING_AS_OS_STR: [&str; 5] = ["std", "ffi", "os_str", "OsString", "as_os_str"];
 pub const OS_STR_TO_OS_STRING: [&str; 5] = ["std", "ffi", "os_str", "OsStr", "to_os_string"];
+pub const PARKING_LOT_MAPPED_MUTEX_GUARD: [&str; 3] = ["lock_api", "mutex", "MappedMutexGuard"];
+pub const PARKING_LOT_MAPPED_REENTRANT_MUTEX_GUARD: [&str; 3] = ["lock_api", "remutex", "MappedReentrantMutexGuard"];
+pub const PARKING_LOT_MAPPED_RWLOCK_READ_GUARD: [&str; 3] = ["lock_api", "rwlock", "MappedRwLockReadGuard"];
+pub const PARKING_LOT_MAPPED_RWLOCK_WRITE_GUARD: [&str; 3] = ["lock_api", "rwlock", "MappedRwLockWriteGuard"];
 pub const PARKING_LOT_MUTEX_GUARD: [&str; 3] = ["lock_api", "mutex", "MutexGuard"];
+pub const PARKING_LOT_REENTRANT_MUTEX_GUARD: [&str; 3] = ["lock_api", "remutex", "ReentrantMutexGuard"];
 pub const PARKING_LOT_RWLOCK_READ_GUARD: [&str; 3] = ["lock_api", "rwlock", "RwLockReadGuard"];
+pub const PARKING_LOT_RWLOCK_UPGRADABLE_READ_GUARD: [&str; 3] = ["lock_api", "rwlock", "RwLockUpgradableReadGuard"];
 pub const PARKING_LOT_RWLOCK_WRITE_GUARD: [&str; 3] = ["lock_api", "rwlock", "RwLockWriteGuard"];
 pub const PATH_BUF_AS_PATH: [&str; 4] = ["std", "path", "PathBuf", "as_path"];
 pub const PATH_MAIN_SEPARATOR: [&str; 3] = ["std", "path", "MAIN_SEPARATOR"];
@@ -92,6 +112,7 @@ pub const PTR_WRITE_BYTES: [&str; 3] = ["core", "intrinsics", "write_bytes"];
 pub const PTR_WRITE_UNALIGNED: [&str; 3] = ["core", "ptr", "write_unaligned"];
 pub const PTR_WRITE_VOLATILE: [&str; 3] = ["core", "ptr", "write_volatile"];
 pub const PUSH_STR: [&str; 4] = ["alloc", "string", "String", "push_str"];
+pub const RANDOM_STATE: [&str; 5] = ["std", "collections", "hash", "map", "RandomState"];
 pub const RANGE_ARGUMENT_TRAIT: [&str; 3] = ["core", "ops", "RangeBounds"];
 pub const RC_PTR_EQ: [&str; 4] = ["alloc", "rc", "Rc", "ptr_eq"];
 pub const REFCELL_REF: [&str; 3] = ["core", "cell", "Ref"];
@@ -114,13 +135,25 @@ pub const STDERR: [&str; 4] = ["std", "io", "stdio", "stderr"];
 pub const STDOUT: [&str; 4] = ["std", "io", "stdio", "stdout"];
 pub const CONVERT_IDENTITY: [&str; 3] = ["core", "convert", "identity"];
 pub const STD_FS_CREATE_DIR: [&str; 3] = ["std", "fs", "create_dir"];
+pub const STD_IO_BUFREAD: [&str; 3] = ["std", "io", "BufRead"];
 pub const STD_IO_LINES: [&str; 3] = ["std", "io", "Lines"];
 pub const STD_IO_SEEK: [&str; 3] = ["std", "io", "Seek"];
 pub const STD_IO_SEEK_FROM_CURRENT: [&str; 4] = ["std", "io", "SeekFrom", "Current"];
 pub const STD_IO_SEEKFROM_START: [&str; 4] = ["std", "io", "SeekFrom", "Start"];
+pub const STD_IO_STDIN: [&str; 4] = ["std", "io", "stdio", "Stdin"];
+pub const STD_IO_STDIN_READ_LINE: [&str; 5] = ["std", "io", "stdio", "Stdin", "read_line"];
+pub const STD_MPSC_RECEIVER: [&str; 4] = ["std", "sync", "mpsc", "Receiver"];
+pub const STD_NET_TCP_STREAM_CONNECT: [&str; 5] = ["std", "net", "tcp", "TcpStream", "connect"];
 pub const STD_PROCESS_COMMAND: [&str; 3] = ["std", "process", "Command"];
+pub const STD_THREAD_JOIN_HANDLE: [&str; 3] = ["std", "thread", "JoinHandle"];
+pub const STD_THREAD_PARK: [&str; 3] = ["std", "thread", "park"];
+pub const STD_THREAD_PARK_TIMEOUT: [&str; 3] = ["std", "thread", "park_timeout"];
+pub const STD_THREAD_SLEEP: [&str; 3] = ["std", "thread", "sleep"];
+pub const STD_THREAD_SPAWN: [&str; 3] = ["std", "thread", "spawn"];
+pub const STD_THREAD_YIELD_NOW: [&str; 3] = ["std", "thread", "yield_now"];
 pub const STRING_AS_MUT_STR: [&str; 4] = ["alloc", "string", "String", "as_mut_str"];
 pub const STRING_AS_STR: [&str; 4] = ["alloc", "string", "String", "as_str"];
+pub const STRING_DRAIN: [&str; 3] = ["alloc", "string", "Drain"];
 pub const STRING_NEW: [&str; 4] = ["alloc", "string", "String", "new"];
 pub const STR_BYTES: [&str; 4] = ["core", "str", "<impl str>", "bytes"];
 pub const STR_CHARS: [&str; 4] = ["core", "str", "<impl str>", "chars"];
@@ -142,13 +175,34 @@ pub const SYNTAX_CONTEXT: [&str; 3] = ["rustc_span", "hygiene", "SyntaxContext"]
 pub const TO_OWNED_METHOD: [&str; 4] = ["alloc", "borrow", "ToOwned", "to_owned"];
 pub const TO_STRING_METHOD: [&str; 4] = ["alloc", "string", "ToString", "to_string"];
 #[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const TOKIO_HANDLE_BLOCK_ON: [&str; 5] = ["tokio", "runtime", "handle", "Handle", "block_on"];
+pub const TOKIO_HANDLE_SPAWN_BLOCKING: [&str; 5] = ["tokio", "runtime", "handle", "Handle", "spawn_blocking"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const TOKIO_IO_ASYNCBUFREADEXT: [&str; 5] = ["tokio", "io", "util", "async_buf_read_ext", "AsyncBufReadExt"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
 pub const TOKIO_IO_ASYNCREADEXT: [&str; 5] = ["tokio", "io", "util", "async_read_ext", "AsyncReadExt"];
 #[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
 pub const TOKIO_IO_ASYNCWRITEEXT: [&str; 5] = ["tokio", "io", "util", "async_write_ext", "AsyncWriteExt"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const TOKIO_MUTEX_BLOCKING_LOCK: [&str; 5] = ["tokio", "sync", "mutex", "Mutex", "blocking_lock"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const TOKIO_RUNTIME_BLOCK_ON: [&str; 5] = ["tokio", "runtime", "runtime", "Runtime", "block_on"];
+pub const TOKIO_RUNTIME_SPAWN_BLOCKING: [&str; 5] = ["tokio", "runtime", "runtime", "Runtime", "spawn_blocking"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const TOKIO_RWLOCK_BLOCKING_READ: [&str; 5] = ["tokio", "sync", "rwlock", "RwLock", "blocking_read"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const TOKIO_RWLOCK_BLOCKING_WRITE: [&str; 5] = ["tokio", "sync", "rwlock", "RwLock", "blocking_write"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const TOKIO_SPAWN: [&str; 4] = ["tokio", "task", "spawn", "spawn"];
+pub const TOKIO_SPAWN_BLOCKING: [&str; 4] = ["tokio", "task", "blocking", "spawn_blocking"];
+pub const TRACING_SPAN_ENTERED: [&str; 3] = ["tracing", "span", "Entered"];
+pub const TRACING_SPAN_ENTERED_SPAN: [&str; 3] = ["tracing", "span", "EnteredSpan"];
 pub const TRY_FROM: [&str; 4] = ["core", "convert", "TryFrom", "try_from"];
 pub const VEC_AS_MUT_SLICE: [&str; 4] = ["alloc", "vec", "Vec", "as_mut_slice"];
 pub const VEC_AS_SLICE: [&str; 4] = ["alloc", "vec", "Vec", "as_slice"];
+pub const VEC_DEQUE_DRAIN: [&str; 5] = ["alloc", "collections", "vec_deque", "drain", "Drain"];
 pub const VEC_DEQUE_ITER: [&str; 5] = ["alloc", "collections", "vec_deque", "VecDeque", "iter"];
+pub const VEC_DRAIN: [&str; 4] = ["alloc", "vec", "drain", "Drain"];
 pub const VEC_FROM_ELEM: [&str; 3] = ["alloc", "vec", "from_elem"];
 pub const VEC_NEW: [&str; 4] = ["alloc", "vec", "Vec", "new"];
 pub const VEC_RESIZE: [&str; 4] = ["alloc", "vec", "Vec", "resize"];
@@ -157,6 +211,10 @@ pub const WEAK_RC: [&str; 3] = ["alloc", "rc", "Weak"];
 pub const PTR_NON_NULL: [&str; 4] = ["core", "ptr", "non_null", "NonNull"];
 pub const INSTANT_NOW: [&str; 4] = ["std", "time", "Instant", "now"];
 pub const INSTANT: [&str; 3] = ["std", "time", "Instant"];
+pub const SYSTEM_TIME_NOW: [&str; 4] = ["std", "time", "SystemTime", "now"];
+pub const SYSTEM_TIME: [&str; 3] = ["std", "time", "SystemTime"];
+pub const ENV_VAR: [&str; 3] = ["std", "env", "var"];
+pub const ENV_VAR_OS: [&str; 3] = ["std", "env", "var_os"];
 pub const VEC_IS_EMPTY: [&str; 4] = ["alloc", "vec", "Vec", "is_empty"];
 pub const VEC_POP: [&str; 4] = ["alloc", "vec", "Vec", "pop"];
 pub const OPTION_UNWRAP: [&str; 4] = ["core", "option", "Option", "unwrap"];
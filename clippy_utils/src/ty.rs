@@ -1116,7 +1116,7 @@ pub fn make_normalized_projection<'tcx>(
             .substs
             .iter()
             .enumerate()
-            .find(|(_, subst)| subst.has_late_bound_regions())
+            .find(|(_, subst)| subst.has_escaping_bound_vars())
         {
             debug_assert!(
                 false,
@@ -1194,7 +1194,7 @@ pub fn make_normalized_projection_with_regions<'tcx>(
             .substs
             .iter()
             .enumerate()
-            .find(|(_, subst)| subst.has_late_bound_regions())
+            .find(|(_, subst)| subst.has_escaping_bound_vars())
         {
             debug_assert!(
                 false,
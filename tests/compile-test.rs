@@ -35,6 +35,7 @@ static TEST_DEPENDENCIES: &[&str] = &[
     "serde_derive",
     "syn",
     "tokio",
+    "tracing",
     "parking_lot",
     "rustc_semver",
 ];
@@ -63,6 +64,8 @@ extern crate rustc_semver;
 extern crate syn;
 #[allow(unused_extern_crates)]
 extern crate tokio;
+#[allow(unused_extern_crates)]
+extern crate tracing;
 
 /// Produces a string with an `--extern` flag for all UI test crate
 /// dependencies.
@@ -0,0 +1,20 @@
+#![warn(clippy::blocking_call_in_async_fn)]
+
+use std::process::Command;
+
+fn wait() {}
+
+async fn bad() {
+    wait();
+    let _ = Command::new("ls").output();
+}
+
+async fn good() {
+    let _ = Command::new("ls");
+}
+
+fn not_async() {
+    wait();
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#![warn(clippy::chained_replace)]
+
+fn main() {
+    let s = "hello world";
+    let _ = s.replace("hello", "goodbye").replace("world", "moon");
+    let _ = s.replace("hello", "goodbye");
+}
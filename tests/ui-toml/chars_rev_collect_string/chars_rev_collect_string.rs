@@ -0,0 +1,15 @@
+#![warn(clippy::chars_rev_collect_string)]
+
+const GREETING: &str = "hello";
+const ACCENTED: &str = "héllo";
+
+fn main() {
+    let s = String::from("hello");
+    let _: String = s.chars().rev().collect();
+    let _: String = ACCENTED.chars().rev().collect();
+    let _: String = "e\u{301}".chars().rev().collect();
+
+    // the string is known to be ASCII
+    let _: String = "hello".chars().rev().collect();
+    let _: String = GREETING.chars().rev().collect();
+}
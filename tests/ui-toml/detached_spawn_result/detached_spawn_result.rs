@@ -0,0 +1,16 @@
+#![warn(clippy::detached_spawn_result)]
+
+async fn save() {}
+
+fn fire_and_forget<F: std::future::Future<Output = ()> + Send + 'static>(task: F) {
+    tokio::spawn(task);
+}
+
+fn not_allowed<F: std::future::Future<Output = ()> + Send + 'static>(task: F) {
+    tokio::spawn(task);
+}
+
+fn main() {
+    fire_and_forget(save());
+    not_allowed(save());
+}
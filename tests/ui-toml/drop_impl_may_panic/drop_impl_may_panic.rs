@@ -0,0 +1,20 @@
+#![warn(clippy::drop_impl_may_panic)]
+
+#[track_caller]
+fn checked_len(v: &[u8]) -> usize {
+    assert!(v.len() < 1024);
+    v.len()
+}
+
+pub struct Buffer(Vec<u8>, Option<u8>);
+
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        let _ = checked_len(&self.0);
+        let _ = self.1.unwrap();
+        // `expect` isn't allowed
+        let _ = self.1.expect("missing");
+    }
+}
+
+fn main() {}
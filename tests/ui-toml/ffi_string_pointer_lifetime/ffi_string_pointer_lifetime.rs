@@ -0,0 +1,23 @@
+#![warn(clippy::ffi_string_pointer_lifetime)]
+#![allow(temporary_cstring_as_ptr)]
+
+use std::ffi::{c_char, c_void, CString};
+
+extern "C" {
+    fn set_user_data(data: *const c_void);
+    fn print(message: *const c_char);
+}
+
+pub struct Registry;
+
+impl Registry {
+    fn register(&self, _name: *const u8) {}
+}
+
+fn main() {
+    unsafe { set_user_data(vec![1u8, 2].as_ptr().cast()) };
+    Registry.register(String::from("name").as_ptr());
+
+    // ok: `print` doesn't keep the pointer
+    unsafe { print(CString::new("message").unwrap().as_ptr()) };
+}
@@ -0,0 +1,11 @@
+#![warn(clippy::float_accumulation_in_loop)]
+
+fn main() {
+    let mut sum = 0.0;
+    for i in 0..10 {
+        sum += f64::from(i);
+    }
+    for x in [1.0, 2.0, 3.0] {
+        sum += x;
+    }
+}
@@ -0,0 +1,21 @@
+#![allow(unused)]
+#![warn(clippy::hashmap_default_hasher_hot_path)]
+
+use std::collections::{HashMap, HashSet};
+
+fn handle_request(ids: &[u32]) -> usize {
+    let unique: HashSet<u32> = ids.iter().copied().collect();
+    unique.len()
+}
+
+fn in_loop() {
+    for _ in 0..10 {
+        let map: HashMap<u32, u32> = HashMap::new();
+    }
+}
+
+fn not_hot() -> HashMap<u32, u32> {
+    HashMap::new()
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+#![warn(clippy::interval_tick_drift)]
+
+use std::time::Duration;
+
+fn work() {}
+
+fn pause(_: Duration) {}
+
+pub fn custom() {
+    loop {
+        work();
+        pause(Duration::from_secs(1));
+    }
+}
+
+pub fn std_is_replaced() {
+    loop {
+        work();
+        std::thread::sleep(Duration::from_secs(1));
+    }
+}
+
+fn main() {}
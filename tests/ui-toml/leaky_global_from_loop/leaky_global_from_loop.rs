@@ -0,0 +1,11 @@
+#![warn(clippy::leaky_global_from_loop)]
+
+fn main() {}
+
+fn load_config() -> &'static str {
+    Box::leak(String::from("config").into_boxed_str())
+}
+
+fn init() -> &'static str {
+    Box::leak(String::from("config").into_boxed_str())
+}
@@ -0,0 +1,14 @@
+#![warn(clippy::lock_unwrap_poison_note)]
+
+use std::sync::Mutex;
+
+pub fn locks(mutex: &Mutex<u32>, lot: &parking_lot::Mutex<u32>) -> u32 {
+    let a = *mutex.lock().unwrap();
+    let b = *mutex.lock().expect("poisoned");
+    // fine
+    let c = *lot.lock();
+    let d = mutex.lock().map_or(0, |guard| *guard);
+    a + b + c + d
+}
+
+fn main() {}
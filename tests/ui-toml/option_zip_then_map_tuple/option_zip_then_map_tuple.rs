@@ -0,0 +1,20 @@
+//@run-rustfix
+#![warn(clippy::option_zip_then_map_tuple)]
+
+fn get() -> Option<i32> {
+    Some(3)
+}
+
+fn main() {
+    let a = Some(1);
+    let b = Some(2);
+
+    let _ = a.zip(b).map(|(x, y)| x + y);
+    let _ = a.zip(get().map(|y| (y, y))).map(|(x, (y, _))| x + y).unwrap_or(0);
+    let _ = a.zip(b).map(|(x, _)| x);
+
+    // don't lint
+    let _ = a.and_then(|x| b.map(|y| x + y));
+    let _ = a.zip(b).map(|pair| pair.0 + pair.1);
+    let _ = [1].iter().zip([2].iter()).map(|(x, y)| x + y);
+}
@@ -0,0 +1,29 @@
+#![warn(clippy::send_non_send_across_spawn)]
+#![allow(clippy::unsound_send_sync_impl_heuristic)]
+
+pub struct Pointer(*mut u8);
+
+unsafe impl Send for Pointer {}
+
+pub struct Other(*mut u8);
+
+unsafe impl Send for Other {}
+
+impl Pointer {
+    fn is_null(&self) -> bool {
+        self.0.is_null()
+    }
+}
+
+impl Other {
+    fn is_null(&self) -> bool {
+        self.0.is_null()
+    }
+}
+
+pub fn threads(pointer: Pointer, other: Other) {
+    std::thread::spawn(move || pointer.is_null());
+    std::thread::spawn(move || other.is_null());
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+//@run-rustfix
+#![warn(clippy::sort_unstable_candidate)]
+#![allow(clippy::stable_sort_primitive, clippy::useless_vec)]
+
+fn main() {
+    let mut v = vec![3, 1, 2];
+    v.sort();
+
+    // don't lint
+    let mut indexed: Vec<_> = ["b", "a"].iter().enumerate().collect();
+    indexed.sort_by_key(|&(i, _)| i);
+}
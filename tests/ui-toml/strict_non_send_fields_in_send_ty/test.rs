@@ -1,4 +1,5 @@
 #![warn(clippy::non_send_fields_in_send_ty)]
+#![allow(clippy::unsound_send_sync_impl_heuristic)]
 #![feature(extern_types)]
 
 use std::rc::Rc;
@@ -0,0 +1,8 @@
+#![warn(clippy::truncating_cast_of_len)]
+#![allow(clippy::cast_possible_truncation, clippy::useless_vec)]
+
+fn main() {
+    let v = vec![1u8, 2, 3];
+    let _ = v.len() as u32;
+    let _ = v.len() as u16;
+}
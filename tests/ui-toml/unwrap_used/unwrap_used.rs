@@ -2,6 +2,7 @@
 
 #![allow(
     unused_mut,
+    clippy::box_collection_temporary,
     clippy::get_first,
     clippy::from_iter_instead_of_collect,
     clippy::useless_vec
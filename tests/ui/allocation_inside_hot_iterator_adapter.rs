@@ -0,0 +1,73 @@
+#![allow(unused, clippy::cmp_owned)]
+#![warn(clippy::allocation_inside_hot_iterator_adapter)]
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+fn main() {
+    let names = ["Alice", "bob"];
+    let rows: Vec<Vec<u8>> = vec![vec![1, 2], vec![3]];
+    let needle: &[u8] = &[1, 2];
+    let set: HashSet<String> = HashSet::new();
+    let map: HashMap<Vec<u8>, usize> = HashMap::new();
+    let tree: BTreeSet<String> = BTreeSet::new();
+
+    // comparisons
+    let _ = rows.iter().filter(|row| row.to_vec() == needle).count();
+    let _ = rows.iter().any(|row| needle == row.to_vec());
+    let _ = names.iter().filter(|name| name.to_ascii_lowercase() == "bob").count();
+    let _ = names.iter().position(|name| name.to_ascii_uppercase().eq("BOB"));
+    let _ = names.iter().find(|name| format!("{name}!") == "bob!");
+    let _ = rows.iter().map(|row| row.to_vec().cmp(&needle.to_vec()));
+
+    // lookups and hashing
+    let _ = names.iter().filter(|name| set.contains(&name.to_string())).count();
+    let _ = names.iter().filter(|name| !tree.contains(&(**name).to_owned())).count();
+    let _ = rows.iter().filter_map(|row| map.get(&row.to_vec())).count();
+    let _ = names.iter().map(|name| {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        name.to_string().hash(&mut hasher);
+        hasher.finish()
+    });
+
+    // allocating in `map` only to compare in the next adapter
+    let _ = names.iter().map(|name| name.to_string()).any(|name| name == "bob");
+    let _ = rows.iter().map(|row| row.to_vec()).all(|row| map.contains_key(&row));
+    let _ = names
+        .iter()
+        .map(|name| name.to_ascii_lowercase())
+        .position(|name| "bob" == name);
+}
+
+fn no_lint() {
+    let names = ["Alice", "bob"];
+    let mut set: HashSet<String> = HashSet::new();
+
+    // the allocation is kept
+    let _: Vec<String> = names.iter().map(|name| name.to_string()).collect();
+    let _: Vec<String> = names
+        .iter()
+        .filter(|name| name.len() > 3)
+        .map(|name| name.to_ascii_lowercase())
+        .collect();
+    let _ = names.iter().filter(|name| set.insert(name.to_string())).count();
+    let _ = names
+        .iter()
+        .map(|name| name.to_string())
+        .any(|name| name == "bob" || name.into_bytes().is_empty());
+
+    // `cmp_owned` lints these
+    let _ = names.iter().filter(|name| name.to_string() == "bob").count();
+
+    // the hash of a `format!` differs from the hash of its parts
+    let _ = names.iter().filter(|name| set.contains(&format!("{name}!"))).count();
+
+    // not in an iterator adapter
+    let _ = names[0].to_ascii_lowercase() == "alice";
+    for name in names {
+        let _ = name.to_ascii_lowercase() == "alice";
+    }
+
+    // integers don't borrow as `str`
+    let _ = [1, 2].iter().filter(|n| set.contains(&n.to_string())).count();
+}
@@ -0,0 +1,56 @@
+#![warn(clippy::arc_mutex_of_copy_primitive)]
+
+use std::sync::{Arc, Mutex};
+
+static DONE: Mutex<bool> = Mutex::new(false);
+static COUNT: Mutex<u32> = Mutex::new(0);
+static BIG: Mutex<u128> = Mutex::new(0);
+
+#[derive(Debug)]
+struct Worker {
+    progress: Arc<Mutex<usize>>,
+    total: Mutex<i64>,
+    ticks: Mutex<u8>,
+    busy: Mutex<bool>,
+}
+
+impl Worker {
+    fn run(&self) {
+        *self.progress.lock().unwrap() = 10;
+        *self.total.lock().expect("poisoned") = -1;
+        *self.ticks.lock().unwrap() += 1;
+        let mut busy = self.busy.lock().unwrap();
+        *busy = true;
+    }
+
+    fn report(&self) -> String {
+        let progress = *self.progress.lock().unwrap();
+        format!("{progress} of {}", *self.total.lock().unwrap())
+    }
+}
+
+pub struct Exported {
+    pub flag: Mutex<bool>,
+}
+
+fn main() {
+    *DONE.lock().unwrap() = true;
+    if *DONE.lock().unwrap() {
+        *COUNT.lock().unwrap() += 1;
+    }
+    *BIG.lock().unwrap() = 1;
+
+    let worker = Worker {
+        progress: Arc::new(Mutex::new(0)),
+        total: Mutex::new(0),
+        ticks: Mutex::new(0),
+        busy: Mutex::new(false),
+    };
+    worker.run();
+    println!("{} {worker:?}", worker.report());
+
+    let exported = Exported {
+        flag: Mutex::new(false),
+    };
+    *exported.flag.lock().unwrap() = true;
+}
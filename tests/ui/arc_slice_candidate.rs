@@ -0,0 +1,84 @@
+#![allow(unused)]
+#![warn(clippy::arc_slice_candidate)]
+
+use std::sync::Arc;
+
+struct Config {
+    names: Arc<Vec<String>>,
+    path: Arc<String>,
+    // mutated with `Arc::make_mut`
+    ids: Arc<Vec<u32>>,
+    // its capacity is needed
+    buffer: Arc<Vec<u8>>,
+}
+
+impl Config {
+    fn first(&self) -> Option<&String> {
+        self.names.first()
+    }
+
+    fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn push_id(&mut self, id: u32) {
+        Arc::make_mut(&mut self.ids).push(id);
+    }
+
+    fn capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+}
+
+pub struct Exported {
+    pub names: Arc<Vec<String>>,
+}
+
+fn read(exported: &Exported) -> usize {
+    exported.names.len()
+}
+
+fn takes_vec(_: &Vec<u8>) {}
+
+fn takes_slice(_: &[u8]) {}
+
+fn main() {
+    let data: Arc<Vec<u8>> = Arc::new(vec![1, 2, 3]);
+    let shared = Arc::clone(&data);
+    takes_slice(&data);
+    let first = data[0];
+    std::thread::spawn(move || shared.len());
+
+    let name = Arc::new(String::from("name"));
+    let upper = name.to_uppercase();
+    println!("{name}");
+
+    let items = std::sync::Arc::new(vec![1u64, 2]);
+    let sum: u64 = items.iter().sum();
+
+    // mutated
+    let mut counts = Arc::new(vec![0u8]);
+    Arc::make_mut(&mut counts).push(1);
+
+    // unwrapped
+    let owned = Arc::new(String::new());
+    let _ = Arc::try_unwrap(owned);
+
+    // needs a `Vec`
+    let buffer: Arc<Vec<u8>> = Arc::new(Vec::new());
+    takes_vec(&buffer);
+
+    // moved
+    let moved = Arc::new(vec![0u8]);
+    let config = Config {
+        names: Arc::new(Vec::new()),
+        path: Arc::new(String::new()),
+        ids: Arc::new(Vec::new()),
+        buffer: moved,
+    };
+
+    // used in a closure
+    let mut mutated_in_closure = Arc::new(vec![0u8]);
+    let mut f = || Arc::get_mut(&mut mutated_in_closure).map(|v| v.push(1));
+    f();
+}
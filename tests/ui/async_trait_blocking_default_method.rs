@@ -0,0 +1,39 @@
+#![feature(async_fn_in_trait, return_position_impl_trait_in_trait)]
+#![allow(incomplete_features)]
+#![warn(clippy::async_trait_blocking_default_method)]
+
+use std::future::Future;
+use std::pin::Pin;
+
+pub trait Store {
+    async fn load(&self) -> std::io::Result<String> {
+        std::fs::read_to_string("store.json")
+    }
+
+    fn load_later(&self) -> impl Future<Output = std::io::Result<String>> {
+        async { std::fs::read_to_string("store.json") }
+    }
+
+    // what `#[async_trait]` expands to
+    fn load_boxed<'a>(&'a self) -> Pin<Box<dyn Future<Output = std::io::Result<String>> + Send + 'a>> {
+        Box::pin(async move { std::fs::read_to_string("store.json") })
+    }
+
+    // required methods have no body
+    async fn save(&self, data: &str) -> std::io::Result<()>;
+
+    // not async
+    fn load_blocking(&self) -> std::io::Result<String> {
+        std::fs::read_to_string("store.json")
+    }
+}
+
+pub struct File;
+
+impl Store for File {
+    async fn save(&self, _: &str) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+//@run-rustfix
+#![warn(clippy::atomics_ordering_mismatch)]
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering::{self, *};
+
+pub fn exchange(value: &AtomicUsize) {
+    let _ = value.compare_exchange(0, 1, Ordering::Relaxed, Ordering::Acquire);
+    let _ = value.compare_exchange_weak(0, 1, Release, Acquire);
+    let _ = value.compare_exchange(0, 1, Ordering::Acquire, Ordering::SeqCst);
+    // fine
+    let _ = value.compare_exchange(0, 1, Ordering::AcqRel, Ordering::Acquire);
+    let _ = value.compare_exchange(0, 1, Ordering::Release, Ordering::Relaxed);
+    let _ = value.compare_exchange(0, 1, Ordering::SeqCst, Ordering::SeqCst);
+}
+
+fn main() {}
@@ -154,6 +154,21 @@ mod parking_lot_mutex {
             baz().await
         }
     }
+
+    pub async fn bad_upgradable(x: &RwLock<u32>) -> u32 {
+        let guard = x.upgradable_read();
+        baz().await
+    }
+
+    pub async fn bad_mapped(x: &Mutex<(u32, u32)>) -> u32 {
+        let guard = parking_lot::MutexGuard::map(x.lock(), |(a, _)| a);
+        baz().await
+    }
+
+    pub async fn bad_reentrant(x: &parking_lot::ReentrantMutex<u32>) -> u32 {
+        let guard = x.lock();
+        baz().await
+    }
 }
 
 async fn baz() -> u32 {
@@ -0,0 +1,29 @@
+#![warn(clippy::await_holding_refcell_borrow_mut_extended)]
+
+#[clippy::has_significant_drop]
+struct Connection;
+
+struct Plain;
+
+async fn baz() -> u32 {
+    42
+}
+
+async fn bad() -> u32 {
+    let conn = Connection;
+    baz().await
+}
+
+async fn bad_param(conn: Connection) -> u32 {
+    baz().await
+}
+
+async fn good() -> u32 {
+    {
+        let conn = Connection;
+    }
+    let plain = Plain;
+    baz().await
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+#![warn(clippy::await_holding_span_guard)]
+
+use tracing::Instrument;
+
+async fn baz() -> u32 {
+    42
+}
+
+pub async fn bad() -> u32 {
+    let span = tracing::info_span!("bad");
+    let _guard = span.enter();
+    baz().await
+}
+
+pub async fn bad_entered() -> u32 {
+    let _span = tracing::info_span!("bad_entered").entered();
+    baz().await
+}
+
+pub async fn good_dropped() -> u32 {
+    let span = tracing::info_span!("good_dropped");
+    {
+        let _guard = span.enter();
+    }
+    baz().await
+}
+
+pub async fn good_in_scope() -> u32 {
+    let span = tracing::info_span!("good_in_scope");
+    let x = span.in_scope(|| 1);
+    baz().await + x
+}
+
+pub async fn good_instrument() -> u32 {
+    baz().instrument(tracing::info_span!("good_instrument")).await
+}
+
+pub fn not_async() -> u32 {
+    let span = tracing::info_span!("not_async");
+    let _guard = span.enter();
+    42
+}
+
+fn main() {}
@@ -0,0 +1,68 @@
+//@run-rustfix
+#![warn(clippy::await_in_loop_collectable)]
+#![allow(clippy::redundant_closure, clippy::unused_async)]
+
+pub async fn fetch(id: u32) -> u32 {
+    id
+}
+
+pub async fn fetch_ref(id: &u32) -> u32 {
+    *id
+}
+
+pub struct Conn;
+
+impl Conn {
+    pub async fn send(&mut self, id: u32) -> u32 {
+        id
+    }
+}
+
+pub async fn lint(ids: Vec<u32>) {
+    for id in ids.clone() {
+        fetch(id).await;
+    }
+
+    let mut out = Vec::new();
+    for id in ids.iter().copied() {
+        out.push(fetch(id).await);
+    }
+
+    for id in &ids {
+        let x = fetch_ref(id).await;
+        out.push(x);
+    }
+}
+
+pub async fn no_lint(ids: Vec<u32>) -> Option<u32> {
+    // the future borrows the loop's item
+    for id in ids.clone() {
+        fetch_ref(&id).await;
+    }
+
+    // the futures mutate shared state
+    let mut conn = Conn;
+    for id in ids.clone() {
+        conn.send(id).await;
+    }
+
+    // the next future depends on the previous output
+    let mut out = vec![0];
+    for id in ids.clone() {
+        out.push(fetch(id + out[out.len() - 1]).await);
+    }
+
+    // more than just awaiting
+    for id in ids.clone() {
+        let x = fetch(id).await;
+        out.push(x + 1);
+    }
+
+    // `?` returns from the enclosing function
+    for id in ids {
+        fetch(Some(id)?).await;
+    }
+    None
+}
+
+fn main() {}
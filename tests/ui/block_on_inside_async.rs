@@ -0,0 +1,34 @@
+#![warn(clippy::block_on_inside_async)]
+#![allow(clippy::unused_async)]
+
+use futures::executor::block_on;
+use tokio::runtime::{Handle, Runtime};
+
+async fn fetch() -> u32 {
+    1
+}
+
+async fn lint(rt: &Runtime, handle: &Handle) -> u32 {
+    let a = block_on(fetch());
+    let b = rt.block_on(fetch());
+    let c = handle.block_on(fetch());
+    let d = async { futures::executor::block_on(fetch()) }.await;
+    a + b + c + d
+}
+
+async fn no_lint() -> u32 {
+    // run on another thread
+    let blocking = || block_on(fetch());
+    let _ = std::thread::spawn(blocking);
+    fetch().await
+}
+
+fn not_async(rt: &Runtime) -> u32 {
+    block_on(fetch()) + rt.block_on(fetch())
+}
+
+fn main() {
+    let _ = not_async;
+    let _ = lint;
+    let _ = no_lint;
+}
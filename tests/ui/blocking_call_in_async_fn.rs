@@ -0,0 +1,50 @@
+#![warn(clippy::blocking_call_in_async_fn)]
+#![allow(clippy::sleep_in_async_with_std)]
+
+use std::fs::{self, File, OpenOptions};
+use std::net::TcpStream;
+use std::time::Duration;
+
+async fn baz() {}
+
+async fn bad() -> std::io::Result<()> {
+    let _ = fs::read_to_string("config.toml")?;
+    let file = File::open("config.toml")?;
+    let _ = file.metadata()?;
+    let _ = OpenOptions::new().read(true).open("config.toml")?;
+    let _ = TcpStream::connect("127.0.0.1:8080")?;
+    std::thread::sleep(Duration::from_millis(10));
+    let mut line = String::new();
+    std::io::stdin().read_line(&mut line)?;
+    baz().await;
+    Ok(())
+}
+
+#[allow(clippy::manual_async_fn)]
+fn bad_block() -> impl std::future::Future<Output = ()> {
+    async {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+async fn good() -> std::io::Result<()> {
+    // not blocking
+    let mut options = OpenOptions::new();
+    options.read(true);
+    let _ = Duration::from_millis(10);
+    // not run in the async context
+    let read = || fs::read_to_string("config.toml");
+    fn helper() {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    baz().await;
+    Ok(())
+}
+
+fn not_async() -> std::io::Result<()> {
+    let _ = fs::read_to_string("config.toml")?;
+    std::thread::sleep(Duration::from_millis(10));
+    Ok(())
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+//@run-rustfix
+#![warn(clippy::box_collection_temporary)]
+#![allow(clippy::needless_borrow, clippy::useless_vec, unused)]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+struct Point {
+    x: i32,
+}
+
+fn take_slice(_: &[i32]) {}
+
+fn main() {
+    let _: i32 = Box::new(vec![1, 2]).iter().sum();
+    let _ = Box::new(vec![1, 2]).len();
+    let _ = Rc::new(String::from("a")).len();
+    let _ = Arc::new([1, 2])[0];
+    let _ = Box::new(Point { x: 1 }).x;
+    let _ = *Box::new(1) + 1;
+    let _ = *Box::new(1 + 2) * 2;
+    let _: &i32 = *Box::new(&0);
+    take_slice(&Box::new(vec![1, 2]));
+    let _ = Box::new(1_i32 + 2).pow(2);
+    let v = vec![1, 2];
+    let _ = Box::new(v).len();
+
+    // don't lint
+    let boxed = Box::new(vec![1, 2]);
+    let _ = boxed.len();
+    let _ = Box::new(vec![1, 2]).clone();
+    let _: Box<[i32]> = Box::new([1, 2]);
+    let _ = &Box::new(vec![1, 2]);
+    let rc = Rc::new(1);
+    let _ = Rc::clone(&rc);
+}
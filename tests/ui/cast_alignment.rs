@@ -35,6 +35,12 @@ fn main() {
     (&1u32 as *const u32 as *const libc::c_void) as *const u32;
     // For ZST, we should trust the user. See #4256
     (&1u32 as *const u32 as *const ()) as *const u32;
+    // The pointer was aligned for the target type before being cast
+    (&1u64 as *const u64 as *const u8) as *const u16;
+    (&mut 1u64 as *mut u64).cast::<u8>().cast::<u32>();
+    (&1u16 as *const u16 as *const u8).cast::<u16>();
+    // Not aligned for the target type anywhere in the chain
+    (&1u16 as *const u16 as *const u8) as *const u32;
 
     // Issue #2881
     let mut data = [0u8, 0u8];
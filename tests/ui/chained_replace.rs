@@ -0,0 +1,31 @@
+#![warn(clippy::chained_replace)]
+#![allow(clippy::collapsible_str_replace)]
+
+fn main() {
+    let s = "<a & b>";
+    let _ = s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+    let _ = s
+        .replace("aa", "1")
+        .replace("bb", "2")
+        .replace("cc", "3")
+        .replace("dd", "4");
+
+    let owned = String::from(s);
+    let _ = owned.replace("aa", "1").replace("bb", "2").replace("cc", "3");
+
+    // Only two calls, don't lint
+    let _ = s.replace("aa", "1").replace("bb", "2");
+    let _ = s
+        .replace("aa", "1")
+        .to_lowercase()
+        .replace("bb", "2")
+        .replace("cc", "3");
+}
+
+#[warn(clippy::collapsible_str_replace)]
+fn collapsible() {
+    let s = "abc";
+    // Left to `collapsible_str_replace`
+    let _ = s.replace('a', "").replace('b', "").replace('c', "");
+    let _ = s.replace('a', "").replace('b', "").replace('c', "x");
+}
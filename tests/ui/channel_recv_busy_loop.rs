@@ -0,0 +1,75 @@
+#![warn(clippy::channel_recv_busy_loop)]
+#![allow(clippy::single_match)]
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::time::Duration;
+
+pub fn lint(rx: &Receiver<u32>) {
+    loop {
+        match rx.try_recv() {
+            Ok(msg) => println!("{msg}"),
+            Err(_) => continue,
+        }
+    }
+}
+
+pub fn lint_while(rx: &Receiver<u32>, running: &AtomicBool) {
+    while running.load(Ordering::Relaxed) {
+        match rx.try_recv() {
+            Ok(msg) => println!("{msg}"),
+            Err(TryRecvError::Empty) => {},
+            Err(TryRecvError::Disconnected) => break,
+        }
+    }
+    while running.load(Ordering::Relaxed) {
+        if let Ok(msg) = rx.try_recv() {
+            println!("{msg}");
+        }
+    }
+}
+
+pub fn no_lint(rx: &Receiver<u32>, running: &AtomicBool) {
+    // stops once the channel is empty
+    while let Ok(msg) = rx.try_recv() {
+        println!("{msg}");
+    }
+    loop {
+        match rx.try_recv() {
+            Ok(msg) => println!("{msg}"),
+            Err(TryRecvError::Empty) => break,
+            Err(TryRecvError::Disconnected) => return,
+        }
+    }
+    // sleeps
+    while running.load(Ordering::Relaxed) {
+        match rx.try_recv() {
+            Ok(msg) => println!("{msg}"),
+            Err(_) => std::thread::sleep(Duration::from_millis(10)),
+        }
+    }
+    while running.load(Ordering::Relaxed) {
+        if let Ok(msg) = rx.try_recv() {
+            println!("{msg}");
+        }
+        std::thread::yield_now();
+    }
+    // does other work between the polls
+    while running.load(Ordering::Relaxed) {
+        if let Ok(msg) = rx.try_recv() {
+            println!("{msg}");
+        }
+        render_frame();
+    }
+    loop {
+        match rx.try_recv() {
+            Ok(msg) => println!("{msg}"),
+            Err(_) => {},
+        }
+        render_frame();
+    }
+}
+
+fn render_frame() {}
+
+fn main() {}
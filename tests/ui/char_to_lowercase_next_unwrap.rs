@@ -0,0 +1,18 @@
+//@run-rustfix
+#![warn(clippy::char_to_lowercase_next_unwrap)]
+
+fn main() {
+    let c = 'a';
+    let _ = 'A'.to_lowercase().next().unwrap();
+    if c.is_ascii() {
+        let _ = c.to_uppercase().next().unwrap();
+    }
+    if c.is_ascii_alphabetic() && c != 'x' {
+        let _ = c.to_lowercase().next().expect("there is always one");
+    }
+    for c in "abc".chars() {
+        if c.is_ascii_lowercase() {
+            let _ = c.to_uppercase().next().unwrap();
+        }
+    }
+}
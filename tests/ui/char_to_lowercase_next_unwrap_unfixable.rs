@@ -0,0 +1,19 @@
+#![warn(clippy::char_to_lowercase_next_unwrap)]
+
+fn main() {
+    let c = 'ß';
+    let _ = c.to_uppercase().next().unwrap();
+    let _ = 'İ'.to_lowercase().next().unwrap();
+    if !c.is_ascii() {
+        let _ = c.to_uppercase().next().unwrap();
+    }
+    if c.is_ascii() {
+    } else {
+        let _ = c.to_lowercase().next().unwrap();
+    }
+
+    // ok
+    let _: String = c.to_uppercase().collect();
+    let _ = c.to_uppercase().next();
+    let _ = c.to_ascii_uppercase();
+}
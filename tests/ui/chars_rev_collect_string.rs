@@ -0,0 +1,16 @@
+#![warn(clippy::chars_rev_collect_string)]
+
+const GREETING: &str = "hello";
+
+fn main() {
+    let s = String::from("héllo");
+    let _: String = s.chars().rev().collect();
+    let _ = s.as_str().chars().rev().collect::<String>();
+    let _: String = "hello".chars().rev().collect();
+    let _: String = GREETING.chars().rev().collect();
+
+    // don't lint
+    let _: Vec<char> = s.chars().rev().collect();
+    let _: String = s.chars().collect();
+    let _: String = s.chars().rev().map(|c| c.to_ascii_uppercase()).collect();
+}
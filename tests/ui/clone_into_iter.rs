@@ -0,0 +1,60 @@
+//@run-rustfix
+#![warn(clippy::clone_into_iter)]
+#![allow(unused, clippy::explicit_into_iter_loop, clippy::ptr_arg, clippy::redundant_clone)]
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+struct Wrapper {
+    items: Vec<String>,
+}
+
+fn by_ref(v: &Vec<String>, d: &VecDeque<u8>, s: &BTreeSet<u8>) {
+    let _: Vec<String> = v.clone().into_iter().rev().collect();
+    let _: u32 = d.clone().into_iter().map(u32::from).sum();
+    let _ = s.clone().into_iter().max();
+}
+
+fn field(w: &Wrapper) -> usize {
+    w.items.clone().into_iter().filter(|s| s.is_empty()).count()
+}
+
+impl Wrapper {
+    fn duplicate(&mut self) {
+        // don't lint, `self.items` is mutated while iterating
+        for x in self.items.clone().into_iter() {
+            self.items.push(x);
+        }
+    }
+
+    fn count_empty(&self) -> usize {
+        self.items.clone().into_iter().filter(|s| s.is_empty()).count()
+    }
+}
+
+fn read_only(v: &Vec<String>) {
+    for s in v.clone().into_iter() {
+        println!("{}", s.len());
+    }
+    for s in v.clone().into_iter() {
+        println!("{}", &s);
+    }
+}
+
+fn main() {
+    let v = vec![String::new()];
+    let _: Vec<String> = v.clone().into_iter().rev().collect();
+
+    // don't lint
+    let mut v = vec![1, 2];
+    let it = v.clone().into_iter();
+    v.push(3);
+    let _: Vec<i32> = it.collect();
+    let map = HashMap::from([(1, 2)]);
+    let _: Vec<_> = map.clone().into_iter().collect();
+}
+
+fn moved_in_loop(v: &Vec<String>) {
+    for s in v.clone().into_iter() {
+        drop(s);
+    }
+}
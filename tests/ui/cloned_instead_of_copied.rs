@@ -2,7 +2,7 @@
 
 #![warn(clippy::cloned_instead_of_copied)]
 #![allow(unused)]
-#![allow(clippy::useless_vec)]
+#![allow(clippy::box_collection_temporary, clippy::useless_vec)]
 
 fn main() {
     // yay
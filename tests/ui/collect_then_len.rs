@@ -0,0 +1,38 @@
+//@run-rustfix
+#![warn(clippy::collect_then_len)]
+#![allow(unused, clippy::filter_next, clippy::suspicious_map)]
+
+use std::collections::{HashSet, VecDeque};
+
+fn main() {
+    let s = "héllo wörld";
+    let _ = s.chars().filter(|c| c.is_alphabetic()).collect::<String>().len();
+    let _ = s.chars().filter(|c| c.is_alphabetic()).collect::<String>().is_empty();
+    let _ = s.split(' ').collect::<String>().len();
+    let _ = s.split(' ').collect::<String>().is_empty();
+
+    // the closure has side effects, so stopping early is observable
+    let mut seen = 0;
+    let _ = s
+        .chars()
+        .map(|c| {
+            seen += 1;
+            c
+        })
+        .collect::<String>()
+        .is_empty();
+
+    let mut it = s.chars().filter(|c| c.is_alphabetic());
+    let _ = it.collect::<String>().is_empty();
+    let it = s.chars().map(|c| c.to_ascii_uppercase());
+    let _ = it.collect::<String>().len();
+
+    // don't lint, `needless_collect` covers other collections
+    let v = [1, 2, 3, 4];
+    let _ = v.iter().filter(|x| **x > 1).collect::<Vec<_>>().len();
+    let _ = v.iter().map(|x| x * 2).collect::<VecDeque<_>>().is_empty();
+    let _ = v.iter().collect::<HashSet<_>>().len();
+    let _ = s.split(' ').map(String::from).collect::<String>().len();
+    let collected = s.chars().collect::<String>();
+    let _ = collected.len();
+}
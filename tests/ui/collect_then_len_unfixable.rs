@@ -0,0 +1,10 @@
+#![warn(clippy::collect_then_len)]
+
+fn main() {
+    // `next` and `all` take the iterator by `&mut`, which needs a `mut` binding
+    let s = "a b";
+    let chars = s.chars().filter(|c| c.is_alphabetic());
+    let _ = chars.collect::<String>().is_empty();
+    let words = s.split(' ');
+    let _ = words.collect::<String>().is_empty();
+}
@@ -0,0 +1,26 @@
+//@run-rustfix
+#![warn(clippy::collect_to_string_via_vec)]
+#![allow(clippy::unnecessary_join)]
+
+fn main() {
+    let chars = ['a', 'b', 'c'];
+    let words = ["hello", "world"];
+
+    let _ = chars.into_iter().map(|c| c.to_string()).collect::<Vec<_>>().join("");
+    let _ = chars
+        .into_iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>()
+        .concat();
+    let _ = chars.iter().map(|c| c.to_string()).collect::<Vec<_>>().concat();
+    let _ = words.into_iter().map(|w| w.to_string()).collect::<Vec<_>>().concat();
+    let _ = words.iter().map(|w| w.to_uppercase()).collect::<Vec<_>>().concat();
+    let _ = words.iter().map(|w| w.to_uppercase()).collect::<Vec<String>>().join("");
+    let _ = words.into_iter().collect::<Vec<&str>>().concat();
+    let _ = words.into_iter().collect::<Vec<_>>().join("");
+    let _: String = (1..4).map(|n| n.to_string()).collect::<Vec<_>>().concat();
+
+    // should not lint
+    let _ = words.into_iter().collect::<Vec<_>>().join(" ");
+    let _ = vec![vec![1], vec![2]].into_iter().collect::<Vec<_>>().concat();
+}
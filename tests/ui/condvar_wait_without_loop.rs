@@ -0,0 +1,34 @@
+#![warn(clippy::condvar_wait_without_loop)]
+
+use std::sync::{Condvar, Mutex};
+use std::time::Duration;
+
+pub fn lint(lock: &Mutex<bool>, cvar: &Condvar) {
+    let mut ready = lock.lock().unwrap();
+    if !*ready {
+        ready = cvar.wait(ready).unwrap();
+    }
+    let (guard, _) = cvar.wait_timeout(ready, Duration::from_millis(10)).unwrap();
+    // a `for` loop doesn't re-check the condition
+    let mut ready = guard;
+    for _ in 0..3 {
+        ready = cvar.wait(ready).unwrap();
+    }
+    drop(ready);
+}
+
+pub fn no_lint(lock: &Mutex<bool>, cvar: &Condvar) {
+    let mut ready = lock.lock().unwrap();
+    while !*ready {
+        ready = cvar.wait(ready).unwrap();
+    }
+    loop {
+        if *ready {
+            break;
+        }
+        ready = cvar.wait_timeout(ready, Duration::from_millis(10)).unwrap().0;
+    }
+    let _ready = cvar.wait_while(ready, |ready| !*ready).unwrap();
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+// The signature of a closure has late-bound regions, which are bound in the closure's type and
+// don't prevent normalizing the item type of an iterator adapter holding the closure.
+
+#![warn(clippy::needless_collect)]
+#![allow(clippy::collect_then_len)]
+
+fn main() {
+    let s = "abc";
+    let _ = s.chars().filter(|c| c.is_alphabetic()).collect::<String>().is_empty();
+}
@@ -98,6 +98,30 @@ impl Default for StrDefault<'_> {
     }
 }
 
+struct FloatCharDefault {
+    a: f64,
+    b: f32,
+    c: char,
+}
+
+impl Default for FloatCharDefault {
+    fn default() -> Self {
+        Self {
+            a: 0.0,
+            b: 0_0.0_f32,
+            c: '\0',
+        }
+    }
+}
+
+struct FloatNonDefault(f64, char);
+
+impl Default for FloatNonDefault {
+    fn default() -> Self {
+        Self(0.5, ' ')
+    }
+}
+
 #[derive(Default)]
 struct AlreadyDerived(i32, bool);
 
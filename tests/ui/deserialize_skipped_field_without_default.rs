@@ -0,0 +1,76 @@
+#![warn(clippy::deserialize_skipped_field_without_default)]
+#![allow(dead_code)]
+
+extern crate serde;
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Deserialize)]
+struct Cache<T> {
+    name: String,
+    #[serde(skip)]
+    entry: Option<T>,
+    #[serde(skip_deserializing)]
+    value: T,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    name: String,
+    #[serde(flatten)]
+    extra: HashMap<String, String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Inner {
+    id: u32,
+}
+
+#[derive(Deserialize)]
+struct Outer {
+    name: String,
+    #[serde(flatten)]
+    inner: Inner,
+}
+
+// ok: the fields can be defaulted
+#[derive(Deserialize)]
+struct WithDefault<T> {
+    #[serde(skip, default = "Option::default")]
+    entry: Option<T>,
+    #[serde(skip)]
+    len: usize,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct ContainerDefault<T: Default> {
+    #[serde(skip)]
+    value: T,
+}
+
+#[derive(Deserialize)]
+struct Bounded<T: Default> {
+    #[serde(skip)]
+    value: T,
+}
+
+// ok: `flatten` without `deny_unknown_fields`
+#[derive(Deserialize)]
+struct Flattened {
+    name: String,
+    #[serde(flatten)]
+    extra: HashMap<String, String>,
+}
+
+#[allow(clippy::deserialize_skipped_field_without_default)]
+#[derive(Deserialize)]
+struct Allowed<T> {
+    #[serde(skip)]
+    value: T,
+}
+
+fn main() {}
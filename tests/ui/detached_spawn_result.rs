@@ -0,0 +1,17 @@
+#![warn(clippy::detached_spawn_result)]
+
+async fn save() {}
+
+async fn bad() {
+    tokio::spawn(save());
+    tokio::task::spawn(async { save().await });
+}
+
+async fn good() {
+    let handle = tokio::spawn(save());
+    handle.await.unwrap();
+    let _handle = tokio::spawn(save());
+    tokio::spawn(save()).await.unwrap();
+}
+
+fn main() {}
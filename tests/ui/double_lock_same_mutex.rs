@@ -0,0 +1,49 @@
+#![warn(clippy::double_lock_same_mutex)]
+#![allow(clippy::iter_on_locked_collection)]
+
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+
+pub struct State {
+    jobs: Mutex<Vec<u32>>,
+    config: RwLock<u32>,
+}
+
+impl State {
+    pub fn requeue(&self) {
+        let guard = self.jobs.lock().unwrap();
+        for job in guard.iter() {
+            self.jobs.lock().unwrap().push(job + 1);
+        }
+    }
+
+    pub fn update(&self) {
+        let config = self.config.read().unwrap();
+        if *config == 0 {
+            *self.config.write().unwrap() = 1;
+        }
+        // fine
+        let _value = *self.config.read().unwrap();
+    }
+
+    pub fn released(&self) {
+        let guard = self.jobs.lock().unwrap();
+        let len = guard.len();
+        drop(guard);
+        self.jobs.lock().unwrap().push(len as u32);
+    }
+}
+
+pub fn shared(jobs: &Arc<Mutex<Vec<u32>>>, other: &Mutex<Vec<u32>>) {
+    let mut guard = jobs.lock().expect("poisoned");
+    guard.push(1);
+    other.lock().unwrap().push(2);
+    let _ = jobs.lock();
+    // runs later
+    let later = || jobs.lock().unwrap().len();
+    consume(guard);
+    jobs.lock().unwrap().push(later() as u32);
+}
+
+fn consume(_guard: MutexGuard<'_, Vec<u32>>) {}
+
+fn main() {}
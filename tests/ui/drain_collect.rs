@@ -0,0 +1,43 @@
+//@run-rustfix
+#![warn(clippy::drain_collect)]
+#![allow(unused)]
+
+use std::collections::VecDeque;
+
+struct Wrapper {
+    items: Vec<u8>,
+}
+
+fn vec(v: &mut Vec<u8>) -> Vec<u8> {
+    v.drain(..).collect()
+}
+
+fn vec_deque(v: &mut VecDeque<u8>) -> VecDeque<u8> {
+    v.drain(..).collect()
+}
+
+fn string(s: &mut String) -> String {
+    s.drain(..).collect()
+}
+
+fn field(w: &mut Wrapper) -> Vec<u8> {
+    w.items.drain(..).collect()
+}
+
+fn main() {
+    let mut v = vec![1, 2, 3];
+    let _: Vec<i32> = v.drain(..).collect();
+    let _: Vec<i32> = v.drain(0..v.len()).collect();
+    let _: Vec<i32> = v.drain(1..).collect();
+    let mut s = String::from("hello");
+    let _: String = s.drain(2..).collect();
+    let mut d = VecDeque::from([1, 2, 3]);
+    let _ = d.drain(1..).collect::<VecDeque<_>>();
+
+    // don't lint
+    let _: VecDeque<i32> = v.drain(..).collect();
+    let _: Vec<i32> = v.drain(..2).collect();
+    let _: Vec<i32> = v.drain(1..=2).collect();
+    let _: Vec<i32> = vec![1, 2].drain(..).collect();
+    let _: Vec<char> = s.drain(..).collect();
+}
@@ -0,0 +1,71 @@
+#![warn(clippy::drop_impl_may_panic)]
+
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::Write;
+
+pub struct Log(File);
+
+impl Drop for Log {
+    fn drop(&mut self) {
+        self.0.flush().unwrap();
+    }
+}
+
+pub struct Buffer {
+    data: Vec<u8>,
+    len: usize,
+    shared: RefCell<Vec<u8>>,
+}
+
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        let written = &self.data[..self.len];
+        let first = self.data[0];
+        self.shared.borrow_mut().extend_from_slice(written);
+        assert!(first != 0, "corrupted buffer");
+        if self.len > self.data.len() {
+            panic!("length out of bounds");
+        }
+        let _ = self.data.first().expect("empty buffer");
+    }
+}
+
+// ok: errors are logged
+pub struct Logged(File);
+
+impl Drop for Logged {
+    fn drop(&mut self) {
+        if let Err(e) = self.0.flush() {
+            eprintln!("failed to flush: {e}");
+        }
+        let _ = self.0.flush();
+    }
+}
+
+// ok: constant indices into arrays, full ranges and closures
+pub struct Arrays {
+    data: [u8; 4],
+    value: Option<u8>,
+    callback: Option<Box<dyn Fn() -> u8>>,
+}
+
+impl Drop for Arrays {
+    fn drop(&mut self) {
+        self.data[0] = 0;
+        let _all = &self.data[..];
+        let value = self.value;
+        self.callback = Some(Box::new(move || value.unwrap()));
+    }
+}
+
+// ok: not `Drop`
+pub struct NotDrop(Vec<u8>);
+
+impl NotDrop {
+    pub fn close(&mut self) {
+        self.0[0] = 1;
+    }
+}
+
+fn main() {}
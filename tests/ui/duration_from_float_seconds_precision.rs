@@ -0,0 +1,31 @@
+//@run-rustfix
+#![warn(clippy::duration_from_float_seconds_precision)]
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+const MILLIS_PER_SEC: f64 = 1000.0;
+
+fn main() {
+    let millis: u64 = 1500;
+    let micros: u32 = 1500;
+    let nanos: usize = 1500;
+    let small: u16 = 15;
+
+    let _ = Duration::from_secs_f64(millis as f64 / 1000.0);
+    let _ = Duration::from_secs_f64(millis as f64 / MILLIS_PER_SEC);
+    let _ = Duration::from_secs_f64(micros as f64 / 1e6);
+    let _ = Duration::from_secs_f64(nanos as f64 / 1_000_000_000.0);
+    let _ = Duration::from_secs_f32(small as f32 / 1000.0);
+    let _ = std::time::Duration::from_secs_f64(millis as f64 / 1e3);
+
+    // ok
+    let signed: i64 = 1500;
+    let float = 1.5;
+    let large: u128 = 1500;
+    let _ = Duration::from_secs_f64(signed as f64 / 1000.0);
+    let _ = Duration::from_secs_f64(float / 1000.0);
+    let _ = Duration::from_secs_f64(large as f64 / 1000.0);
+    let _ = Duration::from_secs_f64(millis as f64 / 60.0);
+    let _ = Duration::from_secs_f64(millis as f64 * 1000.0);
+}
@@ -0,0 +1,24 @@
+#![warn(clippy::eager_map_err_format)]
+#![allow(clippy::map_err_ignore)]
+
+fn parse(s: &str) -> Result<u32, String> {
+    let n = s.parse::<u32>().map_err(|e| format!("invalid number: {e}"))?;
+    let m = s.parse::<u32>().map_err(|e| {
+        let e = e.to_string();
+        format!("invalid number: {e}")
+    })?;
+    let _ = s.parse::<u32>().map_err(|_| format!("invalid number: {s}"))?;
+    Ok(n + m)
+}
+
+fn no_try(s: &str) -> Result<u32, String> {
+    s.parse::<u32>().map_err(|e| format!("invalid number: {e}"))
+}
+
+fn other(s: &str) -> Result<u32, String> {
+    let n = s.parse::<u32>().map_err(|e| e.to_string())?;
+    let m: Option<u32> = s.parse::<u32>().ok().map(|n| n + 1);
+    Ok(n + m.unwrap_or_default())
+}
+
+fn main() {}
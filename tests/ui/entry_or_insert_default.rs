@@ -0,0 +1,53 @@
+//@run-rustfix
+#![warn(clippy::entry_or_insert_default)]
+#![allow(clippy::unnecessary_lazy_evaluations, clippy::redundant_closure, unused)]
+
+use std::collections::{BTreeMap, HashMap};
+
+fn expensive() -> Vec<u32> {
+    vec![1, 2, 3]
+}
+
+fn with_arg(x: u32) -> u32 {
+    x + 1
+}
+
+#[derive(Default)]
+struct Counter(u64);
+
+impl Counter {
+    fn new() -> Self {
+        Counter(0)
+    }
+}
+
+fn main() {
+    let mut map: HashMap<u32, Vec<u32>> = HashMap::new();
+    map.entry(1).or_insert(Vec::new());
+    map.entry(2).or_insert(vec![]);
+    map.entry(3).or_insert(Default::default());
+    map.entry(4).or_insert_with(Vec::new);
+    map.entry(5).or_insert_with(|| Vec::new());
+    map.entry(6).or_insert(expensive());
+
+    let mut strings: BTreeMap<u32, String> = BTreeMap::new();
+    strings.entry(1).or_insert(String::new());
+    strings.entry(2).or_insert_with(String::new);
+    strings.entry(3).or_insert(String::from(""));
+
+    let mut counts: HashMap<&str, u32> = HashMap::new();
+    *counts.entry("a").or_insert(0) += 1;
+    *counts.entry("b").or_insert_with(|| 0) += 1;
+    counts.entry("c").or_insert(with_arg(1));
+
+    let mut counters: HashMap<u32, Counter> = HashMap::new();
+    counters.entry(1).or_insert(Counter::new());
+
+    // don't lint
+    *counts.entry("d").or_insert(1) += 1;
+    map.entry(7).or_insert(vec![1]);
+    map.entry(8).or_insert_with(expensive);
+    strings.entry(4).or_insert(String::from("x"));
+    let mut opts: HashMap<u32, Option<u32>> = HashMap::new();
+    opts.entry(1).or_insert(Some(0));
+}
@@ -0,0 +1,23 @@
+//@run-rustfix
+#![warn(clippy::env_var_parsed_without_error_context)]
+#![allow(dead_code, clippy::unnecessary_literal_unwrap)]
+
+use std::env;
+
+fn main() {
+    let _ = env::var("HOME").unwrap();
+    let _ = env::var_os("PATH").unwrap();
+    let _: u16 = env::var("PORT").unwrap().parse().unwrap();
+    let _: u16 = env::var("PORT").expect("PORT should be set").trim().parse().unwrap();
+    let threads = env::var("THREADS").unwrap_or_default();
+    let _ = threads.parse::<usize>().unwrap();
+
+    // the context is there
+    let _ = env::var("HOME").expect("HOME should be set");
+    let _: u16 = env::var("PORT").unwrap_or_default().parse().unwrap_or(8080);
+    let _: u16 = "8080".parse().unwrap();
+}
+
+fn propagate() -> Result<u32, env::VarError> {
+    Ok(env::var("COUNT")?.parse().unwrap())
+}
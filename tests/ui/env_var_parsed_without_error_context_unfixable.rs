@@ -0,0 +1,11 @@
+#![warn(clippy::env_var_parsed_without_error_context)]
+
+use std::env;
+
+const NAME: &str = "NAME";
+
+fn main() {
+    let _ = env::var(NAME).unwrap();
+    let name = String::from("PORT");
+    let _: u16 = env::var(name).unwrap_or_default().parse().unwrap();
+}
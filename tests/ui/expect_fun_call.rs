@@ -1,6 +1,7 @@
 //@run-rustfix
 #![warn(clippy::expect_fun_call)]
 #![allow(
+    dead_code,
     clippy::to_string_in_format_args,
     clippy::uninlined_format_args,
     clippy::unnecessary_literal_unwrap
@@ -111,3 +112,17 @@ fn main() {
     let format_capture_and_value: Option<i32> = None;
     format_capture_and_value.expect(&format!("{error_code}, {}", 1));
 }
+
+fn result_error_names() {
+    let res: Result<i32, ()> = Ok(1);
+    let err = 1;
+    res.expect(&format!("code {err}"));
+    let (e, error) = (2, 3);
+    res.expect(&format!("code {err} {e} {error}"));
+}
+
+#[clippy::msrv = "1.57"]
+fn msrv_1_57() {
+    let res: Result<i32, ()> = Ok(1);
+    res.expect(&format!("{} failed", 1));
+}
@@ -6,6 +6,7 @@
     dead_code,
     unused_braces,
     clippy::borrowed_box,
+    clippy::box_collection_temporary,
     clippy::needless_borrow,
     clippy::needless_return,
     clippy::ptr_arg,
@@ -0,0 +1,80 @@
+#![warn(clippy::extern_fn_unwind_panic)]
+#![allow(improper_ctypes_definitions, clippy::no_effect, clippy::unnecessary_operation)]
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+pub extern "C" fn index(values: &[u32], i: usize) -> u32 {
+    values[i]
+}
+
+pub extern "C" fn unwrap(value: Option<&u32>) -> u32 {
+    *value.unwrap()
+}
+
+pub extern "C" fn panics(value: u32) {
+    assert!(value > 0);
+    if value == 1 {
+        panic!("one");
+    }
+    unreachable!();
+}
+
+pub extern "system" fn system(value: Result<u32, ()>) -> u32 {
+    value.expect("no value")
+}
+
+pub struct Callbacks;
+
+impl Callbacks {
+    pub extern "C" fn method(&self, values: &[u8]) -> u8 {
+        values[1..][0]
+    }
+}
+
+pub extern "C" fn checked_other(values: &[u32], other: &[u32], i: usize) -> u32 {
+    if i < other.len() { values[i] } else { 0 }
+}
+
+// ok: `C-unwind` allows unwinding
+pub extern "C-unwind" fn unwind(values: &[u32], i: usize) -> u32 {
+    values[i]
+}
+
+// ok: the Rust ABI
+pub fn rust(values: &[u32], i: usize) -> u32 {
+    values[i]
+}
+
+// ok: the panic is caught
+pub extern "C" fn caught(values: &[u32], i: usize) -> u32 {
+    catch_unwind(AssertUnwindSafe(|| values[i])).unwrap_or(0)
+}
+
+// ok: can't panic
+pub extern "C" fn no_panic(values: &[u32; 4], a: u32) -> u32 {
+    let all = &values[..];
+    let sum = 1 + 2;
+    values[3].wrapping_add(a / 2) ^ sum ^ all.first().copied().unwrap_or(0)
+}
+
+// ok: the index is bounds checked
+pub extern "C" fn checked(values: &[u32], i: usize, j: usize, k: usize) -> u32 {
+    if j >= values.len() || k >= values.len() {
+        return 0;
+    }
+    let first = if i < values.len() { values[i] } else { 0 };
+    let second = if values.len() <= i { 0 } else { values[i] };
+    first + second + values[j] + values[k]
+}
+
+// ok: arithmetic only panics with overflow checks
+pub extern "C" fn arithmetic(a: u32, b: u32) -> u32 {
+    a + b * 2
+}
+
+#[allow(clippy::extern_fn_unwind_panic)]
+pub extern "C" fn allowed(a: u32) -> u32 {
+    a * 2
+}
+
+fn main() {}
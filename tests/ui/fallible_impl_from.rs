@@ -1,5 +1,5 @@
 #![deny(clippy::fallible_impl_from)]
-#![allow(clippy::uninlined_format_args)]
+#![allow(clippy::uninlined_format_args, clippy::result_ok_then_unwrap_chain)]
 
 // docs example
 struct Foo(i32);
@@ -0,0 +1,74 @@
+#![warn(clippy::ffi_string_pointer_lifetime)]
+#![allow(temporary_cstring_as_ptr, clippy::unnecessary_cast)]
+
+use std::ffi::{c_char, CString};
+
+fn name() -> String {
+    String::from("name")
+}
+
+fn bytes() -> Vec<u8> {
+    vec![1, 2, 3]
+}
+
+extern "C" {
+    fn use_name(name: *const c_char);
+}
+
+pub struct Handle {
+    data: *const u8,
+}
+
+pub struct Raw(*mut u8);
+
+fn stored() {
+    let ptr = name().as_ptr();
+    let _ = ptr;
+    let c_ptr = CString::new("name").unwrap().as_ptr();
+    let _ = c_ptr;
+    let cast = bytes().as_ptr() as *const i8;
+    let _ = cast;
+    let handle = Handle {
+        data: bytes().as_ptr().cast(),
+    };
+    let _ = handle.data;
+    let raw = Raw(bytes().as_mut_ptr());
+    let _ = raw.0;
+    let boxed = vec![1u8].into_boxed_slice().as_ptr();
+    let _ = boxed;
+    let offset = unsafe { name().as_ptr().add(1) };
+    let _ = offset;
+}
+
+fn assigned(ptr: &mut *const u8) {
+    *ptr = name().as_ptr();
+}
+
+fn returned() -> *const u8 {
+    bytes().as_ptr()
+}
+
+fn returned_early(early: bool) -> *const u8 {
+    if early {
+        return name().as_ptr();
+    }
+    std::ptr::null()
+}
+
+// ok: the pointer is only used in the statement
+fn in_statement() {
+    unsafe { use_name(CString::new("name").unwrap().as_ptr()) };
+    let _len = unsafe { *bytes().as_ptr() };
+    let _ = name().as_ptr();
+}
+
+// ok: the buffer lives long enough
+fn bound() -> usize {
+    let name = name();
+    let ptr = name.as_ptr();
+    let bytes = &bytes();
+    let bytes_ptr = bytes.as_ptr();
+    ptr as usize + bytes_ptr as usize
+}
+
+fn main() {}
@@ -0,0 +1,53 @@
+#![warn(clippy::float_accumulation_in_loop)]
+#![allow(clippy::assign_op_pattern, clippy::needless_range_loop)]
+
+fn main() {
+    let values = vec![0.1_f64; 1000];
+
+    let mut sum = 0.0;
+    for x in &values {
+        sum += x;
+    }
+
+    let mut total = 0.0_f32;
+    for i in 0..10_000 {
+        total = total + i as f32;
+    }
+
+    let mut diff = 0.0;
+    for x in values.iter() {
+        diff -= x;
+    }
+
+    // too few iterations
+    let mut small = 0.0;
+    for i in 0..10 {
+        small += f64::from(i);
+    }
+    let mut small = 0.0;
+    for x in [1.0, 2.0, 3.0] {
+        small += x;
+    }
+
+    // integers are exact
+    let mut count = 0;
+    for _ in &values {
+        count += 2;
+    }
+
+    // declared in the loop
+    for x in &values {
+        let mut y = 0.0;
+        y += x;
+    }
+
+    // Kahan summation
+    let mut sum = 0.0;
+    let mut compensation = 0.0;
+    for x in &values {
+        let y = x - compensation;
+        let t = sum + y;
+        compensation = (t - sum) - y;
+        sum = t;
+    }
+}
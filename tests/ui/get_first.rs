@@ -31,6 +31,9 @@ fn main() {
     let _ = z.get(1);
     let _ = z[0];
 
+    let strings = vec![String::from("a")];
+    let _ = strings.get(0); // Use strings.first()
+
     let vecdeque: VecDeque<_> = x.iter().cloned().collect();
     let hashmap: HashMap<u8, char> = HashMap::from_iter(vec![(0, 'a'), (1, 'b')]);
     let btreemap: BTreeMap<u8, char> = BTreeMap::from_iter(vec![(0, 'a'), (1, 'b')]);
@@ -26,6 +26,32 @@ fn use_last_with_different_vec_length() {
     let _ = x.get(y.len() - 1);
 }
 
+fn through_binding(slice: &[u8]) {
+    let x = vec![2, 3, 5];
+    let len = x.len();
+    let _ = x.get(len - 1);
+
+    let n = slice.len();
+    let _ = slice.get(n - 1);
+}
+
+fn through_binding_mutated() {
+    let mut x = vec![2, 3, 5];
+    let len = x.len();
+    x.push(7);
+    let _ = x.get(len - 1);
+
+    let y = &mut vec![1, 2];
+    let len = y.len();
+    y.clear();
+    let _ = y.get(len - 1);
+
+    let z = vec![1];
+    let mut len = z.len();
+    len += 1;
+    let _ = z.get(len - 1);
+}
+
 struct S {
     field: Vec<usize>,
 }
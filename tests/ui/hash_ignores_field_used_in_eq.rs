@@ -0,0 +1,97 @@
+#![warn(clippy::hash_ignores_field_used_in_eq)]
+#![allow(clippy::derived_hash_with_manual_eq, clippy::explicit_auto_deref)]
+
+use std::hash::{Hash, Hasher};
+
+#[derive(PartialEq, Eq)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Hash for Point {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+    }
+}
+
+pub struct Entry {
+    key: String,
+    version: u32,
+    generation: u32,
+    cached_len: usize,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.version == other.version && self.generation == other.generation
+    }
+}
+
+impl Hash for Entry {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
+
+#[derive(PartialEq)]
+pub struct Tuple(u8, u8);
+
+impl Hash for Tuple {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u8(self.1);
+    }
+}
+
+// ok: `cached_len` isn't compared
+pub struct Cached {
+    key: String,
+    cached_len: usize,
+}
+
+impl PartialEq for Cached {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Hash for Cached {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
+
+// ok: `self` is passed to another function
+#[derive(PartialEq)]
+pub struct Opaque {
+    a: u32,
+    b: u32,
+}
+
+impl Opaque {
+    fn key(&self) -> (u32, u32) {
+        (self.a, self.b)
+    }
+}
+
+impl Hash for Opaque {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+// ok: all fields are hashed
+#[derive(PartialEq)]
+pub struct Complete {
+    a: u32,
+    b: u32,
+}
+
+impl Hash for Complete {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.a.hash(state);
+        (*self).b.hash(state);
+    }
+}
+
+fn main() {}
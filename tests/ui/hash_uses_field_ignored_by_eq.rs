@@ -0,0 +1,76 @@
+#![warn(clippy::hash_uses_field_ignored_by_eq)]
+#![allow(clippy::hash_ignores_field_used_in_eq)]
+
+use std::hash::{Hash, Hasher};
+
+pub struct Entry {
+    key: String,
+    hits: u32,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Hash for Entry {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+        self.hits.hash(state);
+    }
+}
+
+pub struct Id {
+    id: u64,
+    name: String,
+    generation: u32,
+}
+
+impl PartialEq for Id {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Hash for Id {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.id);
+        self.name.hash(state);
+        self.generation.hash(state);
+    }
+}
+
+// ok: hashes a subset of the compared fields
+pub struct Subset {
+    a: u32,
+    b: u32,
+}
+
+impl PartialEq for Subset {
+    fn eq(&self, other: &Self) -> bool {
+        self.a == other.a && self.b == other.b
+    }
+}
+
+impl Hash for Subset {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.a.hash(state);
+    }
+}
+
+// ok: `PartialEq` is derived and compares all fields
+#[derive(PartialEq)]
+pub struct Derived {
+    a: u32,
+    b: u32,
+}
+
+impl Hash for Derived {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.a.hash(state);
+        self.b.hash(state);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,70 @@
+#![allow(unused, clippy::never_loop)]
+#![warn(clippy::hashmap_default_hasher_hot_path)]
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::BuildHasherDefault;
+
+fn in_loops(lines: &[&str]) {
+    for line in lines {
+        let mut counts: HashMap<u32, usize> = HashMap::new();
+        let seen: HashSet<i64> = HashSet::with_capacity(line.len());
+        let lens: HashMap<usize, &str> = lines.iter().map(|l| (l.len(), *l)).collect();
+    }
+    loop {
+        let map = HashMap::<u8, u8>::default();
+        let set: HashSet<u16> = Default::default();
+        break;
+    }
+    let mut i = 0;
+    while i < 10 {
+        let set = HashSet::from([i]);
+        i += 1;
+    }
+}
+
+#[inline]
+fn inlined() -> HashMap<u64, u64> {
+    HashMap::new()
+}
+
+#[inline(always)]
+fn always_inlined() -> HashSet<usize> {
+    HashSet::new()
+}
+
+struct S;
+
+impl S {
+    #[inline]
+    fn method(&self) -> HashMap<i32, ()> {
+        HashMap::new()
+    }
+}
+
+fn no_lint(lines: &[&str]) {
+    // not in a hot path
+    let map: HashMap<u32, u32> = HashMap::new();
+
+    for line in lines {
+        // not integer keys
+        let words: HashSet<&str> = line.split(' ').collect();
+        let map: HashMap<u128, u8> = HashMap::new();
+        // not the default hasher
+        let map: HashMap<u32, u32, BuildHasherDefault<DefaultHasher>> = HashMap::default();
+        // not a `HashMap`
+        let map: BTreeMap<u32, u32> = BTreeMap::new();
+    }
+    // created before the loop
+    let mut seen = HashSet::new();
+    for i in 0..10u32 {
+        seen.insert(i);
+    }
+}
+
+#[inline(never)]
+fn never_inlined() -> HashMap<u64, u64> {
+    HashMap::new()
+}
+
+fn main() {}
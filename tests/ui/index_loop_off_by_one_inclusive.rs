@@ -0,0 +1,82 @@
+//@run-rustfix
+#![warn(clippy::index_loop_off_by_one_inclusive)]
+#![allow(clippy::needless_range_loop, clippy::explicit_counter_loop, unused)]
+
+use std::collections::VecDeque;
+
+fn for_loops(v: &[u32], mut w: Vec<u32>, d: VecDeque<u32>, arr: [u8; 4]) {
+    for i in 0..=v.len() {
+        println!("{}", v[i]);
+    }
+    for i in 1..=w.len() {
+        w[i] += 1;
+    }
+    for i in 0..=d.len() {
+        let _ = d[i];
+    }
+    for j in 0..=arr.len() {
+        if j % 2 == 0 {
+            let _ = arr[j];
+        }
+    }
+
+    // ok
+    for i in 0..v.len() {
+        let _ = v[i];
+    }
+    for i in 0..=v.len() {
+        let _ = v[i - 1];
+    }
+    for i in 0..=v.len() {
+        let _ = w[i.min(0)];
+    }
+    let mut s = 0;
+    for i in 0..=v.len() {
+        if i == v.len() {
+            break;
+        }
+        s += v[i];
+    }
+    for i in 0..=v.len() {
+        s += if i < v.len() { v[i] } else { 0 };
+    }
+    for i in 0..=v.len() {
+        s += if i == v.len() { 0 } else { v[i] };
+    }
+}
+
+fn while_loops(v: &[u32]) {
+    let mut i = 0;
+    while i <= v.len() {
+        let _ = v[i];
+        i += 1;
+    }
+    let mut i = 0;
+    while v.len() >= i {
+        let _ = v[i];
+        i += 1;
+    }
+
+    // ok
+    let mut i = 0;
+    while i < v.len() {
+        let _ = v[i];
+        i += 1;
+    }
+    let mut i = 1;
+    while i <= v.len() {
+        let _ = v[i - 1];
+        i += 1;
+    }
+    let mut i = 0;
+    while i <= v.len() {
+        if i >= v.len() {
+            println!("done");
+            return;
+        }
+        let _ = v[i];
+        i += 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+#![warn(clippy::instant_elapsed_in_condition)]
+
+use std::time::{Duration, Instant};
+
+fn main() {
+    let start = Instant::now();
+    let timeout = Duration::from_millis(1);
+    let deadline = start + timeout;
+
+    while Instant::now() != deadline {}
+    while deadline != Instant::now() {}
+    while Instant::now() == deadline {}
+    while start.elapsed() != timeout && !deadline.elapsed().is_zero() {}
+    while Instant::now() - start != timeout {}
+    while timeout != Instant::now().duration_since(start) {}
+
+    // ok
+    while start.elapsed() < timeout {}
+    while Instant::now() < deadline {}
+    while Instant::now().duration_since(start) < timeout {}
+    while Instant::now() - start < timeout {}
+    let _ = Instant::now() != deadline;
+}
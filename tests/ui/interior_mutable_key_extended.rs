@@ -0,0 +1,103 @@
+#![warn(clippy::interior_mutable_key_extended)]
+
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::{Arc, Mutex};
+
+macro_rules! impl_eq {
+    ($($ty:ty),*) => {$(
+        impl PartialEq for $ty {
+            fn eq(&self, _: &Self) -> bool {
+                unimplemented!()
+            }
+        }
+        impl Eq for $ty {}
+    )*};
+}
+
+impl_eq!(Session, Counter<'_>, Shared, Wrapper, Cached);
+
+pub struct Session {
+    id: u64,
+    user: Arc<Mutex<String>>,
+}
+
+impl Hash for Session {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.user.lock().unwrap().hash(state);
+    }
+}
+
+pub struct Counter<'a> {
+    count: &'a AtomicUsize,
+}
+
+impl Hash for Counter<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.count.load(AtomicOrdering::Relaxed).hash(state);
+    }
+}
+
+pub struct Shared {
+    value: Rc<Cell<u32>>,
+}
+
+impl PartialOrd for Shared {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Shared {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.get().cmp(&other.value.get())
+    }
+}
+
+pub struct Wrapper(Cell<u32>);
+
+impl Hash for Wrapper {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.get().hash(state);
+    }
+}
+
+#[derive(PartialEq, Eq, Hash)]
+pub struct Derived {
+    wrapper: Rc<Wrapper>,
+}
+
+// ok: the interior mutable field isn't hashed
+pub struct Cached {
+    id: u64,
+    stats: Arc<Mutex<u32>>,
+}
+
+impl Hash for Cached {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+// ok: no interior mutability
+#[derive(PartialEq, Eq, Hash)]
+pub struct Plain {
+    name: Arc<String>,
+}
+
+fn sessions(_: HashSet<Session>) {}
+
+fn counters<'a>() -> HashMap<Counter<'a>, u32> {
+    HashMap::new()
+}
+
+fn main() {
+    let _shared: BTreeSet<Shared> = BTreeSet::new();
+    let _derived: HashSet<Derived> = HashSet::new();
+    let _cached: HashSet<Cached> = HashSet::new();
+    let _plain: HashSet<Plain> = HashSet::new();
+}
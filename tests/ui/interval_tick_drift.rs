@@ -0,0 +1,68 @@
+#![warn(clippy::interval_tick_drift)]
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+const PERIOD: Duration = Duration::from_millis(100);
+
+fn work() {}
+
+pub fn threads(running: &AtomicBool, period: Duration) {
+    loop {
+        work();
+        sleep(Duration::from_secs(1));
+    }
+}
+
+pub fn more_threads(running: &AtomicBool, period: Duration) {
+    while running.load(Ordering::Relaxed) {
+        work();
+        std::thread::sleep(period);
+    }
+    while running.load(Ordering::Relaxed) {
+        sleep(PERIOD * 2);
+        work();
+    }
+}
+
+pub async fn tasks(running: &AtomicBool) {
+    while running.load(Ordering::Relaxed) {
+        work();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+pub fn no_lint(running: &AtomicBool, mut backoff: Duration) {
+    // nothing else to do
+    while running.load(Ordering::Relaxed) {
+        sleep(PERIOD);
+    }
+    // backoff
+    while running.load(Ordering::Relaxed) {
+        work();
+        sleep(backoff);
+        backoff *= 2;
+    }
+    // deadline based
+    let mut deadline = Instant::now();
+    while running.load(Ordering::Relaxed) {
+        work();
+        deadline += PERIOD;
+        sleep(deadline.saturating_duration_since(Instant::now()));
+    }
+    // computed each iteration
+    while running.load(Ordering::Relaxed) {
+        let start = Instant::now();
+        work();
+        let remaining = PERIOD.saturating_sub(start.elapsed());
+        sleep(remaining);
+    }
+    // not periodic
+    for _ in 0..3 {
+        work();
+        sleep(PERIOD);
+    }
+}
+
+fn main() {}
@@ -1,7 +1,7 @@
 //@run-rustfix
 
 #![allow(unused)]
-#![allow(clippy::useless_vec)]
+#![allow(clippy::into_iter_on_ref, clippy::useless_vec)]
 
 use std::collections::HashSet;
 use std::collections::VecDeque;
@@ -31,3 +31,17 @@ fn main() {
     // Issue #6703
     let _: Vec<isize> = v.iter().copied().collect();
 }
+
+fn into_iter_on_ref() {
+    let arr = [1, 2, 3];
+    let v = vec![String::new()];
+    let s: &[u8] = &[1, 2];
+
+    let _: Vec<i32> = (&arr).into_iter().copied().collect();
+    let _: Vec<String> = (&v).into_iter().cloned().collect();
+    let _: Vec<u8> = s.into_iter().copied().collect();
+
+    // don't lint
+    let _: Vec<i32> = arr.into_iter().collect();
+    let _: Vec<i32> = arr.iter().copied().rev().collect();
+}
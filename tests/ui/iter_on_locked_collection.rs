@@ -0,0 +1,65 @@
+#![warn(clippy::iter_on_locked_collection)]
+
+use std::sync::{Mutex, RwLock};
+
+struct State {
+    items: Mutex<Vec<i32>>,
+}
+
+fn mutex(m: &Mutex<Vec<i32>>) {
+    let len = m.lock().unwrap().len();
+    let sum: i32 = m.lock().unwrap().iter().sum();
+    println!("{len} {sum}");
+
+    let mut i = 0;
+    while i < m.lock().unwrap().len() {
+        println!("{}", m.lock().expect("poisoned")[i]);
+        i += 1;
+    }
+}
+
+fn nested(m: &Mutex<Vec<i32>>) {
+    for _ in 0..2 {
+        m.lock().unwrap().push(1);
+        m.lock().unwrap().push(2);
+    }
+}
+
+fn field(state: &State) {
+    state.items.lock().unwrap().push(1);
+    *state.items.lock().unwrap() = vec![];
+}
+
+fn rwlock(l: &RwLock<Vec<i32>>) {
+    let first = l.read().unwrap().first().copied();
+    let last = l.read().unwrap().last().copied();
+    println!("{first:?} {last:?}");
+}
+
+fn no_lint(m: &Mutex<Vec<i32>>, other: &Mutex<Vec<i32>>, l: &RwLock<Vec<i32>>) {
+    // locked once
+    let guard = m.lock().unwrap();
+    println!("{}", guard.len());
+    drop(guard);
+
+    // different locks
+    m.lock().unwrap().push(1);
+    other.lock().unwrap().push(1);
+
+    // locked for reading and writing
+    l.write().unwrap().push(1);
+    println!("{}", l.read().unwrap().len());
+
+    // in closures
+    let f = || m.lock().unwrap().len();
+    let g = || m.lock().unwrap().len();
+    println!("{} {}", f(), g());
+}
+
+async fn no_lint_async(m: &Mutex<Vec<i32>>) {
+    m.lock().unwrap().push(1);
+    async {}.await;
+    m.lock().unwrap().push(2);
+}
+
+fn main() {}
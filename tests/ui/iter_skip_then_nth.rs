@@ -0,0 +1,23 @@
+//@run-rustfix
+#![warn(clippy::iter_skip_then_nth)]
+#![allow(
+    clippy::iter_nth,
+    clippy::iter_nth_zero,
+    clippy::iter_skip_next,
+    clippy::useless_vec,
+    unused_mut
+)]
+
+fn main() {
+    let v = vec![1, 2, 3, 4, 5];
+    let n = 1;
+    let _ = v.iter().skip(1).nth(2);
+    let _ = v.iter().skip(n).nth(n * 2);
+    let _ = v.iter().skip(n + 1).nth(1);
+    let mut it = v.iter();
+    let _ = it.skip(1).nth(1);
+
+    // don't lint
+    let _ = v.iter().skip(2).nth(0);
+    let _ = v.iter().skip(2).next();
+}
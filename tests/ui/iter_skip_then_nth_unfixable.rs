@@ -0,0 +1,13 @@
+#![warn(clippy::iter_skip_then_nth)]
+#![allow(clippy::useless_vec)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let _: Vec<_> = v.iter().take(1).collect();
+    let it = v.iter();
+    let _ = it.skip(1).nth(1);
+
+    // don't lint
+    let _: Vec<_> = v.iter().take(2).collect();
+    let _: String = "ab".chars().take(1).collect();
+}
@@ -0,0 +1,45 @@
+#![warn(clippy::iterator_step_by_zero_runtime)]
+#![allow(clippy::iterator_step_by_zero, clippy::useless_vec)]
+
+macro_rules! step {
+    ($a:expr, $b:expr) => {
+        $a - $b
+    };
+}
+
+fn main() {
+    let a = vec![1, 2, 3];
+    let b = vec![1];
+
+    let _ = (0..10).step_by(a.len() - b.len());
+    let step = a.len() - b.len();
+    let _ = (0..10).step_by(step);
+    let parsed: usize = "3".parse().unwrap();
+    let _ = (0..10).step_by(parsed);
+    let parsed = "3".parse::<u32>().expect("not a number");
+    let renamed = parsed as usize;
+    let _ = (0..10).step_by(renamed);
+    let rem = a.len() % 3;
+    let _ = a.iter().step_by(rem);
+
+    // should not lint
+    let _ = (0..10).step_by(0);
+    let _ = (0..10).step_by(3 - 1);
+    let width = a.len();
+    let _ = (0..10).step_by(width - 1);
+    let _ = (0..10).step_by(a.len() % 8 + 1);
+    let _ = (0..10).step_by(a.len());
+    let _ = (0..10).step_by((a.len() - b.len()).max(1));
+    let _ = (0..10).step_by("3".parse().unwrap_or(1));
+    let checked = a.len() - b.len();
+    if checked > 0 {
+        let _ = (0..10).step_by(checked);
+    }
+    let asserted = a.len() - b.len();
+    assert!(asserted != 0);
+    let _ = (0..10).step_by(asserted);
+    let _ = (0..10).step_by(step!(a.len(), b.len()));
+    let mut reassigned = a.len() - b.len();
+    reassigned += 1;
+    let _ = (0..10).step_by(reassigned);
+}
@@ -0,0 +1,52 @@
+#![warn(clippy::join_handle_unwrap_in_drop)]
+
+use std::thread::JoinHandle;
+
+pub struct Worker {
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        if let Some(thread) = self.thread.take() {
+            thread.join().unwrap();
+        }
+    }
+}
+
+pub struct Pool {
+    threads: Vec<JoinHandle<u32>>,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl Drop for Pool {
+    fn drop(&mut self) {
+        for thread in self.threads.drain(..) {
+            thread.join().expect("worker panicked");
+        }
+        self.runtime.block_on(async {});
+        futures::executor::block_on(async {});
+    }
+}
+
+pub struct Defensive {
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Defensive {
+    pub fn shutdown(mut self) {
+        // not in `drop`
+        self.thread.take().unwrap().join().unwrap();
+    }
+}
+
+impl Drop for Defensive {
+    fn drop(&mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        let _ = std::thread::spawn(|| 1).join().unwrap_or_default();
+    }
+}
+
+fn main() {}
@@ -0,0 +1,65 @@
+#![feature(lazy_cell)]
+#![warn(clippy::leaky_global_from_loop)]
+
+use std::sync::{LazyLock, OnceLock};
+
+fn main() {
+    // leaking once in `main` is fine
+    let _: &'static mut [u8] = Box::leak(vec![0; 10].into_boxed_slice());
+
+    for i in 0..10 {
+        let _: &'static mut i32 = Box::leak(Box::new(i));
+    }
+    let mut n = 0;
+    while n < 10 {
+        let _: &'static mut [i32] = vec![n].leak();
+        n += 1;
+    }
+}
+
+fn intern(name: String) -> &'static str {
+    Box::leak(name.into_boxed_str())
+}
+
+fn in_closure(names: Vec<String>) -> Vec<&'static str> {
+    names
+        .into_iter()
+        .map(|name| &*Box::leak(name.into_boxed_str()))
+        .collect()
+}
+
+fn init() -> &'static [u8] {
+    vec![0; 10].leak()
+}
+
+fn setup() {
+    loop {
+        let _: &'static mut i32 = Box::leak(Box::new(0));
+    }
+}
+
+fn once_lock() -> &'static [u8] {
+    static DATA: OnceLock<&'static [u8]> = OnceLock::new();
+    DATA.get_or_init(|| {
+        let mut data = Vec::new();
+        for i in 0..10 {
+            data.push(i);
+        }
+        data.leak()
+    })
+}
+
+static LAZY: LazyLock<&'static str> = LazyLock::new(|| Box::leak(String::from("lazy").into_boxed_str()));
+
+fn lazy_in_fn() -> usize {
+    let lazy = LazyLock::new(|| Box::leak(String::from("lazy").into_boxed_str()));
+    lazy.len()
+}
+
+struct Interner;
+
+impl Interner {
+    fn intern(&self, name: String) -> &'static str {
+        Box::leak(name.into_boxed_str())
+    }
+}
@@ -0,0 +1,15 @@
+//@run-rustfix
+#![warn(clippy::lock_unwrap_poison_note)]
+
+use std::sync::{Arc, Mutex, RwLock};
+
+pub fn locks(mutex: &Mutex<u32>, shared: &Arc<Mutex<u32>>, rw: &RwLock<u32>) -> u32 {
+    let a = *mutex.lock().unwrap();
+    let b = *shared.lock().unwrap();
+    // fine
+    let c = *mutex.lock().expect("poisoned");
+    let d = *rw.read().unwrap();
+    a + b + c + d
+}
+
+fn main() {}
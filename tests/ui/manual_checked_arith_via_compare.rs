@@ -0,0 +1,26 @@
+//@run-rustfix
+#![warn(clippy::manual_checked_arith_via_compare)]
+
+fn main() {
+    check(5, 3, 200, 100, 5, 3);
+}
+
+fn check(a: u32, b: u32, x: u8, y: u8, c: i32, d: i32) {
+    let _ = if a > b { a - b } else { 0 };
+    let _ = if a >= b { a - b } else { 0 };
+    let _ = if b < a { a - b } else { 0 };
+    let _ = if a < b { 0 } else { a - b };
+    let _ = if a > b { a - b } else { 0 };
+
+    let _ = if x > u8::MAX - y { None } else { Some(x + y) };
+    let _ = if x <= u8::MAX - y { Some(y + x) } else { None };
+    let _ = if x > u8::MAX - y { u8::MAX } else { x + y };
+    let _ = if x > 255 - y { 255 } else { x + y };
+
+    // ok
+    let _ = if c > d { c - d } else { 0 };
+    let _ = if a > b { b - a } else { 0 };
+    let _ = if a > b { a - b } else { 1 };
+    let _ = if x >= u8::MAX - y { None } else { Some(x + y) };
+    let _ = if u8::MAX - y > x { Some(x + y) } else { None };
+}
@@ -0,0 +1,13 @@
+#![warn(clippy::manual_checked_arith_via_compare)]
+
+fn add(x: u32, y: u32) -> u32 {
+    if x > u32::MAX - y {
+        panic!("overflow");
+    } else {
+        x + y
+    }
+}
+
+fn main() {
+    let _ = add(1, 2);
+}
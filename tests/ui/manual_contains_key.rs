@@ -0,0 +1,44 @@
+//@run-rustfix
+#![warn(clippy::manual_contains_key)]
+#![allow(clippy::redundant_pattern_matching, clippy::needless_if, clippy::get_first, unused)]
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+struct NotAMap;
+
+impl NotAMap {
+    fn get(&self, _: &u32) -> Option<u32> {
+        None
+    }
+}
+
+fn main() {
+    let map: HashMap<u32, String> = HashMap::new();
+    let bmap: BTreeMap<&str, u32> = BTreeMap::new();
+    let key = 1;
+
+    let _ = map.get(&key).is_some();
+    let _ = map.get(&key).is_none();
+    let _ = bmap.get("a").is_some();
+    let _ = bmap.get(&"a").is_none();
+    let _ = map.get(&key).is_none().then_some(1);
+    let _ = bmap.get::<str>("c").is_some();
+
+    let map_ref = &map;
+    if map_ref.get(&2).is_some() {}
+
+    if let Some(_) = map.get(&key) {}
+    if let None = bmap.get("b") {
+        println!("missing");
+    }
+
+    // don't lint
+    if let Some(v) = map.get(&key) {
+        let _ = v.len();
+    }
+    let _ = NotAMap.get(&1).is_some();
+    let set: HashSet<u32> = HashSet::new();
+    let _ = set.get(&1).is_some();
+    let v = [1, 2, 3];
+    let _ = v.get(0).is_some();
+}
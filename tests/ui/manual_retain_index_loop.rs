@@ -0,0 +1,142 @@
+//@run-rustfix
+#![warn(clippy::manual_retain_index_loop)]
+#![allow(unused)]
+
+use std::collections::VecDeque;
+
+fn is_bad(x: &i32) -> bool {
+    *x < 0
+}
+
+fn main() {
+    let mut v = vec![1, 2, 3, 4];
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] % 2 == 0 {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+
+    let mut v = vec![1, 2, 3, 4];
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] > 2 {
+            i += 1;
+        } else {
+            v.remove(i);
+        }
+    }
+
+    let mut v = vec![1, -2, 3];
+    let mut i = 0;
+    while v.len() > i {
+        if is_bad(&v[i]) {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+
+    let mut v = vec![String::from("a"), String::new()];
+    let mut i = 0;
+    while i < v.len() {
+        if !v[i].is_empty() {
+            i += 1;
+        } else {
+            v.remove(i);
+        }
+    }
+
+    let mut v = vec![String::from("a"), String::new()];
+    let mut i = 0;
+    while i < v.len() {
+        if v[i].is_empty() {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    let x = 3;
+
+    let mut d = VecDeque::from([1, 2, 3]);
+    let mut i = 0;
+    while i < d.len() {
+        if d[i] == x || d[i] == 1 {
+            d.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+
+    // should not lint
+
+    // `i` is used after the loop
+    let mut v = vec![1, 2, 3, 4];
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] % 2 == 0 {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    println!("{i}");
+
+    // the predicate uses the index
+    let mut v = vec![1, 2, 3, 4];
+    let mut i = 0;
+    while i < v.len() {
+        if i % 2 == 0 {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+
+    // the predicate looks at other elements
+    let mut v = vec![1, 2, 2, 4];
+    let mut i = 0;
+    while i < v.len() {
+        if i > 0 && v[i - 1] == v[i] {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+
+    // the predicate mutates the element
+    let mut v = vec![String::from("a"), String::new()];
+    let mut i = 0;
+    while i < v.len() {
+        if v[i].pop().is_none() {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+
+    // not starting at zero
+    let mut v = vec![1, 2, 3, 4];
+    let mut i = 1;
+    while i < v.len() {
+        if v[i] % 2 == 0 {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+
+    // something else happens in the loop
+    let mut v = vec![1, 2, 3, 4];
+    let mut i = 0;
+    while i < v.len() {
+        println!("{}", v[i]);
+        if v[i] % 2 == 0 {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+}
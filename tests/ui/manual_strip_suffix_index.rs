@@ -0,0 +1,99 @@
+#![warn(clippy::manual_strip_suffix_index)]
+#![allow(clippy::manual_strip, clippy::needless_return, clippy::let_and_return)]
+
+struct File {
+    name: String,
+}
+
+fn fields(file: &File) {
+    if file.name.ends_with(".rs") {
+        str::to_string(&file.name[..file.name.len() - ".rs".len()]);
+        file.name[..file.name.len() - 3].to_string();
+    }
+
+    if file.name.starts_with("./") {
+        file.name[2..].to_string();
+    }
+}
+
+fn trimming(s: &str, prefix: &str) {
+    if s.starts_with(prefix) {
+        s.trim_start_matches(prefix).to_string();
+    }
+
+    if s.ends_with('/') {
+        s.trim_end_matches('/').to_string();
+    }
+
+    // Different pattern, don't lint
+    if s.ends_with('/') {
+        s.trim_end_matches('\\').to_string();
+    }
+}
+
+fn early_return(file: &str) -> Option<&str> {
+    if !file.ends_with(".rs") {
+        return None;
+    }
+    let name = &file[..file.len() - ".rs".len()];
+    Some(name)
+}
+
+fn early_return_prefix(path: &str) -> &str {
+    if !path.starts_with("./") {
+        panic!("not relative");
+    }
+    let rest = &path[2..];
+    rest
+}
+
+fn early_return_trimming(path: &str) -> &str {
+    // `"././x".trim_start_matches("./")` is `"x"`, the suggestion is only `MaybeIncorrect`
+    if !path.starts_with("./") {
+        panic!("not relative");
+    }
+    let rest = path.trim_start_matches("./");
+    rest
+}
+
+fn early_return_field(files: &[File]) {
+    for file in files {
+        if !file.name.starts_with('.') {
+            continue;
+        }
+        let hidden = &file.name[1..];
+        let _ = hidden;
+    }
+}
+
+fn no_lint(file: &str, other: &str) {
+    // The guard is not diverging
+    if !file.ends_with(".rs") {
+        println!("unexpected file");
+    }
+    let name = &file[..file.len() - 3];
+
+    // Statement in between
+    if !file.ends_with(".rs") {
+        return;
+    }
+    let _ = name;
+    let name = &file[..file.len() - 3];
+
+    // A different string is sliced
+    if !file.ends_with(".rs") {
+        return;
+    }
+    let _ = (name, &other[..other.len() - 3]);
+}
+
+#[clippy::msrv = "1.64"]
+fn msrv_1_64(file: &str) -> Option<&str> {
+    if !file.ends_with(".rs") {
+        return None;
+    }
+    let name = &file[..file.len() - 3];
+    Some(name)
+}
+
+fn main() {}
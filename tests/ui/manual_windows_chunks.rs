@@ -0,0 +1,55 @@
+//@run-rustfix
+#![warn(clippy::manual_windows_chunks)]
+#![allow(clippy::needless_range_loop, clippy::useless_vec, unused)]
+
+fn takes_slice(_: &[i32]) {}
+
+fn main() {
+    let v = vec![1, 2, 3, 4];
+
+    for i in 0..v.len() - 1 {
+        println!("{}", v[i + 1] - v[i]);
+    }
+    for i in 0..v.len() - 2 {
+        takes_slice(&v[i..i + 3]);
+    }
+    for i in 0..=v.len() - 2 {
+        println!("{:?} {}", &v[i..i + 1], v[1 + i]);
+    }
+    for i in 0..v.len() / 2 {
+        println!("{}", v[i * 2] + v[2 * i + 1]);
+    }
+    for i in 0..v.len() / 2 {
+        takes_slice(&v[i * 2..(i + 1) * 2]);
+    }
+    let a = [1, 2, 3];
+    for i in 0..a.len() - 1 {
+        let sum = a[i] + a[i + 1];
+        println!("{sum}");
+    }
+
+    // should not lint
+    for i in 0..v.len() - 1 {
+        println!("{i}: {}", v[i]);
+    }
+    for i in 0..v.len() - 1 {
+        println!("{}", v[i + 2]);
+    }
+    for i in 0..v.len() / 2 {
+        println!("{}", v[i]);
+    }
+    for i in 0..v.len() - 1 {
+        println!("{} {}", v[i], v.len());
+    }
+    let mut m = vec![1, 2, 3];
+    for i in 0..m.len() - 1 {
+        m[i] += m[i + 1];
+    }
+    for i in 1..v.len() - 1 {
+        println!("{}", v[i]);
+    }
+    let window = 1;
+    for i in 0..v.len() - 1 {
+        println!("{}", v[i] + window);
+    }
+}
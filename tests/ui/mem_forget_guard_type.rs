@@ -0,0 +1,43 @@
+#![warn(clippy::mem_forget_guard_type)]
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::mem::{self, ManuallyDrop};
+use std::sync::{Mutex, RwLock};
+
+#[clippy::has_significant_drop]
+pub struct Connection;
+
+impl Drop for Connection {
+    fn drop(&mut self) {}
+}
+
+pub fn bad(mutex: &Mutex<u32>, lock: &RwLock<u32>, cell: &RefCell<u32>) {
+    mem::forget(mutex.lock().unwrap());
+    mem::forget(lock.read().unwrap());
+    let _ = ManuallyDrop::new(lock.write().unwrap());
+    mem::forget(cell.borrow());
+    mem::forget(cell.borrow_mut());
+    mem::forget(parking_lot::Mutex::new(0).lock());
+}
+
+pub fn bad_drain(v: &mut Vec<u32>, d: &mut VecDeque<u32>, s: &mut String) {
+    mem::forget(v.drain(..1));
+    mem::forget(d.drain(..1));
+    let _ = ManuallyDrop::new(s.drain(..1));
+    mem::forget(Connection);
+}
+
+pub fn good(mutex: &Mutex<u32>, v: Vec<u32>) {
+    drop(mutex.lock().unwrap());
+    mem::forget(v);
+    let _ = ManuallyDrop::new(String::new());
+}
+
+#[allow(clippy::mem_forget_guard_type)]
+#[warn(clippy::mem_forget)]
+pub fn allowed(mutex: &Mutex<u32>) {
+    mem::forget(mutex.lock().unwrap());
+}
+
+fn main() {}
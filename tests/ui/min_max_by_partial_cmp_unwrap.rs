@@ -0,0 +1,29 @@
+//@run-rustfix
+#![warn(clippy::min_max_by_partial_cmp_unwrap)]
+#![allow(unused)]
+
+fn main() {
+    let floats = [1.0_f64, 3.0, 2.0];
+    let _ = floats.iter().max_by(|a, b| a.partial_cmp(b).unwrap());
+    let _ = floats.iter().min_by(|a, b| a.partial_cmp(b).unwrap());
+    let _ = floats.iter().max_by(|a, b| b.partial_cmp(a).unwrap());
+    let _ = floats.iter().max_by(|a, b| a.partial_cmp(b).expect("NaN"));
+    let _ = floats.into_iter().min_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let singles = [1.0_f32, 2.0];
+    let _ = singles.iter().copied().max_by(|a, b| a.partial_cmp(b).unwrap());
+
+    // don't lint
+    let ints = [1, 3, 2];
+    let _ = ints.iter().max_by(|a, b| a.partial_cmp(b).unwrap());
+    let _ = floats.iter().max_by(|a, b| a.total_cmp(b));
+    let _ = floats
+        .iter()
+        .max_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+}
+
+#[clippy::msrv = "1.62"]
+fn msrv_1_62() {
+    let floats = [1.0_f64, 3.0, 2.0];
+    let _ = floats.iter().max_by(|a, b| a.partial_cmp(b).unwrap());
+}
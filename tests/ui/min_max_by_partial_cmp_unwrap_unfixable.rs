@@ -0,0 +1,8 @@
+#![warn(clippy::min_max_by_partial_cmp_unwrap)]
+
+#[clippy::msrv = "1.61"]
+fn main() {
+    let floats = [1.0_f64, 3.0, 2.0];
+    let _ = floats.iter().max_by(|a, b| a.partial_cmp(b).unwrap());
+    let _ = floats.iter().min_by(|a, b| a.partial_cmp(b).unwrap());
+}
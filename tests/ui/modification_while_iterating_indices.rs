@@ -0,0 +1,97 @@
+#![warn(clippy::modification_while_iterating_indices)]
+#![allow(clippy::needless_range_loop)]
+
+use std::collections::VecDeque;
+
+struct Queue {
+    items: Vec<u32>,
+}
+
+impl Queue {
+    fn dedup(&mut self) {
+        for i in 1..self.items.len() {
+            if self.items[i] == self.items[i - 1] {
+                self.items.remove(i);
+            }
+        }
+    }
+}
+
+fn remove_zero(v: &mut Vec<u32>) -> Option<u32> {
+    // ok: the loop stops after the modification
+    for i in 0..v.len() {
+        if v[i] == 0 {
+            return Some(v.remove(i));
+        }
+    }
+    for i in 0..v.len() {
+        if v[i] == 1 {
+            let x = v.remove(i);
+            return Some(x);
+        }
+    }
+    None
+}
+
+fn main() {
+    let mut v = vec![1, 2, 3];
+    for i in 0..v.len() {
+        if v[i] % 2 == 0 {
+            v.remove(i);
+        }
+    }
+    for i in 0..v.len() {
+        if v[i] > 1 {
+            v.insert(0, 0);
+        }
+    }
+    let mut d = VecDeque::from([1, 2, 3]);
+    for i in 0..d.len() {
+        if d[i] == 0 {
+            d.pop_front();
+        }
+    }
+
+    // ok: the loop stops after the modification
+    for i in 0..v.len() {
+        if v[i] == 2 {
+            v.remove(i);
+            break;
+        }
+    }
+    // ok: appended elements are not visited
+    for i in 0..v.len() {
+        v.push(v[i] * 2);
+    }
+    for i in 0..d.len() {
+        d.push_back(d[i]);
+        d.push_front(0);
+    }
+    // lint: the `break` leaves the inner loop only
+    for i in 0..v.len() {
+        for _ in 0..2 {
+            if v[i] == 3 {
+                v.remove(i);
+                break;
+            }
+        }
+    }
+    // ok: the `break` leaves the outer loop
+    'outer: for i in 0..v.len() {
+        for _ in 0..2 {
+            if v[i] == 3 {
+                v.remove(i);
+                break 'outer;
+            }
+        }
+    }
+    // ok: another vector
+    let mut w = Vec::new();
+    for i in 0..v.len() {
+        w.push(v[i]);
+    }
+    // ok: not iterating over the indices
+    for i in 0..3 {
+        v.push(i);
+    }
+}
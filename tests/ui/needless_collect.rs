@@ -34,7 +34,8 @@ fn main() {
     sample.iter().collect::<BinaryHeap<_>>().len();
     sample.iter().collect::<BinaryHeap<_>>().is_empty();
 
-    // Don't lint string from str
+    // Don't lint string from str, `collect_then_len` covers it
+    #[allow(clippy::collect_then_len)]
     let _ = ["", ""].into_iter().collect::<String>().is_empty();
 
     let _ = sample.iter().collect::<HashSet<_>>().is_empty();
@@ -0,0 +1,57 @@
+//@run-rustfix
+#![warn(clippy::nested_option_result_flattenable)]
+#![allow(
+    clippy::option_option,
+    clippy::single_match,
+    clippy::unnecessary_literal_unwrap,
+    dead_code
+)]
+
+fn opt() -> Option<Option<i32>> {
+    Some(Some(1))
+}
+
+#[allow(clippy::nested_option_result_flattenable)]
+fn res() -> Result<Result<i32, String>, String> {
+    Ok(Ok(1))
+}
+
+fn mixed() -> Result<Result<i32, u8>, String> {
+    Ok(Ok(1))
+}
+
+fn main() {
+    let _ = opt().unwrap().unwrap();
+    let _ = opt().expect("outer").expect("inner");
+    let _ = res().unwrap().unwrap();
+
+    if let Some(Some(x)) = opt() {
+        println!("{x}");
+    }
+    match res() {
+        Ok(Ok(x)) => println!("{x}"),
+        _ => {},
+    }
+
+    let s = "1";
+    if let Some(Some(x)) = Some(s).map(|s| s.parse::<i32>().ok()) {
+        println!("{x}");
+    }
+    let _ = Some(s).map(|s| s.parse::<i32>().ok()).unwrap().unwrap();
+
+    // don't lint
+    let _ = mixed().unwrap().unwrap();
+    if let Some(None) = opt() {}
+    match opt() {
+        Some(Some(x)) => println!("{x}"),
+        Some(None) => {},
+        None => {},
+    }
+    let _ = Some(1).unwrap();
+}
+
+#[clippy::msrv = "1.39"]
+fn msrv_1_39() {
+    let _ = opt().unwrap().unwrap();
+    let _ = res().unwrap().unwrap();
+}
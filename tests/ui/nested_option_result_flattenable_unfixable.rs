@@ -0,0 +1,37 @@
+#![warn(clippy::nested_option_result_flattenable)]
+#![allow(clippy::option_option, dead_code)]
+
+fn res() -> Result<Result<i32, String>, String> {
+    Ok(Ok(1))
+}
+
+struct S;
+
+impl S {
+    fn method(&self) -> Result<Result<(), ()>, ()> {
+        Ok(Ok(()))
+    }
+}
+
+trait T {
+    fn required(&self) -> Result<Result<(), ()>, ()>;
+}
+
+// don't lint
+impl T for S {
+    fn required(&self) -> Result<Result<(), ()>, ()> {
+        Ok(Ok(()))
+    }
+}
+
+fn mixed() -> Result<Result<i32, u8>, String> {
+    Ok(Ok(1))
+}
+
+fn opt() -> Option<Option<i32>> {
+    None
+}
+
+fn main() {
+    let _ = |x: Result<(), ()>| -> Result<Result<(), ()>, ()> { Ok(x) };
+}
@@ -0,0 +1,23 @@
+#![warn(clippy::non_monotonic_time_for_durations)]
+
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+fn main() {
+    let start = SystemTime::now();
+    let _ = start.elapsed().unwrap();
+    let _ = SystemTime::now().duration_since(start).unwrap();
+
+    let deadline = SystemTime::now() + Duration::from_secs(5);
+    while SystemTime::now() < deadline {}
+    let _ = deadline > SystemTime::now();
+
+    // wall clock timestamps are fine
+    let _ = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    let modified = std::fs::metadata("file").unwrap().modified().unwrap();
+    let _ = modified.elapsed();
+    let _ = SystemTime::now() > modified;
+    let _ = start.duration_since(UNIX_EPOCH);
+
+    let start = Instant::now();
+    let _ = start.elapsed();
+}
@@ -1,5 +1,5 @@
 #![warn(clippy::non_send_fields_in_send_ty)]
-#![allow(suspicious_auto_trait_impls)]
+#![allow(suspicious_auto_trait_impls, clippy::unsound_send_sync_impl_heuristic)]
 #![feature(extern_types)]
 
 use std::cell::UnsafeCell;
@@ -0,0 +1,36 @@
+//@run-rustfix
+#![warn(clippy::option_as_ref_map_clone_chain)]
+#![allow(clippy::map_clone, unused)]
+
+#[derive(Clone)]
+struct NotCopy;
+
+struct NotClone;
+
+fn takes_ref(opt: &Option<String>) -> Option<String> {
+    opt.as_ref().cloned()
+}
+
+fn main() {
+    let opt = Some(String::from("a"));
+    let _ = opt.as_ref().cloned();
+    let _ = opt.as_ref().map(Clone::clone);
+    let _ = opt.as_ref().map(String::clone);
+    let _ = opt.as_ref().map(|x| x.clone());
+    let _ = opt.as_ref().map(ToOwned::to_owned);
+    let _ = opt.as_deref().map(ToOwned::to_owned);
+    let _ = opt.as_deref().map(str::to_owned);
+    let _ = opt.as_deref().map(|s| s.to_owned());
+    let other = Some(NotCopy);
+    let _ = other.as_ref().map(NotCopy::clone);
+
+    // should not lint
+    let _ = opt.as_deref().map(String::from);
+    let _ = opt.as_ref().map(|x| x.to_uppercase());
+    let n = Some(NotClone);
+    let _: Option<&NotClone> = n.as_ref().map(|x| x.clone());
+    let r = &&opt;
+    let _ = r.as_ref().cloned();
+    let boxed: Option<Box<str>> = Some("a".into());
+    let _: Option<String> = boxed.as_deref().map(ToOwned::to_owned);
+}
@@ -0,0 +1,47 @@
+//@run-rustfix
+#![warn(clippy::option_filter_map_bool)]
+#![allow(
+    unused,
+    clippy::redundant_closure,
+    clippy::single_match,
+    clippy::unnecessary_lazy_evaluations
+)]
+
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+fn main() {
+    let opt = Some(2u32);
+    let _ = opt.filter(|&x| x > 1).map(|x| x * 2).unwrap_or(0);
+    let _ = opt.filter(|&x| x.is_power_of_two()).map(|x| x + 1).unwrap();
+    let _ = opt.filter(|&x| x > 1).map(double).unwrap_or_default();
+    let _ = opt.filter(|&x| x > 1).map(|x| double(x)).expect("too small");
+    match opt.filter(|&n| n % 2 == 0).map(|n| n / 2) {
+        Some(half) => println!("{half}"),
+        None => {},
+    }
+    if let Some(half) = opt.filter(|&n| n % 2 == 0).map(|n| n / 2) {
+        println!("{half}");
+    }
+    // `x - 1` would overflow if it was evaluated before the test
+    let _ = opt.filter(|&x| x > 0).map(|x| x - 1).unwrap_or(0);
+    let _ = opt.filter(|&x| x > 1).map(|x| (x, true)).unwrap();
+
+    // Not consumed right away, don't lint
+    let halved = opt.filter(|&n| n % 2 == 0).map(|n| n / 2);
+    // Iterators, don't lint
+    let _: Vec<u32> = [1, 2].into_iter().filter(|&x| x > 1).map(|x| x * 2).collect();
+}
+
+#[clippy::msrv = "1.61"]
+fn msrv_1_61() {
+    let opt = Some(2u32);
+    let _ = opt.filter(|&x| x > 1).map(|x| (x, true)).unwrap();
+}
+
+#[clippy::msrv = "1.49"]
+fn msrv_1_49() {
+    let opt = Some(2u32);
+    let _ = opt.filter(|&x| x > 1).map(|x| x * 2).unwrap_or(0);
+}
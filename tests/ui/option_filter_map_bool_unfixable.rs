@@ -0,0 +1,24 @@
+#![warn(clippy::option_filter_map_bool)]
+
+struct Item {
+    size: usize,
+}
+
+fn main() {
+    let opt = Some(Item { size: 3 });
+    let _ = opt
+        .as_ref()
+        .filter(|item| item.size > 1)
+        .map(|item| item.size)
+        .unwrap_or(0);
+    let _ = Some(2).filter(|&x| x > 1).map(|y| y * 2).unwrap_or(0);
+
+    // Predicate with statements, don't lint
+    let _ = Some(2)
+        .filter(|&x| {
+            let limit = 1;
+            x > limit
+        })
+        .map(|x| x * 2)
+        .unwrap_or(0);
+}
@@ -0,0 +1,34 @@
+//@run-rustfix
+#![warn(clippy::option_zip_then_map_tuple)]
+#![allow(dead_code, clippy::redundant_closure)]
+
+fn get() -> Option<i32> {
+    Some(3)
+}
+
+fn main() {
+    let a = Some(1);
+    let b = Some(2);
+
+    let _ = a.and_then(|x| b.map(|y| x + y));
+    let _ = a.and_then(|x| b.map(|y| x * y));
+    let _ = Some((1, 2)).and_then(|(x, _)| b.map(|y| x + y));
+
+    // don't lint
+    let _ = a.and_then(|x| get().map(|y| x - y));
+    let _ = a.zip(b).map(|(x, y)| x + y);
+    let _ = a.and_then(|x| Some(x + 1).map(|y| x + y));
+    let _ = a.and_then(|x| b.map(|y| y + 1).filter(|y| *y > x));
+    let _ = a.and_then(move |x| b.map(|y| x + y));
+    let _ = a.and_then(|x: i32| b.map(|y| x + y));
+}
+
+#[clippy::msrv = "1.45"]
+fn msrv_1_45(a: Option<i32>, b: Option<i32>) {
+    let _ = a.and_then(|x| b.map(|y| x + y));
+}
+
+#[clippy::msrv = "1.46"]
+fn msrv_1_46(a: Option<i32>, b: Option<i32>) {
+    let _ = a.and_then(|x| b.map(|y| x + y));
+}
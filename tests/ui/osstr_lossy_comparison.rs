@@ -0,0 +1,29 @@
+//@run-rustfix
+#![warn(clippy::osstr_lossy_comparison)]
+
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+const NAME: &str = "foo.txt";
+
+fn main() {
+    let path = Path::new("foo.txt");
+    let path_buf = PathBuf::from("foo.txt");
+    let os_str = OsStr::new("foo.txt");
+    let os_string = OsString::from("foo.txt");
+
+    let _ = path.to_string_lossy() == "foo.txt";
+    let _ = path.to_str().unwrap() != "foo.txt";
+    let _ = path.to_str().expect("valid path") == NAME;
+    let _ = "foo.txt" == path_buf.to_string_lossy();
+    let _ = os_str.to_string_lossy() == "foo.txt";
+    let _ = os_string.to_str().unwrap() == "foo.txt";
+    let _ = path.file_name().unwrap().to_string_lossy() == "foo.txt";
+
+    // ok
+    let name = String::from("foo.txt");
+    let _ = path.to_string_lossy() == name.as_str();
+    let _ = path.to_string_lossy() == "\u{FFFD}";
+    let _ = path.to_str() == Some("foo.txt");
+    let _ = path.to_string_lossy().len() == 7;
+}
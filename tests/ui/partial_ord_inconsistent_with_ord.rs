@@ -0,0 +1,110 @@
+//@run-rustfix
+#![warn(clippy::partial_ord_inconsistent_with_ord)]
+#![allow(clippy::derive_ord_xor_partial_ord)]
+
+use std::cmp::Ordering;
+
+#[derive(PartialEq, Eq)]
+pub struct Version {
+    major: u32,
+    minor: u32,
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.minor, self.major).partial_cmp(&(other.minor, other.major))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor).cmp(&(other.major, other.minor))
+    }
+}
+
+#[derive(PartialEq, Eq)]
+pub struct Named<T>(T);
+
+impl<T: Ord> PartialOrd for Named<T> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&rhs.0)
+    }
+}
+
+impl<T: Ord> Ord for Named<T> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.0.cmp(&rhs.0)
+    }
+}
+
+// ok: delegates to `cmp`
+#[derive(PartialEq, Eq)]
+pub struct Delegates(u32);
+
+impl PartialOrd for Delegates {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Delegates {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+#[derive(PartialEq, Eq)]
+pub struct DelegatesPath(u32);
+
+impl PartialOrd for DelegatesPath {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(Ord::cmp(self, other))
+    }
+}
+
+impl Ord for DelegatesPath {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+// ok: `cmp` delegates to `partial_cmp`
+#[derive(PartialEq, Eq)]
+pub struct Reverse(u32);
+
+impl PartialOrd for Reverse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.0.partial_cmp(&self.0)
+    }
+}
+
+impl Ord for Reverse {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.partial_cmp(other).unwrap()
+    }
+}
+
+// ok: `Ord` isn't implemented
+#[derive(PartialEq)]
+pub struct Float(f64);
+
+impl PartialOrd for Float {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+// ok: `PartialOrd` with another type
+impl PartialOrd<u32> for Version {
+    fn partial_cmp(&self, other: &u32) -> Option<Ordering> {
+        self.major.partial_cmp(other)
+    }
+}
+
+impl PartialEq<u32> for Version {
+    fn eq(&self, other: &u32) -> bool {
+        self.major == *other
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+#![warn(clippy::path_join_with_absolute_literal)]
+
+use std::path::{Path, PathBuf};
+
+const ROOT: &str = "/etc";
+
+fn main() {
+    let base = Path::new("/usr");
+    let _ = base.join("/bin");
+    let _ = base.join("\\bin");
+    let _ = base.join(ROOT);
+    let _ = base.join("C:\\Windows");
+    let _ = PathBuf::from("/usr").join("/lib");
+
+    // ok
+    let _ = base.join("bin");
+    let _ = base.join("./bin");
+    let _ = base.join(String::from("/bin"));
+    let _ = base.join("");
+}
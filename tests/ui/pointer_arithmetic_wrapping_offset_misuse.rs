@@ -0,0 +1,72 @@
+#![warn(clippy::pointer_arithmetic_wrapping_offset_misuse)]
+#![allow(clippy::missing_safety_doc, clippy::let_and_return)]
+
+pub unsafe fn local(ptr: *const u8) -> u8 {
+    let second = ptr.wrapping_add(1);
+    *second
+}
+
+pub unsafe fn direct(ptr: *mut u32, i: isize) {
+    *ptr.wrapping_offset(i) = 0;
+}
+
+pub unsafe fn methods(ptr: *mut u32, n: usize) -> u32 {
+    let last = ptr.wrapping_sub(n).cast::<u8>();
+    last.write(1);
+    last.read_unaligned() as u32
+}
+
+pub unsafe fn copied(ptr: *const u16) -> u16 {
+    let p = ptr.wrapping_add(2);
+    let q = p as *const i16;
+    *q as u16
+}
+
+pub unsafe fn assigned(mut ptr: *mut u8, n: usize) {
+    for _ in 0..n {
+        *ptr = 0;
+        ptr = ptr.wrapping_add(1);
+    }
+}
+
+// ok: compared with the end of the buffer
+pub unsafe fn loop_with_check(mut ptr: *mut u8, end: *mut u8) {
+    while ptr < end {
+        *ptr = 0;
+        ptr = ptr.wrapping_add(1);
+    }
+}
+
+// ok: checked with `contains`
+pub unsafe fn contains(buf: &[u8], i: usize) -> u8 {
+    let p = buf.as_ptr().wrapping_add(i);
+    if buf.as_ptr_range().contains(&p) { *p } else { 0 }
+}
+
+// ok: the same expression is checked
+pub unsafe fn same_expression(ptr: *const u8, end: *const u8, i: usize) -> u8 {
+    if ptr.wrapping_add(i) < end {
+        *ptr.wrapping_add(i)
+    } else {
+        0
+    }
+}
+
+// ok: not dereferenced
+pub fn not_dereferenced(ptr: *const u8) -> *const u8 {
+    let p = ptr.wrapping_add(4);
+    p
+}
+
+// ok: only the address is taken
+pub fn addr_of(ptr: *const (u8, u8)) -> *const u8 {
+    let p = ptr.wrapping_add(1);
+    unsafe { core::ptr::addr_of!((*p).1) }
+}
+
+// ok: not a pointer
+pub fn integer(a: u32) -> u32 {
+    a.wrapping_add(1)
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+//@run-rustfix
+#![warn(clippy::range_zip_with_len)]
+#![allow(clippy::useless_vec, unused)]
+
+struct S {
+    v: Vec<i32>,
+}
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    for (x, i) in v.iter().zip(0usize..) {
+        println!("{i}: {x}");
+    }
+    for (x, i) in v.iter().zip(0..v.len()) {
+        println!("{i}: {x}");
+    }
+    let _: Vec<_> = v.iter().zip(0..).map(|(x, i)| v[i] + x).collect();
+    let _ = v.iter().zip(0..).any(|(&x, i)| x as usize == i);
+    let _: Vec<_> = (0..v.len()).zip(v.iter()).collect();
+    let _: Vec<(usize, _)> = (0..).zip(v.iter().skip(1)).collect();
+    let s = S { v: vec![1] };
+    for (x, i) in s.v.iter().zip(0..s.v.len()) {
+        println!("{i}: {x}");
+    }
+
+    // should not lint
+    let _: Vec<(_, usize)> = v.iter().zip(0..).collect();
+    let _: Vec<(_, usize)> = v.iter().zip(0..).filter(|(x, i)| **x as usize == *i).collect();
+    for pair in v.iter().zip(0usize..) {
+        println!("{pair:?}");
+    }
+    let w = vec![1];
+    for (x, i) in v.iter().zip(0..w.len()) {
+        println!("{i}: {x}");
+    }
+    for (x, i) in v.iter().zip(1usize..) {
+        println!("{i}: {x}");
+    }
+    for (x, i) in v.iter().zip(0u32..) {
+        println!("{i}: {x}");
+    }
+    let _: Vec<(usize, _)> = (0..).zip(&v).collect();
+}
@@ -0,0 +1,56 @@
+#![warn(clippy::rc_in_send_context)]
+#![allow(clippy::non_send_fields_in_send_ty)]
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+pub struct Handle {
+    value: Box<dyn Any>,
+    name: String,
+}
+
+unsafe impl Send for Handle {}
+
+pub struct Local {
+    value: Rc<u32>,
+}
+
+pub fn spawn_thread() {
+    std::thread::spawn(|| {
+        let config = Rc::new(String::from("config"));
+        let state = RefCell::new(0);
+        *state.borrow_mut() += config.len();
+        // fine
+        let shared = Arc::new(1);
+        let _ = shared;
+    });
+}
+
+pub async fn spawn_task() {
+    tokio::spawn(async {
+        let counter = Rc::new(1);
+        let _ = counter;
+    });
+    // not spawned
+    let local = Rc::new(1);
+    let _ = local;
+}
+
+pub fn handles() -> (Handle, Local) {
+    let handle = Handle {
+        value: Box::new(Rc::new(1)),
+        name: String::new(),
+    };
+    // `Local` isn't `Send`
+    let local = Local { value: Rc::new(1) };
+    // fine
+    let _ = Handle {
+        value: Box::new(RefCell::new(1)),
+        name: String::new(),
+    };
+    (handle, local)
+}
+
+fn main() {}
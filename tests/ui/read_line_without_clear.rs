@@ -0,0 +1,62 @@
+#![warn(clippy::read_line_without_clear)]
+
+use std::io::{self, BufRead};
+
+fn main() {
+    let stdin = io::stdin();
+    let mut buf = String::new();
+    loop {
+        stdin.read_line(&mut buf).unwrap();
+        if buf.trim() == "quit" {
+            break;
+        }
+    }
+
+    let mut input = String::new();
+    while io::stdin().lock().read_line(&mut input).unwrap() > 0 {
+        println!("{input}");
+    }
+
+    let mut line = String::new();
+    (0..3).for_each(|_| {
+        stdin.read_line(&mut line).unwrap();
+    });
+
+    // ok, cleared in the loop
+    let mut buf = String::new();
+    loop {
+        buf.clear();
+        stdin.read_line(&mut buf).unwrap();
+        if buf.trim() == "quit" {
+            break;
+        }
+    }
+
+    // ok, taken in the loop
+    let mut buf = String::new();
+    let mut lines = Vec::new();
+    while stdin.read_line(&mut buf).unwrap() > 0 {
+        lines.push(std::mem::take(&mut buf));
+    }
+
+    // ok, replaced in the loop
+    let mut buf = String::new();
+    for _ in 0..3 {
+        stdin.read_line(&mut buf).unwrap();
+        lines.push(buf);
+        buf = String::new();
+    }
+
+    // ok, declared in the loop
+    loop {
+        let mut buf = String::new();
+        stdin.read_line(&mut buf).unwrap();
+        if buf.is_empty() {
+            break;
+        }
+    }
+
+    // ok, not in a loop
+    let mut buf = String::new();
+    stdin.read_line(&mut buf).unwrap();
+}
@@ -321,3 +321,58 @@ fn main() {
     let c = C;
     c.to_string();
 }
+
+// Check calls to helper methods which recurse
+struct Helper;
+
+impl Helper {
+    fn describe(&self) -> String {
+        self.to_string()
+    }
+
+    fn write_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+
+    fn name(&self) -> &'static str {
+        "Helper"
+    }
+}
+
+impl fmt::Display for Helper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.describe())
+    }
+}
+
+impl fmt::Debug for Helper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Self::write_debug(self, f)
+    }
+}
+
+// Ok: the helpers don't recurse with this trait
+struct OkHelper;
+
+impl OkHelper {
+    fn write_debug(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+
+    fn name(&self) -> &'static str {
+        "OkHelper"
+    }
+}
+
+impl fmt::Display for OkHelper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())?;
+        self.write_debug(f)
+    }
+}
+
+impl fmt::Debug for OkHelper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(Helper.name())
+    }
+}
@@ -0,0 +1,64 @@
+//@run-rustfix
+#![warn(clippy::redundant_sort_before_extreme)]
+#![allow(unused, clippy::ptr_arg, clippy::unnecessary_sort_by, clippy::useless_vec)]
+
+use itertools::Itertools;
+
+fn main() {
+    let mut v = vec![3, 1, 2];
+    v.sort();
+    let _ = v.first();
+
+    let mut v = vec![3, 1, 2];
+    v.sort_unstable();
+    let smallest = v[0];
+    let largest = v[v.len() - 1];
+
+    let mut words = vec!["b", "a"];
+    words.sort();
+    let _ = words.last();
+    let _ = &words[0];
+    let _ = words[0].len();
+
+    let mut pairs = [(1, 'a'), (0, 'b')];
+    pairs.sort_by_key(|pair| pair.1);
+    let _ = pairs.last();
+
+    let mut pairs = [(1, 'a'), (0, 'b')];
+    pairs.sort_by(|a, b| a.0.cmp(&b.0));
+    let _ = pairs.first();
+
+    let _ = [3, 1, 2].into_iter().sorted().next();
+    let _ = [3, 1, 2].into_iter().sorted().last();
+    let _ = [3, 1, 2].into_iter().sorted_by_key(|x| *x % 3).next();
+    let _ = [3, 1, 2].into_iter().sorted_by(|a, b| b.cmp(a)).next();
+}
+
+fn no_lint(param: &mut Vec<u32>) {
+    // Used in some other way
+    let mut v = vec![3, 1, 2];
+    v.sort();
+    let _ = (v[0], v.len());
+
+    let mut v = vec![3, 1, 2];
+    v.sort();
+    let _ = v[1];
+
+    // Not used at all
+    let mut v = vec![3, 1, 2];
+    v.sort();
+
+    // The caller sees the sorted vector
+    param.sort();
+    let _ = param.first();
+
+    // Sorted on every iteration
+    let mut v = vec![3, 1, 2];
+    for i in 0..3 {
+        v.push(i);
+        v.sort();
+        let _ = v.first();
+    }
+
+    let _ = [3, 1, 2].into_iter().sorted().nth(1);
+}
@@ -0,0 +1,74 @@
+#![warn(clippy::regex_compiled_in_loop_or_hot_fn)]
+#![allow(clippy::trivial_regex)]
+
+extern crate regex;
+
+use regex::{Regex, RegexSet};
+use std::sync::OnceLock;
+
+const PATTERN: &str = "[a-z]+";
+
+fn main() {
+    let lines = ["a", "b1", "c"];
+
+    for line in lines {
+        let re = Regex::new("^[a-z]+[0-9]*$").unwrap();
+        let _ = re.is_match(line);
+        let _ = is_id(line);
+        let _ = matches_set(line);
+    }
+
+    let _ = lines
+        .iter()
+        .filter(|line| Regex::new(PATTERN).unwrap().is_match(line))
+        .count();
+
+    let mut i = 0;
+    while i < lines.len() {
+        let _ = Wrapper.check(lines[i]);
+        i += 1;
+    }
+
+    // compiled once
+    let re = Regex::new(PATTERN).unwrap();
+    for line in lines {
+        let _ = re.is_match(line);
+        let _ = cached(line);
+        let _ = dynamic(line);
+    }
+    let _ = not_called_in_loop("a");
+}
+
+fn is_id(s: &str) -> bool {
+    Regex::new("^[a-z]+[0-9]*$").unwrap().is_match(s)
+}
+
+// reached through `is_id_indirect`
+fn matches_set(s: &str) -> bool {
+    is_id_indirect(s)
+}
+
+fn is_id_indirect(s: &str) -> bool {
+    RegexSet::new(["^[a-z]+$", "^[0-9]+$"]).unwrap().is_match(s)
+}
+
+struct Wrapper;
+
+impl Wrapper {
+    fn check(&self, s: &str) -> bool {
+        Regex::new(PATTERN).unwrap().is_match(s)
+    }
+}
+
+fn cached(s: &str) -> bool {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(PATTERN).unwrap()).is_match(s)
+}
+
+fn dynamic(s: &str) -> bool {
+    Regex::new(&format!("^{s}$")).unwrap().is_match(s)
+}
+
+fn not_called_in_loop(s: &str) -> bool {
+    Regex::new(PATTERN).unwrap().is_match(s)
+}
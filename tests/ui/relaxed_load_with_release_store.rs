@@ -0,0 +1,54 @@
+//@run-rustfix
+#![warn(clippy::relaxed_load_with_release_store)]
+
+use std::sync::atomic::Ordering::{self, *};
+use std::sync::atomic::{AtomicBool, AtomicUsize};
+
+static READY: AtomicBool = AtomicBool::new(false);
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+pub struct Shared {
+    done: AtomicBool,
+    hits: AtomicUsize,
+}
+
+pub fn publish(shared: &Shared) {
+    READY.store(true, Ordering::Release);
+    shared.done.store(true, Release);
+    COUNT.store(1, Ordering::Relaxed);
+    shared.hits.store(1, Ordering::SeqCst);
+}
+
+pub fn observe(shared: &Shared) -> bool {
+    let ready = READY.load(Ordering::Relaxed);
+    let done = shared.done.load(Relaxed);
+    // no `Release` stores
+    let count = COUNT.load(Ordering::Relaxed);
+    let hits = shared.hits.load(Ordering::Relaxed);
+    // synchronizes
+    let ready_acquire = READY.load(Ordering::Acquire);
+    #[allow(clippy::relaxed_load_with_release_store)]
+    let allowed = READY.load(Ordering::Relaxed);
+    ready && done && count == hits && ready_acquire && allowed
+}
+
+pub fn observe_fence() -> bool {
+    // the fence synchronizes with the `Release` store
+    let ready = READY.load(Ordering::Relaxed);
+    if ready {
+        std::sync::atomic::fence(Ordering::Acquire);
+    }
+    ready
+}
+
+pub fn observe_fence_before() -> bool {
+    // the fence has to come after the load
+    std::sync::atomic::fence(Acquire);
+    READY.load(Relaxed)
+}
+
+pub fn observe_shared(shared: &std::sync::Arc<Shared>) -> bool {
+    shared.done.load(Relaxed)
+}
+
+fn main() {}
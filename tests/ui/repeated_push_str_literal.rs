@@ -0,0 +1,46 @@
+//@run-rustfix
+#![warn(clippy::repeated_push_str_literal)]
+#![allow(clippy::single_char_add_str, dead_code)]
+
+use std::fmt::Write as _;
+
+struct Buf {
+    s: String,
+}
+
+fn main() {
+    let mut s = String::new();
+    s.push_str("Hello, ");
+    s.push_str("world");
+    s.push_str("!\n");
+
+    s += &format!("{}: {}", 1, 2);
+    s.push_str(&format!("{:?}", [1]));
+
+    let mut buf = Buf { s: String::new() };
+    buf.s.push_str("a");
+    buf.s.push_str("\"b\"");
+    buf.s += &format!["{}", 1];
+
+    let t = &mut s;
+    t.push_str("c");
+    t.push_str("d");
+    *t += &format!("{}", 2);
+
+    // don't lint
+    s.push_str("a");
+    buf.s.push_str("b");
+    s.push_str(r"c");
+    s.push_str("d");
+    let x = "e";
+    s.push_str(x);
+    s.push_str("f");
+    s += &x.repeat(2);
+}
+
+fn get() -> String {
+    let mut s = String::new();
+    s.push_str("a");
+    s.push_str(concat!("b", "c"));
+    s
+}
@@ -0,0 +1,84 @@
+#![warn(clippy::repr_c_enum_with_implicit_discriminants_ffi)]
+#![allow(improper_ctypes, improper_ctypes_definitions)]
+
+#[repr(C)]
+pub enum Status {
+    Ok = 0,
+    Retry,
+    Failed,
+}
+
+#[repr(C)]
+pub enum Mode {
+    Read,
+    Write,
+}
+
+pub enum Kind {
+    Small,
+    Large,
+}
+
+#[repr(C)]
+pub struct Request {
+    pub kind: Kind,
+    pub len: usize,
+}
+
+#[repr(C)]
+pub struct Outer {
+    pub request: *const Request,
+}
+
+extern "C" {
+    fn poll_device() -> Status;
+    fn open(path: *const u8, mode: Mode) -> i32;
+    fn submit(outer: &Outer);
+    // uses are reported once per enum
+    fn reopen(mode: Mode);
+}
+
+pub extern "C" fn callback(status: &[Status; 2]) {}
+
+// ok: explicit discriminants
+#[repr(C)]
+pub enum Explicit {
+    A = 0,
+    B = 1,
+}
+
+// ok: an integer `#[repr]`
+#[repr(u8)]
+pub enum Byte {
+    A = 1,
+    B = 2,
+}
+
+#[repr(C)]
+pub struct Ok {
+    pub byte: Byte,
+}
+
+// ok: not used in an `extern` signature
+#[repr(C)]
+pub enum Unused {
+    A,
+}
+
+pub fn rust(mode: Unused) {}
+
+extern "C" {
+    fn explicit(value: Explicit, ok: Ok);
+}
+
+#[allow(clippy::repr_c_enum_with_implicit_discriminants_ffi)]
+#[repr(C)]
+pub enum Allowed {
+    A,
+}
+
+extern "C" {
+    fn allowed(value: Allowed);
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+//@run-rustfix
+#![warn(clippy::result_ok_then_unwrap_chain)]
+#![allow(dead_code)]
+
+struct NoDebug;
+
+fn res() -> Result<u8, std::num::ParseIntError> {
+    "1".parse()
+}
+
+fn main() {
+    let _ = res().ok().unwrap();
+    let _ = "a".parse::<u8>().err().unwrap();
+    let _ = res().ok().unwrap();
+
+    // don't lint
+    let r: Result<NoDebug, NoDebug> = Ok(NoDebug);
+    let _ = r.ok().unwrap();
+    let r: Result<NoDebug, NoDebug> = Err(NoDebug);
+    let _ = r.err().unwrap();
+    // `ok_expect` covers this
+    #[allow(clippy::ok_expect)]
+    let _ = res().ok().expect("not a number");
+}
@@ -0,0 +1,34 @@
+//@run-rustfix
+#![warn(clippy::retain_with_constant_predicate)]
+#![allow(dead_code)]
+
+use std::collections::{HashMap, VecDeque};
+
+const KEEP: bool = true;
+
+fn main() {
+    let mut v = vec![1, 2, 3];
+    v.retain(|_| true);
+    v.retain(|_| false);
+    v.retain_mut(|_| false);
+    v.retain(|_| KEEP);
+    v.retain(|_| !KEEP);
+
+    let mut map: HashMap<i32, i32> = HashMap::new();
+    map.retain(|_, _| false);
+    let mut deque: VecDeque<i32> = VecDeque::new();
+    deque.retain(|_| false);
+    let mut s = String::from("abc");
+    s.retain(|_| false);
+
+    let _: Vec<_> = v.iter().filter(|_| true).collect();
+    let _: Vec<_> = v.iter().filter(|_| true).collect();
+
+    // ok
+    v.retain(|x| *x > 1);
+    v.retain(|_| {
+        println!("called");
+        true
+    });
+    let _: Vec<_> = v.iter().filter(|_| false).collect();
+}
@@ -18,6 +18,13 @@ fn main() {
     let _ = (1..3)
         .find(|x| [1, 2, 3].contains(x) || *x == 0 || [4, 5, 6].contains(x) || *x == -1)
         .is_none();
+    // Check `find().is_none()` with an annotated or destructured parameter.
+    let _ = v.iter().find(|&x: &&i32| *x < 0).is_none();
+    let _ = v.iter().find(|&&x: &&i32| x < 0).is_none();
+    let _ = v.iter().find(|&x: &_| *x < 0).is_none();
+    let pairs = [(1, 2), (3, 1)];
+    let _ = pairs.iter().find(|&&(a, b): &&(i32, i32)| a < b).is_none();
+    let _ = pairs.iter().find(move |&(a, _)| *a < 0).is_none();
 
     // Check `position().is_none()`, single-line case.
     let _ = v.iter().position(|&x| x < 0).is_none();
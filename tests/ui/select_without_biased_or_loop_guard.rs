@@ -0,0 +1,58 @@
+#![warn(clippy::select_without_biased_or_loop_guard)]
+
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWriteExt, BufReader};
+
+// stands in for `tokio::select!`, which polls all futures until one of them completes
+macro_rules! select {
+    ($($pat:pat = $fut:expr => $handler:expr),+ $(,)?) => {{
+        $(
+            let $pat = $fut.await;
+            $handler;
+        )+
+    }};
+}
+
+async fn shutdown() {}
+
+async fn bad(mut reader: impl AsyncRead + Unpin, mut writer: Vec<u8>) {
+    let mut buf = [0; 4];
+    loop {
+        select! {
+            _ = reader.read_exact(&mut buf) => {},
+            _ = shutdown() => {},
+        }
+    }
+    let mut reader = BufReader::new(reader);
+    let mut line = String::new();
+    while !line.is_empty() {
+        select! {
+            _ = reader.read_line(&mut line) => {},
+            _ = writer.write_all(b"ping") => {},
+        }
+    }
+}
+
+async fn good(mut reader: impl AsyncRead + Unpin) {
+    let mut buf = [0; 4];
+    // not in a loop
+    select! {
+        _ = reader.read_exact(&mut buf) => {},
+        _ = shutdown() => {},
+    }
+    loop {
+        // the read in the handler runs to completion
+        select! {
+            _ = shutdown() => {
+                let _ = reader.read_exact(&mut buf).await;
+            },
+        }
+        // cancel safe
+        select! {
+            _ = reader.read(&mut buf) => {},
+        }
+        // awaited outside of a `select!`
+        let _ = reader.read_exact(&mut buf).await;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+#![warn(clippy::send_non_send_across_spawn)]
+#![allow(clippy::non_send_fields_in_send_ty, clippy::unsound_send_sync_impl_heuristic)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+pub struct Shared {
+    name: Rc<String>,
+}
+
+unsafe impl Send for Shared {}
+
+impl Shared {
+    fn len(&self) -> usize {
+        self.name.len()
+    }
+}
+
+pub struct Cache(Vec<RefCell<u32>>);
+
+unsafe impl Send for Cache {}
+
+pub struct Pointer(*mut u8);
+
+unsafe impl Send for Pointer {}
+
+impl Pointer {
+    fn is_null(&self) -> bool {
+        self.0.is_null()
+    }
+}
+
+pub fn threads(shared: Shared, cache: Cache, pointer: Pointer, fine: Arc<String>) {
+    std::thread::spawn(move || shared.len());
+    std::thread::spawn(move || {
+        let cache = cache;
+        cache.0.len()
+    });
+    // raw pointers are only linted if configured
+    std::thread::spawn(move || pointer.is_null());
+    std::thread::spawn(move || fine.len());
+}
+
+pub async fn tasks(shared: Shared) {
+    tokio::spawn(async move { shared.len() });
+}
+
+fn main() {}
@@ -1,5 +1,6 @@
 //@run-rustfix
 #![warn(clippy::single_char_add_str)]
+#![allow(clippy::to_owned_then_as_ref_argument, clippy::repeated_push_str_literal)]
 
 macro_rules! get_string {
     () => {
@@ -42,4 +43,14 @@ fn main() {
     string.insert_str(Y, r##"'"##);
 
     get_string!().insert_str(1, "?");
+
+    // `char`s converted to strings
+    let c = 'x';
+    let mut string = String::new();
+    string.push_str(&c.to_string());
+    string.push_str(c.to_string().as_str());
+    string.insert_str(0, &c.to_string());
+    string.insert_str(x, 'y'.to_string().as_str());
+    let s = String::from("ab");
+    string.push_str(&s.to_string());
 }
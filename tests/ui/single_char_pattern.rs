@@ -64,4 +64,12 @@ fn main() {
     // Must escape backslash in raw strings when converting to char #8060
     x.split(r#"\"#);
     x.split(r"\");
+
+    // `char`s converted to strings
+    let c = 'a';
+    x.split(&c.to_string());
+    x.replace(c.to_string().as_str(), "b");
+    x.contains(&'b'.to_string());
+    let s = String::from("ab");
+    x.contains(&s.to_string()); // should not warn
 }
@@ -0,0 +1,29 @@
+//@run-rustfix
+#![warn(clippy::sleep_in_async_with_std)]
+#![allow(clippy::manual_async_fn)]
+
+use std::time::Duration;
+
+pub async fn lint() {
+    std::thread::sleep(Duration::from_millis(10));
+    let delay = Duration::from_secs(1);
+    std::thread::sleep(delay);
+}
+
+pub fn lint_block() -> impl std::future::Future<Output = ()> {
+    async {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+pub async fn no_lint() {
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    // not run in the async context
+    let _ = std::thread::spawn(|| std::thread::sleep(Duration::from_millis(10)));
+}
+
+pub fn not_async() {
+    std::thread::sleep(Duration::from_millis(10));
+}
+
+fn main() {}
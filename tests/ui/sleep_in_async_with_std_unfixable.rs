@@ -0,0 +1,10 @@
+#![warn(clippy::sleep_in_async_with_std)]
+
+use std::time::Duration;
+
+pub async fn lint() {
+    // no async runtime is used
+    std::thread::sleep(Duration::from_millis(10));
+}
+
+fn main() {}
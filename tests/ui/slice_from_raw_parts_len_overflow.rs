@@ -0,0 +1,46 @@
+#![warn(clippy::slice_from_raw_parts_len_overflow)]
+#![allow(clippy::missing_safety_doc, clippy::manual_slice_size_calculation)]
+
+use std::mem::size_of;
+use std::slice::{from_raw_parts, from_raw_parts_mut};
+
+pub unsafe fn unchecked_division(bytes: &[u8]) -> &[u32] {
+    let len = bytes.len() / size_of::<u32>();
+    from_raw_parts(bytes.as_ptr().cast(), len)
+}
+
+pub unsafe fn unchecked_division_inline(bytes: &mut [u8]) -> &mut [u16] {
+    from_raw_parts_mut(bytes.as_mut_ptr() as *mut u16, bytes.len() / size_of::<u16>())
+}
+
+pub unsafe fn byte_len(bytes: &[u8]) -> &[u64] {
+    from_raw_parts(bytes.as_ptr() as *const u64, bytes.len())
+}
+
+pub unsafe fn byte_len_local(bytes: &Vec<u16>) -> &[u32] {
+    let ptr = bytes.as_ptr().cast::<u32>();
+    from_raw_parts(ptr, bytes.len())
+}
+
+pub unsafe fn checked_division(bytes: &[u8]) -> Option<&[u32]> {
+    if bytes.len() % size_of::<u32>() != 0 {
+        return None;
+    }
+    let len = bytes.len() / size_of::<u32>();
+    Some(from_raw_parts(bytes.as_ptr().cast(), len))
+}
+
+pub unsafe fn asserted_division(bytes: &[u8]) -> &[u32] {
+    assert_eq!(bytes.len() % 4, 0);
+    from_raw_parts(bytes.as_ptr().cast(), bytes.len() / size_of::<u32>())
+}
+
+pub unsafe fn same_size(words: &[u32]) -> &[i32] {
+    from_raw_parts(words.as_ptr().cast(), words.len())
+}
+
+pub unsafe fn smaller(words: &[u32]) -> &[u8] {
+    from_raw_parts(words.as_ptr().cast(), words.len() * size_of::<u32>())
+}
+
+fn main() {}
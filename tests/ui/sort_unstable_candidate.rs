@@ -0,0 +1,78 @@
+//@run-rustfix
+#![warn(clippy::sort_unstable_candidate)]
+#![allow(
+    clippy::stable_sort_primitive,
+    clippy::unnecessary_sort_by,
+    clippy::useless_vec,
+    dead_code
+)]
+
+use std::cmp::Reverse;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Version(u32, u32);
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum Level {
+    Low,
+    High(u8),
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Wrapper<T>(T);
+
+// equal values can still be told apart by `id`
+#[derive(PartialEq, Eq)]
+struct Task {
+    priority: u8,
+    id: u32,
+}
+
+impl PartialOrd for Task {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Task {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+fn main() {
+    let mut v = vec![3, 1, 2];
+    v.sort();
+    v.sort_by(|a, b| b.cmp(a));
+    v.sort_by_key(|x| Reverse(*x));
+
+    let mut versions = vec![Version(1, 2), Version(1, 0)];
+    versions.sort();
+    let mut levels = [Level::High(1), Level::Low];
+    levels.sort();
+    let mut wrapped = vec![Wrapper((1, 'a')), Wrapper((0, 'b'))];
+    wrapped.sort();
+    let mut strs = vec!["b", "a"];
+    strs.sort();
+
+    let mut indexed: Vec<_> = ["b", "a"].iter().enumerate().collect();
+    indexed.sort_by_key(|&(i, _)| Reverse(i));
+    let mut indexed: Vec<(usize, char)> = "ba".chars().enumerate().collect();
+    indexed.sort_by(|a, b| b.0.cmp(&a.0));
+
+    // don't lint
+    let mut tasks = vec![Task { priority: 1, id: 0 }, Task { priority: 1, id: 1 }];
+    tasks.sort();
+    let mut wrapped_tasks = vec![Wrapper(Task { priority: 1, id: 0 })];
+    wrapped_tasks.sort();
+    let mut strings = vec![String::from("b"), String::from("a")];
+    strings.sort();
+    let mut pairs = vec![(1, "b"), (0, "a")];
+    pairs.sort_by_key(|&(i, _)| i);
+    v.sort_by_key(|x| *x % 2);
+    v.sort_by(|a, b| (a % 2).cmp(&(b % 2)));
+
+    let mut indexed: Vec<_> = ["b", "a"].iter().enumerate().collect();
+    indexed.push((0, &"c"));
+    indexed.sort_by_key(|&(i, _)| i);
+}
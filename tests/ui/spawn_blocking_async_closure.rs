@@ -0,0 +1,29 @@
+#![warn(clippy::spawn_blocking_async_closure)]
+#![allow(clippy::redundant_closure)]
+
+use tokio::runtime::{Handle, Runtime};
+use tokio::task::spawn_blocking;
+
+async fn send() {}
+
+pub fn bad() {
+    tokio::task::spawn_blocking(|| async {
+        send().await;
+    });
+    spawn_blocking(move || async move { send().await });
+    spawn_blocking(send);
+    spawn_blocking(|| send());
+}
+
+pub fn bad_methods(rt: &Runtime, handle: &Handle) {
+    rt.spawn_blocking(|| async { send().await });
+    handle.spawn_blocking(|| async { send().await });
+}
+
+pub fn good(handle: Handle) {
+    spawn_blocking(|| std::thread::sleep(std::time::Duration::from_millis(1)));
+    spawn_blocking(move || handle.block_on(send()));
+    tokio::spawn(async { send().await });
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+//@run-rustfix
+#![warn(clippy::split_whitespace_collect_count)]
+#![allow(
+    clippy::comparison_to_empty,
+    clippy::len_zero,
+    clippy::needless_collect,
+    clippy::single_char_pattern
+)]
+
+fn main() {
+    let s = "a  b\tc";
+    let owned = String::from(s);
+
+    let _: Vec<_> = s.split(' ').filter(|w| !w.is_empty()).collect();
+    let _: Vec<_> = s.split(" ").filter(|w| w.len() > 0).collect();
+    let _: Vec<_> = owned.split(' ').filter(|w| w.len() != 0).collect();
+    let _: Vec<_> = s.split(' ').filter(|w| *w != "").collect();
+    let _: Vec<_> = s.split(char::is_whitespace).filter(|w| !w.is_empty()).collect();
+    let _: Vec<_> = s
+        .split(|c: char| c.is_ascii_whitespace())
+        .filter(|w| !w.is_empty())
+        .collect();
+    let _ = s.split(' ').count();
+
+    // don't lint
+    let _ = s.split(char::is_whitespace).count();
+    let _: Vec<_> = s.split(',').filter(|w| !w.is_empty()).collect();
+    let _: Vec<_> = s.split(' ').filter(|w| w.starts_with('a')).collect();
+    let _: Vec<_> = s.split_whitespace().filter(|w| !w.is_empty()).collect();
+    let _ = s.split(',').count();
+    let _: Vec<_> = s.split(|c: char| c.is_numeric()).filter(|w| !w.is_empty()).collect();
+}
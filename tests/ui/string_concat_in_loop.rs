@@ -0,0 +1,37 @@
+//@run-rustfix
+#![warn(clippy::string_concat_in_loop)]
+#![allow(unused, clippy::assign_op_pattern)]
+
+fn main() {
+    let names = vec!["a", "b", "c"];
+    let owned = String::from("x");
+
+    let mut s = String::new();
+    for name in &names {
+        s = s + name;
+        s = s + &owned;
+    }
+
+    let mut s = String::new();
+    for i in 0..10 {
+        s = s + "--";
+    }
+
+    let mut i = 0;
+    while i < 3 {
+        s = s + names[i];
+        i += 1;
+    }
+
+    // declared in the loop
+    for name in &names {
+        let mut line = String::new();
+        line = line + name;
+    }
+
+    // not concatenation
+    let mut n = 0;
+    for i in 0..10 {
+        n = n + i;
+    }
+}
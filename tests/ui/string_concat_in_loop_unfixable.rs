@@ -0,0 +1,27 @@
+#![warn(clippy::string_concat_in_loop)]
+#![allow(clippy::format_push_string, clippy::repeated_push_str_literal)]
+
+fn main() {
+    let names = vec!["a", "b", "c"];
+
+    let mut s = String::new();
+    for name in &names {
+        s += &format!("{name}, ");
+    }
+
+    loop {
+        s.push_str(&format!("{}", s.len()));
+        if s.len() > 100 {
+            break;
+        }
+    }
+
+    for i in [1, 2, 3] {
+        s += &format!("{i}");
+    }
+
+    // not a `format!`
+    for name in &names {
+        s += name;
+    }
+}
@@ -1,4 +1,5 @@
 //@run-rustfix
+#![allow(clippy::repeated_push_str_literal)]
 
 #[derive(Copy, Clone)]
 struct HasChars;
@@ -0,0 +1,31 @@
+//@run-rustfix
+#![warn(clippy::string_from_utf8_lossy_into_owned_roundtrip)]
+#![allow(clippy::unnecessary_to_owned)]
+
+fn takes_str(_: &str) {}
+
+fn main() {
+    let bytes = vec![b'a', 0xff];
+    let _ = String::from_utf8_lossy(&bytes).to_string();
+
+    let bytes = vec![b'a', 0xff];
+    let _ = String::from_utf8_lossy(&bytes).to_string();
+    let _ = bytes.len();
+
+    let mut bytes = vec![b'a'];
+    for _ in 0..2 {
+        let _ = String::from_utf8_lossy(&bytes).to_string();
+        bytes.clear();
+    }
+
+    let slice: &[u8] = b"abc";
+    let _ = String::from_utf8_lossy(slice).to_string();
+    let _ = std::string::String::from_utf8_lossy(&[b'a']).to_string();
+
+    // `unnecessary_to_owned` is allowed here
+    takes_str(&String::from_utf8_lossy(slice).to_string());
+
+    // ok
+    let _ = String::from_utf8_lossy(slice).into_owned();
+    let _ = String::from_utf8_lossy(slice).len();
+}
@@ -0,0 +1,20 @@
+#![warn(clippy::sync_mutex_in_async_context)]
+
+use tokio::sync::{Mutex, RwLock};
+
+fn bad(m: &Mutex<u32>, rw: &RwLock<u32>) {
+    *m.blocking_lock() += 1;
+    let _ = *rw.blocking_read();
+    *rw.blocking_write() += 1;
+}
+
+async fn good(m: &Mutex<u32>, rw: &RwLock<u32>) {
+    *m.lock().await += 1;
+    let _ = *rw.read().await;
+}
+
+fn good_std(m: &std::sync::Mutex<u32>) {
+    *m.lock().unwrap() += 1;
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+//@run-rustfix
+#![warn(clippy::thread_spawn_join_immediately)]
+#![allow(clippy::redundant_closure, clippy::unnecessary_literal_unwrap)]
+
+use std::thread;
+
+fn compute() -> u32 {
+    1
+}
+
+fn parse() -> Option<u32> {
+    Some(1)
+}
+
+pub fn lint() -> u32 {
+    let a = thread::spawn(|| compute()).join().unwrap();
+    let b = std::thread::spawn(|| {
+        let x = compute();
+        x + 1
+    })
+    .join()
+    .expect("thread panicked");
+    let c = thread::spawn(move || compute()).join().unwrap();
+    a + b + c
+}
+
+pub fn no_lint() -> Option<u32> {
+    // the values are moved to the thread
+    let v = String::from("ab");
+    let a = thread::spawn(move || v.len() as u32).join().unwrap();
+    // the result of `join` is used
+    let b = thread::spawn(|| compute()).join().unwrap_or(0);
+    // runs concurrently
+    let handle = thread::spawn(|| compute());
+    let c = compute() + handle.join().unwrap();
+    // `?` leaves the closure
+    let d = thread::spawn(|| Some(parse()? + 1)).join().unwrap()?;
+    Some(a + b + c + d)
+}
+
+fn main() {}
@@ -0,0 +1,52 @@
+//@run-rustfix
+#![warn(clippy::to_owned_then_as_ref_argument)]
+#![allow(clippy::needless_borrow, clippy::redundant_clone, clippy::unnecessary_to_owned)]
+
+fn take_str(_: &str) {}
+fn take_slice<T>(_: &[T]) {}
+fn take_generic<T: AsRef<str>>(_: T) {}
+
+struct S;
+
+impl S {
+    fn take_str(&self, _: &str) {}
+}
+
+fn main() {
+    unnecessary_to_owned("a");
+    let owned = String::from("a");
+    let owned_ref = &owned;
+    let s = "a";
+    let v = vec![1, 2];
+
+    take_str(&owned.to_string());
+    take_str(&owned.to_owned());
+    take_str(&owned_ref.to_string());
+    take_str(String::from(&owned).as_str());
+    take_str(String::from(s).as_str());
+    take_str(s.to_owned().as_str());
+    take_str(&String::from(s));
+    take_slice(&v.to_vec());
+    take_slice(v.to_owned().as_slice());
+    take_slice(&v[..].to_vec());
+    S.take_str(&owned.to_string());
+    let mut buf = String::new();
+    buf.push_str(&owned.to_string());
+
+    // don't lint
+    let mut other = String::from("b");
+    other.push_str(&other.to_string());
+    other.push_str(other.clone().as_str());
+    take_generic(&owned.to_string());
+    take_str(&1.to_string());
+    take_str(String::from('c').as_str());
+    take_slice(&[1, 2].to_vec());
+}
+
+#[warn(clippy::unnecessary_to_owned)]
+fn unnecessary_to_owned(s: &str) {
+    // linted by `unnecessary_to_owned`
+    take_str(&s.to_string());
+    // not linted by `unnecessary_to_owned`
+    take_str(s.to_string().as_str());
+}
@@ -0,0 +1,55 @@
+#![warn(clippy::transmute_between_repr_rust_types)]
+#![allow(dead_code)]
+
+use std::mem::transmute;
+
+struct Point {
+    x: u16,
+    y: u32,
+}
+
+struct Pair {
+    a: u16,
+    b: u32,
+}
+
+#[repr(C)]
+struct PointC {
+    x: u16,
+    y: u32,
+}
+
+#[repr(C)]
+struct PairC {
+    a: u16,
+    b: u32,
+}
+
+#[repr(transparent)]
+struct Wrapper(u64);
+
+struct Meters(u64);
+
+struct Empty;
+
+struct Generic<T>(T, u8);
+
+struct Tagged(Point, ());
+
+fn bad(p: Point, m: Meters) {
+    let _: Pair = unsafe { transmute(p) };
+    let _: Wrapper = unsafe { transmute(m) };
+    let _: PointC = unsafe { transmute(Pair { a: 1, b: 2 }) };
+    let _: Point = unsafe { transmute(0u32..1) };
+}
+
+fn good(p: PointC, w: Wrapper, g: Generic<u8>) {
+    let _: PairC = unsafe { transmute(p) };
+    let _: Meters = unsafe { transmute(w) };
+    let _: Empty = unsafe { transmute(()) };
+    let _: Generic<i8> = unsafe { transmute(g) };
+    let _: u64 = unsafe { transmute(Meters(1)) };
+    let _: Point = unsafe { transmute(Tagged(Point { x: 1, y: 2 }, ())) };
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+#![warn(clippy::truncating_cast_of_len)]
+#![allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::useless_vec)]
+
+use std::convert::TryInto;
+
+fn main() {
+    let v = vec![1u8, 2, 3];
+    let s = "hello";
+
+    let _ = v.len() as u32;
+    let _ = s.len() as u16;
+    let _ = v.len() as i32;
+    let _: u32 = v.len().try_into().unwrap();
+    let _ = u16::try_from(s.len()).expect("too long");
+
+    // wide enough
+    let _ = v.len() as u64;
+    let _ = v.len() as i64;
+    let _ = v.len() as u128;
+    let _: u64 = v.len().try_into().unwrap();
+    // the length of an array is known
+    let _ = [0; 4].len() as u8;
+    // the error is handled
+    let _: Result<u32, _> = v.len().try_into();
+    let _ = u32::try_from(v.len()).unwrap_or(u32::MAX);
+}
@@ -0,0 +1,65 @@
+#![warn(clippy::unbuffered_file_io_in_loop)]
+#![allow(clippy::unused_io_amount)]
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+
+fn write_bytes(bytes: &[u8]) -> std::io::Result<()> {
+    let mut file = File::create("out.txt")?;
+    for b in bytes {
+        file.write_all(&[*b])?;
+    }
+    bytes.iter().for_each(|b| {
+        file.write(&[*b]).unwrap();
+    });
+    Ok(())
+}
+
+fn read_chunks(file: &mut File) -> std::io::Result<()> {
+    let mut buf = [0; 4];
+    loop {
+        if file.read(&mut buf)? == 0 {
+            break;
+        }
+    }
+    while file.read_exact(&mut buf).is_ok() {}
+    Ok(())
+}
+
+fn read_repeatedly() -> std::io::Result<()> {
+    let mut file = File::open("in.txt")?;
+    let mut s = String::new();
+    for _ in 0..3 {
+        file.read_to_string(&mut s)?;
+    }
+    Ok(())
+}
+
+// should not lint
+fn buffered() -> std::io::Result<()> {
+    let mut reader = BufReader::new(File::open("in.txt")?);
+    let mut writer = BufWriter::new(File::create("out.txt")?);
+    let mut buf = [0; 4];
+    for _ in 0..3 {
+        reader.read_exact(&mut buf)?;
+        writer.write_all(&buf)?;
+    }
+    Ok(())
+}
+
+fn opened_in_loop() -> std::io::Result<()> {
+    for name in ["a.txt", "b.txt"] {
+        let mut file = File::open(name)?;
+        let mut s = String::new();
+        file.read_to_string(&mut s)?;
+    }
+    Ok(())
+}
+
+fn outside_of_loop() -> std::io::Result<()> {
+    let mut file = File::create("out.txt")?;
+    file.write_all(b"hello")?;
+    Ok(())
+}
+
+fn main() {}
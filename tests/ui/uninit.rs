@@ -32,6 +32,20 @@ fn main() {
     // Was a false negative.
     let _: usize = unsafe { MaybeUninit::uninit().assume_init() };
 
+    // Through a variable, an array of `MaybeUninit`s or a transmute.
+    let uninit = MaybeUninit::uninit();
+    let _: usize = unsafe { uninit.assume_init() };
+    let uninits = [MaybeUninit::uninit(); 4];
+    let _: &u8 = unsafe { uninits[0].assume_init() };
+    let _: Box<u8> = unsafe { std::mem::transmute(MaybeUninit::<Box<u8>>::uninit()) };
+    let _: [Option<bool>; 2] = unsafe { std::mem::transmute([MaybeUninit::<Option<bool>>::uninit(); 2]) };
+
+    // This is OK, because the `MaybeUninit` is written first.
+    let mut init = MaybeUninit::uninit();
+    init.write(1usize);
+    let _: usize = unsafe { init.assume_init() };
+    let _: [MaybeUninit<u8>; 4] = unsafe { std::mem::transmute(MaybeUninit::<[u8; 4]>::uninit()) };
+
     polymorphic::<()>();
     polymorphic_maybe_uninit_array::<10>();
     polymorphic_maybe_uninit::<u8>();
@@ -0,0 +1,72 @@
+#![warn(clippy::union_field_access_without_cfg_layout)]
+
+#[derive(Clone, Copy)]
+pub union Bits {
+    byte: u8,
+    word: u32,
+    float: f32,
+    pair: (u16, u16),
+}
+
+pub fn init() -> u32 {
+    let bits = Bits { byte: 1 };
+    unsafe { bits.word }
+}
+
+pub fn smaller() -> u8 {
+    let mut bits = Bits { byte: 0 };
+    bits.word = 0x1234_5678;
+    unsafe { bits.byte }
+}
+
+pub fn reassigned() -> u32 {
+    let mut bits = Bits { word: 0 };
+    bits = Bits { byte: 2 };
+    unsafe { bits.word + 1 }
+}
+
+pub fn compound() -> (u16, u16) {
+    let mut bits = Bits { byte: 0 };
+    unsafe {
+        bits.byte += 1;
+        bits.pair
+    }
+}
+
+// ok: the same size
+pub fn same_size() -> u32 {
+    let mut bits = Bits { float: 1.0 };
+    let word = unsafe { bits.word };
+    bits.pair = (1, 2);
+    word + unsafe { bits.word }
+}
+
+// ok: the field written last is read
+pub fn same_field() -> u8 {
+    let mut bits = Bits { word: 0 };
+    bits.byte = 1;
+    unsafe { bits.byte }
+}
+
+// ok: the union is written in an unknown way
+pub fn borrowed(write: fn(&mut Bits)) -> u32 {
+    let mut bits = Bits { byte: 0 };
+    write(&mut bits);
+    unsafe { bits.word }
+}
+
+pub fn field_borrowed(write: fn(&mut u32)) -> u32 {
+    let mut bits = Bits { byte: 0 };
+    unsafe {
+        write(&mut bits.word);
+        bits.word
+    }
+}
+
+pub fn partially_written() -> u32 {
+    let mut bits = Bits { byte: 0 };
+    bits.pair.0 = 1;
+    unsafe { bits.word }
+}
+
+fn main() {}
@@ -1,6 +1,6 @@
 //@run-rustfix
 
-#![allow(unused)]
+#![allow(unused, clippy::box_collection_temporary)]
 #![warn(clippy::unnecessary_struct_initialization)]
 
 struct S {
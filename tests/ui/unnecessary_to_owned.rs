@@ -1,6 +1,6 @@
 //@run-rustfix
 
-#![allow(clippy::needless_borrow, clippy::ptr_arg)]
+#![allow(clippy::needless_borrow, clippy::ptr_arg, clippy::to_owned_then_as_ref_argument)]
 #![warn(clippy::unnecessary_to_owned, clippy::redundant_clone)]
 
 use std::borrow::Cow;
@@ -0,0 +1,42 @@
+#![warn(clippy::unpolled_future_created)]
+#![allow(clippy::manual_async_fn, clippy::unused_async)]
+
+use std::future::Future;
+
+async fn fetch() -> u32 {
+    1
+}
+
+fn fetch_later() -> impl Future<Output = u32> {
+    async { 2 }
+}
+
+pub struct Client;
+
+impl Client {
+    async fn send(&self) {}
+}
+
+pub fn lint(client: &Client) {
+    drop(fetch());
+    std::mem::drop(fetch_later());
+    let _fetched = fetch();
+    let _sent = client.send();
+}
+
+pub async fn no_lint(client: &Client) {
+    let _fetched = fetch().await;
+    // used afterwards
+    let _fut = fetch();
+    let _ = _fut.await;
+    // not freshly created
+    let fut = client.send();
+    drop(fut);
+    // the handle of a spawned task
+    let _handle = std::thread::spawn(|| {});
+    // `let_underscore_future` lints this
+    #[allow(clippy::let_underscore_future)]
+    let _ = fetch();
+}
+
+fn main() {}
@@ -0,0 +1,61 @@
+#![warn(clippy::unsound_send_sync_impl_heuristic)]
+#![allow(clippy::non_send_fields_in_send_ty, dead_code)]
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+struct WithRc {
+    shared: Rc<String>,
+    len: usize,
+}
+
+unsafe impl Send for WithRc {}
+
+struct WithRefCell {
+    cache: RefCell<Vec<u8>>,
+}
+
+// `RefCell` is `Send`, but not `Sync`
+unsafe impl Send for WithRefCell {}
+unsafe impl Sync for WithRefCell {}
+
+struct WithPtr<T> {
+    ptr: *mut T,
+    value: T,
+}
+
+unsafe impl<T> Send for WithPtr<T> {}
+
+struct Inner {
+    counter: Cell<u32>,
+}
+
+struct Outer {
+    inner: Vec<Inner>,
+    shared: Arc<RefCell<u8>>,
+}
+
+unsafe impl Sync for Outer {}
+unsafe impl Send for Outer {}
+
+struct Documented<T> {
+    shared: Rc<String>,
+    value: T,
+}
+
+// SAFETY: `shared` is never cloned, so its only reference is sent along with the value.
+unsafe impl<T: Send> Send for Documented<T> {}
+
+// SAFETY: sending this is fine.
+unsafe impl<T> Sync for Documented<T> {}
+
+struct ThreadSafe<T> {
+    value: Mutex<T>,
+    ptr: *const u8,
+}
+
+unsafe impl<T: Send> Send for ThreadSafe<T> {}
+unsafe impl<T: Send> Sync for ThreadSafe<T> {}
+
+fn main() {}
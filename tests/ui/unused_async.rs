@@ -62,6 +62,11 @@ macro_rules! async_trait_impl {
 }
 async_trait_impl!();
 
+// changing the signature would break other crates
+pub async fn exported() -> i32 {
+    4
+}
+
 fn main() {
     foo();
     bar();
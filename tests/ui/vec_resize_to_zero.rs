@@ -11,9 +11,17 @@ fn main() {
 
     let mut v = vec!["foo", "bar", "baz"];
 
-    // applicable here, but only implemented for integer literals for now
+    // applicable here
     v.resize(0, "bar");
 
     // not applicable
-    v.resize(2, "bar")
+    v.resize(2, "bar");
+
+    let mut strings = vec![String::new()];
+    strings.resize(0, String::new());
+    strings.resize(0, default_value());
+}
+
+fn default_value() -> String {
+    String::new()
 }
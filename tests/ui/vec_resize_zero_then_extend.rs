@@ -0,0 +1,15 @@
+//@run-rustfix
+#![warn(clippy::vec_resize_zero_then_extend)]
+
+fn main() {
+    let mut v: Vec<i32> = Vec::with_capacity(0);
+    let _ = Vec::<u8>::with_capacity(0);
+    v.push(1);
+    v.truncate(0);
+
+    // ok
+    let _: Vec<i32> = Vec::with_capacity(1);
+    v.truncate(1);
+    let mut s = String::from("abc");
+    s.truncate(0);
+}
@@ -0,0 +1,37 @@
+//@run-rustfix
+#![warn(clippy::volatile_ordinary_read_write)]
+#![allow(dead_code)]
+
+use std::ptr::{self, addr_of_mut};
+
+const STATUS: *mut u32 = 0x4000_0000 as *mut u32;
+static mut TICKS: u32 = 0;
+static mut UNRELATED: u32 = 0;
+
+unsafe fn volatile() {
+    while STATUS.read_volatile() & 1 == 0 {}
+    ptr::write_volatile(addr_of_mut!(TICKS), 0);
+    (0x4000_0004 as *mut u16).write_volatile(1);
+}
+
+unsafe fn ordinary() {
+    *STATUS = 0;
+    let _status = *STATUS;
+    TICKS = 1;
+    let _ticks = TICKS;
+    let control = 0x4000_0004 as *mut u16;
+    *control = 2;
+}
+
+// ok: these places are never accessed with volatile operations
+unsafe fn unrelated() {
+    UNRELATED = 1;
+    *(0x5000_0000 as *mut u32) = 1;
+}
+
+#[allow(clippy::volatile_ordinary_read_write)]
+unsafe fn allowed() {
+    TICKS = 2;
+}
+
+fn main() {}
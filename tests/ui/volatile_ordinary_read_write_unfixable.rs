@@ -0,0 +1,31 @@
+#![warn(clippy::volatile_ordinary_read_write)]
+#![allow(dead_code)]
+
+use std::ptr::{addr_of, addr_of_mut};
+
+static mut TICKS: u32 = 0;
+static mut BUFFER: [u8; 4] = [0; 4];
+
+#[repr(C)]
+pub struct Registers {
+    pub control: u32,
+    pub status: u32,
+}
+
+const REGISTERS: *mut Registers = 0x4000_0000 as *mut Registers;
+
+unsafe fn volatile() {
+    addr_of_mut!(TICKS).write_volatile(0);
+    let _ = addr_of!(BUFFER).read_volatile();
+    let _ = REGISTERS.read_volatile();
+}
+
+unsafe fn ordinary() {
+    TICKS += 1;
+    BUFFER[0] = 1;
+    (*REGISTERS).control = 1;
+    // ok: only borrows `BUFFER`
+    let _len = BUFFER.len();
+}
+
+fn main() {}